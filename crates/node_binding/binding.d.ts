@@ -265,6 +265,7 @@ export declare class JsStats {
   hasWarnings(): boolean
   hasErrors(): boolean
   getLogging(acceptedTypes: number): Array<JsStatsLogging>
+  getCacheMisses(): Array<JsStatsCacheMiss>
 }
 
 export declare class RawExternalItemFnCtx {
@@ -335,6 +336,7 @@ export declare enum BuiltinPluginName {
   RuntimePlugin = 'RuntimePlugin',
   JsonModulesPlugin = 'JsonModulesPlugin',
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
+  InferPureFunctionsPlugin = 'InferPureFunctionsPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
@@ -893,6 +895,13 @@ export interface JsStatsAssetsByChunkName {
   files: Array<string>
 }
 
+export interface JsStatsCacheMiss {
+  moduleIdentifier: string
+  moduleName: string
+  reason: string
+  path?: string
+}
+
 export interface JsStatsChildGroupChildAssets {
   preload?: Array<string>
   prefetch?: Array<string>
@@ -1021,10 +1030,23 @@ export interface JsStatsModuleCommonAttributes {
   reasons?: Array<JsStatsModuleReason>
   providedExports?: Array<string>
   optimizationBailout?: Array<string>
+  inclusionReason?: JsStatsModuleInclusionReason
   depth?: number
   source?: string | Buffer
 }
 
+export interface JsStatsModuleImport {
+  originModuleDescriptor?: JsModuleDescriptor
+  moduleDescriptor: JsModuleDescriptor
+  userRequest?: string
+  loc?: string
+}
+
+export interface JsStatsModuleInclusionReason {
+  importChain: Array<JsStatsModuleImport>
+  sideEffects: JsStatsModuleSideEffects
+}
+
 export interface JsStatsModuleIssuer {
   moduleDescriptor: JsModuleDescriptor
 }
@@ -1042,6 +1064,15 @@ export interface JsStatsModuleReason {
   userRequest?: string
 }
 
+export interface JsStatsModuleSideEffects {
+  type: "moduleRule" | "packageJson" | "notAnalyzed" | "statement" | "dependency" | "none"
+  sideEffects: boolean
+  descriptionFile?: string
+  statementType?: string
+  loc?: string
+  moduleDescriptor?: JsModuleDescriptor
+}
+
 export interface JsStatsModuleTrace {
   origin: JsStatsModuleTraceModule
   module: JsStatsModuleTraceModule
@@ -1073,6 +1104,7 @@ export interface JsStatsOptions {
   moduleAssets: boolean
   nestedModules: boolean
   optimizationBailout: boolean
+  inclusionReason: boolean
   providedExports: boolean
   reasons: boolean
   source: boolean
@@ -1401,6 +1433,7 @@ export interface RawExperimentSnapshotOptions {
   immutablePaths: Array<string|RegExp>
   unmanagedPaths: Array<string|RegExp>
   managedPaths: Array<string|RegExp>
+  hashPaths: Array<string|RegExp>
 }
 
 export interface RawExposeOptions {
@@ -1753,6 +1786,8 @@ export interface RawOptimizationOptions {
   mangleExports: boolean | string
   concatenateModules: boolean
   avoidEntryIife: boolean
+  inferPureFunctions: boolean
+  inlineExports: boolean
 }
 
 export interface RawOptions {
@@ -1865,6 +1900,13 @@ export interface RawRemoteOptions {
   shareScope: string
 }
 
+export interface RawRemoteStoreOptions {
+  url: string
+  push: boolean
+  connectTimeout: number
+  readTimeout: number
+}
+
 export interface RawResolveOptions {
   preferRelative?: boolean
   preferAbsolute?: boolean
@@ -2012,6 +2054,9 @@ export interface RawStatsOptions {
 export interface RawStorageOptions {
   type: "filesystem"
   directory: string
+  remote?: RawRemoteStoreOptions
+  compression?: "deflate"
+  maxSize?: number
 }
 
 export interface RawSwcJsMinimizerOptions {
@@ -51,8 +51,9 @@ use rspack_plugin_html::HtmlRspackPlugin;
 use rspack_plugin_ignore::IgnorePlugin;
 use rspack_plugin_javascript::{
   api_plugin::APIPlugin, define_plugin::DefinePlugin, provide_plugin::ProvidePlugin,
-  FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, InferAsyncModulesPlugin, JsPlugin,
-  MangleExportsPlugin, ModuleConcatenationPlugin, SideEffectsFlagPlugin,
+  FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, InferAsyncModulesPlugin,
+  InferPureFunctionsPlugin, JsPlugin, MangleExportsPlugin, ModuleConcatenationPlugin,
+  SideEffectsFlagPlugin,
 };
 use rspack_plugin_json::JsonPlugin;
 use rspack_plugin_library::enable_library_plugin;
@@ -161,6 +162,7 @@ pub enum BuiltinPluginName {
   RuntimePlugin,
   JsonModulesPlugin,
   InferAsyncModulesPlugin,
+  InferPureFunctionsPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
   AssetModulesPlugin,
@@ -398,6 +400,9 @@ impl BuiltinPlugin {
       BuiltinPluginName::InferAsyncModulesPlugin => {
         plugins.push(InferAsyncModulesPlugin::default().boxed())
       }
+      BuiltinPluginName::InferPureFunctionsPlugin => {
+        plugins.push(InferPureFunctionsPlugin::default().boxed())
+      }
       BuiltinPluginName::JavascriptModulesPlugin => plugins.push(JsPlugin::default().boxed()),
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::default().boxed())
@@ -1,5 +1,5 @@
 use napi_derive::napi;
-use rspack_core::cache::persistent::storage::StorageOptions;
+use rspack_core::cache::persistent::storage::{RemoteStoreOptions, StorageOptions};
 
 #[derive(Debug, Default)]
 #[napi(object)]
@@ -7,6 +7,19 @@ pub struct RawStorageOptions {
   #[napi(ts_type = r#""filesystem""#)]
   pub r#type: String,
   pub directory: String,
+  pub remote: Option<RawRemoteStoreOptions>,
+  #[napi(ts_type = r#""deflate""#)]
+  pub compression: Option<String>,
+  pub max_size: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+#[napi(object)]
+pub struct RawRemoteStoreOptions {
+  pub url: String,
+  pub push: bool,
+  pub connect_timeout: u32,
+  pub read_timeout: u32,
 }
 
 impl From<RawStorageOptions> for StorageOptions {
@@ -14,8 +27,26 @@ impl From<RawStorageOptions> for StorageOptions {
     match value.r#type.as_str() {
       "filesystem" => StorageOptions::FileSystem {
         directory: value.directory.into(),
+        remote: value.remote.map(Into::into),
+        compression: match value.compression.as_deref() {
+          None => false,
+          Some("deflate") => true,
+          Some(s) => panic!("unsupported storage compression {s}"),
+        },
+        max_size: value.max_size.map(|size| size as u64),
       },
       s => panic!("unsupported storage type {s}"),
     }
   }
 }
+
+impl From<RawRemoteStoreOptions> for RemoteStoreOptions {
+  fn from(value: RawRemoteStoreOptions) -> Self {
+    Self {
+      url: value.url,
+      push: value.push,
+      connect_timeout: value.connect_timeout.into(),
+      read_timeout: value.read_timeout.into(),
+    }
+  }
+}
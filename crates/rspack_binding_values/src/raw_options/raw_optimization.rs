@@ -17,6 +17,8 @@ pub struct RawOptimizationOptions {
   pub mangle_exports: WithBool<String>,
   pub concatenate_modules: bool,
   pub avoid_entry_iife: bool,
+  pub infer_pure_functions: bool,
+  pub inline_exports: bool,
 }
 
 macro_rules! impl_from_with_bool {
@@ -50,6 +52,8 @@ impl TryFrom<RawOptimizationOptions> for Optimization {
       mangle_exports: value.mangle_exports.into(),
       concatenate_modules: value.concatenate_modules,
       avoid_entry_iife: value.avoid_entry_iife,
+      infer_pure_functions: value.infer_pure_functions,
+      inline_exports: value.inline_exports,
     })
   }
 }
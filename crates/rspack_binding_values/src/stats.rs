@@ -187,6 +187,25 @@ impl From<rspack_core::StatsErrorModuleTraceModule> for JsStatsModuleTraceModule
   }
 }
 
+#[napi(object, object_from_js = false)]
+pub struct JsStatsCacheMiss {
+  pub module_identifier: String,
+  pub module_name: String,
+  pub reason: String,
+  pub path: Option<String>,
+}
+
+impl From<rspack_core::StatsCacheMiss> for JsStatsCacheMiss {
+  fn from(stats: rspack_core::StatsCacheMiss) -> Self {
+    Self {
+      module_identifier: stats.module_identifier.to_string(),
+      module_name: stats.module_name,
+      reason: stats.reason,
+      path: stats.path,
+    }
+  }
+}
+
 #[napi(object, object_from_js = false)]
 pub struct JsStatsLogging {
   pub name: String,
@@ -441,6 +460,9 @@ pub struct JsStatsModuleCommonAttributes {
   // optimizationBailout
   pub optimization_bailout: Option<Vec<String>>,
 
+  // inclusionReason
+  pub inclusion_reason: Option<JsStatsModuleInclusionReason>,
+
   // depth
   pub depth: Option<u32>,
 
@@ -576,6 +598,9 @@ impl TryFrom<StatsModule<'_>> for JsStatsModule {
         .provided_exports
         .map(|exports| exports.into_iter().map(|i| i.to_string()).collect()),
       optimization_bailout: stats.optimization_bailout.map(|bailout| bailout.to_vec()),
+      inclusion_reason: stats
+        .inclusion_reason
+        .map(JsStatsModuleInclusionReason::from),
       pre_order_index: stats.pre_order_index,
       post_order_index: stats.post_order_index,
       cached: stats.cached,
@@ -701,6 +726,92 @@ impl From<rspack_core::StatsModuleReason<'_>> for JsStatsModuleReason {
   }
 }
 
+#[napi(object, object_from_js = false)]
+pub struct JsStatsModuleInclusionReason {
+  pub import_chain: Vec<JsStatsModuleImport>,
+  pub side_effects: JsStatsModuleSideEffects,
+}
+
+impl From<rspack_core::StatsModuleInclusionReason<'_>> for JsStatsModuleInclusionReason {
+  fn from(stats: rspack_core::StatsModuleInclusionReason) -> Self {
+    Self {
+      import_chain: stats
+        .import_chain
+        .into_iter()
+        .map(JsStatsModuleImport::from)
+        .collect(),
+      side_effects: stats.side_effects.into(),
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsModuleImport {
+  #[napi(ts_type = "JsModuleDescriptor")]
+  pub origin_module_descriptor: Option<JsModuleDescriptorWrapper>,
+  #[napi(ts_type = "JsModuleDescriptor")]
+  pub module_descriptor: JsModuleDescriptorWrapper,
+  pub user_request: Option<String>,
+  pub loc: Option<String>,
+}
+
+impl From<rspack_core::StatsModuleImport<'_>> for JsStatsModuleImport {
+  fn from(stats: rspack_core::StatsModuleImport) -> Self {
+    Self {
+      origin_module_descriptor: stats.origin_module_identifier.map(|identifier| {
+        JsModuleDescriptor {
+          identifier: identifier.into(),
+          name: stats.origin_module_name.unwrap_or_default().into_owned(),
+          id: stats.origin_module_id.map(|s| s.to_string()),
+        }
+        .into()
+      }),
+      module_descriptor: JsModuleDescriptor {
+        identifier: stats.module_identifier.into(),
+        name: stats.module_name.into_owned(),
+        id: stats.module_id.map(|s| s.to_string()),
+      }
+      .into(),
+      user_request: stats.user_request,
+      loc: stats.loc,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsModuleSideEffects {
+  #[napi(
+    ts_type = r#""moduleRule" | "packageJson" | "notAnalyzed" | "statement" | "dependency" | "none""#
+  )]
+  pub r#type: &'static str,
+  pub side_effects: bool,
+  pub description_file: Option<String>,
+  pub statement_type: Option<String>,
+  pub loc: Option<String>,
+  #[napi(ts_type = "JsModuleDescriptor")]
+  pub module_descriptor: Option<JsModuleDescriptorWrapper>,
+}
+
+impl From<rspack_core::StatsModuleSideEffects<'_>> for JsStatsModuleSideEffects {
+  fn from(stats: rspack_core::StatsModuleSideEffects) -> Self {
+    Self {
+      r#type: stats.r#type,
+      side_effects: stats.side_effects,
+      description_file: stats.description_file,
+      statement_type: stats.statement_type,
+      loc: stats.loc,
+      module_descriptor: stats.module_identifier.map(|identifier| {
+        JsModuleDescriptor {
+          identifier: identifier.into(),
+          name: stats.module_name.unwrap_or_default().into_owned(),
+          id: stats.module_id.map(|s| s.to_string()),
+        }
+        .into()
+      }),
+    }
+  }
+}
+
 #[napi(object, object_from_js = false)]
 pub struct JsOriginRecord {
   #[napi(ts_type = "JsModuleDescriptor")]
@@ -928,6 +1039,7 @@ pub struct JsStatsOptions {
   pub module_assets: bool,
   pub nested_modules: bool,
   pub optimization_bailout: bool,
+  pub inclusion_reason: bool,
   pub provided_exports: bool,
   pub reasons: bool,
   pub source: bool,
@@ -960,6 +1072,7 @@ impl From<JsStatsOptions> for ExtendedStatsOptions {
       module_assets: value.module_assets,
       nested_modules: value.nested_modules,
       optimization_bailout: value.optimization_bailout,
+      inclusion_reason: value.inclusion_reason,
       provided_exports: value.provided_exports,
       reasons: value.reasons,
       source: value.source,
@@ -1172,6 +1285,16 @@ impl JsStats {
       .collect()
   }
 
+  #[napi]
+  pub fn get_cache_misses(&self) -> Vec<JsStatsCacheMiss> {
+    self
+      .inner
+      .get_cache_misses()
+      .into_iter()
+      .map(Into::into)
+      .collect()
+  }
+
   fn hash(&self) -> Option<String> {
     self.inner.get_hash().map(|hash| hash.to_string())
   }
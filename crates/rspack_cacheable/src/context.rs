@@ -1,4 +1,4 @@
-use std::{any::Any, ptr::NonNull};
+use std::{any::Any, path::Path, ptr::NonNull};
 
 use rkyv::{
   de::{ErasedPtr, Pooling, PoolingState},
@@ -10,14 +10,30 @@ use crate::{DeserializeError, SerializeError};
 const CONTEXT_ADDR: usize = 0;
 unsafe fn default_drop(_: ErasedPtr) {}
 
+/// Context of serialization and deserialization
+pub trait CacheableContext: Any {
+  /// Absolute paths under project root are stored with a placeholder and
+  /// rebased to the project root of the reading side, return `None` to keep
+  /// absolute paths as is.
+  fn project_root(&self) -> Option<&Path> {
+    None
+  }
+}
+
+impl CacheableContext for () {}
+
 /// A context wrapper that provides shared context methods
 pub struct ContextGuard<'a> {
   context: &'a dyn Any,
+  project_root: Option<&'a str>,
 }
 
 impl<'a> ContextGuard<'a> {
-  pub fn new(context: &'a dyn Any) -> Self {
-    Self { context }
+  pub fn new<C: CacheableContext>(context: &'a C) -> Self {
+    Self {
+      context,
+      project_root: context.project_root().and_then(|root| root.to_str()),
+    }
   }
 
   pub fn add_to_sharing<S: Sharing<SerializeError> + ?Sized>(
@@ -28,18 +44,21 @@ impl<'a> ContextGuard<'a> {
     sharing.finish_sharing(CONTEXT_ADDR, self as *const _ as usize)
   }
 
-  pub fn sharing_context<S: Sharing<SerializeError> + ?Sized>(
+  pub fn sharing_guard<S: Sharing<SerializeError> + ?Sized>(
     sharing: &'a mut S,
-  ) -> Result<&'a dyn Any, SerializeError> {
+  ) -> Result<&'a Self, SerializeError> {
     match sharing.start_sharing(CONTEXT_ADDR) {
-      SharingState::Finished(addr) => {
-        let guard: &Self = unsafe { &*(addr as *const Self) };
-        Ok(guard.context)
-      }
+      SharingState::Finished(addr) => Ok(unsafe { &*(addr as *const Self) }),
       _ => Err(SerializeError::NoContext),
     }
   }
 
+  pub fn sharing_context<S: Sharing<SerializeError> + ?Sized>(
+    sharing: &'a mut S,
+  ) -> Result<&'a dyn Any, SerializeError> {
+    Ok(Self::sharing_guard(sharing)?.context)
+  }
+
   pub fn add_to_pooling<P: Pooling<DeserializeError> + ?Sized>(
     &self,
     pooling: &mut P,
@@ -51,15 +70,22 @@ impl<'a> ContextGuard<'a> {
     }
   }
 
-  pub fn pooling_context<P: Pooling<DeserializeError> + ?Sized>(
+  pub fn pooling_guard<P: Pooling<DeserializeError> + ?Sized>(
     pooling: &'a mut P,
-  ) -> Result<&'a dyn Any, DeserializeError> {
+  ) -> Result<&'a Self, DeserializeError> {
     match pooling.start_pooling(CONTEXT_ADDR) {
-      PoolingState::Finished(ptr) => {
-        let guard: &Self = unsafe { &*(ptr.data_address() as *const Self) };
-        Ok(guard.context)
-      }
+      PoolingState::Finished(ptr) => Ok(unsafe { &*(ptr.data_address() as *const Self) }),
       _ => Err(DeserializeError::NoContext),
     }
   }
+
+  pub fn pooling_context<P: Pooling<DeserializeError> + ?Sized>(
+    pooling: &'a mut P,
+  ) -> Result<&'a dyn Any, DeserializeError> {
+    Ok(Self::pooling_guard(pooling)?.context)
+  }
+
+  pub fn project_root(&self) -> Option<&'a str> {
+    self.project_root
+  }
 }
@@ -7,6 +7,7 @@ pub mod with;
 
 mod context;
 mod deserialize;
+mod portable;
 mod serialize;
 
 #[doc(hidden)]
@@ -17,5 +18,7 @@ pub mod __private {
   pub extern crate rkyv;
 }
 
+pub use context::CacheableContext;
 pub use deserialize::{from_bytes, DeserializeError, Deserializer, Validator};
+pub use portable::{from_portable, to_portable};
 pub use serialize::{to_bytes, SerializeError, Serializer};
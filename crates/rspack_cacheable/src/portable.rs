@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+
+use rkyv::{
+  de::Pooling,
+  rancor::Fallible,
+  ser::{Sharing, Writer},
+  string::{ArchivedString, StringResolver},
+  Place,
+};
+
+use crate::{context::ContextGuard, DeserializeError, SerializeError};
+
+/// Placeholder of project root in serialized data
+const PROJECT_ROOT: &str = "<PROJECT_ROOT>";
+
+fn normalize_root(root: &str) -> Option<&str> {
+  let root = root.trim_end_matches(['/', '\\']);
+  // rewriting the file system root would touch every absolute path
+  if root.is_empty() || root.ends_with(':') {
+    None
+  } else {
+    Some(root)
+  }
+}
+
+fn is_path_name_char(c: char) -> bool {
+  c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | '\\')
+}
+
+/// Literal placeholders in the data are stored with one more backslash after `<`, so they are not
+/// mixed up with the placeholders of project root, e.g. `<\PROJECT_ROOT>` for `<PROJECT_ROOT>`.
+const PLACEHOLDER_NAME: &str = "PROJECT_ROOT>";
+
+/// Returns the number of backslashes of the placeholder starting at `start`, which is a `<`.
+fn placeholder_escapes(value: &str, start: usize) -> Option<usize> {
+  let rest = &value[start + 1..];
+  let escapes = rest.len() - rest.trim_start_matches('\\').len();
+  rest[escapes..]
+    .starts_with(PLACEHOLDER_NAME)
+    .then_some(escapes)
+}
+
+/// Replace the absolute paths under `root` in `value` with a placeholder
+///
+/// Paths may be embedded in strings such as module identifiers,
+/// so all the occurrences of `root` which are not a part of another path are replaced.
+pub fn to_portable<'a>(value: &'a str, root: &str) -> Cow<'a, str> {
+  let Some(root) = normalize_root(root) else {
+    return Cow::Borrowed(value);
+  };
+
+  let mut result = String::new();
+  let mut last_end = 0;
+  let mut pos = 0;
+  while pos < value.len() {
+    if value[pos..].starts_with(root) {
+      let end = pos + root.len();
+      let prev_is_boundary = value[..pos]
+        .chars()
+        .next_back()
+        .is_none_or(|c| !is_path_name_char(c));
+      let next_is_boundary = value[end..]
+        .chars()
+        .next()
+        .is_none_or(|c| matches!(c, '/' | '\\') || !is_path_name_char(c));
+      if prev_is_boundary && next_is_boundary {
+        result.push_str(&value[last_end..pos]);
+        result.push_str(PROJECT_ROOT);
+        last_end = end;
+        pos = end;
+        continue;
+      }
+    }
+    if value.as_bytes()[pos] == b'<' && placeholder_escapes(value, pos).is_some() {
+      // escape the literal placeholder
+      result.push_str(&value[last_end..=pos]);
+      result.push('\\');
+      last_end = pos + 1;
+    }
+    pos += value[pos..].chars().next().map_or(1, char::len_utf8);
+  }
+
+  if last_end == 0 {
+    Cow::Borrowed(value)
+  } else {
+    result.push_str(&value[last_end..]);
+    Cow::Owned(result)
+  }
+}
+
+/// Rebase the placeholders in `value` to `root`
+pub fn from_portable<'a>(value: &'a str, root: &str) -> Cow<'a, str> {
+  let Some(root) = normalize_root(root) else {
+    return Cow::Borrowed(value);
+  };
+
+  let mut result = String::new();
+  let mut last_end = 0;
+  for (start, _) in value.match_indices('<') {
+    let Some(escapes) = placeholder_escapes(value, start) else {
+      continue;
+    };
+    result.push_str(&value[last_end..start]);
+    if escapes == 0 {
+      result.push_str(root);
+      last_end = start + PROJECT_ROOT.len();
+    } else {
+      // unescape the literal placeholder
+      result.push('<');
+      last_end = start + 2;
+    }
+  }
+
+  if last_end == 0 {
+    Cow::Borrowed(value)
+  } else {
+    result.push_str(&value[last_end..]);
+    Cow::Owned(result)
+  }
+}
+
+/// Resolver of the strings which may contain absolute paths
+pub struct PortableStringResolver {
+  inner: StringResolver,
+  /// The rewritten value if the string contains project root
+  value: Option<String>,
+}
+
+impl PortableStringResolver {
+  pub fn serialize<S>(value: &str, serializer: &mut S) -> Result<Self, SerializeError>
+  where
+    S: Fallible<Error = SerializeError> + Writer + Sharing + ?Sized,
+  {
+    let portable = ContextGuard::sharing_guard(serializer)?
+      .project_root()
+      .and_then(|root| match to_portable(value, root) {
+        Cow::Owned(portable) => Some(portable),
+        Cow::Borrowed(_) => None,
+      });
+    Ok(Self {
+      inner: ArchivedString::serialize_from_str(portable.as_deref().unwrap_or(value), serializer)?,
+      value: portable,
+    })
+  }
+
+  pub fn resolve(self, value: &str, out: Place<ArchivedString>) {
+    ArchivedString::resolve_from_str(self.value.as_deref().unwrap_or(value), self.inner, out);
+  }
+}
+
+/// Read the string and rebase the paths in it to current project root
+pub fn deserialize_portable<'a, D>(
+  field: &'a ArchivedString,
+  deserializer: &mut D,
+) -> Result<Cow<'a, str>, DeserializeError>
+where
+  D: Fallible<Error = DeserializeError> + Pooling + ?Sized,
+{
+  Ok(
+    match ContextGuard::pooling_guard(deserializer)?.project_root() {
+      Some(root) => from_portable(field.as_str(), root),
+      None => Cow::Borrowed(field.as_str()),
+    },
+  )
+}
@@ -0,0 +1,55 @@
+use rkyv::{
+  de::Pooling,
+  rancor::Fallible,
+  ser::{Sharing, Writer},
+  string::ArchivedString,
+  with::{ArchiveWith, DeserializeWith, SerializeWith},
+  Place,
+};
+
+use super::AsRefStrConverter;
+use crate::{
+  portable::{deserialize_portable, PortableStringResolver},
+  DeserializeError, SerializeError,
+};
+
+/// Same as `AsRefStr`, but the absolute paths under project root are
+/// rebased to the project root of the deserializing side.
+pub struct AsPortable;
+
+impl<T> ArchiveWith<T> for AsPortable
+where
+  T: AsRefStrConverter,
+{
+  type Archived = ArchivedString;
+  type Resolver = PortableStringResolver;
+
+  #[inline]
+  fn resolve_with(field: &T, resolver: Self::Resolver, out: Place<Self::Archived>) {
+    resolver.resolve(field.as_str(), out);
+  }
+}
+
+impl<T, S> SerializeWith<T, S> for AsPortable
+where
+  T: AsRefStrConverter,
+  S: Fallible<Error = SerializeError> + Writer + Sharing + ?Sized,
+{
+  #[inline]
+  fn serialize_with(field: &T, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+    PortableStringResolver::serialize(field.as_str(), serializer)
+  }
+}
+
+impl<T, D> DeserializeWith<ArchivedString, T, D> for AsPortable
+where
+  T: AsRefStrConverter,
+  D: Fallible<Error = DeserializeError> + Pooling + ?Sized,
+{
+  #[inline]
+  fn deserialize_with(field: &ArchivedString, de: &mut D) -> Result<T, D::Error> {
+    Ok(AsRefStrConverter::from_str(
+      deserialize_portable(field, de)?.as_ref(),
+    ))
+  }
+}
@@ -1,41 +1,47 @@
 use camino::Utf8PathBuf;
 use rkyv::{
-  rancor::{Fallible, Source},
-  ser::Writer,
-  string::{ArchivedString, StringResolver},
+  de::Pooling,
+  rancor::Fallible,
+  ser::{Sharing, Writer},
+  string::ArchivedString,
   with::{ArchiveWith, DeserializeWith, SerializeWith},
   Place,
 };
 
 use super::AsPreset;
+use crate::{
+  portable::{deserialize_portable, PortableStringResolver},
+  DeserializeError, SerializeError,
+};
 
 impl ArchiveWith<Utf8PathBuf> for AsPreset {
   type Archived = ArchivedString;
-  type Resolver = StringResolver;
+  type Resolver = PortableStringResolver;
 
   #[inline]
   fn resolve_with(field: &Utf8PathBuf, resolver: Self::Resolver, out: Place<Self::Archived>) {
-    ArchivedString::resolve_from_str(field.as_str(), resolver, out);
+    resolver.resolve(field.as_str(), out);
   }
 }
 
 impl<S> SerializeWith<Utf8PathBuf, S> for AsPreset
 where
-  S: ?Sized + Fallible + Writer,
-  S::Error: Source,
+  S: Fallible<Error = SerializeError> + Writer + Sharing + ?Sized,
 {
   #[inline]
   fn serialize_with(field: &Utf8PathBuf, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
-    ArchivedString::serialize_from_str(field.as_str(), serializer)
+    PortableStringResolver::serialize(field.as_str(), serializer)
   }
 }
 
 impl<D> DeserializeWith<ArchivedString, Utf8PathBuf, D> for AsPreset
 where
-  D: ?Sized + Fallible,
+  D: Fallible<Error = DeserializeError> + Pooling + ?Sized,
 {
   #[inline]
-  fn deserialize_with(field: &ArchivedString, _: &mut D) -> Result<Utf8PathBuf, D::Error> {
-    Ok(Utf8PathBuf::from(field.as_str()))
+  fn deserialize_with(field: &ArchivedString, de: &mut D) -> Result<Utf8PathBuf, D::Error> {
+    Ok(Utf8PathBuf::from(
+      deserialize_portable(field, de)?.into_owned(),
+    ))
   }
 }
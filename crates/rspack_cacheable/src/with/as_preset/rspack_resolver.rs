@@ -1,14 +1,19 @@
 use rkyv::{
   bytecheck::{CheckBytes, StructCheckContext},
-  rancor::{Fallible, Source, Trace},
-  ser::Writer,
-  string::{ArchivedString, StringResolver},
+  de::Pooling,
+  rancor::{Fallible, Trace},
+  ser::{Sharing, Writer},
+  string::ArchivedString,
   with::{ArchiveWith, DeserializeWith, SerializeWith},
-  Archive, Deserialize, Place, Portable,
+  Archive, Place, Portable,
 };
 use rspack_resolver::AliasValue;
 
 use super::AsPreset;
+use crate::{
+  portable::{deserialize_portable, PortableStringResolver},
+  DeserializeError, SerializeError,
+};
 
 pub struct ArchivedAliasValue {
   is_ignore: bool,
@@ -18,7 +23,7 @@ pub struct ArchivedAliasValue {
 unsafe impl Portable for ArchivedAliasValue {}
 
 pub struct AliasValueResolver {
-  path: StringResolver,
+  path: PortableStringResolver,
 }
 
 impl ArchiveWith<AliasValue> for AsPreset {
@@ -38,14 +43,13 @@ impl ArchiveWith<AliasValue> for AsPreset {
     } else {
       ""
     };
-    ArchivedString::resolve_from_str(path, resolver.path, field_out);
+    resolver.path.resolve(path, field_out);
   }
 }
 
 impl<S> SerializeWith<AliasValue, S> for AsPreset
 where
-  S: Fallible + Writer + ?Sized,
-  S::Error: Source,
+  S: Fallible<Error = SerializeError> + Writer + Sharing + ?Sized,
 {
   #[inline]
   fn serialize_with(field: &AliasValue, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
@@ -55,7 +59,7 @@ where
       ""
     };
     Ok(AliasValueResolver {
-      path: ArchivedString::serialize_from_str(path, serializer)?,
+      path: PortableStringResolver::serialize(path, serializer)?,
     })
   }
 }
@@ -92,7 +96,7 @@ where
 
 impl<D> DeserializeWith<ArchivedAliasValue, AliasValue, D> for AsPreset
 where
-  D: ?Sized + Fallible,
+  D: Fallible<Error = DeserializeError> + Pooling + ?Sized,
 {
   fn deserialize_with(
     field: &ArchivedAliasValue,
@@ -101,7 +105,7 @@ where
     Ok(if field.is_ignore {
       AliasValue::Ignore
     } else {
-      AliasValue::Path(field.path.deserialize(deserializer)?)
+      AliasValue::Path(deserialize_portable(&field.path, deserializer)?.into_owned())
     })
   }
 }
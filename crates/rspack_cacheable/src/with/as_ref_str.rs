@@ -51,6 +51,19 @@ where
   }
 }
 
+// for String
+impl AsRefStrConverter for String {
+  fn as_str(&self) -> &str {
+    self
+  }
+  fn from_str(s: &str) -> Self
+  where
+    Self: Sized,
+  {
+    String::from(s)
+  }
+}
+
 // for Cow<'static, str>
 impl AsRefStrConverter for std::borrow::Cow<'static, str> {
   fn as_str(&self) -> &str {
@@ -100,3 +113,29 @@ impl AsRefStrConverter for Box<str> {
     s.into()
   }
 }
+
+// for Ustr
+impl AsRefStrConverter for ustr::Ustr {
+  fn as_str(&self) -> &str {
+    ustr::Ustr::as_str(self)
+  }
+  fn from_str(s: &str) -> Self
+  where
+    Self: Sized,
+  {
+    ustr::Ustr::from(s)
+  }
+}
+
+// for Atom
+impl AsRefStrConverter for swc_core::ecma::atoms::Atom {
+  fn as_str(&self) -> &str {
+    swc_core::ecma::atoms::Atom::as_str(self)
+  }
+  fn from_str(s: &str) -> Self
+  where
+    Self: Sized,
+  {
+    swc_core::ecma::atoms::Atom::from(s)
+  }
+}
@@ -3,6 +3,7 @@ mod as_cacheable;
 mod as_inner;
 mod as_map;
 mod as_owned;
+mod as_portable;
 mod as_preset;
 mod as_ref_str;
 mod as_string;
@@ -16,6 +17,7 @@ pub use as_cacheable::AsCacheable;
 pub use as_inner::{AsInner, AsInnerConverter};
 pub use as_map::{AsMap, AsMapConverter};
 pub use as_owned::AsOwned;
+pub use as_portable::AsPortable;
 pub use as_preset::AsPreset;
 pub use as_ref_str::{AsRefStr, AsRefStrConverter};
 pub use as_string::{AsString, AsStringConverter};
@@ -3,7 +3,7 @@ use std::{any::Any, sync::Arc};
 use rspack_cacheable::{
   cacheable, from_bytes, to_bytes,
   with::{As, AsConverter},
-  DeserializeError, SerializeError,
+  CacheableContext, DeserializeError, SerializeError,
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -16,6 +16,8 @@ struct Context {
   option: Arc<CompilerOptions>,
 }
 
+impl CacheableContext for Context {}
+
 #[cacheable]
 struct FromContext;
 
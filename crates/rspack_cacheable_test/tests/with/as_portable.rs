@@ -0,0 +1,117 @@
+use std::path::{Path, PathBuf};
+
+use camino::Utf8PathBuf;
+use rspack_cacheable::{
+  cacheable, from_bytes, from_portable, to_bytes, to_portable,
+  with::{AsPortable, AsPreset},
+  CacheableContext,
+};
+use ustr::Ustr;
+
+struct Context {
+  root: PathBuf,
+}
+
+impl CacheableContext for Context {
+  fn project_root(&self) -> Option<&Path> {
+    Some(&self.root)
+  }
+}
+
+#[cacheable]
+#[derive(Debug, PartialEq, Eq)]
+struct Module {
+  #[cacheable(with=AsPortable)]
+  request: String,
+  #[cacheable(with=AsPreset)]
+  path: Utf8PathBuf,
+  #[cacheable(with=AsPortable)]
+  identifier: Ustr,
+  /// Not a path, kept as is
+  #[cacheable(with=AsPreset)]
+  name: Ustr,
+}
+
+#[test]
+fn test_portable_str() {
+  let root = "/builds/job-123/app";
+  let cases = [
+    ("/builds/job-123/app", "<PROJECT_ROOT>"),
+    (
+      "/builds/job-123/app/src/index.js",
+      "<PROJECT_ROOT>/src/index.js",
+    ),
+    (
+      "javascript/auto|/builds/job-123/app/src/a.js|/builds/job-123/app/src/b.js",
+      "javascript/auto|<PROJECT_ROOT>/src/a.js|<PROJECT_ROOT>/src/b.js",
+    ),
+    (
+      "/builds/job-123/app2/index.js",
+      "/builds/job-123/app2/index.js",
+    ),
+    (
+      "/other/builds/job-123/app/index.js",
+      "/other/builds/job-123/app/index.js",
+    ),
+    ("./src/index.js", "./src/index.js"),
+    // literal placeholders are escaped
+    ("<PROJECT_ROOT>/index.js", "<\\PROJECT_ROOT>/index.js"),
+    (
+      "<\\PROJECT_ROOT>|/builds/job-123/app",
+      "<\\\\PROJECT_ROOT>|<PROJECT_ROOT>",
+    ),
+    ("<PROJECT>", "<PROJECT>"),
+  ];
+  for (value, portable) in cases {
+    assert_eq!(to_portable(value, root), portable);
+    assert_eq!(to_portable(value, "/builds/job-123/app/"), portable);
+    assert_eq!(from_portable(portable, root), value);
+  }
+  assert_eq!(
+    from_portable("<PROJECT_ROOT>/src/index.js", "/builds/job-456/app"),
+    "/builds/job-456/app/src/index.js"
+  );
+
+  // file system root is never rewritten
+  assert_eq!(to_portable("/src/index.js", "/"), "/src/index.js");
+  assert_eq!(
+    to_portable("C:\\src\\index.js", "C:\\"),
+    "C:\\src\\index.js"
+  );
+}
+
+#[test]
+fn test_as_portable() {
+  let old_context = Context {
+    root: PathBuf::from("/builds/job-123/app"),
+  };
+  let new_context = Context {
+    root: PathBuf::from("/builds/job-456/app"),
+  };
+  let module = Module {
+    request: String::from("builtin:swc-loader!/builds/job-123/app/src/index.js"),
+    path: Utf8PathBuf::from("/builds/job-123/app/src/index.js"),
+    identifier: Ustr::from("javascript/auto|/builds/job-123/app/src/index.js"),
+    name: Ustr::from("/builds/job-123/app/src/index.js"),
+  };
+
+  let bytes = to_bytes(&module, &old_context).unwrap();
+  let new_module: Module = from_bytes(&bytes, &new_context).unwrap();
+  assert_eq!(
+    new_module,
+    Module {
+      request: String::from("builtin:swc-loader!/builds/job-456/app/src/index.js"),
+      path: Utf8PathBuf::from("/builds/job-456/app/src/index.js"),
+      identifier: Ustr::from("javascript/auto|/builds/job-456/app/src/index.js"),
+      name: Ustr::from("/builds/job-123/app/src/index.js"),
+    }
+  );
+  // same project root
+  let new_module: Module = from_bytes(&bytes, &old_context).unwrap();
+  assert_eq!(module, new_module);
+
+  // context without project root keeps absolute paths
+  let bytes = to_bytes(&module, &()).unwrap();
+  let new_module: Module = from_bytes(&bytes, &new_context).unwrap();
+  assert_eq!(module, new_module);
+}
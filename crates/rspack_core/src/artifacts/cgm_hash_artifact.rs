@@ -6,6 +6,8 @@ use crate::{ModuleIdentifier, RuntimeSpec, RuntimeSpecMap};
 #[derive(Debug, Default)]
 pub struct CgmHashArtifact {
   module_to_hashes: IdentifierMap<RuntimeSpecMap<RspackHashDigest>>,
+  /// The hashes with the paths under project root replaced, used as the key of persistent cache
+  module_to_portable_hashes: IdentifierMap<RuntimeSpecMap<RspackHashDigest>>,
 }
 
 impl CgmHashArtifact {
@@ -22,7 +24,25 @@ impl CgmHashArtifact {
     self.module_to_hashes.insert(module, hashes);
   }
 
+  pub fn get_portable(
+    &self,
+    module: &ModuleIdentifier,
+    runtime: &RuntimeSpec,
+  ) -> Option<&RspackHashDigest> {
+    let hashes = self.module_to_portable_hashes.get(module)?;
+    hashes.get(runtime)
+  }
+
+  pub fn set_portable_hashes(
+    &mut self,
+    module: ModuleIdentifier,
+    hashes: RuntimeSpecMap<RspackHashDigest>,
+  ) {
+    self.module_to_portable_hashes.insert(module, hashes);
+  }
+
   pub fn remove(&mut self, module: &ModuleIdentifier) -> Option<RuntimeSpecMap<RspackHashDigest>> {
+    self.module_to_portable_hashes.remove(module);
     self.module_to_hashes.remove(module)
   }
 }
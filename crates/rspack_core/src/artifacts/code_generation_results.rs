@@ -4,7 +4,7 @@ use std::ops::{Deref, DerefMut};
 use std::sync::atomic::AtomicU32;
 
 use anymap::CloneAny;
-use rspack_collections::IdentifierMap;
+use rspack_collections::{Identifier, IdentifierMap};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDigest};
 use rspack_sources::BoxSource;
 use rspack_util::atom::Atom;
@@ -341,3 +341,20 @@ pub struct CodeGenerationJob {
   pub runtime: RuntimeSpec,
   pub runtimes: Vec<RuntimeSpec>,
 }
+
+impl CodeGenerationJob {
+  /// The key to look up cached code generation result of this job.
+  pub fn cache_key(&self) -> Identifier {
+    get_code_generation_cache_key(&self.module, &self.hash)
+  }
+}
+
+/// The module hash already covers the module content and everything it depends on
+/// in chunk graph, so the cache key only needs the module identifier and the module hash.
+/// Persistent cache uses the portable module hash, which doesn't change with the project root.
+pub fn get_code_generation_cache_key(
+  module: &ModuleIdentifier,
+  hash: &RspackHashDigest,
+) -> Identifier {
+  Identifier::from(format!("{}|{}", module, hash.encoded()))
+}
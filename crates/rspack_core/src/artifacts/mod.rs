@@ -1,5 +1,6 @@
-use rspack_collections::{IdentifierMap, IdentifierSet, UkeyMap};
+use rspack_collections::{IdentifierDashMap, IdentifierMap, IdentifierSet, UkeyMap};
 use rspack_error::Diagnostic;
+use rspack_sources::BoxSource;
 
 use crate::{
   chunk_graph_chunk::ChunkId, ChunkHashesResult, ChunkRenderResult, ChunkUkey, ModuleId,
@@ -23,3 +24,7 @@ pub type ChunkIdsArtifact = UkeyMap<ChunkUkey, ChunkId>;
 pub type CgcRuntimeRequirementsArtifact = UkeyMap<ChunkUkey, RuntimeGlobals>;
 pub type ChunkHashesArtifact = UkeyMap<ChunkUkey, ChunkHashesResult>;
 pub type ChunkRenderArtifact = UkeyMap<ChunkUkey, ChunkRenderResult>;
+/// Code generation results recovered from persistent cache, keyed by `{module}|{module hash}`.
+pub type CodeGenerationCacheArtifact = IdentifierMap<code_generation_results::CodeGenerationResult>;
+/// Rendered chunk sources keyed by chunk content hash, shared with persistent cache.
+pub type ChunkRenderCacheArtifact = IdentifierDashMap<BoxSource>;
@@ -331,6 +331,38 @@ fn get_active_state_of_connections(
   merged
 }
 
+/// A block in a guarded branch that is ruled out by a constant export keeps its dependencies
+/// unresolved, see `release_guarded_dependencies`, so no chunk group is created for it.
+fn is_dead_guarded_block(
+  block_id: &AsyncDependenciesBlockIdentifier,
+  module_id: &ModuleIdentifier,
+  module_graph: &ModuleGraph,
+) -> bool {
+  let Some(build_info) = module_graph
+    .module_by_identifier(module_id)
+    .and_then(|module| module.build_info())
+  else {
+    return false;
+  };
+  if build_info.guarded_dependencies.is_empty() {
+    return false;
+  }
+  let Some(block) = module_graph.block_by_id(block_id) else {
+    return false;
+  };
+  let dependencies = block.get_dependencies();
+  !dependencies.is_empty()
+    && dependencies.iter().all(|dependency_id| {
+      build_info
+        .guarded_dependencies
+        .iter()
+        .any(|guarded| guarded.dependencies.contains(dependency_id))
+        && module_graph
+          .connection_by_dependency_id(dependency_id)
+          .is_none()
+    })
+}
+
 impl CodeSplitter {
   pub fn get_module_ordinal(&self, module_id: ModuleIdentifier) -> u64 {
     *self.ordinal_by_module.get(&module_id).unwrap_or_else(|| {
@@ -1255,6 +1287,10 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
     item_chunk_ukey: ChunkUkey,
     compilation: &mut Compilation,
   ) {
+    if is_dead_guarded_block(&block_id, &module_id, &compilation.get_module_graph()) {
+      return;
+    }
+
     self.edges.insert(block_id, module_id);
 
     let Some(item_chunk_group_info) = self.chunk_group_infos.get_mut(&item_chunk_group_info_ukey)
@@ -37,6 +37,27 @@ pub trait Cache: Debug + Send + Sync {
   async fn after_make(&self, _make_artifact: &MakeArtifact) -> Result<()> {
     Ok(())
   }
+
+  async fn before_code_generation(&self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+  async fn after_code_generation(&self, _compilation: &Compilation) -> Result<()> {
+    Ok(())
+  }
+
+  async fn before_chunks_hashes(&self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+  async fn after_chunks_hashes(&self, _compilation: &Compilation) -> Result<()> {
+    Ok(())
+  }
+
+  async fn before_chunks_render(&self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+  async fn after_chunks_render(&self, _compilation: &Compilation) -> Result<()> {
+    Ok(())
+  }
 }
 
 pub fn new_cache(
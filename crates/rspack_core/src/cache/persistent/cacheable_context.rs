@@ -1,6 +1,8 @@
-use std::{any::Any, sync::Arc};
+use std::{any::Any, path::Path, sync::Arc};
 
-use rspack_cacheable::{cacheable, with::AsConverter, DeserializeError, SerializeError};
+use rspack_cacheable::{
+  cacheable, from_portable, to_portable, with::AsConverter, DeserializeError, SerializeError,
+};
 use rspack_fs::ReadableFileSystem;
 
 use crate::CompilerOptions;
@@ -11,6 +13,26 @@ pub struct CacheableContext {
   pub input_filesystem: Arc<dyn ReadableFileSystem>,
 }
 
+impl CacheableContext {
+  /// Storage key of the string which may contain absolute paths of the project
+  pub fn to_portable_key(&self, value: &str) -> Vec<u8> {
+    to_portable(value, self.options.context.as_str())
+      .as_bytes()
+      .to_vec()
+  }
+
+  /// Rebase the storage key to current project root
+  pub fn rebase_portable_key(&self, key: &[u8]) -> String {
+    from_portable(&String::from_utf8_lossy(key), self.options.context.as_str()).into_owned()
+  }
+}
+
+impl rspack_cacheable::CacheableContext for CacheableContext {
+  fn project_root(&self) -> Option<&Path> {
+    Some(self.options.context.as_ref())
+  }
+}
+
 #[cacheable]
 pub struct FromContext;
 
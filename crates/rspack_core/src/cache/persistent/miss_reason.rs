@@ -0,0 +1,106 @@
+use std::{fmt::Display, path::Path};
+
+use rspack_paths::ArcPath;
+
+use super::snapshot::{SnapshotChange, SnapshotChanges};
+use crate::Module;
+
+/// The reason why a module missed the persistent cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheMissReason {
+  /// There is no cache for the current cache version, the version changes
+  /// with build dependencies, `cache.version` and rspack version.
+  VersionChanged,
+  /// The resource of the module has changed.
+  SnapshotChanged {
+    path: ArcPath,
+    change: SnapshotChange,
+  },
+  /// A file, context, missing or build dependency of the module has changed.
+  DependencyChanged {
+    path: ArcPath,
+    change: SnapshotChange,
+  },
+}
+
+impl CacheMissReason {
+  pub fn path(&self) -> Option<&ArcPath> {
+    match self {
+      Self::VersionChanged => None,
+      Self::SnapshotChanged { path, .. } | Self::DependencyChanged { path, .. } => Some(path),
+    }
+  }
+}
+
+impl Display for CacheMissReason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let (kind, path, change) = match self {
+      Self::VersionChanged => return write!(f, "cache version changed"),
+      Self::SnapshotChanged { path, change } => ("resource", path, change),
+      Self::DependencyChanged { path, change } => ("dependency", path, change),
+    };
+    write!(
+      f,
+      "{kind} {} {} (detected by {})",
+      path.display(),
+      if change.deleted {
+        "deleted"
+      } else {
+        "modified"
+      },
+      change.strategy
+    )
+  }
+}
+
+/// Snapshot changes found when recovering from the persistent cache,
+/// used to explain why the built modules missed the cache.
+#[derive(Debug, Default)]
+pub struct CacheMissInfo {
+  changes: Option<SnapshotChanges>,
+}
+
+impl CacheMissInfo {
+  pub fn new(changes: SnapshotChanges) -> Self {
+    Self {
+      changes: Some(changes),
+    }
+  }
+
+  /// Whether the compilation is recovered from the persistent cache
+  pub fn is_enabled(&self) -> bool {
+    self.changes.is_some()
+  }
+
+  /// Find the reason why the built module missed the cache
+  pub fn module_reason(&self, module: &dyn Module) -> Option<CacheMissReason> {
+    let changes = self.changes.as_ref()?;
+    if changes.empty {
+      return Some(CacheMissReason::VersionChanged);
+    }
+    if let Some(resource) = module.name_for_condition() {
+      if let Some((path, change)) = changes.paths.get_key_value(Path::new(&*resource)) {
+        return Some(CacheMissReason::SnapshotChanged {
+          path: path.clone(),
+          change: change.clone(),
+        });
+      }
+    }
+    let build_info = module.build_info();
+    build_info
+      .file_dependencies
+      .iter()
+      .chain(build_info.context_dependencies.iter())
+      .chain(build_info.missing_dependencies.iter())
+      .chain(build_info.build_dependencies.iter())
+      .find_map(|path| {
+        changes
+          .paths
+          .get(path)
+          .map(|change| CacheMissReason::DependencyChanged {
+            path: path.clone(),
+            change: change.clone(),
+          })
+      })
+  }
+}
@@ -1,12 +1,22 @@
 mod cacheable_context;
+mod miss_reason;
 mod occasion;
+mod portable_hasher;
 pub mod snapshot;
 pub mod storage;
 mod version;
-use std::{path::PathBuf, sync::Arc};
+use std::{
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+};
 
 pub use cacheable_context::{CacheableContext, FromContext};
-use occasion::MakeOccasion;
+pub use miss_reason::{CacheMissInfo, CacheMissReason};
+use occasion::{ChunkHashesOccasion, ChunkRenderOccasion, CodeGenerateOccasion, MakeOccasion};
+pub use portable_hasher::PortableHasher;
 use rspack_error::Result;
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem};
 use rspack_macros::rspack_version;
@@ -34,7 +44,13 @@ pub struct PersistentCache {
   storage: Arc<dyn Storage>,
   snapshot: Snapshot,
   make_occasion: MakeOccasion,
+  code_generate_occasion: CodeGenerateOccasion,
+  chunk_hashes_occasion: ChunkHashesOccasion,
+  chunk_render_occasion: ChunkRenderOccasion,
   async_mode: bool,
+  // the results of code generation, chunk hashes and chunk render are only recovered
+  // for the first compilation, later compilations reuse the results in memory
+  warm_start: AtomicBool,
 }
 
 impl PersistentCache {
@@ -52,16 +68,36 @@ impl PersistentCache {
       vec![compiler_path, &option.version, rspack_version!()],
     );
     let storage = create_storage(option.storage.clone(), version, intermediate_filesystem);
+    let snapshot = Snapshot::new(
+      option.snapshot.clone(),
+      compiler_options.context.clone(),
+      input_filesystem.clone(),
+      storage.clone(),
+    );
     let context = Arc::new(CacheableContext {
       options: compiler_options,
-      input_filesystem: input_filesystem.clone(),
+      input_filesystem,
     });
-    let make_occasion = MakeOccasion::new(storage.clone(), context);
+    let make_occasion = MakeOccasion::new(storage.clone(), context.clone());
+    let code_generate_occasion = CodeGenerateOccasion::new(storage.clone(), context.clone());
+    let chunk_hashes_occasion = ChunkHashesOccasion::new(storage.clone(), context);
+    let chunk_render_occasion = ChunkRenderOccasion::new(storage.clone());
     Self {
-      snapshot: Snapshot::new(option.snapshot.clone(), input_filesystem, storage.clone()),
+      snapshot,
       storage,
       make_occasion,
+      code_generate_occasion,
+      chunk_hashes_occasion,
+      chunk_render_occasion,
       async_mode,
+      warm_start: AtomicBool::new(true),
+    }
+  }
+
+  /// Report the storage errors which don't break the build, e.g. the remote store is unavailable
+  fn push_storage_warnings(&self, compilation: &mut Compilation) {
+    for warning in self.storage.take_warnings() {
+      compilation.push_diagnostic(rspack_error::Error::from(warning).into());
     }
   }
 }
@@ -71,11 +107,15 @@ impl Cache for PersistentCache {
   async fn before_compile(&self, compilation: &mut Compilation) -> Result<()> {
     if compilation.modified_files.is_empty() && compilation.removed_files.is_empty() {
       // inject modified_files and removed_files
-      let (modified_paths, removed_paths) = self.snapshot.calc_modified_paths().await?;
+      let changes = self.snapshot.calc_changes().await?;
+      let modified_paths = changes.modified_paths();
+      let removed_paths = changes.deleted_paths();
       tracing::info!("cache::snapshot recovery {modified_paths:?} {removed_paths:?}",);
       compilation.modified_files = modified_paths;
       compilation.removed_files = removed_paths;
+      compilation.cache_miss_info = CacheMissInfo::new(changes);
     }
+    self.push_storage_warnings(compilation);
     Ok(())
   }
 
@@ -137,4 +177,40 @@ impl Cache for PersistentCache {
     self.make_occasion.save(make_artifact);
     Ok(())
   }
+
+  async fn before_code_generation(&self, compilation: &mut Compilation) -> Result<()> {
+    if self.warm_start.load(Ordering::Relaxed) {
+      compilation.code_generation_cache_artifact = self.code_generate_occasion.recovery().await?;
+    }
+    self.push_storage_warnings(compilation);
+    Ok(())
+  }
+
+  async fn after_code_generation(&self, compilation: &Compilation) -> Result<()> {
+    self.code_generate_occasion.save(compilation).await
+  }
+
+  async fn before_chunks_hashes(&self, compilation: &mut Compilation) -> Result<()> {
+    if self.warm_start.load(Ordering::Relaxed) {
+      self.chunk_hashes_occasion.recovery(compilation).await?;
+    }
+    self.push_storage_warnings(compilation);
+    Ok(())
+  }
+
+  async fn after_chunks_hashes(&self, compilation: &Compilation) -> Result<()> {
+    self.chunk_hashes_occasion.save(compilation).await
+  }
+
+  async fn before_chunks_render(&self, compilation: &mut Compilation) -> Result<()> {
+    if self.warm_start.swap(false, Ordering::Relaxed) {
+      compilation.chunk_render_cache_artifact = self.chunk_render_occasion.recovery().await?;
+    }
+    self.push_storage_warnings(compilation);
+    Ok(())
+  }
+
+  async fn after_chunks_render(&self, compilation: &Compilation) -> Result<()> {
+    self.chunk_render_occasion.save(compilation).await
+  }
 }
@@ -0,0 +1,233 @@
+use std::{hash::Hash, sync::Arc};
+
+use rspack_cacheable::{cacheable, from_bytes, to_bytes, with::AsMap};
+use rspack_collections::Identifier;
+use rspack_error::Result;
+use rspack_hash::{RspackHash, RspackHashDigest};
+use rustc_hash::FxHashMap as HashMap;
+
+use super::super::{cacheable_context::CacheableContext, Storage};
+use crate::{get_runtime_key, ChunkContentHash, ChunkGraph, Compilation};
+
+const SCOPE: &str = "occasion_chunk_hashes";
+
+#[cacheable]
+struct ChunkHashes {
+  id: String,
+  hash: RspackHashDigest,
+  #[cacheable(with=AsMap)]
+  content_hash: ChunkContentHash,
+}
+
+/// The value struct of current storage scope
+///
+/// The results of hashing are stored as a whole and keyed by the fingerprint of the inputs.
+#[cacheable]
+struct Node {
+  chunks: Vec<ChunkHashes>,
+  runtime_modules: Vec<(Identifier, RspackHashDigest)>,
+  hash: RspackHashDigest,
+}
+
+/// The fingerprint of the inputs of chunk hashes
+///
+/// The chunk hashes only depend on the chunk graph, the code generation results and the
+/// runtime modules which are created from them, so the chunk hashes can be reused when all
+/// of these are unchanged. Returns `None` if any chunk has no id.
+fn fingerprint(compilation: &Compilation) -> Option<String> {
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .values()
+    .map(|chunk| Some((chunk.id(&compilation.chunk_ids_artifact)?, chunk)))
+    .collect::<Option<Vec<_>>>()?;
+  chunks.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+  let mut hasher = RspackHash::from(&compilation.options.output);
+  for (id, chunk) in chunks {
+    id.hash(&mut hasher);
+    chunk.name().hash(&mut hasher);
+    get_runtime_key(chunk.runtime()).hash(&mut hasher);
+    chunk
+      .filename_template()
+      .and_then(|filename| filename.template())
+      .hash(&mut hasher);
+    chunk
+      .css_filename_template()
+      .and_then(|filename| filename.template())
+      .hash(&mut hasher);
+    for module in compilation
+      .chunk_graph
+      .get_ordered_chunk_modules_identifier(&chunk.ukey())
+    {
+      module.hash(&mut hasher);
+      compilation
+        .code_generation_results
+        .get_hash(&module, Some(chunk.runtime()))
+        .hash(&mut hasher);
+    }
+    for (runtime_module, _) in compilation
+      .chunk_graph
+      .get_chunk_runtime_modules_in_order(&chunk.ukey(), compilation)
+    {
+      runtime_module.hash(&mut hasher);
+    }
+    compilation
+      .cgc_runtime_requirements_artifact
+      .get(&chunk.ukey())
+      .map(|runtime_requirements| runtime_requirements.bits())
+      .hash(&mut hasher);
+    for (module, chunk_group) in compilation
+      .chunk_graph
+      .get_chunk_entry_modules_with_chunk_group_iterable(&chunk.ukey())
+    {
+      ChunkGraph::get_module_id(&compilation.module_ids_artifact, *module).hash(&mut hasher);
+      compilation
+        .chunk_group_by_ukey
+        .get(chunk_group)
+        .map(|chunk_group| chunk_group.id(compilation))
+        .hash(&mut hasher);
+    }
+    let mut groups = chunk
+      .groups()
+      .iter()
+      .filter_map(|group| compilation.chunk_group_by_ukey.get(group))
+      .map(|group| {
+        let mut children = group
+          .children
+          .iter()
+          .filter_map(|child| compilation.chunk_group_by_ukey.get(child))
+          .map(|child| child.id(compilation))
+          .collect::<Vec<_>>();
+        children.sort_unstable();
+        (group.id(compilation), group.name(), children)
+      })
+      .collect::<Vec<_>>();
+    groups.sort_unstable();
+    groups.hash(&mut hasher);
+  }
+  compilation.hot_index.hash(&mut hasher);
+  Some(
+    hasher
+      .digest(&compilation.options.output.hash_digest)
+      .encoded()
+      .to_string(),
+  )
+}
+
+#[derive(Debug)]
+pub struct ChunkHashesOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+}
+
+impl ChunkHashesOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self { storage, context }
+  }
+
+  #[tracing::instrument(name = "ChunkHashesOccasion::save", skip_all)]
+  pub async fn save(&self, compilation: &Compilation) -> Result<()> {
+    let Some(fingerprint) = fingerprint(compilation) else {
+      return Ok(());
+    };
+    let stored_keys = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_iter()
+      .map(|(key, _)| key)
+      .collect::<Vec<_>>();
+    if stored_keys
+      .iter()
+      .any(|key| key.as_slice() == fingerprint.as_bytes())
+    {
+      return Ok(());
+    }
+
+    let Some(hash) = compilation.hash.clone() else {
+      return Ok(());
+    };
+    let chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| {
+        Some(ChunkHashes {
+          id: chunk
+            .id(&compilation.chunk_ids_artifact)?
+            .as_str()
+            .to_string(),
+          hash: chunk.hash(&compilation.chunk_hashes_artifact)?.clone(),
+          content_hash: chunk
+            .content_hash(&compilation.chunk_hashes_artifact)?
+            .clone(),
+        })
+      })
+      .collect();
+    let runtime_modules = compilation
+      .runtime_modules_hash
+      .iter()
+      .map(|(identifier, hash)| (*identifier, hash.clone()))
+      .collect();
+    let node = Node {
+      chunks,
+      runtime_modules,
+      hash,
+    };
+    match to_bytes(&node, self.context.as_ref()) {
+      Ok(bytes) => {
+        // only the hashes of the latest chunk graph are kept
+        for key in stored_keys {
+          self.storage.remove(SCOPE, &key);
+        }
+        self.storage.set(SCOPE, fingerprint.into_bytes(), bytes);
+      }
+      Err(err) => tracing::warn!("to bytes failed {:?}", err),
+    }
+    Ok(())
+  }
+
+  /// Set the hashes to compilation if the fingerprint is matched
+  #[tracing::instrument(name = "ChunkHashesOccasion::recovery", skip_all)]
+  pub async fn recovery(&self, compilation: &mut Compilation) -> Result<()> {
+    let Some(fingerprint) = fingerprint(compilation) else {
+      return Ok(());
+    };
+    let Some((_, value)) = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_iter()
+      .find(|(key, _)| key.as_slice() == fingerprint.as_bytes())
+    else {
+      return Ok(());
+    };
+    let node: Node = from_bytes(&value, self.context.as_ref())
+      .expect("unexpected chunk hashes deserialize failed");
+
+    let mut chunk_hashes = node
+      .chunks
+      .into_iter()
+      .map(|item| (item.id, (item.hash, item.content_hash)))
+      .collect::<HashMap<_, _>>();
+    for chunk in compilation.chunk_by_ukey.values() {
+      let Some((hash, content_hash)) = chunk
+        .id(&compilation.chunk_ids_artifact)
+        .and_then(|id| chunk_hashes.remove(id.as_str()))
+      else {
+        // the fingerprint matched but the chunk is missing, keep computing the hashes
+        compilation.chunk_hashes_artifact.clear();
+        return Ok(());
+      };
+      chunk.set_hashes(&mut compilation.chunk_hashes_artifact, hash, content_hash);
+    }
+    compilation
+      .runtime_modules_hash
+      .extend(node.runtime_modules);
+    compilation.hash = Some(node.hash);
+    tracing::info!(
+      "recovery {} chunk hashes",
+      compilation.chunk_hashes_artifact.len()
+    );
+    Ok(())
+  }
+}
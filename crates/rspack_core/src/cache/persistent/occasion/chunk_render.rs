@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use rspack_cacheable::{cacheable, from_bytes, to_bytes, with::AsPreset};
+use rspack_collections::Identifier;
+use rspack_error::Result;
+use rspack_sources::BoxSource;
+use rustc_hash::FxHashSet as HashSet;
+
+use super::super::Storage;
+use crate::{ChunkRenderCacheArtifact, Compilation, Logger};
+
+const SCOPE: &str = "occasion_chunk_render";
+
+/// The value struct of current storage scope
+#[cacheable]
+struct Node {
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+}
+
+#[derive(Debug)]
+pub struct ChunkRenderOccasion {
+  storage: Arc<dyn Storage>,
+}
+
+impl ChunkRenderOccasion {
+  pub fn new(storage: Arc<dyn Storage>) -> Self {
+    Self { storage }
+  }
+
+  #[tracing::instrument(name = "ChunkRenderOccasion::save", skip_all)]
+  pub async fn save(&self, compilation: &Compilation) -> Result<()> {
+    let stored_keys = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_iter()
+      .map(|(key, _)| Identifier::from(String::from_utf8_lossy(&key).as_ref()))
+      .collect::<HashSet<_>>();
+
+    // the rendered sources are keyed by content hash, so only the content hashes
+    // of current chunks are still alive
+    let current_keys = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| chunk.content_hash(&compilation.chunk_hashes_artifact))
+      .flat_map(|content_hash| content_hash.values())
+      .map(|hash| Identifier::from(hash.encoded()))
+      .collect::<HashSet<_>>();
+    let artifact = &compilation.chunk_render_cache_artifact;
+    artifact.retain(|key, _| current_keys.contains(key));
+
+    let logger = compilation.get_logger("rspack.Compilation");
+    let mut counter = logger.cache("chunk render cache");
+    for item in artifact.iter() {
+      if stored_keys.contains(item.key()) {
+        counter.hit();
+      } else {
+        counter.miss();
+      }
+    }
+    logger.cache_end(counter);
+
+    let nodes = artifact
+      .iter()
+      .filter(|item| !stored_keys.contains(item.key()))
+      .map(|item| (*item.key(), item.value().clone()))
+      .collect::<Vec<_>>()
+      .into_par_iter()
+      .filter_map(|(key, source)| match to_bytes(&Node { source }, &()) {
+        Ok(bytes) => Some((key.as_bytes().to_vec(), bytes)),
+        Err(err) => {
+          tracing::warn!("to bytes failed {:?}", err);
+          None
+        }
+      })
+      .collect::<Vec<_>>();
+    tracing::info!("save {} chunk render results", nodes.len());
+    for (key, bytes) in nodes {
+      self.storage.set(SCOPE, key, bytes);
+    }
+
+    for key in stored_keys.difference(&current_keys) {
+      self.storage.remove(SCOPE, key.as_bytes());
+    }
+    Ok(())
+  }
+
+  #[tracing::instrument(name = "ChunkRenderOccasion::recovery", skip_all)]
+  pub async fn recovery(&self) -> Result<ChunkRenderCacheArtifact> {
+    let artifact = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_par_iter()
+      .map(|(key, value)| {
+        let node: Node =
+          from_bytes(&value, &()).expect("unexpected chunk render deserialize failed");
+        (
+          Identifier::from(String::from_utf8_lossy(&key).as_ref()),
+          node.source,
+        )
+      })
+      .collect::<ChunkRenderCacheArtifact>();
+    tracing::info!("recovery {} chunk render results", artifact.len());
+    Ok(artifact)
+  }
+}
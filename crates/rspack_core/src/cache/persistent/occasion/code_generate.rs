@@ -0,0 +1,228 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rayon::prelude::*;
+use rspack_cacheable::{cacheable, from_bytes, from_portable, to_bytes, to_portable, with::AsMap};
+use rspack_collections::Identifier;
+use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
+use rspack_sources::{
+  BoxSource, RawBufferSource, Source, SourceExt, SourceMap, SourceMapSource, WithoutOriginalOptions,
+};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::super::{cacheable_context::CacheableContext, Storage};
+use crate::{
+  get_code_generation_cache_key, CodeGenerationCacheArtifact, CodeGenerationResult, Compilation,
+  CompilerOptions, RuntimeGlobals, SourceType,
+};
+
+const SCOPE: &str = "occasion_code_generate";
+
+/// The generated code with the paths under project root replaced
+///
+/// Code generation results may contain absolute paths, such as the module paths in comments
+/// and the sources of source map, they are rebased to current project root in recovery.
+#[cacheable]
+struct PortableSource {
+  code: Vec<u8>,
+  map: Option<String>,
+}
+
+impl PortableSource {
+  /// Returns the portable source and whether any path is replaced
+  fn new(source: &BoxSource, project_root: &str) -> Option<(Self, bool)> {
+    let mut rewritten = false;
+    let mut rewrite = |value: &str| match to_portable(value, project_root) {
+      Cow::Owned(value) => {
+        rewritten = true;
+        value
+      }
+      Cow::Borrowed(value) => value.to_string(),
+    };
+    let code = match std::str::from_utf8(&source.buffer()) {
+      Ok(code) => rewrite(code).into_bytes(),
+      Err(_) => source.buffer().to_vec(),
+    };
+    let map = match source.map(&Default::default()) {
+      Some(map) => Some(rewrite(&map.to_json().ok()?)),
+      None => None,
+    };
+    Some((Self { code, map }, rewritten))
+  }
+
+  fn into_source(self, project_root: &str) -> BoxSource {
+    let code = match String::from_utf8(self.code) {
+      Ok(code) => from_portable(&code, project_root).into_owned(),
+      Err(err) => return RawBufferSource::from(err.into_bytes()).boxed(),
+    };
+    if let Some(source_map) = self
+      .map
+      .and_then(|map| SourceMap::from_json(&from_portable(&map, project_root)).ok())
+    {
+      return SourceMapSource::new(WithoutOriginalOptions {
+        value: code,
+        name: "persistent-cache",
+        source_map,
+      })
+      .boxed();
+    }
+    RawBufferSource::from(code.into_bytes()).boxed()
+  }
+}
+
+/// The value struct of current storage scope
+///
+/// Only the part of `CodeGenerationResult` without dynamic data is saved,
+/// results that carry `data`, `chunk_init_fragments` or `concatenation_scope`
+/// will be generated again.
+#[cacheable]
+struct Node {
+  #[cacheable(with=AsMap)]
+  inner: HashMap<SourceType, PortableSource>,
+  runtime_requirements: RuntimeGlobals,
+  /// `None` if the sources contain paths under project root,
+  /// the hash is created again after the sources are rebased.
+  hash: Option<RspackHashDigest>,
+}
+
+impl Node {
+  fn from_result(result: &CodeGenerationResult, project_root: &str) -> Option<Self> {
+    if !result.data.is_empty()
+      || !result.chunk_init_fragments.is_empty()
+      || result.concatenation_scope.is_some()
+    {
+      return None;
+    }
+    let mut portable = true;
+    let mut inner = HashMap::default();
+    for (source_type, source) in &result.inner {
+      let (source, rewritten) = PortableSource::new(source, project_root)?;
+      portable &= !rewritten;
+      inner.insert(*source_type, source);
+    }
+    Some(Self {
+      inner,
+      runtime_requirements: result.runtime_requirements,
+      hash: portable.then(|| result.hash.clone()).flatten(),
+    })
+  }
+
+  fn into_result(self, options: &CompilerOptions) -> CodeGenerationResult {
+    let project_root = options.context.as_str();
+    let mut result = CodeGenerationResult {
+      inner: self
+        .inner
+        .into_iter()
+        .map(|(source_type, source)| (source_type, source.into_source(project_root)))
+        .collect(),
+      runtime_requirements: self.runtime_requirements,
+      hash: self.hash,
+      ..Default::default()
+    };
+    if result.hash.is_none() {
+      result.set_hash(
+        &options.output.hash_function,
+        &options.output.hash_digest,
+        &options.output.hash_salt,
+      );
+    }
+    result
+  }
+}
+
+#[derive(Debug)]
+pub struct CodeGenerateOccasion {
+  context: Arc<CacheableContext>,
+  storage: Arc<dyn Storage>,
+}
+
+impl CodeGenerateOccasion {
+  pub fn new(storage: Arc<dyn Storage>, context: Arc<CacheableContext>) -> Self {
+    Self { storage, context }
+  }
+
+  #[tracing::instrument(name = "CodeGenerateOccasion::save", skip_all)]
+  pub async fn save(&self, compilation: &Compilation) -> Result<()> {
+    let stored_keys = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_iter()
+      .map(|(key, _)| Identifier::from(self.context.rebase_portable_key(&key)))
+      .collect::<HashSet<_>>();
+
+    let mut current_keys = HashSet::default();
+    let mut updated = vec![];
+    for module in compilation.get_module_graph().modules().keys() {
+      if compilation.chunk_graph.get_number_of_module_chunks(*module) == 0 {
+        continue;
+      }
+      let runtimes = compilation
+        .chunk_graph
+        .get_module_runtimes(*module, &compilation.chunk_by_ukey);
+      for runtime in runtimes.values() {
+        let Some(hash) = compilation.cgm_hash_artifact.get_portable(module, runtime) else {
+          continue;
+        };
+        let key = get_code_generation_cache_key(module, hash);
+        if !current_keys.insert(key) {
+          continue;
+        }
+        if !stored_keys.contains(&key) && compilation.code_generated_modules.contains(module) {
+          updated.push((
+            key,
+            compilation
+              .code_generation_results
+              .get(module, Some(runtime)),
+          ));
+        }
+      }
+    }
+
+    let project_root = self.context.options.context.as_str();
+    let nodes = updated
+      .into_par_iter()
+      .filter_map(|(key, result)| {
+        let node = Node::from_result(result, project_root)?;
+        match to_bytes(&node, self.context.as_ref()) {
+          Ok(bytes) => Some((self.context.to_portable_key(&key), bytes)),
+          Err(err) => {
+            tracing::warn!("to bytes failed {:?}", err);
+            None
+          }
+        }
+      })
+      .collect::<Vec<_>>();
+    tracing::info!("save {} code generation results", nodes.len());
+    for (key, bytes) in nodes {
+      self.storage.set(SCOPE, key, bytes);
+    }
+
+    for key in stored_keys.difference(&current_keys) {
+      self
+        .storage
+        .remove(SCOPE, &self.context.to_portable_key(key));
+    }
+    Ok(())
+  }
+
+  #[tracing::instrument(name = "CodeGenerateOccasion::recovery", skip_all)]
+  pub async fn recovery(&self) -> Result<CodeGenerationCacheArtifact> {
+    let artifact = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_par_iter()
+      .map(|(key, value)| {
+        let node: Node = from_bytes(&value, self.context.as_ref())
+          .expect("unexpected code generation deserialize failed");
+        (
+          Identifier::from(self.context.rebase_portable_key(&key)),
+          node.into_result(&self.context.options),
+        )
+      })
+      .collect::<CodeGenerationCacheArtifact>();
+    tracing::info!("recovery {} code generation results", artifact.len());
+    Ok(artifact)
+  }
+}
@@ -6,7 +6,7 @@ use rspack_error::Result;
 use rspack_paths::ArcPath;
 use rustc_hash::FxHashMap as HashMap;
 
-use super::Storage;
+use super::{CacheableContext, Storage};
 use crate::FileCounter;
 
 const SCOPE: &str = "occasion_make_dependencies";
@@ -44,6 +44,7 @@ pub fn save_dependencies_info(
   missing_dependencies: &FileCounter,
   build_dependencies: &FileCounter,
   storage: &Arc<dyn Storage>,
+  context: &CacheableContext,
 ) {
   let f = file_dependencies
     .updated_files_count_info()
@@ -98,7 +99,7 @@ pub fn save_dependencies_info(
     .chain(b)
     .par_bridge()
     .for_each(|(dep_ref, count)| {
-      let dep_ref = to_bytes(&dep_ref, &()).expect("should to bytes success");
+      let dep_ref = to_bytes(&dep_ref, context).expect("should to bytes success");
       if count == 0 {
         storage.remove(SCOPE, &dep_ref);
       } else {
@@ -109,6 +110,7 @@ pub fn save_dependencies_info(
 
 pub async fn recovery_dependencies_info(
   storage: &Arc<dyn Storage>,
+  context: &CacheableContext,
 ) -> Result<(FileCounter, FileCounter, FileCounter, FileCounter)> {
   let file_dep = Mutex::new(HashMap::default());
   let context_dep = Mutex::new(HashMap::default());
@@ -125,7 +127,7 @@ pub async fn recovery_dependencies_info(
           .try_into()
           .expect("should parse count success"),
       );
-      let Dependency { r#type, path } = from_bytes(&k, &()).expect("should from bytes success");
+      let Dependency { r#type, path } = from_bytes(&k, context).expect("should from bytes success");
       match r#type {
         DepType::File => file_dep
           .lock()
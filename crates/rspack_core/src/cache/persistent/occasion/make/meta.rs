@@ -5,7 +5,7 @@ use rspack_collections::IdentifierSet;
 use rspack_error::Result;
 use rustc_hash::FxHashSet as HashSet;
 
-use super::Storage;
+use super::{CacheableContext, Storage};
 use crate::{BuildDependency, DEPENDENCY_ID};
 
 const SCOPE: &str = "occasion_make_meta";
@@ -33,6 +33,7 @@ pub fn save_meta(
   make_failed_dependencies: &HashSet<BuildDependency>,
   make_failed_module: &IdentifierSet,
   storage: &Arc<dyn Storage>,
+  context: &CacheableContext,
 ) {
   let meta = MetaRef {
     make_failed_dependencies,
@@ -42,17 +43,18 @@ pub fn save_meta(
   storage.set(
     SCOPE,
     "default".as_bytes().to_vec(),
-    to_bytes(&meta, &()).expect("should to bytes success"),
+    to_bytes(&meta, context).expect("should to bytes success"),
   );
 }
 
 pub async fn recovery_meta(
   storage: &Arc<dyn Storage>,
+  context: &CacheableContext,
 ) -> Result<(HashSet<BuildDependency>, IdentifierSet)> {
   let Some((_, value)) = storage.load(SCOPE).await?.pop() else {
     return Ok(Default::default());
   };
-  let meta: Meta = from_bytes(&value, &()).expect("should from bytes success");
+  let meta: Meta = from_bytes(&value, context).expect("should from bytes success");
   // TODO make dependency id to string like module id
   if DEPENDENCY_ID.load(Relaxed) < meta.next_dependencies_id {
     DEPENDENCY_ID.store(meta.next_dependencies_id, Relaxed);
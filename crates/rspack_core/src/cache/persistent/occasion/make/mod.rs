@@ -4,6 +4,7 @@ mod module_graph;
 
 use std::sync::Arc;
 
+use rspack_collections::IdentifierSet;
 use rspack_error::Result;
 
 use super::super::{cacheable_context::CacheableContext, Storage};
@@ -33,11 +34,13 @@ impl MakeOccasion {
       module_graph_partial,
       revoked_modules,
       built_modules,
+      updated_modules,
       // for meta
       make_failed_dependencies,
       make_failed_module,
       // skip
       entry_dependencies: _,
+      guarded_modules: _,
       initialized: _,
       has_module_graph_change: _,
       diagnostics: _,
@@ -48,17 +51,28 @@ impl MakeOccasion {
       missing_dependencies,
       build_dependencies,
       &self.storage,
+      &self.context,
     );
 
+    // the outgoing connections of updated modules are changed
+    let built_modules = built_modules
+      .union(updated_modules)
+      .copied()
+      .collect::<IdentifierSet>();
     module_graph::save_module_graph(
       module_graph_partial,
       revoked_modules,
-      built_modules,
+      &built_modules,
       &self.storage,
       &self.context,
     );
 
-    meta::save_meta(make_failed_dependencies, make_failed_module, &self.storage);
+    meta::save_meta(
+      make_failed_dependencies,
+      make_failed_module,
+      &self.storage,
+      &self.context,
+    );
   }
 
   #[tracing::instrument(name = "MakeOccasion::recovery", skip_all)]
@@ -68,19 +82,32 @@ impl MakeOccasion {
     // TODO can call recovery with multi thread
     // TODO return DeserializeError not panic
     let (file_dependencies, context_dependencies, missing_dependencies, build_dependencies) =
-      dependencies::recovery_dependencies_info(&self.storage).await?;
+      dependencies::recovery_dependencies_info(&self.storage, &self.context).await?;
     artifact.file_dependencies = file_dependencies;
     artifact.context_dependencies = context_dependencies;
     artifact.missing_dependencies = missing_dependencies;
     artifact.build_dependencies = build_dependencies;
 
-    let (make_failed_dependencies, make_failed_module) = meta::recovery_meta(&self.storage).await?;
+    let (make_failed_dependencies, make_failed_module) =
+      meta::recovery_meta(&self.storage, &self.context).await?;
     artifact.make_failed_dependencies = make_failed_dependencies;
     artifact.make_failed_module = make_failed_module;
 
     let (partial, make_failed_dependencies) =
       module_graph::recovery_module_graph(&self.storage, &self.context).await?;
     artifact.module_graph_partial = partial;
+    // guarded modules are not saved, they can be collected from the build info
+    artifact.guarded_modules = artifact
+      .get_module_graph()
+      .modules()
+      .into_iter()
+      .filter(|(_, module)| {
+        module
+          .build_info()
+          .is_some_and(|build_info| !build_info.guarded_dependencies.is_empty())
+      })
+      .map(|(identifier, _)| identifier)
+      .collect();
     artifact
       .make_failed_dependencies
       .extend(make_failed_dependencies);
@@ -58,7 +58,7 @@ pub fn save_module_graph(
 ) {
   let mg = ModuleGraph::new(vec![partial], None);
   for identifier in revoked_modules {
-    storage.remove(SCOPE, identifier.as_bytes());
+    storage.remove(SCOPE, &context.to_portable_key(identifier));
   }
 
   // save module_graph
@@ -102,7 +102,7 @@ pub fn save_module_graph(
         blocks,
       };
       match to_bytes(&node, context) {
-        Ok(bytes) => Some((identifier.as_bytes().to_vec(), bytes)),
+        Ok(bytes) => Some((context.to_portable_key(identifier), bytes)),
         Err(err) => {
           if matches!(err, SerializeError::UnsupportedField) {
             tracing::warn!("to bytes failed {:?}", err);
@@ -1,3 +1,9 @@
+mod chunk_hashes;
+mod chunk_render;
+mod code_generate;
 mod make;
 
+pub use chunk_hashes::ChunkHashesOccasion;
+pub use chunk_render::ChunkRenderOccasion;
+pub use code_generate::CodeGenerateOccasion;
 pub use make::MakeOccasion;
@@ -0,0 +1,46 @@
+use std::hash::Hasher;
+
+use rspack_cacheable::to_portable;
+
+/// A hasher that writes the data to two hashers, the paths under project root
+/// are replaced with a placeholder for the portable one
+///
+/// The portable hash is used as the key of persistent cache, so the cache is still valid
+/// after the project is moved. Only the paths written in one piece are replaced, the data
+/// that writes a path by components, e.g. `Path::hash`, makes the portable hash non-portable,
+/// which only results in a cache miss.
+pub struct PortableHasher<'a> {
+  hasher: &'a mut dyn Hasher,
+  portable_hasher: &'a mut dyn Hasher,
+  project_root: &'a str,
+}
+
+impl<'a> PortableHasher<'a> {
+  pub fn new(
+    hasher: &'a mut dyn Hasher,
+    portable_hasher: &'a mut dyn Hasher,
+    project_root: &'a str,
+  ) -> Self {
+    Self {
+      hasher,
+      portable_hasher,
+      project_root,
+    }
+  }
+}
+
+impl Hasher for PortableHasher<'_> {
+  fn finish(&self) -> u64 {
+    self.hasher.finish()
+  }
+
+  fn write(&mut self, bytes: &[u8]) {
+    self.hasher.write(bytes);
+    match std::str::from_utf8(bytes) {
+      Ok(value) => self
+        .portable_hasher
+        .write(to_portable(value, self.project_root).as_bytes()),
+      Err(_) => self.portable_hasher.write(bytes),
+    }
+  }
+}
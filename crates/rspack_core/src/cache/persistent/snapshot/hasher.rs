@@ -0,0 +1,143 @@
+use std::{hash::Hasher, path::Path, sync::Arc};
+
+use dashmap::DashMap;
+use rayon::prelude::*;
+use rspack_cacheable::cacheable;
+use rspack_fs::ReadableFileSystem;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_paths::{ArcPath, AssertUtf8};
+
+/// The content hash of a file
+///
+/// The modified time and size are recorded so that the file is only read
+/// again when its metadata changes.
+#[cacheable]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileHash {
+  pub hash: String,
+  pub mtime: u64,
+  pub size: u64,
+}
+
+/// Content hasher of snapshot files
+///
+/// The hasher lives as long as the persistent cache, the hashes of files are
+/// cached by their metadata, so in watch mode each file is hashed at most
+/// once per change.
+#[derive(Debug)]
+pub struct ContentHasher {
+  fs: Arc<dyn ReadableFileSystem>,
+  cache: DashMap<ArcPath, FileHash>,
+}
+
+impl ContentHasher {
+  pub fn new(fs: Arc<dyn ReadableFileSystem>) -> Self {
+    Self {
+      fs,
+      cache: Default::default(),
+    }
+  }
+
+  /// Get the content hash of a file
+  ///
+  /// The `known` hash is returned directly if the metadata of file is not changed.
+  /// Return `None` if the path does not exist or is not a file.
+  pub fn hash(&self, path: &Path, known: Option<&FileHash>) -> Option<FileHash> {
+    let utf8_path = path.assert_utf8();
+    let metadata = self.fs.metadata(utf8_path).ok()?;
+    if !metadata.is_file {
+      return None;
+    }
+    let is_fresh = |file_hash: &FileHash| {
+      file_hash.mtime == metadata.mtime_ms && file_hash.size == metadata.size
+    };
+
+    if let Some(file_hash) = self.cache.get(path) {
+      if is_fresh(&file_hash) {
+        return Some(file_hash.clone());
+      }
+    }
+    let file_hash = match known {
+      Some(file_hash) if is_fresh(file_hash) => file_hash.clone(),
+      _ => {
+        let content = self.fs.read(utf8_path).ok()?;
+        let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+        hasher.write(&content);
+        FileHash {
+          hash: hasher.digest(&HashDigest::Hex).encoded().to_string(),
+          mtime: metadata.mtime_ms,
+          size: metadata.size,
+        }
+      }
+    };
+    self.cache.insert(path.into(), file_hash.clone());
+    Some(file_hash)
+  }
+
+  /// Hash files in parallel, the results are in the same order as `paths`
+  pub fn hash_all(&self, paths: &[(&Path, Option<&FileHash>)]) -> Vec<Option<FileHash>> {
+    paths
+      .par_iter()
+      .map(|(path, known)| self.hash(path, *known))
+      .collect()
+  }
+
+  pub fn remove(&self, path: &Path) {
+    self.cache.remove(path);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{path::Path, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+
+  use super::ContentHasher;
+
+  #[tokio::test]
+  async fn should_content_hasher_work() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/dir".into()).await.unwrap();
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file2".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/file3".into(), "abcd".as_bytes()).await.unwrap();
+
+    let hasher = ContentHasher::new(fs.clone());
+    let hashes = hasher.hash_all(&[
+      (Path::new("/file1"), None),
+      (Path::new("/file2"), None),
+      (Path::new("/file3"), None),
+      (Path::new("/dir"), None),
+      (Path::new("/file4"), None),
+    ]);
+    let file1 = hashes[0].clone().unwrap();
+    assert_eq!(file1.size, 3);
+    assert_eq!(file1.hash, hashes[1].as_ref().unwrap().hash);
+    assert_ne!(file1.hash, hashes[2].as_ref().unwrap().hash);
+    assert!(hashes[3].is_none());
+    assert!(hashes[4].is_none());
+
+    // known hash is trusted when metadata is not changed
+    let mut known = file1.clone();
+    known.hash = "known".into();
+    let hasher = ContentHasher::new(fs.clone());
+    assert_eq!(
+      hasher.hash(Path::new("/file1"), Some(&known)).unwrap().hash,
+      "known"
+    );
+
+    // touched file with same content has same hash
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    fs.write("/file1".into(), "abc".as_bytes()).await.unwrap();
+    let touched = hasher.hash(Path::new("/file1"), Some(&known)).unwrap();
+    assert_ne!(touched.mtime, file1.mtime);
+    assert_eq!(touched.hash, file1.hash);
+
+    fs.write("/file1".into(), "abcde".as_bytes()).await.unwrap();
+    assert_ne!(
+      hasher.hash(Path::new("/file1"), None).unwrap().hash,
+      file1.hash
+    );
+  }
+}
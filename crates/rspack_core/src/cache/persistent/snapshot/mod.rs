@@ -1,48 +1,112 @@
+mod hasher;
 mod option;
 mod strategy;
 
 use std::{path::Path, sync::Arc};
 
-use rspack_cacheable::{from_bytes, to_bytes};
+use rspack_cacheable::{from_bytes, from_portable, to_bytes, to_portable};
 use rspack_error::Result;
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, AssertUtf8};
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 pub use self::option::{PathMatcher, SnapshotOptions};
-use self::strategy::{Strategy, StrategyHelper, ValidateResult};
+use self::{
+  hasher::ContentHasher,
+  strategy::{Strategy, StrategyHelper, ValidateResult},
+};
 use super::storage::Storage;
+use crate::Context;
 
 const SCOPE: &str = "snapshot";
 
+/// A path which is modified or deleted since the snapshot was taken
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotChange {
+  pub deleted: bool,
+  /// The name of the strategy which detected the change
+  pub strategy: &'static str,
+}
+
+/// Changes detected by `Snapshot::calc_changes`
+#[derive(Debug, Default)]
+pub struct SnapshotChanges {
+  /// No snapshot is saved for the current cache version, which means
+  /// this is the first build or the cache version has changed.
+  pub empty: bool,
+  pub paths: HashMap<ArcPath, SnapshotChange>,
+}
+
+impl SnapshotChanges {
+  pub fn modified_paths(&self) -> HashSet<ArcPath> {
+    self
+      .paths
+      .iter()
+      .filter(|(_, change)| !change.deleted)
+      .map(|(path, _)| path.clone())
+      .collect()
+  }
+
+  pub fn deleted_paths(&self) -> HashSet<ArcPath> {
+    self
+      .paths
+      .iter()
+      .filter(|(_, change)| change.deleted)
+      .map(|(path, _)| path.clone())
+      .collect()
+  }
+}
+
 /// Snapshot is used to check if files have been modified or deleted.
 ///
 /// Snapshot will generate `Strategy` for target file, and check the modification
 /// through the generated `Strategy`
+///
+/// The paths under project root are saved with a placeholder, so that the snapshot
+/// can be reused after the project is moved to another directory.
 #[derive(Debug)]
 pub struct Snapshot {
   options: SnapshotOptions,
+  context: Context,
   fs: Arc<dyn ReadableFileSystem>,
+  hasher: Arc<ContentHasher>,
   storage: Arc<dyn Storage>,
 }
 
 impl Snapshot {
   pub fn new(
     options: SnapshotOptions,
+    context: Context,
     fs: Arc<dyn ReadableFileSystem>,
     storage: Arc<dyn Storage>,
   ) -> Self {
     Self {
       options,
+      context,
+      hasher: Arc::new(ContentHasher::new(fs.clone())),
       fs,
       storage,
     }
   }
 
+  fn get_key(&self, path: &Path) -> Vec<u8> {
+    to_portable(&path.to_string_lossy(), self.context.as_str())
+      .as_bytes()
+      .to_vec()
+  }
+
+  fn get_path(&self, key: &[u8]) -> ArcPath {
+    Path::new(&*from_portable(
+      &String::from_utf8_lossy(key),
+      self.context.as_str(),
+    ))
+    .into()
+  }
+
   pub async fn add(&self, paths: impl Iterator<Item = &Path>) {
     let default_strategy = StrategyHelper::compile_time();
-    let mut helper = StrategyHelper::new(self.fs.clone());
-    // TODO use multi thread
+    let mut helper = StrategyHelper::new(self.fs.clone(), self.hasher.clone());
+    let mut hash_paths = vec![];
     // TODO merge package version file
     for path in paths {
       let utf8_path = path.assert_utf8();
@@ -59,50 +123,104 @@ impl Snapshot {
         if let Some(v) = helper.package_version(path).await {
           self.storage.set(
             SCOPE,
-            path.as_os_str().as_encoded_bytes().to_vec(),
+            self.get_key(path),
             to_bytes::<_, ()>(&v, &()).expect("should to bytes success"),
           );
           continue;
         }
       }
+      if self.options.is_hash_path(path_str) {
+        hash_paths.push((path, None));
+        continue;
+      }
       // compiler time
       self.storage.set(
         SCOPE,
-        path.as_os_str().as_encoded_bytes().to_vec(),
+        self.get_key(path),
         to_bytes::<_, ()>(&default_strategy, &()).expect("should to bytes success"),
       );
     }
+
+    // content hash, directories fallback to compile time
+    let hashes = self.hasher.hash_all(&hash_paths);
+    for ((path, _), hash) in hash_paths.into_iter().zip(hashes) {
+      let bytes = match hash {
+        Some(hash) => to_bytes::<_, ()>(&Strategy::ContentHash(hash), &()),
+        None => to_bytes::<_, ()>(&default_strategy, &()),
+      };
+      self.storage.set(
+        SCOPE,
+        self.get_key(path),
+        bytes.expect("should to bytes success"),
+      );
+    }
   }
 
   pub fn remove(&self, paths: impl Iterator<Item = &Path>) {
     for item in paths {
-      self
-        .storage
-        .remove(SCOPE, item.as_os_str().as_encoded_bytes())
+      self.hasher.remove(item);
+      self.storage.remove(SCOPE, &self.get_key(item))
     }
   }
 
   pub async fn calc_modified_paths(&self) -> Result<(HashSet<ArcPath>, HashSet<ArcPath>)> {
-    let mut helper = StrategyHelper::new(self.fs.clone());
-    let mut modified_path = HashSet::default();
-    let mut deleted_path = HashSet::default();
-
-    // TODO use multi thread
-    for (key, value) in self.storage.load(SCOPE).await? {
-      let path: ArcPath = Path::new(&*String::from_utf8_lossy(&key)).into();
-      let strategy: Strategy =
-        from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
-      match helper.validate(&path, &strategy).await {
-        ValidateResult::Modified => {
-          modified_path.insert(path);
-        }
-        ValidateResult::Deleted => {
-          deleted_path.insert(path);
+    let changes = self.calc_changes().await?;
+    Ok((changes.modified_paths(), changes.deleted_paths()))
+  }
+
+  pub async fn calc_changes(&self) -> Result<SnapshotChanges> {
+    let mut helper = StrategyHelper::new(self.fs.clone(), self.hasher.clone());
+
+    let items = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_iter()
+      .map(|(key, value)| {
+        let strategy: Strategy =
+          from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
+        (self.get_path(&key), strategy)
+      })
+      .collect::<Vec<_>>();
+
+    // hash the files in parallel first, validate will use the cached hashes
+    let hash_paths = items
+      .iter()
+      .filter_map(|(path, strategy)| match strategy {
+        Strategy::ContentHash(hash) => Some((path.as_ref(), Some(hash))),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    self.hasher.hash_all(&hash_paths);
+
+    let mut changes = SnapshotChanges {
+      empty: items.is_empty(),
+      paths: Default::default(),
+    };
+    for (path, strategy) in items {
+      let deleted = match helper.validate(&path, &strategy).await {
+        ValidateResult::Modified => false,
+        ValidateResult::Deleted => true,
+        ValidateResult::NoChanged => continue,
+        ValidateResult::Refreshed(strategy) => {
+          // save the refreshed metadata, so the file is not read again in the next build
+          self.storage.set(
+            SCOPE,
+            self.get_key(&path),
+            to_bytes::<_, ()>(&strategy, &()).expect("should to bytes success"),
+          );
+          continue;
         }
-        ValidateResult::NoChanged => {}
-      }
+      };
+      changes.paths.insert(
+        path,
+        SnapshotChange {
+          deleted,
+          strategy: strategy.name(),
+        },
+      );
     }
-    Ok((modified_path, deleted_path))
+    Ok(changes)
   }
 }
 
@@ -110,10 +228,13 @@ impl Snapshot {
 mod tests {
   use std::sync::Arc;
 
+  use rspack_cacheable::from_bytes;
+  use rspack_fs::ReadableFileSystem;
   use rspack_fs::{MemoryFileSystem, WritableFileSystem};
 
-  use super::super::storage::MemoryStorage;
-  use super::{PathMatcher, Snapshot, SnapshotOptions};
+  use super::super::storage::{MemoryStorage, Storage};
+  use super::{PathMatcher, Snapshot, SnapshotChange, SnapshotOptions, Strategy, SCOPE};
+  use crate::Context;
 
   macro_rules! p {
     ($tt:tt) => {
@@ -129,6 +250,7 @@ mod tests {
       vec![PathMatcher::String("constant".into())],
       vec![PathMatcher::String("node_modules/project".into())],
       vec![PathMatcher::String("node_modules".into())],
+      vec![],
     );
 
     fs.create_dir_all("/node_modules/project".into())
@@ -158,7 +280,7 @@ mod tests {
       .await
       .unwrap();
 
-    let snapshot = Snapshot::new(options, fs.clone(), storage);
+    let snapshot = Snapshot::new(options, Context::from("/"), fs.clone(), storage);
 
     snapshot
       .add(
@@ -204,4 +326,182 @@ mod tests {
     assert!(modified_paths.contains(p!("/node_modules/project/file1")));
     assert!(modified_paths.contains(p!("/node_modules/lib/file1")));
   }
+
+  #[tokio::test]
+  async fn should_snapshot_rebase_project_root() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let options = SnapshotOptions::new(vec![], vec![], vec![], vec![]);
+
+    fs.create_dir_all("/builds/1/app".into()).await.unwrap();
+    fs.create_dir_all("/builds/2/app".into()).await.unwrap();
+    fs.write("/builds/1/app/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/builds/1/app/file2".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/builds/2/app/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let snapshot = Snapshot::new(
+      options.clone(),
+      Context::from("/builds/1/app"),
+      fs.clone(),
+      storage.clone(),
+    );
+    snapshot
+      .add([p!("/builds/1/app/file1"), p!("/builds/1/app/file2")].into_iter())
+      .await;
+
+    // the project is moved to another directory
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/builds/2/app/file1".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    let snapshot = Snapshot::new(options, Context::from("/builds/2/app"), fs.clone(), storage);
+    let (modified_paths, deleted_paths) = snapshot.calc_modified_paths().await.unwrap();
+    assert_eq!(modified_paths.len(), 1);
+    assert!(modified_paths.contains(p!("/builds/2/app/file1")));
+    assert_eq!(deleted_paths.len(), 1);
+    assert!(deleted_paths.contains(p!("/builds/2/app/file2")));
+
+    snapshot.remove([p!("/builds/2/app/file2")].into_iter());
+    let (_, deleted_paths) = snapshot.calc_modified_paths().await.unwrap();
+    assert!(deleted_paths.is_empty());
+  }
+
+  #[tokio::test]
+  async fn should_snapshot_content_hash_work() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let options = SnapshotOptions::new(
+      vec![],
+      vec![],
+      vec![PathMatcher::String("node_modules".into())],
+      vec![PathMatcher::String("src".into())],
+    );
+
+    fs.create_dir_all("/src/dir".into()).await.unwrap();
+    fs.create_dir_all("/node_modules/lib".into()).await.unwrap();
+    fs.write("/src/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/src/file2".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/file3".into(), "abc".as_bytes()).await.unwrap();
+    fs.write(
+      "/node_modules/lib/package.json".into(),
+      r#"{"version":"1.0.0"}"#.as_bytes(),
+    )
+    .await
+    .unwrap();
+    fs.write("/node_modules/lib/src".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+
+    let snapshot = Snapshot::new(options, Context::from("/"), fs.clone(), storage);
+    snapshot
+      .add(
+        [
+          p!("/src/file1"),
+          p!("/src/file2"),
+          p!("/src/dir"),
+          p!("/file3"),
+          p!("/node_modules/lib/src"),
+        ]
+        .into_iter(),
+      )
+      .await;
+
+    // files are touched by checkout, only file2 has different content
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/src/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/src/file2".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/file3".into(), "abc".as_bytes()).await.unwrap();
+    fs.write("/node_modules/lib/src".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+
+    let (modified_paths, deleted_paths) = snapshot.calc_modified_paths().await.unwrap();
+    assert!(deleted_paths.is_empty());
+    assert!(!modified_paths.contains(p!("/src/file1")));
+    assert!(modified_paths.contains(p!("/src/file2")));
+    assert!(!modified_paths.contains(p!("/src/dir")));
+    assert!(modified_paths.contains(p!("/file3")));
+    assert!(!modified_paths.contains(p!("/node_modules/lib/src")));
+
+    // the refreshed metadata of file1 is saved
+    let items = snapshot.storage.load(SCOPE).await.unwrap();
+    let (_, value) = items
+      .iter()
+      .find(|(key, _)| **key == snapshot.get_key(p!("/src/file1")))
+      .unwrap();
+    let Strategy::ContentHash(file_hash) = from_bytes::<Strategy, ()>(value, &()).unwrap() else {
+      unreachable!()
+    };
+    assert_eq!(
+      file_hash.mtime,
+      fs.metadata("/src/file1".into()).unwrap().mtime_ms
+    );
+
+    fs.remove_file("/src/file1".into()).await.unwrap();
+    let (_, deleted_paths) = snapshot.calc_modified_paths().await.unwrap();
+    assert!(deleted_paths.contains(p!("/src/file1")));
+  }
+
+  #[tokio::test]
+  async fn should_snapshot_report_changes() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let options = SnapshotOptions::new(
+      vec![],
+      vec![],
+      vec![],
+      vec![PathMatcher::String("src".into())],
+    );
+
+    fs.create_dir_all("/src".into()).await.unwrap();
+    fs.write("/src/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/file2".into(), "abc".as_bytes()).await.unwrap();
+
+    let snapshot = Snapshot::new(options, Context::from("/"), fs.clone(), storage);
+    let changes = snapshot.calc_changes().await.unwrap();
+    assert!(changes.empty);
+    assert!(changes.paths.is_empty());
+
+    snapshot
+      .add([p!("/src/file1"), p!("/file2")].into_iter())
+      .await;
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/src/file1".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    fs.remove_file("/file2".into()).await.unwrap();
+
+    let changes = snapshot.calc_changes().await.unwrap();
+    assert!(!changes.empty);
+    assert_eq!(
+      changes.paths.get(p!("/src/file1")),
+      Some(&SnapshotChange {
+        deleted: false,
+        strategy: "content hash"
+      })
+    );
+    assert_eq!(
+      changes.paths.get(p!("/file2")),
+      Some(&SnapshotChange {
+        deleted: true,
+        strategy: "compile time"
+      })
+    );
+  }
 }
@@ -9,6 +9,8 @@ use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, AssertUtf8};
 use rustc_hash::FxHashMap as HashMap;
 
+use super::hasher::{ContentHasher, FileHash};
+
 /// Snapshot check strategy
 #[cacheable]
 #[derive(Debug, PartialEq)]
@@ -23,6 +25,22 @@ pub enum Strategy {
   ///
   /// This strategy will compare the compile time and the file update time
   CompileTime(u64),
+
+  /// Check by content hash
+  ///
+  /// This strategy will compare the hash of file content, the file is only
+  /// read again when its modified time or size changes.
+  ContentHash(FileHash),
+}
+
+impl Strategy {
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::PackageVersion(_) => "package version",
+      Self::CompileTime(_) => "compile time",
+      Self::ContentHash(_) => "content hash",
+    }
+  }
 }
 
 /// Validate Result
@@ -34,17 +52,22 @@ pub enum ValidateResult {
   Modified,
   /// The target file has no changed
   NoChanged,
+  /// The target file has no changed, but the strategy is refreshed and should be saved,
+  /// e.g. the modified time of file is changed without changing the content
+  Refreshed(Strategy),
 }
 
 pub struct StrategyHelper {
   fs: Arc<dyn ReadableFileSystem>,
+  hasher: Arc<ContentHasher>,
   package_version_cache: HashMap<ArcPath, Option<String>>,
 }
 
 impl StrategyHelper {
-  pub fn new(fs: Arc<dyn ReadableFileSystem>) -> Self {
+  pub fn new(fs: Arc<dyn ReadableFileSystem>, hasher: Arc<ContentHasher>) -> Self {
     Self {
       fs,
+      hasher,
       package_version_cache: Default::default(),
     }
   }
@@ -131,6 +154,19 @@ impl StrategyHelper {
           ValidateResult::Deleted
         }
       }
+      Strategy::ContentHash(file_hash) => {
+        if let Some(cur_hash) = self.hasher.hash(path, Some(file_hash)) {
+          if cur_hash == *file_hash {
+            ValidateResult::NoChanged
+          } else if cur_hash.hash == file_hash.hash {
+            ValidateResult::Refreshed(Strategy::ContentHash(cur_hash))
+          } else {
+            ValidateResult::Modified
+          }
+        } else {
+          ValidateResult::Deleted
+        }
+      }
     }
   }
 }
@@ -141,7 +177,7 @@ mod tests {
 
   use rspack_fs::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
 
-  use super::{Strategy, StrategyHelper, ValidateResult};
+  use super::{ContentHasher, Strategy, StrategyHelper, ValidateResult};
 
   #[tokio::test]
   async fn should_strategy_works() {
@@ -172,7 +208,7 @@ mod tests {
     };
     assert!(time1 < time2);
 
-    let mut helper = StrategyHelper::new(fs.clone());
+    let mut helper = StrategyHelper::new(fs.clone(), Arc::new(ContentHasher::new(fs.clone())));
     // modified_time
     assert_eq!(
       helper.modified_time(Path::new("/file1")).await,
@@ -253,6 +289,38 @@ mod tests {
       ValidateResult::Deleted
     ));
 
+    // content_hash
+    let hash = Strategy::ContentHash(helper.hasher.hash(Path::new("/file1"), None).unwrap());
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &hash).await,
+      ValidateResult::NoChanged
+    ));
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    fs.write("/file1".into(), "abcd".as_bytes()).await.unwrap();
+    let ValidateResult::Refreshed(Strategy::ContentHash(refreshed)) =
+      helper.validate(Path::new("/file1"), &hash).await
+    else {
+      unreachable!()
+    };
+    assert_eq!(
+      refreshed.mtime,
+      fs.metadata("/file1".into()).unwrap().mtime_ms
+    );
+    let refreshed = Strategy::ContentHash(refreshed);
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &refreshed).await,
+      ValidateResult::NoChanged
+    ));
+    fs.write("/file1".into(), "abcde".as_bytes()).await.unwrap();
+    assert!(matches!(
+      helper.validate(Path::new("/file1"), &hash).await,
+      ValidateResult::Modified
+    ));
+    assert!(matches!(
+      helper.validate(Path::new("/file2"), &hash).await,
+      ValidateResult::Deleted
+    ));
+
     let version = Strategy::PackageVersion("1.0.0".into());
     assert!(matches!(
       helper
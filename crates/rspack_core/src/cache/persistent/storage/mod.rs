@@ -6,7 +6,10 @@ use std::{path::PathBuf, sync::Arc};
 pub use memory::MemoryStorage;
 use rspack_fs::IntermediateFileSystem;
 pub use rspack_storage::Storage;
-use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
+use rspack_storage::{
+  BridgeFileSystem, HttpRemoteStore, HttpRemoteStoreOptions, PackCompression, PackStorage,
+  PackStorageOptions, RemoteStorage, RemoteStorageOptions,
+};
 
 /// Storage Options
 ///
@@ -14,7 +17,27 @@ use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
 /// Since MemoryStorage is only used in unit test, there is no need to add it here.
 #[derive(Debug, Clone)]
 pub enum StorageOptions {
-  FileSystem { directory: PathBuf },
+  FileSystem {
+    directory: PathBuf,
+    remote: Option<RemoteStoreOptions>,
+    /// Compress the pack files with deflate
+    compression: bool,
+    /// The max size of the cache directory in bytes, the least recently used versions
+    /// are removed first when exceeded
+    max_size: Option<u64>,
+  },
+}
+
+/// Remote Store Options
+///
+/// The filesystem storage pulls the missing scopes from the http store at `url`,
+/// and uploads the saved scopes when `push` is enabled.
+#[derive(Debug, Clone)]
+pub struct RemoteStoreOptions {
+  pub url: String,
+  pub push: bool,
+  pub connect_timeout: u64,
+  pub read_timeout: u64,
 }
 
 pub fn create_storage(
@@ -23,20 +46,52 @@ pub fn create_storage(
   fs: Arc<dyn IntermediateFileSystem>,
 ) -> Arc<dyn Storage> {
   match options {
-    StorageOptions::FileSystem { directory } => {
+    StorageOptions::FileSystem {
+      directory,
+      remote,
+      compression,
+      max_size,
+    } => {
       let option = PackStorageOptions {
         temp_root: directory.join(".temp"),
         root: directory,
         clean: true,
         bucket_size: 20,
         pack_size: 500 * 1024,
+        compression: if compression {
+          PackCompression::Deflate(6)
+        } else {
+          PackCompression::None
+        },
         expire: 7 * 24 * 60 * 60 * 1000,
+        max_size,
         fs: Arc::new(BridgeFileSystem(fs)),
         fresh_generation: Some(1),
         release_generation: Some(2),
-        version,
+        lock_timeout: 30 * 1000,
+        version: version.clone(),
+      };
+      let local = Arc::new(PackStorage::new(option));
+      let Some(remote) = remote else {
+        return local;
       };
-      Arc::new(PackStorage::new(option))
+      match HttpRemoteStore::new(HttpRemoteStoreOptions {
+        url: remote.url,
+        connect_timeout: remote.connect_timeout,
+        read_timeout: remote.read_timeout,
+      }) {
+        Ok(store) => Arc::new(RemoteStorage::new(RemoteStorageOptions {
+          local,
+          store: Arc::new(store),
+          version,
+          push: remote.push,
+        })),
+        Err(err) => {
+          // an invalid url only disables the remote store
+          tracing::warn!("create remote storage failed {err}");
+          local
+        }
+      }
     }
   }
 }
@@ -37,21 +37,25 @@ use super::{
 };
 use crate::{
   build_chunk_graph::build_chunk_graph,
-  cache::Cache,
-  get_runtime_key,
+  cache::{
+    persistent::{CacheMissInfo, PortableHasher},
+    Cache,
+  },
+  get_code_generation_cache_key, get_runtime_key,
   incremental::{Incremental, IncrementalPasses, Mutation},
   is_source_equal,
   old_cache::{use_code_splitting_cache, Cache as OldCache, CodeSplittingCache},
   to_identifier, AsyncModulesArtifact, BoxDependency, BoxModule, CacheCount, CacheOptions,
   CgcRuntimeRequirementsArtifact, CgmHashArtifact, CgmRuntimeRequirementsArtifact, Chunk,
   ChunkByUkey, ChunkContentHash, ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkHashesArtifact,
-  ChunkIdsArtifact, ChunkKind, ChunkRenderArtifact, ChunkRenderResult, ChunkUkey,
-  CodeGenerationJob, CodeGenerationResult, CodeGenerationResults, CompilationLogger,
-  CompilationLogging, CompilerOptions, DependenciesDiagnosticsArtifact, DependencyId,
-  DependencyType, Entry, EntryData, EntryOptions, EntryRuntime, Entrypoint, ExecuteModuleId,
-  Filename, ImportVarMap, LocalFilenameFn, Logger, ModuleFactory, ModuleGraph, ModuleGraphPartial,
-  ModuleIdentifier, ModuleIdsArtifact, PathData, ResolverFactory, RuntimeGlobals, RuntimeModule,
-  RuntimeSpecMap, SharedPluginDriver, SideEffectsOptimizeArtifact, SourceType, Stats,
+  ChunkIdsArtifact, ChunkKind, ChunkRenderArtifact, ChunkRenderCacheArtifact, ChunkRenderResult,
+  ChunkUkey, CodeGenerationCacheArtifact, CodeGenerationJob, CodeGenerationResult,
+  CodeGenerationResults, CompilationLogger, CompilationLogging, CompilerOptions,
+  DependenciesDiagnosticsArtifact, DependencyId, DependencyType, Entry, EntryData, EntryOptions,
+  EntryRuntime, Entrypoint, ExecuteModuleId, ExperimentCacheOptions, Filename, ImportVarMap,
+  LocalFilenameFn, Logger, ModuleFactory, ModuleGraph, ModuleGraphPartial, ModuleIdentifier,
+  ModuleIdsArtifact, PathData, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpecMap,
+  SharedPluginDriver, SideEffectsOptimizeArtifact, SourceType, Stats,
 };
 
 pub type BuildDependency = (
@@ -203,6 +207,10 @@ pub struct Compilation {
   pub chunk_hashes_artifact: ChunkHashesArtifact,
   // artifact for create_chunk_assets
   pub chunk_render_artifact: ChunkRenderArtifact,
+  // artifact for code_generation recovered from persistent cache
+  pub code_generation_cache_artifact: CodeGenerationCacheArtifact,
+  // artifact for create_chunk_assets shared with persistent cache
+  pub chunk_render_cache_artifact: ChunkRenderCacheArtifact,
 
   pub code_generated_modules: IdentifierSet,
   pub build_time_executed_modules: IdentifierSet,
@@ -227,6 +235,8 @@ pub struct Compilation {
 
   pub modified_files: HashSet<ArcPath>,
   pub removed_files: HashSet<ArcPath>,
+  // changes found by persistent cache to explain the cache misses of built modules
+  pub cache_miss_info: CacheMissInfo,
   pub make_artifact: MakeArtifact,
   pub input_filesystem: Arc<dyn ReadableFileSystem>,
 
@@ -313,6 +323,8 @@ impl Compilation {
       cgc_runtime_requirements_artifact: Default::default(),
       chunk_hashes_artifact: Default::default(),
       chunk_render_artifact: Default::default(),
+      code_generation_cache_artifact: Default::default(),
+      chunk_render_cache_artifact: Default::default(),
       code_generated_modules: Default::default(),
       build_time_executed_modules: Default::default(),
       cache,
@@ -337,6 +349,7 @@ impl Compilation {
       make_artifact: Default::default(),
       modified_files,
       removed_files,
+      cache_miss_info: Default::default(),
       input_filesystem,
 
       intermediate_filesystem,
@@ -524,6 +537,10 @@ impl Compilation {
         import_var
       }
     };
+    // a module may be imported both eagerly and by `import defer` in the same module
+    if module_dep.get_phase().is_defer() {
+      return format!("{import_var}_deferred");
+    }
     import_var
   }
 
@@ -826,12 +843,33 @@ impl Compilation {
 
     let artifact = std::mem::take(&mut self.make_artifact);
     self.make_artifact = make_module_graph(self, artifact).await?;
+    self.log_module_build_cache();
 
     self.in_finish_make.store(true, Ordering::Release);
 
     Ok(())
   }
 
+  fn log_module_build_cache(&self) {
+    if !self.cache_miss_info.is_enabled() {
+      return;
+    }
+    let logger = self.get_logger("rspack.Compilation");
+    let mut counter = logger.cache("module build cache");
+    let module_graph = self.get_module_graph();
+    for (identifier, module) in module_graph.modules() {
+      if !self.built_modules().contains(&identifier) {
+        counter.hit();
+        continue;
+      }
+      match self.cache_miss_info.module_reason(module.as_ref()) {
+        Some(reason) => counter.miss_with_reason(format!("{identifier}: {reason}")),
+        None => counter.miss(),
+      }
+    }
+    logger.cache_end(counter);
+  }
+
   pub async fn rebuild_module<T>(
     &mut self,
     module_identifiers: IdentifierSet,
@@ -938,6 +976,18 @@ impl Compilation {
       .into_par_iter()
       .map(|job| {
         let module = job.module;
+        // results of persistent cache are keyed by portable hash
+        if let Some(codegen_res) = self
+          .cgm_hash_artifact
+          .get_portable(&module, &job.runtime)
+          .and_then(|hash| {
+            self
+              .code_generation_cache_artifact
+              .get(&get_code_generation_cache_key(&module, hash))
+          })
+        {
+          return (module, (Ok(codegen_res.clone()), job.runtimes, true));
+        }
         (
           module,
           self
@@ -992,6 +1042,29 @@ impl Compilation {
 
   #[instrument(name = "compilation::create_module_assets", skip_all)]
   async fn create_module_assets(&mut self, _plugin_driver: SharedPluginDriver) {
+    // assets emitted by rust loaders are stored in the build info, so they are restored with the
+    // modules from cache
+    let loader_assets = self
+      .get_module_graph()
+      .modules()
+      .into_iter()
+      .flat_map(|(module_identifier, module)| {
+        module
+          .build_info()
+          .into_iter()
+          .flat_map(|build_info| &build_info.assets)
+          .map(move |(filename, source)| (module_identifier, filename.clone(), source.clone()))
+      })
+      .collect::<Vec<_>>();
+    for (module_identifier, filename, source) in loader_assets {
+      self.emit_asset(filename.clone(), CompilationAsset::from(source));
+      self
+        .module_assets
+        .entry(module_identifier)
+        .or_default()
+        .insert(filename);
+    }
+
     let mut temp = vec![];
     for (module_identifier, assets) in self.module_assets.iter() {
       // assets of executed modules are not in this compilation
@@ -1208,6 +1281,13 @@ impl Compilation {
           .difference(&self.make_artifact.revoked_modules)
           .map(|&module| Mutation::ModuleAdd { module }),
       );
+      mutations.extend(
+        self
+          .make_artifact
+          .updated_modules
+          .difference(&self.make_artifact.built_modules)
+          .map(|&module| Mutation::ModuleUpdate { module }),
+      );
     }
 
     let start = logger.time("finish modules");
@@ -1418,7 +1498,14 @@ impl Compilation {
     } else {
       self.get_module_graph().modules().keys().copied().collect()
     };
+    let cache = self.cache.clone();
+    if let Err(err) = cache.before_code_generation(self).await {
+      self.push_diagnostic(err.into());
+    }
     self.code_generation(code_generation_modules)?;
+    if let Err(err) = cache.after_code_generation(self).await {
+      self.push_diagnostic(err.into());
+    }
     logger.time_end(start);
 
     let start = logger.time("runtime requirements");
@@ -1498,35 +1585,48 @@ impl Compilation {
     logger.time_end(start);
 
     let start = logger.time("hashing");
-    let create_hash_chunks = if let Some(mutations) = self
-      .incremental
-      .mutations_read(IncrementalPasses::CHUNKS_HASHES)
-      && !self.chunk_hashes_artifact.is_empty()
-    {
-      let removed_chunks = mutations.iter().filter_map(|mutation| match mutation {
-        Mutation::ChunkRemove { chunk } => Some(*chunk),
-        _ => None,
-      });
-      for removed_chunk in removed_chunks {
-        self.chunk_hashes_artifact.remove(&removed_chunk);
-      }
+    if let Err(err) = cache.before_chunks_hashes(self).await {
+      self.push_diagnostic(err.into());
+    }
+    // the hashes are recovered from persistent cache when the chunk graph is unchanged
+    if self.hash.is_none() {
+      let create_hash_chunks = if let Some(mutations) = self
+        .incremental
+        .mutations_read(IncrementalPasses::CHUNKS_HASHES)
+        && !self.chunk_hashes_artifact.is_empty()
+      {
+        let removed_chunks = mutations.iter().filter_map(|mutation| match mutation {
+          Mutation::ChunkRemove { chunk } => Some(*chunk),
+          _ => None,
+        });
+        for removed_chunk in removed_chunks {
+          self.chunk_hashes_artifact.remove(&removed_chunk);
+        }
+        self
+          .chunk_hashes_artifact
+          .retain(|chunk, _| self.chunk_by_ukey.contains(chunk));
+        let chunks = mutations.get_affected_chunks_with_chunk_graph(self);
+        let logger = self.get_logger("rspack.incremental.chunksHashes");
+        logger.log(format!(
+          "{} chunks are affected, {} in total",
+          chunks.len(),
+          self.chunk_by_ukey.len(),
+        ));
+        chunks
+      } else {
+        self.chunk_by_ukey.keys().copied().collect()
+      };
+      let diagnostics_len = self.diagnostics.len();
       self
-        .chunk_hashes_artifact
-        .retain(|chunk, _| self.chunk_by_ukey.contains(chunk));
-      let chunks = mutations.get_affected_chunks_with_chunk_graph(self);
-      let logger = self.get_logger("rspack.incremental.chunksHashes");
-      logger.log(format!(
-        "{} chunks are affected, {} in total",
-        chunks.len(),
-        self.chunk_by_ukey.len(),
-      ));
-      chunks
-    } else {
-      self.chunk_by_ukey.keys().copied().collect()
-    };
-    self
-      .create_hash(create_hash_chunks, plugin_driver.clone())
-      .await?;
+        .create_hash(create_hash_chunks, plugin_driver.clone())
+        .await?;
+      // diagnostics of hashing can't be recovered, so only the hashes without diagnostics are saved
+      if self.diagnostics.len() == diagnostics_len
+        && let Err(err) = cache.after_chunks_hashes(self).await
+      {
+        self.push_diagnostic(err.into());
+      }
+    }
     self.runtime_modules_code_generation()?;
     logger.time_end(start);
 
@@ -1535,7 +1635,13 @@ impl Compilation {
     logger.time_end(start);
 
     let start = logger.time("create chunk assets");
+    if let Err(err) = cache.before_chunks_render(self).await {
+      self.push_diagnostic(err.into());
+    }
     self.create_chunk_assets(plugin_driver.clone()).await?;
+    if let Err(err) = cache.after_chunks_render(self).await {
+      self.push_diagnostic(err.into());
+    }
     logger.time_end(start);
 
     let start = logger.time("process assets");
@@ -2087,7 +2193,14 @@ impl Compilation {
   #[instrument(name = "compilation:create_module_hashes", skip_all)]
   pub fn create_module_hashes(&mut self, modules: IdentifierSet) -> Result<()> {
     let mg = self.get_module_graph();
-    let results: Vec<(ModuleIdentifier, RuntimeSpecMap<RspackHashDigest>)> = modules
+    // the portable hashes are only used as the key of persistent cache
+    let project_root = matches!(
+      self.options.experiments.cache,
+      ExperimentCacheOptions::Persistent(_)
+    )
+    .then(|| self.options.context.as_str());
+    type ModuleHashes = RuntimeSpecMap<RspackHashDigest>;
+    let results: Vec<(ModuleIdentifier, ModuleHashes, Option<ModuleHashes>)> = modules
       .into_par_iter()
       .map(|module| {
         (
@@ -2099,19 +2212,40 @@ impl Compilation {
       })
       .map(|(module_identifier, runtimes)| {
         let mut hashes = RuntimeSpecMap::new();
+        let mut portable_hashes = project_root.map(|_| RuntimeSpecMap::new());
         for runtime in runtimes.into_values() {
           let mut hasher = RspackHash::from(&self.options.output);
           let module = mg
             .module_by_identifier(&module_identifier)
             .expect("should have module");
-          module.update_hash(&mut hasher, self, Some(&runtime))?;
+          if let Some(project_root) = project_root
+            && let Some(portable_hashes) = &mut portable_hashes
+          {
+            let mut portable_hasher = RspackHash::from(&self.options.output);
+            module.update_hash(
+              &mut PortableHasher::new(&mut hasher, &mut portable_hasher, project_root),
+              self,
+              Some(&runtime),
+            )?;
+            portable_hashes.set(
+              runtime.clone(),
+              portable_hasher.digest(&self.options.output.hash_digest),
+            );
+          } else {
+            module.update_hash(&mut hasher, self, Some(&runtime))?;
+          }
           hashes.set(runtime, hasher.digest(&self.options.output.hash_digest));
         }
-        Ok((module_identifier, hashes))
+        Ok((module_identifier, hashes, portable_hashes))
       })
       .collect::<Result<_>>()?;
-    for (module, hashes) in results {
+    for (module, hashes, portable_hashes) in results {
       ChunkGraph::set_module_hashes(self, module, hashes);
+      if let Some(portable_hashes) = portable_hashes {
+        self
+          .cgm_hash_artifact
+          .set_portable_hashes(module, portable_hashes);
+      }
     }
     Ok(())
   }
@@ -0,0 +1,116 @@
+use rspack_collections::IdentifierSet;
+use rspack_util::atom::Atom;
+use rustc_hash::FxHashSet as HashSet;
+
+use super::MakeArtifact;
+use crate::{
+  BuildDependency, DependenciesBlock, DependencyId, DependencyType, ExportNameOrSpec,
+  ExportsOfExportsSpec, ModuleGraph, ModuleIdentifier,
+};
+
+/// Returns the dependencies of guarded branches that need to be built, i.e. the branches that are
+/// not ruled out by a constant export, e.g. `if (IS_DEV) { require("./devtools") }` with
+/// `export const IS_DEV = false`.
+///
+/// The exporting module may itself only be imported in a guarded branch, so this should be called
+/// until nothing is returned. `released` records the dependencies returned so far.
+///
+/// An importer that is not rebuilt in this make, e.g. when only the exporting module changed, is
+/// recorded in `updated_modules` so that its code generation is invalidated.
+pub fn release_guarded_dependencies(
+  artifact: &mut MakeArtifact,
+  released: &mut HashSet<DependencyId>,
+) -> HashSet<BuildDependency> {
+  let module_graph = artifact.get_module_graph();
+  let mut build_dependencies = HashSet::default();
+  let mut updated_modules = IdentifierSet::default();
+  for module_identifier in &artifact.guarded_modules {
+    let Some(build_info) = module_graph
+      .module_by_identifier(module_identifier)
+      .and_then(|module| module.build_info())
+    else {
+      continue;
+    };
+    // a dependency in nested branches is dead as soon as one of the branches is
+    let dead = build_info
+      .guarded_dependencies
+      .iter()
+      .filter(|guarded| {
+        get_guard_truthiness(
+          &module_graph,
+          module_identifier,
+          &guarded.request,
+          &guarded.ids,
+        )
+        .is_some_and(|truthy| truthy != guarded.truthy)
+      })
+      .flat_map(|guarded| &guarded.dependencies)
+      .collect::<HashSet<_>>();
+    for dependency_id in build_info
+      .guarded_dependencies
+      .iter()
+      .flat_map(|guarded| &guarded.dependencies)
+    {
+      if !dead.contains(dependency_id)
+        && module_graph
+          .connection_by_dependency_id(dependency_id)
+          .is_none()
+        && released.insert(*dependency_id)
+      {
+        build_dependencies.insert((*dependency_id, Some(*module_identifier)));
+        if !artifact.built_modules.contains(module_identifier) {
+          updated_modules.insert(*module_identifier);
+        }
+      }
+    }
+  }
+  artifact.updated_modules.extend(updated_modules);
+  build_dependencies
+}
+
+/// The exports info is not available during make, so the value of the export is read from the
+/// export dependencies of the imported module.
+fn get_guard_truthiness(
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+  request: &str,
+  ids: &[Atom],
+) -> Option<bool> {
+  let (name, properties) = ids.split_first()?;
+  let target = module_graph
+    .module_by_identifier(module)?
+    .get_dependencies()
+    .iter()
+    .find_map(|dependency_id| {
+      let dependency = module_graph.dependency_by_id(dependency_id)?;
+      if matches!(dependency.dependency_type(), DependencyType::EsmImport)
+        && dependency.as_module_dependency()?.request() == request
+      {
+        module_graph.module_identifier_by_dependency_id(dependency_id)
+      } else {
+        None
+      }
+    })?;
+  let inlinable = module_graph
+    .module_by_identifier(target)?
+    .get_dependencies()
+    .iter()
+    .find_map(|dependency_id| {
+      let dependency = module_graph.dependency_by_id(dependency_id)?;
+      if !matches!(
+        dependency.dependency_type(),
+        DependencyType::EsmExportSpecifier
+      ) {
+        return None;
+      }
+      let ExportsOfExportsSpec::Array(exports) = dependency.get_exports(module_graph)?.exports
+      else {
+        return None;
+      };
+      exports.into_iter().find_map(|export| match export {
+        ExportNameOrSpec::ExportSpec(spec) if &spec.name == name => spec.inlinable,
+        _ => None,
+      })
+    })?;
+  inlinable.truthiness(properties)
+}
@@ -1,4 +1,5 @@
 pub mod cutout;
+mod guarded_dependencies;
 pub mod repair;
 
 use rspack_collections::IdentifierSet;
@@ -6,7 +7,7 @@ use rspack_error::{Diagnostic, Result};
 use rspack_paths::ArcPath;
 use rustc_hash::FxHashSet as HashSet;
 
-use self::{cutout::Cutout, repair::repair};
+use self::{cutout::Cutout, guarded_dependencies::release_guarded_dependencies, repair::repair};
 use crate::{
   utils::FileCounter, BuildDependency, Compilation, DependencyId, ModuleGraph, ModuleGraphPartial,
   ModuleIdentifier,
@@ -20,6 +21,9 @@ pub struct MakeArtifact {
   pub has_module_graph_change: bool,
   pub built_modules: IdentifierSet,
   pub revoked_modules: IdentifierSet,
+  // modules that are not rebuilt but have new outgoing connections,
+  // e.g. a guarded branch of the module becomes live
+  pub updated_modules: IdentifierSet,
   // Field to mark whether artifact has been initialized.
   // Only Default::default() is false, `update_module_graph` will set this field to true
   // Persistent cache will update MakeArtifact when this is false.
@@ -30,6 +34,8 @@ pub struct MakeArtifact {
   pub make_failed_module: IdentifierSet,
   pub module_graph_partial: ModuleGraphPartial,
   pub entry_dependencies: HashSet<DependencyId>,
+  // modules with dependencies in guarded branches, see `release_guarded_dependencies`
+  pub guarded_modules: IdentifierSet,
   pub file_dependencies: FileCounter,
   pub context_dependencies: FileCounter,
   pub missing_dependencies: FileCounter,
@@ -75,6 +81,7 @@ impl MakeArtifact {
         .build_dependencies
         .remove_batch_file(&build_info.build_dependencies);
     }
+    self.guarded_modules.remove(module_identifier);
     self.revoked_modules.insert(*module_identifier);
     module_graph.revoke_module(module_identifier)
   }
@@ -134,6 +141,7 @@ pub async fn make_module_graph(
   // reset temporary data
   artifact.built_modules = Default::default();
   artifact.revoked_modules = Default::default();
+  artifact.updated_modules = Default::default();
   artifact.diagnostics = Default::default();
   if artifact.initialized {
     artifact.has_module_graph_change = false;
@@ -152,6 +160,15 @@ pub async fn update_module_graph(
   let mut cutout = Cutout::default();
   let build_dependencies = cutout.cutout_artifact(&mut artifact, params);
   artifact = repair(compilation, artifact, build_dependencies).await?;
+  let mut released_dependencies = HashSet::default();
+  loop {
+    let build_dependencies =
+      release_guarded_dependencies(&mut artifact, &mut released_dependencies);
+    if build_dependencies.is_empty() {
+      break;
+    }
+    artifact = repair(compilation, artifact, build_dependencies).await?;
+  }
   cutout.fix_artifact(&mut artifact);
   Ok(artifact)
 }
@@ -2,6 +2,7 @@ use std::{collections::VecDeque, sync::Arc};
 
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray};
 use rspack_fs::ReadableFileSystem;
+use rustc_hash::FxHashSet as HashSet;
 
 use super::{process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
@@ -195,7 +196,21 @@ impl Task<MakeTaskContext> for BuildResultTask {
       }
     }
 
+    // dependencies in guarded branches are processed after the module graph is built,
+    // once it is known whether the branch is live, see `release_guarded_dependencies`
+    let guarded_dependencies = module
+      .build_info()
+      .into_iter()
+      .flat_map(|build_info| &build_info.guarded_dependencies)
+      .flat_map(|guarded| guarded.dependencies.iter().copied())
+      .collect::<HashSet<_>>();
     let module_identifier = module.identifier();
+    if guarded_dependencies.is_empty() {
+      artifact.guarded_modules.remove(&module_identifier);
+    } else {
+      all_dependencies.retain(|dependency_id| !guarded_dependencies.contains(dependency_id));
+      artifact.guarded_modules.insert(module_identifier);
+    }
 
     module_graph.add_module(module);
 
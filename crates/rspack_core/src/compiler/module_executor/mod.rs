@@ -71,6 +71,7 @@ impl ModuleExecutor {
     }
     make_artifact.built_modules = Default::default();
     make_artifact.revoked_modules = Default::default();
+    make_artifact.updated_modules = Default::default();
     make_artifact.diagnostics = Default::default();
     make_artifact.has_module_graph_change = false;
 
@@ -207,6 +207,15 @@ pub struct ExternalModuleInfo {
   pub interop_default_access_used: bool,
   pub interop_default_access_name: Option<Atom>,
   pub name: Option<Atom>,
+  /// A CommonJS module inlined into the concatenated module, which is evaluated in a
+  /// function wrapper instead of being required by its module id
+  pub inlined: bool,
+  pub source: Option<Arc<dyn Source>>,
+  pub runtime_requirements: RuntimeGlobals,
+  pub chunk_init_fragments: ChunkInitFragments,
+  /// Free variables of the inlined module, which must not be shadowed by the top level
+  /// declarations of the concatenated module
+  pub free_names: HashSet<Atom>,
 }
 
 pub struct ConnectionWithRuntimeCondition<'a> {
@@ -559,6 +568,10 @@ impl Module for ConcatenatedModule {
       json_data: Default::default(),
       top_level_declarations: Some(Default::default()),
       module_concatenation_bailout: Default::default(),
+      pure_functions: Default::default(),
+      guarded_dependencies: Default::default(),
+      side_effects_bailout: Default::default(),
+      assets: Default::default(),
     };
     self.clear_diagnostics();
 
@@ -579,6 +592,14 @@ impl Module for ConcatenatedModule {
         build_info.cacheable = false;
       }
 
+      // populate assets
+      build_info.assets.extend(
+        cur_build_info
+          .assets
+          .iter()
+          .map(|(filename, source)| (filename.clone(), source.clone())),
+      );
+
       // populate dependencies
       for dep_id in module.get_dependencies().iter() {
         let dep = module_graph
@@ -674,8 +695,13 @@ impl Module for ConcatenatedModule {
     let mut top_level_declarations: HashSet<Atom> = HashSet::default();
 
     for module_info_id in modules_with_info.iter() {
-      let Some(ModuleInfo::Concatenated(info)) = module_to_info_map.get_mut(module_info_id) else {
-        continue;
+      let info = match module_to_info_map.get_mut(module_info_id) {
+        Some(ModuleInfo::Concatenated(info)) => info,
+        Some(ModuleInfo::External(info)) => {
+          all_used_names.extend(info.free_names.iter().cloned());
+          continue;
+        }
+        None => continue,
       };
       if let Some(ref ast) = info.ast {
         let mut collector = IdentCollector::default();
@@ -1207,6 +1233,32 @@ impl Module for ConcatenatedModule {
           runtime_requirements = runtime_requirements.union(info.runtime_requirements);
           name = info.namespace_object_name.clone();
         }
+        ModuleInfo::External(info) if info.inlined => {
+          result.add(RawStringSource::from(format!(
+            "\n;// INLINED COMMONJS MODULE: {module_readable_identifier}\n"
+          )));
+          let build_meta = box_module.build_meta().expect("should have build meta");
+          let var_name = info.name.as_ref().expect("should have name");
+          // the module and exports objects only live in the wrapper, `this` is the exports at the top level
+          result.add(RawStringSource::from(format!(
+            "var {var_name} = {{ exports: {{}} }};\n(function ({}, {}) {{\n",
+            build_meta.module_argument, build_meta.exports_argument
+          )));
+          result.add(info.source.clone().expect("should have source"));
+          result.add(RawStringSource::from(format!(
+            "\n}}).call({var_name}.exports, {var_name}, {var_name}.exports);\n{var_name} = {var_name}.exports;"
+          )));
+
+          for f in info.chunk_init_fragments.iter() {
+            chunk_init_fragments.push(f.clone());
+          }
+          let mut inlined_runtime_requirements = info.runtime_requirements;
+          inlined_runtime_requirements.remove(
+            RuntimeGlobals::MODULE | RuntimeGlobals::EXPORTS | RuntimeGlobals::THIS_AS_EXPORTS,
+          );
+          runtime_requirements = runtime_requirements.union(inlined_runtime_requirements);
+          name = info.name.clone();
+        }
         ModuleInfo::External(info) => {
           result.add(RawStringSource::from(format!(
             "\n// EXTERNAL MODULE: {module_readable_identifier}\n"
@@ -1464,6 +1516,27 @@ impl ConcatenatedModule {
         }
         indexmap::map::Entry::Vacant(vac) => {
           match concatenation_entry {
+            ConcatenationEntry::Concatenated(_) if is_inlined_commonjs_module(mg, &module_id) => {
+              let info = ExternalModuleInfo {
+                index: i,
+                module: module_id,
+                runtime_condition: RuntimeCondition::Boolean(true),
+                interop_namespace_object_used: false,
+                interop_namespace_object_name: None,
+                interop_namespace_object2_used: false,
+                interop_namespace_object2_name: None,
+                interop_default_access_used: false,
+                interop_default_access_name: None,
+                name: None,
+                inlined: true,
+                source: None,
+                runtime_requirements: RuntimeGlobals::default(),
+                chunk_init_fragments: Default::default(),
+                free_names: Default::default(),
+              };
+              vac.insert(ModuleInfo::External(info));
+              list.push(module_id);
+            }
             ConcatenationEntry::Concatenated(_) => {
               let info = ConcatenatedModuleInfo {
                 index: i,
@@ -1485,6 +1558,11 @@ impl ConcatenatedModule {
                 interop_default_access_used: false,
                 interop_default_access_name: None,
                 name: None,
+                inlined: false,
+                source: None,
+                runtime_requirements: RuntimeGlobals::default(),
+                chunk_init_fragments: Default::default(),
+                free_names: Default::default(),
               };
               vac.insert(ModuleInfo::External(info));
               list.push(module_id)
@@ -1780,6 +1858,39 @@ impl ConcatenatedModule {
       module_info.source = Some(result_source);
       module_info.chunk_init_fragments = chunk_init_fragments;
       Ok(ModuleInfo::Concatenated(Box::new(module_info)))
+    } else if let ModuleInfo::External(mut info) = info {
+      if !info.inlined {
+        return Ok(ModuleInfo::External(info));
+      }
+      // the inlined CommonJS module is generated as a standalone module and wrapped later
+      let module_graph = compilation.get_module_graph();
+      let module = module_graph
+        .module_by_identifier(&info.module)
+        .unwrap_or_else(|| panic!("should have module {}", info.module));
+      let codegen_res = module.code_generation(compilation, runtime, None)?;
+      let CodeGenerationResult {
+        mut inner,
+        mut chunk_init_fragments,
+        runtime_requirements,
+        ..
+      } = codegen_res;
+      if let Some(fragments) = codegen_res.data.get::<ChunkInitFragments>() {
+        chunk_init_fragments.extend(fragments.iter().cloned());
+      }
+      info.source = Some(
+        inner
+          .remove(&SourceType::JavaScript)
+          .expect("should have javascript source"),
+      );
+      info.free_names = collect_free_names(
+        &info.source.as_ref().expect("should have source").source(),
+        module
+          .readable_identifier(&compilation.options.context)
+          .to_string(),
+      )?;
+      info.runtime_requirements = runtime_requirements;
+      info.chunk_init_fragments = chunk_init_fragments;
+      Ok(ModuleInfo::External(info))
     } else {
       Ok(info)
     }
@@ -2222,6 +2333,58 @@ impl ConcatenatedModule {
   }
 }
 
+/// Collect the free variables of an inlined module, the code is parsed in a function
+/// wrapper like it is rendered, so top level `return` is allowed.
+fn collect_free_names(code: &str, filename: String) -> Result<HashSet<Atom>> {
+  let cm: Arc<swc_core::common::SourceMap> = Default::default();
+  let fm = cm.new_source_file(
+    Arc::new(FileName::Custom(filename)),
+    format!("(function () {{\n{code}\n}});").into(),
+  );
+  let program = parse_file_as_module(&fm, Syntax::default(), EsVersion::EsNext, None, &mut vec![])
+    .map_err(|err| {
+      let span: ErrorSpan = err.span().into();
+      rspack_error::Error::from(
+        TraceableError::from_source_file(
+          &fm,
+          span.start as usize,
+          span.end as usize,
+          "JavaScript parsing error:\n".to_string(),
+          err.kind().msg().to_string(),
+        )
+        .with_kind(DiagnosticKind::JavaScript),
+      )
+    })?;
+  let mut ast = Ast::new(Program::Module(program), cm, None);
+  let mut global_ctxt = SyntaxContext::empty();
+  let mut collector = IdentCollector::default();
+  ast.transform(|program, context| {
+    global_ctxt = global_ctxt.apply_mark(context.unresolved_mark);
+    program.visit_mut_with(&mut resolver(
+      context.unresolved_mark,
+      context.top_level_mark,
+      false,
+    ));
+    program.visit_with(&mut collector);
+  });
+  Ok(
+    collector
+      .ids
+      .into_iter()
+      .filter(|ident| ident.id.ctxt == global_ctxt)
+      .map(|ident| ident.id.sym)
+      .collect(),
+  )
+}
+
+/// Modules in the concatenation which are not ES modules are CommonJS modules with a static
+/// export shape, they are inlined and accessed with the interop of external modules.
+pub fn is_inlined_commonjs_module(mg: &ModuleGraph, module: &ModuleIdentifier) -> bool {
+  mg.module_by_identifier(module)
+    .and_then(|module| module.build_meta())
+    .is_some_and(|meta| meta.exports_type != BuildMetaExportsType::Namespace)
+}
+
 pub fn is_esm_dep_like(dep: &BoxDependency) -> bool {
   matches!(
     dep.dependency_type(),
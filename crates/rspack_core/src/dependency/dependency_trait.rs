@@ -17,6 +17,7 @@ use crate::create_exports_object_referenced;
 use crate::AsContextDependency;
 use crate::ExtendedReferencedExport;
 use crate::ImportAttributes;
+use crate::ImportPhase;
 use crate::ModuleLayer;
 use crate::RuntimeSpec;
 use crate::{ConnectionState, Context, ModuleGraph, UsedByExports};
@@ -63,12 +64,20 @@ pub trait Dependency:
     None
   }
 
+  fn get_phase(&self) -> ImportPhase {
+    ImportPhase::Evaluation
+  }
+
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
     None
   }
 
   fn set_used_by_exports(&mut self, _used_by_exports: Option<UsedByExports>) {}
 
+  fn get_used_by_exports(&self) -> Option<&UsedByExports> {
+    None
+  }
+
   fn get_module_evaluation_side_effects_state(
     &self,
     _module_graph: &ModuleGraph,
@@ -16,6 +16,8 @@ pub enum DependencyType {
   // ESM import
   EsmImport,
   EsmImportSpecifier,
+  // ESM source phase import
+  EsmImportSource,
   // ESM export
   EsmExport,
   EsmExportImportedSpecifier,
@@ -62,6 +64,8 @@ pub enum DependencyType {
   CssImport,
   // css modules compose
   CssCompose,
+  // css modules @value from and :import
+  CssIcssImport,
   // css :export
   CssExport,
   // css modules local ident
@@ -74,6 +78,8 @@ pub enum DependencyType {
   ImportContext,
   // import.meta.webpackContext
   ImportMetaContext,
+  // import.meta.glob
+  ImportMetaGlob,
   // commonjs require context
   CommonJSRequireContext,
   // require.context
@@ -123,6 +129,7 @@ impl DependencyType {
       DependencyType::Unknown => "unknown",
       DependencyType::Entry => "entry",
       DependencyType::EsmImport => "esm import",
+      DependencyType::EsmImportSource => "esm import source",
       DependencyType::EsmExport => "esm export",
       DependencyType::EsmExportSpecifier => "esm export specifier",
       DependencyType::EsmExportImportedSpecifier => "esm export import specifier",
@@ -150,6 +157,7 @@ impl DependencyType {
       DependencyType::CssUrl => "css url",
       DependencyType::CssImport => "css import",
       DependencyType::CssCompose => "css compose",
+      DependencyType::CssIcssImport => "css :import",
       DependencyType::CssExport => "css export",
       DependencyType::CssLocalIdent => "css local ident",
       DependencyType::CssSelfReferenceLocalIdent => "css self reference local ident",
@@ -172,6 +180,7 @@ impl DependencyType {
       DependencyType::ExportInfoApi => "export info api",
       // TODO: mode
       DependencyType::ImportMetaContext => "import.meta context",
+      DependencyType::ImportMetaGlob => "import.meta.glob",
       DependencyType::ContainerExposed => "container exposed",
       DependencyType::ContainerEntry => "container entry",
       DependencyType::DllEntry => "dll entry",
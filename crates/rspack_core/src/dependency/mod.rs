@@ -40,8 +40,8 @@ pub use static_exports_dependency::{StaticExportsDependency, StaticExportsSpec};
 use swc_core::ecma::atoms::Atom;
 
 use crate::{
-  ConnectionState, ModuleGraph, ModuleGraphConnection, ModuleIdentifier, ReferencedExport,
-  RuntimeSpec,
+  ConnectionState, EvaluatedInlinableValue, ModuleGraph, ModuleGraphConnection, ModuleIdentifier,
+  ReferencedExport, RuntimeSpec,
 };
 
 #[derive(Debug, Default)]
@@ -51,6 +51,7 @@ pub struct ExportSpec {
   pub exports: Option<Vec<ExportNameOrSpec>>,
   pub can_mangle: Option<bool>,
   pub terminal_binding: Option<bool>,
+  pub inlinable: Option<EvaluatedInlinableValue>,
   pub priority: Option<u8>,
   pub hidden: Option<bool>,
   pub from: Option<ModuleGraphConnection>,
@@ -180,3 +181,30 @@ impl ImportAttributes {
     self.0.insert(k, v)
   }
 }
+
+/// The phase of an import, `import defer` and `import source` don't evaluate the imported
+/// module when the importer is evaluated.
+#[rspack_cacheable::cacheable]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImportPhase {
+  #[default]
+  Evaluation,
+  /// `import source x from "..."`
+  Source,
+  /// `import defer * as ns from "..."`
+  Defer,
+}
+
+impl ImportPhase {
+  pub fn is_evaluation(&self) -> bool {
+    matches!(self, Self::Evaluation)
+  }
+
+  pub fn is_source(&self) -> bool {
+    matches!(self, Self::Source)
+  }
+
+  pub fn is_defer(&self) -> bool {
+    matches!(self, Self::Defer)
+  }
+}
@@ -123,9 +123,12 @@ pub fn export_from_import(
   };
 
   let exports_type = get_exports_type(&compilation.get_module_graph(), id, &module.identifier());
+  // the namespace of a deferred import is a proxy created by `__webpack_require__.z`,
+  // which already applies the interop of the imported module
+  let deferred = is_deferred_import(compilation, id);
 
   let mut exclude_default_export_name = None;
-  if default_interop {
+  if default_interop && !deferred {
     if !export_name.is_empty()
       && let Some(first_export_name) = export_name.first()
       && first_export_name == "default"
@@ -209,7 +212,9 @@ pub fn export_from_import(
     .as_deref()
     .unwrap_or(export_name);
   if !export_name.is_empty() {
-    let used_name: Cow<Vec<Atom>> = {
+    let used_name: Cow<Vec<Atom>> = if deferred && !matches!(exports_type, ExportsType::Namespace) {
+      Cow::Owned(export_name.to_vec())
+    } else {
       let exports_info = compilation
         .get_module_graph()
         .get_exports_info(&module_identifier);
@@ -366,6 +371,21 @@ pub fn module_id(
   }
 }
 
+/// Whether the evaluation of the module imported by the dependency is deferred by `import defer`,
+/// async modules are always evaluated eagerly since they can't be evaluated synchronously on access
+pub fn is_deferred_import(compilation: &Compilation, id: &DependencyId) -> bool {
+  let module_graph = compilation.get_module_graph();
+  let Some(dependency) = module_graph.dependency_by_id(id) else {
+    return false;
+  };
+  if !dependency.get_phase().is_defer() {
+    return false;
+  }
+  module_graph
+    .module_identifier_by_dependency_id(id)
+    .is_some_and(|module| !ModuleGraph::is_async(compilation, module))
+}
+
 pub fn import_statement(
   module: &dyn Module,
   compilation: &Compilation,
@@ -384,18 +404,43 @@ pub fn import_statement(
 
   let module_id_expr = module_id(compilation, id, request, false);
 
-  runtime_requirements.insert(RuntimeGlobals::REQUIRE);
-
   let import_var = compilation.get_import_var(id);
 
   let opt_declaration = if update { "" } else { "var " };
 
+  let exports_type = get_exports_type(&compilation.get_module_graph(), id, &module.identifier());
+  if is_deferred_import(compilation, id) {
+    runtime_requirements.insert(RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT);
+    let mode = match exports_type {
+      ExportsType::Namespace => None,
+      ExportsType::DefaultOnly => Some(FakeNamespaceObjectMode::empty()),
+      ExportsType::DefaultWithNamed => Some(FakeNamespaceObjectMode::MERGE_PROPERTIES),
+      ExportsType::Dynamic => {
+        Some(FakeNamespaceObjectMode::MERGE_PROPERTIES | FakeNamespaceObjectMode::RETURN_VALUE)
+      }
+    };
+    let mode = if let Some(mode) = mode {
+      runtime_requirements.insert(RuntimeGlobals::CREATE_FAKE_NAMESPACE_OBJECT);
+      format!(", {mode}")
+    } else {
+      String::new()
+    };
+    return (
+      format!(
+        "/* ESM import defer */{opt_declaration}{import_var} = {}({module_id_expr}{mode});\n",
+        RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT
+      ),
+      String::new(),
+    );
+  }
+
+  runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+
   let import_content = format!(
     "/* ESM import */{opt_declaration}{import_var} = {}({module_id_expr});\n",
     RuntimeGlobals::REQUIRE
   );
 
-  let exports_type = get_exports_type(&compilation.get_module_graph(), id, &module.identifier());
   if matches!(exports_type, ExportsType::Dynamic) {
     runtime_requirements.insert(RuntimeGlobals::COMPAT_GET_DEFAULT_EXPORT);
     return (
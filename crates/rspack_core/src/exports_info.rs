@@ -12,7 +12,7 @@ use either::Either;
 use itertools::Itertools;
 use rspack_cacheable::{
   cacheable,
-  with::{AsPreset, AsVec},
+  with::{AsPreset, AsTuple2, AsVec},
 };
 use rspack_collections::impl_item_ukey;
 use rspack_collections::Ukey;
@@ -880,6 +880,7 @@ impl ExportInfo {
     data.target_is_set = false;
     data.target.clear();
     data.terminal_binding = false;
+    data.inlinable = None;
   }
 
   pub fn name<'a>(&self, mg: &'a ModuleGraph) -> Option<&'a Atom> {
@@ -918,6 +919,14 @@ impl ExportInfo {
     self.as_export_info_mut(mg).terminal_binding = value;
   }
 
+  pub fn inlinable<'a>(&self, mg: &'a ModuleGraph) -> Option<&'a EvaluatedInlinableValue> {
+    self.as_export_info(mg).inlinable.as_ref()
+  }
+
+  pub fn set_inlinable(&self, mg: &mut ModuleGraph, value: Option<EvaluatedInlinableValue>) {
+    self.as_export_info_mut(mg).inlinable = value;
+  }
+
   pub fn exports_info_owned(&self, mg: &ModuleGraph) -> bool {
     self.as_export_info(mg).exports_info_owned
   }
@@ -1453,6 +1462,7 @@ impl ExportInfo {
     self.get_used(mg, runtime).dyn_hash(hasher);
     data.provided.dyn_hash(hasher);
     data.terminal_binding.dyn_hash(hasher);
+    data.inlinable.dyn_hash(hasher);
     if let Some(exports_info) = data.exports_info
       && !visited.contains(&exports_info)
     {
@@ -1473,6 +1483,8 @@ pub struct ExportInfoData {
   provided: Option<ExportInfoProvided>,
   can_mangle_provide: Option<bool>,
   terminal_binding: bool,
+  /// The constant value of the export, only available when `optimization.inlineExports` is enabled.
+  inlinable: Option<EvaluatedInlinableValue>,
   id: ExportInfo,
   exports_info: Option<ExportsInfo>,
   exports_info_owned: bool,
@@ -1490,6 +1502,60 @@ pub enum ExportInfoProvided {
   Null,
 }
 
+/// The value of a never reassigned export, which can be inlined into the importing modules
+/// instead of being accessed through the exports object.
+#[cacheable]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EvaluatedInlinableValue {
+  /// `null`, `undefined`, a boolean, a number or a short string, rendered as code.
+  Primitive(#[cacheable(with=AsPreset)] Atom),
+  /// The members of a TypeScript enum, each of which is rendered as code.
+  Enum(#[cacheable(with=AsVec<AsTuple2<AsPreset, AsPreset>>)] Vec<(Atom, Atom)>),
+}
+
+impl EvaluatedInlinableValue {
+  /// Returns the code to inline for the property `ids` of the export.
+  pub fn code(&self, ids: &[Atom]) -> Option<&Atom> {
+    match (self, ids) {
+      (Self::Primitive(code), []) => Some(code),
+      (Self::Enum(members), [member]) => members
+        .iter()
+        .find_map(|(name, code)| (name == member).then_some(code)),
+      _ => None,
+    }
+  }
+
+  /// Returns whether the property `ids` of the export is truthy when used as a condition.
+  pub fn truthiness(&self, ids: &[Atom]) -> Option<bool> {
+    let code = self.code(ids)?;
+    let code = code.trim_start_matches('(').trim_end_matches(')');
+    match code {
+      "true" => Some(true),
+      "false" | "null" | "void 0" => Some(false),
+      _ if code.starts_with('"') => Some(code != "\"\""),
+      _ => {
+        // a numeric literal, it's falsy when all its digits are zero
+        let number = code.trim_start_matches('-');
+        let digits = match number.get(..2) {
+          Some("0x" | "0X" | "0o" | "0O" | "0b" | "0B") => &number[2..],
+          _ => number
+            .split(['e', 'E'])
+            .next()
+            .expect("should have mantissa"),
+        };
+        if digits.is_empty()
+          || !digits
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_'))
+        {
+          return None;
+        }
+        Some(!digits.chars().all(|c| matches!(c, '0' | '.' | '_')))
+      }
+    }
+  }
+}
+
 #[derive(Debug, Hash, Clone, Copy)]
 pub enum ExportProvided {
   True,
@@ -1559,6 +1625,7 @@ impl ExportInfoData {
 
     let provided = init_from.and_then(|init_from| init_from.provided);
     let terminal_binding = init_from.is_some_and(|init_from| init_from.terminal_binding);
+    let inlinable = init_from.and_then(|init_from| init_from.inlinable.clone());
     let can_mangle_provide = init_from.and_then(|init_from| init_from.can_mangle_provide);
     let can_mangle_use = init_from.and_then(|init_from| init_from.can_mangle_use);
 
@@ -1600,6 +1667,7 @@ impl ExportInfoData {
       provided,
       can_mangle_provide,
       terminal_binding,
+      inlinable,
       target_is_set: init_from.map(|init| init.target_is_set).unwrap_or_default(),
       id: ExportInfo::new(),
       exports_info: None,
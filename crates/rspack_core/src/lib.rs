@@ -20,6 +20,8 @@ pub use fake_namespace_object::*;
 pub use template::Template;
 mod module_profile;
 pub use module_profile::*;
+mod module_inclusion_reason;
+pub use module_inclusion_reason::*;
 use rspack_collections::Database;
 pub mod external_module;
 pub use external_module::*;
@@ -220,6 +220,7 @@ pub trait Logger {
       label,
       total: 0,
       hit: 0,
+      miss_reasons: vec![],
     }
   }
 
@@ -229,7 +230,10 @@ pub trait Logger {
         label: count.label,
         hit: count.hit,
         total: count.total,
-      })
+      });
+      for reason in count.miss_reasons {
+        self.debug(format!("{} missed: {reason}", count.label));
+      }
     }
   }
 }
@@ -275,6 +279,7 @@ pub struct CacheCount {
   label: &'static str,
   hit: u32,
   total: u32,
+  miss_reasons: Vec<String>,
 }
 
 impl CacheCount {
@@ -286,6 +291,12 @@ impl CacheCount {
   pub fn miss(&mut self) {
     self.total += 1;
   }
+
+  /// Count a miss with the reason, reasons are logged as debug messages by `cache_end`
+  pub fn miss_with_reason(&mut self, reason: impl Into<String>) {
+    self.total += 1;
+    self.miss_reasons.push(reason.into());
+  }
 }
 
 pub type CompilationLogging = Arc<DashMap<String, Vec<LogType>, BuildHasherDefault<FxHasher>>>;
@@ -5,21 +5,21 @@ use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
 use json::JsonValue;
-use rspack_cacheable::with::AsPreset;
+use rspack_cacheable::with::{AsPortable, AsPreset};
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsOption, AsVec},
+  with::{AsCacheable, AsMap, AsOption, AsVec},
 };
 use rspack_collections::{Identifiable, Identifier, IdentifierSet};
 use rspack_error::{Diagnosable, Diagnostic, Result};
 use rspack_fs::ReadableFileSystem;
 use rspack_hash::RspackHashDigest;
 use rspack_paths::ArcPath;
-use rspack_sources::Source;
+use rspack_sources::{BoxSource, Source};
 use rspack_util::atom::Atom;
 use rspack_util::ext::{AsAny, DynHash};
 use rspack_util::source_map::ModuleSourceMapConfig;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use serde::Serialize;
 
 use crate::concatenated_module::ConcatenatedModule;
@@ -29,7 +29,7 @@ use crate::{
   CompilationId, CompilerOptions, ConcatenationScope, ConnectionState, Context, ContextModule,
   DependenciesBlock, DependencyId, DependencyTemplate, ExportInfoProvided, ExternalModule,
   ModuleDependency, ModuleGraph, ModuleLayer, ModuleType, NormalModule, RawModule, Resolve,
-  ResolverFactory, RuntimeSpec, SelfModule, SharedPluginDriver, SourceType,
+  ResolverFactory, RuntimeSpec, SelfModule, SharedPluginDriver, SideEffectsBailoutItem, SourceType,
 };
 
 pub struct BuildContext {
@@ -67,6 +67,16 @@ pub struct BuildInfo {
   #[cacheable(with=AsOption<AsVec<AsPreset>>)]
   pub top_level_declarations: Option<HashSet<Atom>>,
   pub module_concatenation_bailout: Option<String>,
+  /// Purity summary of the module, only available when `optimization.inferPureFunctions` is enabled.
+  pub pure_functions: Option<PureFunctionsInfo>,
+  /// Dependencies in branches guarded by an imported constant, only available when
+  /// `optimization.inlineExports` is enabled.
+  pub guarded_dependencies: Vec<GuardedDependencies>,
+  /// The first top-level statement with side effects, only available when `optimization.sideEffects` is `true`.
+  pub side_effects_bailout: Option<SideEffectsBailoutItem>,
+  /// Assets emitted by the loaders of the module.
+  #[cacheable(with=AsMap<AsCacheable, AsPreset>)]
+  pub assets: HashMap<String, BoxSource>,
 }
 
 impl Default for BuildInfo {
@@ -85,10 +95,77 @@ impl Default for BuildInfo {
       json_data: None,
       top_level_declarations: None,
       module_concatenation_bailout: None,
+      pure_functions: None,
+      guarded_dependencies: Vec::new(),
+      side_effects_bailout: None,
+      assets: HashMap::default(),
     }
   }
 }
 
+/// A function or class that some code relies on to be free of side effects.
+#[cacheable]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PureCallee {
+  /// A top-level function or class of the same module.
+  Local(#[cacheable(with=AsPreset)] Atom),
+  /// An export of the module imported with `request`, e.g. `import { ids[0] } from "request"`.
+  Imported {
+    #[cacheable(with=AsPortable)]
+    request: Atom,
+    #[cacheable(with=AsVec<AsPreset>)]
+    ids: Vec<Atom>,
+  },
+}
+
+/// A top-level function or class whose body has no side effects other than calling `callees`.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct PureFunction {
+  #[cacheable(with=AsPreset)]
+  pub name: Atom,
+  /// Names under which the function is exported by the module.
+  #[cacheable(with=AsVec<AsPreset>)]
+  pub exports: Vec<Atom>,
+  pub callees: Vec<PureCallee>,
+}
+
+/// A top-level statement that is only pure if all `callees` are pure.
+///
+/// The `marker` is added to the `UsedByExports` of the dependencies the inner graph
+/// derives from the statement, so they can be marked as used when a callee turns out to be impure.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct PureCondition {
+  #[cacheable(with=AsPreset)]
+  pub marker: Atom,
+  pub callees: Vec<PureCallee>,
+}
+
+#[cacheable]
+#[derive(Debug, Default, Clone)]
+pub struct PureFunctionsInfo {
+  pub functions: Vec<PureFunction>,
+  pub conditions: Vec<PureCondition>,
+}
+
+/// The dependencies of a branch that is only taken when an imported export is truthy, or falsy,
+/// e.g. `if (IS_DEV) { require("./devtools") }`.
+///
+/// They are not built until the export turns out not to be a constant that rules the branch out.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct GuardedDependencies {
+  /// The request of the import, e.g. `import { ids[0] } from "request"`.
+  #[cacheable(with=AsPortable)]
+  pub request: Atom,
+  #[cacheable(with=AsVec<AsPreset>)]
+  pub ids: Vec<Atom>,
+  /// Whether the branch is taken when the export is truthy.
+  pub truthy: bool,
+  pub dependencies: Vec<DependencyId>,
+}
+
 #[cacheable]
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -193,6 +270,17 @@ pub struct BuildResult {
 #[derive(Debug, Default, Clone)]
 pub struct FactoryMeta {
   pub side_effect_free: Option<bool>,
+  /// What decided `side_effect_free`, `None` when it's set by a plugin.
+  pub side_effects_source: Option<SideEffectsFlagSource>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub enum SideEffectsFlagSource {
+  /// The `sideEffects` option of `module.rules`.
+  ModuleRule,
+  /// The `sideEffects` field of the `package.json` at the path.
+  PackageJson(String),
 }
 
 pub type ModuleIdentifier = Identifier;
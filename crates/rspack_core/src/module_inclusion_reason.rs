@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+use rspack_collections::{IdentifierMap, IdentifierSet};
+
+use crate::{
+  Compilation, ConnectionState, DependenciesBlock, DependencyId, ModuleGraph, ModuleIdentifier,
+  SideEffectsFlagSource,
+};
+
+/// Why a module is included in the output, see [`Compilation::get_module_inclusion_reason`].
+#[derive(Debug, Clone)]
+pub struct ModuleInclusionReason {
+  /// The imports leading from an entry to the module, starting with the entry dependency.
+  pub import_chain: Vec<ModuleImport>,
+  pub side_effects: ModuleSideEffectsReason,
+}
+
+/// A module imported by a dependency of `origin_module`.
+#[derive(Debug, Clone)]
+pub struct ModuleImport {
+  /// The importing module, `None` for entry dependencies.
+  pub origin_module: Option<ModuleIdentifier>,
+  pub module: ModuleIdentifier,
+  pub dependency_id: DependencyId,
+  pub request: Option<String>,
+  pub loc: Option<String>,
+}
+
+/// Whether a module has side effects, and what decided it.
+#[derive(Debug, Clone)]
+pub enum ModuleSideEffectsReason {
+  /// Decided by the `sideEffects` option of `module.rules`.
+  ModuleRule { side_effects: bool },
+  /// Decided by the `sideEffects` field of the `package.json` at `path`.
+  PackageJson { path: String, side_effects: bool },
+  /// The source of the module is not analyzed, e.g. `optimization.sideEffects` is not `true`,
+  /// or the module is not a JavaScript module, so it's considered to have side effects.
+  NotAnalyzed,
+  /// The first top-level statement of the module that has side effects.
+  Statement { ty: String, loc: String },
+  /// The module itself is free of side effects, but a module it imports or reexports isn't.
+  Dependency {
+    dependency_id: DependencyId,
+    module: Option<ModuleIdentifier>,
+  },
+  /// The module has no side effects, it's only included for its used exports.
+  None,
+}
+
+impl Compilation {
+  /// Explains why `module` is included: the shortest chain of active imports from an entry,
+  /// and why the module is considered to have side effects, if it is.
+  ///
+  /// Returns `None` when the module is not reachable from any entry.
+  pub fn get_module_inclusion_reason(
+    &self,
+    module: &ModuleIdentifier,
+  ) -> Option<ModuleInclusionReason> {
+    let module_graph = self.get_module_graph();
+    let import_chain = get_import_chain(&module_graph, module)?;
+    Some(ModuleInclusionReason {
+      import_chain,
+      side_effects: get_side_effects_reason(&module_graph, module),
+    })
+  }
+}
+
+fn get_import_chain(
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+) -> Option<Vec<ModuleImport>> {
+  let create_import = |dependency_id: DependencyId,
+                       origin_module: Option<ModuleIdentifier>,
+                       module: ModuleIdentifier| {
+    let dependency = module_graph.dependency_by_id(&dependency_id);
+    let request = dependency.and_then(|dependency| {
+      if let Some(dependency) = dependency.as_module_dependency() {
+        Some(dependency.user_request().to_string())
+      } else {
+        dependency
+          .as_context_dependency()
+          .map(|dependency| dependency.request().to_string())
+      }
+    });
+    ModuleImport {
+      origin_module,
+      module,
+      dependency_id,
+      request,
+      loc: dependency
+        .and_then(|dependency| dependency.loc())
+        .map(|loc| loc.to_string()),
+    }
+  };
+
+  // walk the incoming connections breadth first, `imports` maps each visited module to the
+  // module it imports on the way to `module`
+  let mut imports: IdentifierMap<(DependencyId, ModuleIdentifier)> = IdentifierMap::default();
+  let mut visited = IdentifierSet::from_iter([*module]);
+  let mut queue = VecDeque::from([*module]);
+  while let Some(current) = queue.pop_front() {
+    for connection in module_graph.get_incoming_connections(&current) {
+      if !connection.is_active(module_graph, None) {
+        continue;
+      }
+      let Some(origin_module) = connection.original_module_identifier else {
+        let mut import_chain = vec![create_import(connection.dependency_id, None, current)];
+        let mut origin_module = current;
+        while let Some((dependency_id, imported)) = imports.get(&origin_module) {
+          import_chain.push(create_import(
+            *dependency_id,
+            Some(origin_module),
+            *imported,
+          ));
+          origin_module = *imported;
+        }
+        return Some(import_chain);
+      };
+      if visited.insert(origin_module) {
+        imports.insert(origin_module, (connection.dependency_id, current));
+        queue.push_back(origin_module);
+      }
+    }
+  }
+  None
+}
+
+fn get_side_effects_reason(
+  module_graph: &ModuleGraph,
+  module_identifier: &ModuleIdentifier,
+) -> ModuleSideEffectsReason {
+  let Some(module) = module_graph.module_by_identifier(module_identifier) else {
+    return ModuleSideEffectsReason::NotAnalyzed;
+  };
+  if let Some(factory_meta) = module.factory_meta()
+    && let Some(side_effect_free) = factory_meta.side_effect_free
+  {
+    match &factory_meta.side_effects_source {
+      Some(SideEffectsFlagSource::ModuleRule) => {
+        return ModuleSideEffectsReason::ModuleRule {
+          side_effects: !side_effect_free,
+        };
+      }
+      Some(SideEffectsFlagSource::PackageJson(path)) => {
+        return ModuleSideEffectsReason::PackageJson {
+          path: path.clone(),
+          side_effects: !side_effect_free,
+        };
+      }
+      // set by a plugin, e.g. to keep all modules
+      None if !side_effect_free => return ModuleSideEffectsReason::NotAnalyzed,
+      None => return ModuleSideEffectsReason::None,
+    }
+  }
+  match module
+    .build_meta()
+    .and_then(|build_meta| build_meta.side_effect_free)
+  {
+    Some(true) => {}
+    Some(false) => {
+      return match module
+        .build_info()
+        .and_then(|build_info| build_info.side_effects_bailout.as_ref())
+      {
+        Some(bailout) => ModuleSideEffectsReason::Statement {
+          ty: bailout.ty.clone(),
+          loc: bailout.msg.clone(),
+        },
+        None => ModuleSideEffectsReason::NotAnalyzed,
+      };
+    }
+    None => return ModuleSideEffectsReason::NotAnalyzed,
+  }
+  let mut module_chain = IdentifierSet::from_iter([*module_identifier]);
+  for dependency_id in module.get_dependencies() {
+    let Some(dependency) = module_graph.dependency_by_id(dependency_id) else {
+      continue;
+    };
+    if matches!(
+      dependency.get_module_evaluation_side_effects_state(module_graph, &mut module_chain),
+      ConnectionState::Bool(true)
+    ) {
+      return ModuleSideEffectsReason::Dependency {
+        dependency_id: *dependency_id,
+        module: module_graph
+          .module_identifier_by_dependency_id(dependency_id)
+          .copied(),
+      };
+    }
+  }
+  ModuleSideEffectsReason::None
+}
@@ -12,7 +12,7 @@ use dashmap::DashMap;
 use derive_more::Debug;
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsMap, AsOption, AsPreset, Skip},
+  with::{AsMap, AsOption, AsPortable, AsPreset, Skip},
 };
 use rspack_collections::{Identifiable, IdentifierSet};
 use rspack_error::{error, Diagnosable, Diagnostic, DiagnosticExt, NodeError, Result, Severity};
@@ -106,10 +106,13 @@ pub struct NormalModule {
   /// Context of this module
   context: Box<Context>,
   /// Request with loaders from config
+  #[cacheable(with=AsPortable)]
   request: String,
   /// Request intended by user (without loaders from config)
+  #[cacheable(with=AsPortable)]
   user_request: String,
   /// Request without resolving
+  #[cacheable(with=AsPortable)]
   raw_request: String,
   /// The resolved module type of a module
   module_type: ModuleType,
@@ -542,6 +545,7 @@ impl Module for NormalModule {
       .into_iter()
       .map(Into::into)
       .collect();
+    build_info.assets = loader_result.assets;
 
     if no_parse {
       self.parsed = false;
@@ -570,6 +574,7 @@ impl Module for NormalModule {
         presentational_dependencies,
         code_generation_dependencies,
         side_effects_bailout,
+        optimization_bailouts: parse_optimization_bailouts,
       },
       diagnostics,
     ) = self
@@ -598,7 +603,7 @@ impl Module for NormalModule {
     } else {
       self.last_successful_build_meta = build_meta.clone();
     }
-    let optimization_bailouts = if let Some(side_effects_bailout) = side_effects_bailout {
+    let mut optimization_bailouts = if let Some(side_effects_bailout) = &side_effects_bailout {
       let short_id = self.readable_identifier(&build_context.compiler_options.context);
       vec![format!(
         "{} with side_effects in source code at {short_id}:{}",
@@ -607,6 +612,8 @@ impl Module for NormalModule {
     } else {
       vec![]
     };
+    optimization_bailouts.extend(parse_optimization_bailouts);
+    build_info.side_effects_bailout = side_effects_bailout;
     // Only side effects used in code_generate can stay here
     // Other side effects should be set outside use_cache
     self.original_source = Some(source.clone());
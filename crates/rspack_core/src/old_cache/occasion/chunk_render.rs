@@ -40,12 +40,21 @@ impl ChunkRenderOccasion {
       return generator().await;
     };
     let cache_key = Identifier::from(content_hash.encoded());
-    if let Some(value) = storage.get(&cache_key) {
-      Ok((value, Vec::new()))
+    let res = if let Some(value) = storage.get(&cache_key) {
+      (value, Vec::new())
+    } else if let Some(value) = compilation.chunk_render_cache_artifact.get(&cache_key) {
+      // recovered from persistent cache
+      storage.set(cache_key, value.clone());
+      (value.clone(), Vec::new())
     } else {
       let res = generator().await?;
       storage.set(cache_key, res.0.clone());
-      Ok(res)
-    }
+      res
+    };
+    // record the rendered source, persistent cache will save it after chunks render
+    compilation
+      .chunk_render_cache_artifact
+      .insert(cache_key, res.0.clone());
+    Ok(res)
   }
 }
@@ -1,4 +1,3 @@
-use rspack_collections::Identifier;
 use rspack_error::Result;
 
 use crate::{old_cache::storage, CodeGenerationResult};
@@ -29,7 +28,7 @@ impl CodeGenerateOccasion {
         return (res, job.runtimes, false);
       }
     };
-    let cache_key = Identifier::from(format!("{}|{}", job.module, job.hash.encoded()));
+    let cache_key = job.cache_key();
     if let Some(value) = storage.get(&cache_key) {
       (Ok(value), job.runtimes, true)
     } else {
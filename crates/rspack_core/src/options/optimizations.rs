@@ -129,6 +129,8 @@ pub struct Optimization {
   pub mangle_exports: MangleExportsOption,
   pub concatenate_modules: bool,
   pub avoid_entry_iife: bool,
+  pub infer_pure_functions: bool,
+  pub inline_exports: bool,
 }
 
 pub static DEFAULT_DELIMITER: &str = "~";
@@ -404,6 +404,18 @@ pub fn get_css_chunk_filename_template<'filename>(
   }
 }
 
+/// The filename template of the stylesheets of hot update chunks, which is
+/// `output.hotUpdateChunkFilename` with the `.js` extension replaced by `.css`.
+pub fn get_css_hot_update_chunk_filename_template(
+  output_options: &OutputOptions,
+) -> FilenameTemplate {
+  let template = output_options.hot_update_chunk_filename.as_str();
+  FilenameTemplate::from(format!(
+    "{}.css",
+    template.strip_suffix(".js").unwrap_or(template)
+  ))
+}
+
 pub fn get_js_chunk_filename_template(
   chunk: &Chunk,
   output_options: &OutputOptions,
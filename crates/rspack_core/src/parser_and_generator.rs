@@ -2,7 +2,7 @@ use std::any::Any;
 use std::borrow::Cow;
 
 use derive_more::Debug;
-use rspack_cacheable::cacheable_dyn;
+use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_error::{Result, TWithDiagnosticArray};
 use rspack_loader_runner::{AdditionalData, ResourceData};
 use rspack_sources::BoxSource;
@@ -38,7 +38,8 @@ pub struct ParseContext<'a> {
   pub build_meta: &'a mut BuildMeta,
 }
 
-#[derive(Debug)]
+#[cacheable]
+#[derive(Debug, Clone)]
 pub struct SideEffectsBailoutItem {
   pub msg: String,
   /// The type of AstNode
@@ -72,6 +73,7 @@ pub struct ParseResult {
   pub code_generation_dependencies: Vec<Box<dyn ModuleDependency>>,
   pub source: BoxSource,
   pub side_effects_bailout: Option<SideEffectsBailoutItem>,
+  pub optimization_bailouts: Vec<String>,
 }
 
 #[derive(Debug)]
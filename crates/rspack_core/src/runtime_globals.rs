@@ -257,6 +257,16 @@ bitflags! {
     // amd module support
     const AMD_DEFINE = 1 << 67;
     const AMD_OPTIONS = 1 << 68;
+
+    /**
+     * create a namespace object which evaluates the module on first access, used by `import defer`
+     */
+    const MAKE_DEFERRED_NAMESPACE_OBJECT = 1 << 69;
+
+    /**
+     * compile a wasm module from id without instantiating it, used by `import source`
+     */
+    const COMPILE_WASM = 1 << 70;
   }
 }
 
@@ -304,11 +314,13 @@ impl RuntimeGlobals {
       R::HMR_RUNTIME_STATE_PREFIX => "__webpack_require__.hmrS",
       R::AMD_DEFINE => "__webpack_require__.amdD",
       R::AMD_OPTIONS => "__webpack_require__.amdO",
+      R::MAKE_DEFERRED_NAMESPACE_OBJECT => "__webpack_require__.z",
       R::EXTERNAL_INSTALL_CHUNK => "__webpack_require__.C",
       R::GET_FULL_HASH => "__webpack_require__.h",
       R::GLOBAL => "__webpack_require__.g",
       R::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime",
       R::INSTANTIATE_WASM => "__webpack_require__.v",
+      R::COMPILE_WASM => "__webpack_require__.vs",
       R::ASYNC_MODULE => "__webpack_require__.a",
       R::BASE_URI => "__webpack_require__.b",
       R::STARTUP_ENTRYPOINT => "__webpack_require__.X",
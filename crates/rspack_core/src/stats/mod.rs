@@ -18,8 +18,8 @@ pub use r#struct::*;
 
 use crate::{
   BoxModule, BoxRuntimeModule, Chunk, ChunkGraph, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey,
-  Compilation, ExecutedRuntimeModule, LogType, ModuleGraph, ModuleIdentifier, ProvidedExports,
-  SourceType, UsedExports,
+  Compilation, ExecutedRuntimeModule, LogType, ModuleGraph, ModuleIdentifier,
+  ModuleSideEffectsReason, ProvidedExports, SourceType, UsedExports,
 };
 
 #[derive(Debug, Clone)]
@@ -676,6 +676,33 @@ impl Stats<'_> {
       .collect()
   }
 
+  /// Get the modules which missed the persistent cache and the reasons
+  pub fn get_cache_misses(&self) -> Vec<StatsCacheMiss> {
+    let cache_miss_info = &self.compilation.cache_miss_info;
+    if !cache_miss_info.is_enabled() {
+      return vec![];
+    }
+    let module_graph = self.compilation.get_module_graph();
+    self
+      .compilation
+      .built_modules()
+      .iter()
+      .filter_map(|identifier| {
+        let module = module_graph.module_by_identifier(identifier)?;
+        let reason = cache_miss_info.module_reason(module.as_ref())?;
+        Some(StatsCacheMiss {
+          module_identifier: *identifier,
+          module_name: module
+            .readable_identifier(&self.compilation.options.context)
+            .to_string(),
+          path: reason.path().map(|path| path.to_string_lossy().to_string()),
+          reason: reason.to_string(),
+        })
+      })
+      .sorted_by(|a, b| a.module_identifier.cmp(&b.module_identifier))
+      .collect()
+  }
+
   pub fn get_hash(&self) -> Option<&str> {
     self.compilation.get_hash()
   }
@@ -762,6 +789,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      inclusion_reason: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
@@ -1017,6 +1045,10 @@ impl Stats<'_> {
       stats.optimization_bailout = Some(&mgm.optimization_bailout);
     }
 
+    if options.inclusion_reason && !executed {
+      stats.inclusion_reason = self.get_inclusion_reason(module_graph, &identifier);
+    }
+
     // 'depth' is used for sorting in the JavaScript side, so it should always be computed.
     stats.depth = module_graph.get_depth(&identifier);
 
@@ -1040,6 +1072,102 @@ impl Stats<'_> {
     Ok(stats)
   }
 
+  fn get_inclusion_reason<'a>(
+    &'a self,
+    module_graph: &'a ModuleGraph,
+    identifier: &ModuleIdentifier,
+  ) -> Option<StatsModuleInclusionReason<'a>> {
+    let reason = self.compilation.get_module_inclusion_reason(identifier)?;
+    let get_name_and_id = |identifier: &ModuleIdentifier| {
+      module_graph
+        .module_by_identifier(identifier)
+        .map(|module| get_stats_module_name_and_id(module, self.compilation))
+    };
+
+    let import_chain = reason
+      .import_chain
+      .into_iter()
+      .map(|import| {
+        let (origin_module_name, origin_module_id) = import
+          .origin_module
+          .as_ref()
+          .and_then(get_name_and_id)
+          .unzip();
+        let (module_name, module_id) = get_name_and_id(&import.module)
+          .unwrap_or_else(|| (import.module.to_string().into(), None));
+        StatsModuleImport {
+          origin_module_identifier: import.origin_module,
+          origin_module_name,
+          origin_module_id: origin_module_id.flatten(),
+          module_identifier: import.module,
+          module_name,
+          module_id,
+          user_request: import.request,
+          loc: import.loc,
+        }
+      })
+      .collect();
+
+    let mut side_effects = StatsModuleSideEffects {
+      r#type: "none",
+      side_effects: true,
+      description_file: None,
+      statement_type: None,
+      loc: None,
+      module_identifier: None,
+      module_name: None,
+      module_id: None,
+    };
+    match reason.side_effects {
+      ModuleSideEffectsReason::ModuleRule {
+        side_effects: module_side_effects,
+      } => {
+        side_effects.r#type = "moduleRule";
+        side_effects.side_effects = module_side_effects;
+      }
+      ModuleSideEffectsReason::PackageJson {
+        path,
+        side_effects: module_side_effects,
+      } => {
+        side_effects.r#type = "packageJson";
+        side_effects.side_effects = module_side_effects;
+        side_effects.description_file = Some(path);
+      }
+      ModuleSideEffectsReason::NotAnalyzed => {
+        side_effects.r#type = "notAnalyzed";
+      }
+      ModuleSideEffectsReason::Statement { ty, loc } => {
+        side_effects.r#type = "statement";
+        side_effects.statement_type = Some(ty);
+        side_effects.loc = Some(loc);
+      }
+      ModuleSideEffectsReason::Dependency {
+        dependency_id,
+        module,
+      } => {
+        side_effects.r#type = "dependency";
+        side_effects.loc = module_graph
+          .dependency_by_id(&dependency_id)
+          .and_then(|dependency| dependency.loc())
+          .map(|loc| loc.to_string());
+        if let Some(module) = module {
+          let (module_name, module_id) = get_name_and_id(&module).unzip();
+          side_effects.module_identifier = Some(module);
+          side_effects.module_name = module_name;
+          side_effects.module_id = module_id.flatten();
+        }
+      }
+      ModuleSideEffectsReason::None => {
+        side_effects.side_effects = false;
+      }
+    }
+
+    Some(StatsModuleInclusionReason {
+      import_chain,
+      side_effects,
+    })
+  }
+
   fn get_executed_runtime_module(
     &self,
     identifier: &ModuleIdentifier,
@@ -1081,6 +1209,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      inclusion_reason: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
@@ -1197,6 +1326,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      inclusion_reason: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
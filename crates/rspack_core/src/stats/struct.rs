@@ -31,6 +31,7 @@ pub struct ExtendedStatsOptions {
   pub module_assets: bool,
   pub nested_modules: bool,
   pub optimization_bailout: bool,
+  pub inclusion_reason: bool,
   pub provided_exports: bool,
   pub reasons: bool,
   pub source: bool,
@@ -156,6 +157,7 @@ pub struct StatsModule<'s> {
   pub provided_exports: Option<Vec<Atom>>,
   pub used_exports: Option<StatsUsedExports>,
   pub optimization_bailout: Option<&'s [String]>,
+  pub inclusion_reason: Option<StatsModuleInclusionReason<'s>>,
   pub depth: Option<usize>,
   pub pre_order_index: Option<u32>,
   pub post_order_index: Option<u32>,
@@ -269,6 +271,45 @@ pub struct StatsModuleReason<'s> {
   pub user_request: Option<&'s str>,
 }
 
+#[derive(Debug)]
+pub struct StatsModuleInclusionReason<'s> {
+  pub import_chain: Vec<StatsModuleImport<'s>>,
+  pub side_effects: StatsModuleSideEffects<'s>,
+}
+
+#[derive(Debug)]
+pub struct StatsModuleImport<'s> {
+  pub origin_module_identifier: Option<ModuleIdentifier>,
+  pub origin_module_name: Option<Cow<'s, str>>,
+  pub origin_module_id: Option<&'s str>,
+  pub module_identifier: ModuleIdentifier,
+  pub module_name: Cow<'s, str>,
+  pub module_id: Option<&'s str>,
+  pub user_request: Option<String>,
+  pub loc: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct StatsModuleSideEffects<'s> {
+  pub r#type: &'static str,
+  pub side_effects: bool,
+  pub description_file: Option<String>,
+  pub statement_type: Option<String>,
+  pub loc: Option<String>,
+  pub module_identifier: Option<ModuleIdentifier>,
+  pub module_name: Option<Cow<'s, str>>,
+  pub module_id: Option<&'s str>,
+}
+
+#[derive(Debug)]
+pub struct StatsCacheMiss {
+  pub module_identifier: ModuleIdentifier,
+  pub module_name: String,
+  pub reason: String,
+  /// The changed path which caused the miss
+  pub path: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct StatsMillisecond {
   pub secs: u64,
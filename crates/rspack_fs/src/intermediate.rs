@@ -15,6 +15,10 @@ pub trait IntermediateFileSystemExtras: Debug + Send + Sync {
   async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()>;
   async fn create_read_stream(&self, file: &Utf8Path) -> Result<Box<dyn ReadStream>>;
   async fn create_write_stream(&self, file: &Utf8Path) -> Result<Box<dyn WriteStream>>;
+  /// Create a file with the data, fail with `AlreadyExists` if the file exists.
+  ///
+  /// Checking and creating the file is atomic, it can be used to create lock files.
+  async fn create_new_file(&self, file: &Utf8Path, data: &[u8]) -> Result<()>;
 }
 
 #[async_trait::async_trait]
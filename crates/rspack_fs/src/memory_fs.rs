@@ -285,6 +285,23 @@ impl IntermediateFileSystemExtras for MemoryFileSystem {
     let writer = MemoryWriteStream::new(file, self.clone());
     Ok(Box::new(writer))
   }
+
+  async fn create_new_file(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    let p = file.parent().expect("should have parent dir");
+    if !self.contains_dir(p)? {
+      return Err(new_error("parent dir not exist"));
+    }
+
+    let mut files = self.files.lock().expect("should get lock");
+    if files.contains_key(file) {
+      return Err(Error::Io(std::io::Error::new(
+        std::io::ErrorKind::AlreadyExists,
+        "file already exists",
+      )));
+    }
+    files.insert(file.to_path_buf(), FileType::new_file(data.to_vec()));
+    Ok(())
+  }
 }
 
 impl IntermediateFileSystem for MemoryFileSystem {}
@@ -96,6 +96,12 @@ impl IntermediateFileSystemExtras for NativeFileSystem {
     let writer = NativeWriteStream::try_new(file)?;
     Ok(Box::new(writer))
   }
+
+  async fn create_new_file(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    let mut file = File::create_new(file).map_err(Error::from)?;
+    file.write_all(data).map_err(Error::from)?;
+    file.flush().map_err(Error::from)
+  }
 }
 
 impl IntermediateFileSystem for NativeFileSystem {}
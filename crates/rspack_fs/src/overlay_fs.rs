@@ -0,0 +1,464 @@
+use std::{
+  collections::{BTreeMap, HashSet},
+  sync::{Arc, Mutex},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+
+use crate::{Error, FileMetadata, ReadableFileSystem, Result, WritableFileSystem};
+
+fn current_time() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .expect("should get current time")
+    .as_millis() as u64
+}
+
+fn new_error(msg: &str) -> Error {
+  Error::Io(std::io::Error::new(std::io::ErrorKind::Other, msg))
+}
+
+fn not_found_error() -> Error {
+  Error::Io(std::io::Error::new(
+    std::io::ErrorKind::NotFound,
+    "file not exist",
+  ))
+}
+
+fn new_metadata(is_file: bool, size: u64) -> FileMetadata {
+  let now = current_time();
+  FileMetadata {
+    is_file,
+    is_directory: !is_file,
+    is_symlink: false,
+    atime_ms: now,
+    mtime_ms: now,
+    ctime_ms: now,
+    size,
+  }
+}
+
+/// A change recorded in the memory layer
+#[derive(Debug, Clone)]
+enum Change {
+  File {
+    content: Vec<u8>,
+    metadata: FileMetadata,
+  },
+  /// Opaque directory hides the children of the same directory in base filesystem,
+  /// it is created when a removed directory is created again.
+  Dir {
+    metadata: FileMetadata,
+    opaque: bool,
+  },
+  Removed,
+}
+
+/// The result of looking up a path in the memory layer
+enum Lookup {
+  Changed(Change),
+  /// The path or one of its parents is removed
+  Hidden,
+  /// The path is not changed, read it from base filesystem
+  Base,
+}
+
+/// Copy-on-write filesystem layered over a base filesystem.
+///
+/// Reads go through the in-memory changes to the base filesystem and writes
+/// are only recorded in memory, the changes can be written to the base
+/// filesystem by `flush` or dropped by `discard`.
+#[derive(Debug, Clone)]
+pub struct OverlayFileSystem {
+  base_reader: Arc<dyn ReadableFileSystem>,
+  base_writer: Arc<dyn WritableFileSystem>,
+  changes: Arc<Mutex<BTreeMap<Utf8PathBuf, Change>>>,
+}
+
+impl OverlayFileSystem {
+  pub fn new<T: ReadableFileSystem + WritableFileSystem + 'static>(base: Arc<T>) -> Self {
+    Self {
+      base_reader: base.clone(),
+      base_writer: base,
+      changes: Default::default(),
+    }
+  }
+
+  /// Paths changed in memory which have not been flushed
+  pub fn changed_paths(&self) -> Vec<Utf8PathBuf> {
+    let changes = self.changes.lock().expect("should get lock");
+    changes.keys().cloned().collect()
+  }
+
+  /// Drop all changes in memory
+  pub fn discard(&self) {
+    let mut changes = self.changes.lock().expect("should get lock");
+    changes.clear();
+  }
+
+  /// Write all changes in memory to the base filesystem
+  ///
+  /// Removed paths are applied first, then directories and files are applied
+  /// from parents to children. Changes are dropped once they are applied, so
+  /// flush can be called again after a failure.
+  pub async fn flush(&self) -> Result<()> {
+    let changes = {
+      let changes = self.changes.lock().expect("should get lock");
+      changes.clone()
+    };
+    let (removed, others): (Vec<_>, Vec<_>) = changes
+      .into_iter()
+      .partition(|(_, change)| matches!(change, Change::Removed));
+
+    for (path, change) in removed.into_iter().chain(others) {
+      match &change {
+        Change::Removed => self.remove_base(&path).await?,
+        Change::Dir { opaque, .. } => {
+          if *opaque {
+            self.remove_base(&path).await?;
+          }
+          self.base_writer.create_dir_all(&path).await?;
+        }
+        Change::File { content, .. } => {
+          if let Some(parent) = path.parent() {
+            self.base_writer.create_dir_all(parent).await?;
+          }
+          self.base_writer.write(&path, content).await?;
+        }
+      }
+      self.applied(&path, &change);
+    }
+    Ok(())
+  }
+
+  async fn remove_base(&self, path: &Utf8Path) -> Result<()> {
+    match self.base_reader.metadata(path) {
+      Ok(metadata) if metadata.is_directory => self.base_writer.remove_dir_all(path).await,
+      Ok(_) => self.base_writer.remove_file(path).await,
+      Err(_) => Ok(()),
+    }
+  }
+
+  /// Drop the change after it is applied, unless it has been changed again during flush
+  fn applied(&self, path: &Utf8Path, change: &Change) {
+    let mut changes = self.changes.lock().expect("should get lock");
+    let same = match (changes.get(path), change) {
+      (Some(Change::Removed), Change::Removed) => true,
+      (Some(Change::Dir { opaque: a, .. }), Change::Dir { opaque: b, .. }) => a == b,
+      (Some(Change::File { content: a, .. }), Change::File { content: b, .. }) => a == b,
+      _ => false,
+    };
+    if same {
+      changes.remove(path);
+    }
+  }
+
+  fn lookup(&self, path: &Utf8Path) -> Lookup {
+    let changes = self.changes.lock().expect("should get lock");
+    if let Some(change) = changes.get(path) {
+      return match change {
+        Change::Removed => Lookup::Hidden,
+        change => Lookup::Changed(change.clone()),
+      };
+    }
+    for ancestor in path.ancestors().skip(1) {
+      match changes.get(ancestor) {
+        Some(Change::Removed) | Some(Change::File { .. }) => return Lookup::Hidden,
+        Some(Change::Dir { opaque: true, .. }) => return Lookup::Hidden,
+        _ => {}
+      }
+    }
+    Lookup::Base
+  }
+
+  fn is_dir(&self, path: &Utf8Path) -> Result<bool> {
+    match ReadableFileSystem::metadata(self, path) {
+      Ok(metadata) if metadata.is_directory => Ok(true),
+      Ok(_) => Err(new_error("invalid dir path")),
+      Err(_) => Ok(false),
+    }
+  }
+
+  fn set_change(&self, path: &Utf8Path, change: Change) {
+    let mut changes = self.changes.lock().expect("should get lock");
+    changes.insert(path.to_path_buf(), change);
+  }
+
+  fn insert_dir(&self, dir: &Utf8Path) {
+    let mut changes = self.changes.lock().expect("should get lock");
+    // a removed directory is created again, hide its children in base filesystem
+    let opaque = matches!(changes.get(dir), Some(Change::Removed));
+    changes.insert(
+      dir.to_path_buf(),
+      Change::Dir {
+        metadata: new_metadata(false, 0),
+        opaque,
+      },
+    );
+  }
+
+  fn remove(&self, path: &Utf8Path) {
+    let mut changes = self.changes.lock().expect("should get lock");
+    changes.retain(|p, _| !p.starts_with(path));
+    changes.insert(path.to_path_buf(), Change::Removed);
+  }
+}
+
+#[async_trait::async_trait]
+impl WritableFileSystem for OverlayFileSystem {
+  async fn create_dir(&self, dir: &Utf8Path) -> Result<()> {
+    if self.is_dir(dir)? {
+      return Ok(());
+    }
+    if let Some(p) = dir.parent() {
+      if !self.is_dir(p)? {
+        return Err(new_error("parent directory not exist"));
+      }
+    }
+    self.insert_dir(dir);
+    Ok(())
+  }
+
+  async fn create_dir_all(&self, dir: &Utf8Path) -> Result<()> {
+    if self.is_dir(dir)? {
+      return Ok(());
+    }
+    if let Some(p) = dir.parent() {
+      WritableFileSystem::create_dir_all(self, p).await?;
+    }
+    self.insert_dir(dir);
+    Ok(())
+  }
+
+  async fn write(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    if let Ok(metadata) = ReadableFileSystem::metadata(self, file) {
+      if !metadata.is_file {
+        return Err(new_error("invalid file path"));
+      }
+    } else {
+      let p = file.parent().expect("should have parent dir");
+      if !self.is_dir(p)? {
+        return Err(new_error("parent dir not exist"));
+      }
+    }
+    self.set_change(
+      file,
+      Change::File {
+        content: data.to_vec(),
+        metadata: new_metadata(true, data.len() as u64),
+      },
+    );
+    Ok(())
+  }
+
+  async fn remove_file(&self, file: &Utf8Path) -> Result<()> {
+    match ReadableFileSystem::metadata(self, file) {
+      Ok(metadata) if metadata.is_file => {
+        self.remove(file);
+        Ok(())
+      }
+      Ok(_) => Err(new_error("invalid file path")),
+      Err(_) => Ok(()),
+    }
+  }
+
+  async fn remove_dir_all(&self, dir: &Utf8Path) -> Result<()> {
+    if self.is_dir(dir)? {
+      self.remove(dir);
+    }
+    Ok(())
+  }
+
+  async fn read_dir(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    let mut res: HashSet<String> = HashSet::default();
+    match self.lookup(dir) {
+      Lookup::Changed(Change::Dir { opaque: true, .. }) => {}
+      Lookup::Changed(Change::Dir { .. }) => {
+        if let Ok(children) = self.base_writer.read_dir(dir).await {
+          res.extend(children);
+        }
+      }
+      Lookup::Base => {
+        res.extend(self.base_writer.read_dir(dir).await?);
+      }
+      _ => return Err(new_error("dir not exist")),
+    }
+
+    let changes = self.changes.lock().expect("should get lock");
+    for (path, change) in changes.iter() {
+      if path.parent() != Some(dir) {
+        continue;
+      }
+      let Some(name) = path.file_name() else {
+        continue;
+      };
+      if matches!(change, Change::Removed) {
+        res.remove(name);
+      } else {
+        res.insert(name.to_string());
+      }
+    }
+    Ok(res.into_iter().collect())
+  }
+
+  async fn read_file(&self, file: &Utf8Path) -> Result<Vec<u8>> {
+    ReadableFileSystem::read(self, file)
+  }
+
+  async fn stat(&self, file: &Utf8Path) -> Result<FileMetadata> {
+    ReadableFileSystem::metadata(self, file)
+  }
+}
+
+#[async_trait::async_trait]
+impl ReadableFileSystem for OverlayFileSystem {
+  fn read(&self, path: &Utf8Path) -> Result<Vec<u8>> {
+    match self.lookup(path) {
+      Lookup::Changed(Change::File { content, .. }) => Ok(content),
+      Lookup::Base => self.base_reader.read(path),
+      _ => Err(not_found_error()),
+    }
+  }
+
+  fn metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    match self.lookup(path) {
+      Lookup::Changed(Change::File { metadata, .. } | Change::Dir { metadata, .. }) => Ok(metadata),
+      Lookup::Base => self.base_reader.metadata(path),
+      _ => Err(not_found_error()),
+    }
+  }
+
+  fn symlink_metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    match self.lookup(path) {
+      Lookup::Base => self.base_reader.symlink_metadata(path),
+      _ => self.metadata(path),
+    }
+  }
+
+  fn canonicalize(&self, path: &Utf8Path) -> Result<Utf8PathBuf> {
+    match self.lookup(path) {
+      Lookup::Base => self.base_reader.canonicalize(path),
+      Lookup::Changed(_) => Ok(path.to_path_buf()),
+      Lookup::Hidden => Err(not_found_error()),
+    }
+  }
+
+  async fn async_read(&self, file: &Utf8Path) -> Result<Vec<u8>> {
+    match self.lookup(file) {
+      Lookup::Base => self.base_reader.async_read(file).await,
+      _ => ReadableFileSystem::read(self, file),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_paths::Utf8Path;
+
+  use super::OverlayFileSystem;
+  use crate::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+
+  #[tokio::test]
+  async fn overlay_fs_test() {
+    let base = Arc::new(MemoryFileSystem::default());
+    WritableFileSystem::create_dir_all(base.as_ref(), Utf8Path::new("/a/b"))
+      .await
+      .unwrap();
+    WritableFileSystem::write(base.as_ref(), Utf8Path::new("/a/file1"), "1".as_bytes())
+      .await
+      .unwrap();
+    WritableFileSystem::write(base.as_ref(), Utf8Path::new("/a/b/file2"), "2".as_bytes())
+      .await
+      .unwrap();
+
+    let fs = OverlayFileSystem::new(base.clone());
+
+    // read through to base
+    assert_eq!(
+      ReadableFileSystem::read(&fs, Utf8Path::new("/a/file1")).unwrap(),
+      "1".as_bytes()
+    );
+
+    // write in memory
+    WritableFileSystem::write(&fs, Utf8Path::new("/a/file1"), "11".as_bytes())
+      .await
+      .unwrap();
+    WritableFileSystem::create_dir(&fs, Utf8Path::new("/a/c"))
+      .await
+      .unwrap();
+    WritableFileSystem::write(&fs, Utf8Path::new("/a/c/file3"), "3".as_bytes())
+      .await
+      .unwrap();
+    assert!(
+      WritableFileSystem::write(&fs, Utf8Path::new("/a/d/file4"), "4".as_bytes())
+        .await
+        .is_err()
+    );
+    assert_eq!(
+      ReadableFileSystem::read(&fs, Utf8Path::new("/a/file1")).unwrap(),
+      "11".as_bytes()
+    );
+    assert_eq!(
+      ReadableFileSystem::read(base.as_ref(), Utf8Path::new("/a/file1")).unwrap(),
+      "1".as_bytes()
+    );
+    assert!(ReadableFileSystem::metadata(base.as_ref(), Utf8Path::new("/a/c")).is_err());
+    let mut children = WritableFileSystem::read_dir(&fs, Utf8Path::new("/a"))
+      .await
+      .unwrap();
+    children.sort();
+    assert_eq!(children, vec!["b", "c", "file1"]);
+
+    // remove in memory
+    WritableFileSystem::remove_dir_all(&fs, Utf8Path::new("/a/b"))
+      .await
+      .unwrap();
+    assert!(ReadableFileSystem::read(&fs, Utf8Path::new("/a/b/file2")).is_err());
+    assert!(ReadableFileSystem::read(base.as_ref(), Utf8Path::new("/a/b/file2")).is_ok());
+    WritableFileSystem::create_dir(&fs, Utf8Path::new("/a/b"))
+      .await
+      .unwrap();
+    assert!(WritableFileSystem::read_dir(&fs, Utf8Path::new("/a/b"))
+      .await
+      .unwrap()
+      .is_empty());
+
+    // discard
+    let fs2 = fs.clone();
+    fs2.discard();
+    assert!(fs.changed_paths().is_empty());
+    assert_eq!(
+      ReadableFileSystem::read(&fs, Utf8Path::new("/a/file1")).unwrap(),
+      "1".as_bytes()
+    );
+    assert!(ReadableFileSystem::read(&fs, Utf8Path::new("/a/b/file2")).is_ok());
+
+    // flush
+    WritableFileSystem::write(&fs, Utf8Path::new("/a/file1"), "11".as_bytes())
+      .await
+      .unwrap();
+    WritableFileSystem::create_dir_all(&fs, Utf8Path::new("/a/c/d"))
+      .await
+      .unwrap();
+    WritableFileSystem::write(&fs, Utf8Path::new("/a/c/d/file3"), "3".as_bytes())
+      .await
+      .unwrap();
+    WritableFileSystem::remove_file(&fs, Utf8Path::new("/a/b/file2"))
+      .await
+      .unwrap();
+    fs.flush().await.unwrap();
+    assert!(fs.changed_paths().is_empty());
+    assert_eq!(
+      ReadableFileSystem::read(base.as_ref(), Utf8Path::new("/a/file1")).unwrap(),
+      "11".as_bytes()
+    );
+    assert_eq!(
+      ReadableFileSystem::read(base.as_ref(), Utf8Path::new("/a/c/d/file3")).unwrap(),
+      "3".as_bytes()
+    );
+    assert!(ReadableFileSystem::read(base.as_ref(), Utf8Path::new("/a/b/file2")).is_err());
+  }
+}
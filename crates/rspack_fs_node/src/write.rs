@@ -159,6 +159,14 @@ impl IntermediateFileSystemExtras for NodeFileSystem {
     let writer = NodeWriteStream::try_new(file, self.0.clone()).await?;
     Ok(Box::new(writer))
   }
+
+  async fn create_new_file(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    // `wx` fails if the file exists
+    let mut writer = NodeWriteStream::open(file, "wx", self.0.clone()).await?;
+    let res = writer.write_all(data).await;
+    writer.close().await?;
+    res
+  }
 }
 
 impl IntermediateFileSystem for NodeFileSystem {}
@@ -259,9 +267,13 @@ pub struct NodeWriteStream {
 
 impl NodeWriteStream {
   pub async fn try_new(file: &Utf8Path, fs: Arc<ThreadsafeNodeFS>) -> Result<Self> {
+    Self::open(file, "w+", fs).await
+  }
+
+  async fn open(file: &Utf8Path, flags: &str, fs: Arc<ThreadsafeNodeFS>) -> Result<Self> {
     let res = fs
       .open
-      .call_with_promise((file.as_str().to_string(), "w+".to_string()))
+      .call_with_promise((file.as_str().to_string(), flags.to_string()))
       .await
       .map_err(map_error_to_fs_error)?;
 
@@ -8,7 +8,7 @@ use anymap::CloneAny;
 use once_cell::sync::OnceCell;
 use rspack_cacheable::{
   cacheable,
-  with::{AsInner, AsOption, AsPreset, AsString},
+  with::{AsInner, AsOption, AsPortable, AsPreset, AsString},
 };
 use rspack_error::{Error, Result};
 use rspack_paths::Utf8PathBuf;
@@ -112,6 +112,7 @@ impl Debug for Content {
 #[derive(Debug, Clone)]
 pub struct ResourceData {
   /// Resource with absolute path, query and fragment
+  #[cacheable(with=AsPortable)]
   pub resource: String,
   /// Absolute resource path only
   #[cacheable(with=AsOption<AsPreset>)]
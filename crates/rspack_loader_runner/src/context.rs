@@ -3,7 +3,7 @@ use std::{path::PathBuf, sync::Arc};
 use derive_more::Debug;
 use rspack_error::Diagnostic;
 use rspack_paths::Utf8Path;
-use rspack_sources::SourceMap;
+use rspack_sources::{BoxSource, SourceMap};
 use rustc_hash::{FxHashMap, FxHashSet as HashSet};
 
 use crate::{
@@ -51,6 +51,8 @@ pub struct LoaderContext<Context> {
   pub build_dependencies: HashSet<PathBuf>,
 
   pub diagnostics: Vec<Diagnostic>,
+  /// Assets emitted by the loaders, keyed by the filename relative to `output.path`.
+  pub assets: FxHashMap<String, BoxSource>,
 
   /// Loader States
   pub(crate) state: State,
@@ -90,6 +92,11 @@ impl<Context> LoaderContext<Context> {
     self.diagnostics.push(diagnostic)
   }
 
+  /// Emit an asset, the same as `this.emitFile` of JavaScript loaders.
+  pub fn emit_asset(&mut self, filename: String, source: BoxSource) {
+    self.assets.insert(filename, source);
+  }
+
   pub fn resource_data(&self) -> &ResourceData {
     &self.resource_data
   }
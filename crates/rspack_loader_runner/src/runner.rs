@@ -2,7 +2,7 @@ use std::{fmt::Debug, path::PathBuf, sync::Arc};
 
 use rspack_error::{error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_fs::ReadableFileSystem;
-use rspack_sources::SourceMap;
+use rspack_sources::{BoxSource, SourceMap};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tokio::task::spawn_blocking;
 
@@ -94,6 +94,7 @@ async fn create_loader_context<Context>(
     plugin,
     resource_data,
     diagnostics: vec![],
+    assets: Default::default(),
   };
 
   if let Some(plugin) = loader_context.plugin.clone() {
@@ -203,6 +204,7 @@ pub struct LoaderResult {
   pub source_map: Option<SourceMap>,
   pub additional_data: Option<AdditionalData>,
   pub parse_meta: HashMap<String, String>,
+  pub assets: HashMap<String, BoxSource>,
 }
 
 impl<Context> TryFrom<LoaderContext<Context>> for TWithDiagnosticArray<LoaderResult> {
@@ -228,6 +230,7 @@ impl<Context> TryFrom<LoaderContext<Context>> for TWithDiagnosticArray<LoaderRes
         source_map: loader_context.source_map,
         additional_data: loader_context.additional_data,
         parse_meta: loader_context.parse_meta,
+        assets: loader_context.assets,
       }
       .with_diagnostic(loader_context.diagnostics),
     )
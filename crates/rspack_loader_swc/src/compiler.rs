@@ -5,6 +5,7 @@
  * Author Donny/강동윤
  * Copyright (c)
  */
+use std::cell::RefCell;
 use std::env;
 use std::fs::File;
 use std::path::Path;
@@ -17,6 +18,7 @@ use indoc::formatdoc;
 use jsonc_parser::parse_to_serde_value;
 use rspack_ast::javascript::{Ast as JsAst, Context as JsAstContext, Program as JsProgram};
 use rspack_error::miette::{self, MietteDiagnostic};
+use rspack_error::TraceableError;
 use rspack_util::itoa;
 use rspack_util::swc::minify_file_comments;
 use serde_json::error::Category;
@@ -39,9 +41,10 @@ use swc_core::ecma::parser::{
 };
 use swc_core::ecma::transforms::base::helpers::{self, Helpers};
 use swc_core::{
-  base::{config::Options, try_with_handler},
+  base::{config::Options, try_with_handler, Compiler, PrintArgs},
   common::Globals,
 };
+use swc_typescript::fast_dts::FastDts;
 use url::Url;
 
 use crate::compiler::miette::Report;
@@ -397,6 +400,54 @@ impl SwcCompiler {
     program
   }
 
+  /// Generates the declarations of `program` with swc's fast dts, which only supports code
+  /// compatible with `isolatedDeclarations`. Returns the code with the errors of the declarations.
+  pub fn emit_isolated_dts(
+    &self,
+    program: &Program,
+  ) -> Result<(String, Vec<TraceableError>), Error> {
+    let mut program = program.clone();
+    let issues = self.run(|| {
+      let unresolved_mark = self
+        .options
+        .unresolved_mark
+        .expect("`unresolved_mark` should be initialized");
+      FastDts::new(self.fm.name.clone(), unresolved_mark, Default::default())
+        .transform(&mut program)
+    });
+    let errors = issues
+      .into_iter()
+      .map(|issue| {
+        let start = self.cm.lookup_byte_offset(issue.range.span.lo).pos.0 as usize;
+        let end = self.cm.lookup_byte_offset(issue.range.span.hi).pos.0 as usize;
+        TraceableError::from_source_file(
+          &self.fm,
+          start,
+          end,
+          "Isolated declarations error".to_string(),
+          issue.message.to_string(),
+        )
+      })
+      .collect();
+
+    // the emitter takes the comments it prints, print with a copy to keep them for the code
+    let comments = {
+      let (leading, trailing) = self.comments.borrow_all();
+      SingleThreadedComments::from_leading_and_trailing(
+        std::rc::Rc::new(RefCell::new(leading.clone())),
+        std::rc::Rc::new(RefCell::new(trailing.clone())),
+      )
+    };
+    let output = Compiler::new(self.cm.clone()).print(
+      &program,
+      PrintArgs {
+        comments: Some(&comments),
+        ..Default::default()
+      },
+    )?;
+    Ok((output.code, errors))
+  }
+
   pub fn input_source_map(
     &self,
     input_src_map: &InputSourceMap,
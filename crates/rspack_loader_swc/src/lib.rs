@@ -2,6 +2,7 @@
 
 mod compiler;
 mod options;
+mod strip_types;
 mod transformer;
 
 use std::default::Default;
@@ -10,9 +11,11 @@ use compiler::{IntoJsAst, SwcCompiler};
 use options::SwcCompilerOptionsWithAdditional;
 pub use options::SwcLoaderJsOptions;
 use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::rspack_sources::{RawStringSource, SourceExt};
 use rspack_core::{Mode, RunnerContext};
 use rspack_error::{error, AnyhowError, Diagnostic, Result};
 use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+use rspack_paths::Utf8Path;
 use rspack_plugin_javascript::ast::{self, SourceMapConfig};
 use rspack_plugin_javascript::TransformOutput;
 use rspack_util::source_map::SourceMapKind;
@@ -89,6 +92,57 @@ impl SwcLoader {
     };
 
     let source = content.into_string_lossy();
+    if let Some((syntax, target, is_module)) = self.options_with_additional.strip_types_options()
+      && let Some(code) = strip_types::strip_types(
+        resource_path.to_string(),
+        &source,
+        syntax,
+        target,
+        is_module,
+      )
+    {
+      // the positions are not changed, so the source map of the previous loader is still valid
+      let map = if !source_map_kind.enabled() {
+        None
+      } else if let Some(source_map) = loader_context.source_map().cloned() {
+        Some(source_map)
+      } else {
+        Some(strip_types::identity_source_map(
+          resource_path.as_str(),
+          source,
+        ))
+      };
+      loader_context.finish_with((code, map));
+      return Ok(());
+    }
+
+    let declaration_filename = if let Some(options) = &self
+      .options_with_additional
+      .rspack_experiments
+      .isolated_declarations
+      && !is_declaration_file(&resource_path)
+    {
+      let context = loader_context.context.options.context.as_path();
+      let root_dir = match &options.root_dir {
+        Some(root_dir) => context.join(root_dir),
+        None => context.to_path_buf(),
+      };
+      let filename =
+        get_declaration_filename(&resource_path, &root_dir, options.out_dir.as_deref());
+      if filename.is_none() {
+        loader_context.emit_diagnostic(Diagnostic::warn(
+          SWC_LOADER_IDENTIFIER.to_string(),
+          format!(
+            "Skipped the declaration file of `{}` which is not in `rootDir` `{}`",
+            resource_path, root_dir
+          ),
+        ));
+      }
+      filename
+    } else {
+      None
+    };
+
     let c = SwcCompiler::new(resource_path.into_std_path_buf(), source, swc_options)
       .map_err(AnyhowError::from)?;
 
@@ -98,6 +152,18 @@ impl SwcLoader {
       })
       .map_err(AnyhowError::from)?;
 
+    if let Some(filename) = declaration_filename
+      && built.syntax.typescript()
+    {
+      let (code, errors) = c
+        .emit_isolated_dts(&built.program)
+        .map_err(AnyhowError::from)?;
+      for error in errors {
+        loader_context.emit_diagnostic(rspack_error::Error::from(error).into());
+      }
+      loader_context.emit_asset(filename, RawStringSource::from(code).boxed());
+    }
+
     let input_source_map = c
       .input_source_map(&built.input_source_map)
       .map_err(|e| error!(e.to_string()))?;
@@ -138,6 +204,32 @@ impl SwcLoader {
 
 pub const SWC_LOADER_IDENTIFIER: &str = "builtin:swc-loader";
 
+fn is_declaration_file(resource_path: &Utf8Path) -> bool {
+  resource_path.file_name().is_some_and(|name| {
+    [".d.ts", ".d.mts", ".d.cts"]
+      .iter()
+      .any(|ext| name.ends_with(ext))
+  })
+}
+
+/// Returns the filename of the declaration file relative to `output.path`, e.g. `src/foo.mts` is
+/// emitted to `types/src/foo.d.mts` with `outDir: "types"`.
+fn get_declaration_filename(
+  resource_path: &Utf8Path,
+  root_dir: &Utf8Path,
+  out_dir: Option<&str>,
+) -> Option<String> {
+  let relative = resource_path.strip_prefix(root_dir).ok()?;
+  let extension = match relative.extension() {
+    Some("mts") => "d.mts",
+    Some("cts") => "d.cts",
+    _ => "d.ts",
+  };
+  let filename =
+    Utf8Path::new(out_dir.unwrap_or_default()).join(relative.with_extension(extension));
+  Some(filename.as_str().replace('\\', "/"))
+}
+
 #[cacheable_dyn]
 #[async_trait::async_trait]
 impl Loader<RunnerContext> for SwcLoader {
@@ -9,16 +9,33 @@ use swc_core::base::config::{
   Config, ErrorConfig, FileMatcher, InputSourceMap, IsModule, JscConfig, ModuleConfig, Options,
   SourceMapsConfig,
 };
+use swc_core::ecma::{
+  ast::EsVersion,
+  parser::{Syntax, TsSyntax},
+};
 
 #[derive(Default, Deserialize, Debug)]
 #[serde(rename_all = "camelCase", default)]
 pub struct RawRspackExperiments {
   pub import: Option<Vec<RawImportOptions>>,
+  pub isolated_declarations: Option<IsolatedDeclarationsOptions>,
+  pub strip_types: bool,
+}
+
+#[derive(Default, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct IsolatedDeclarationsOptions {
+  /// The directory the paths of declaration files are relative to, defaults to `context`.
+  pub root_dir: Option<String>,
+  /// The directory to emit declaration files to, relative to `output.path`.
+  pub out_dir: Option<String>,
 }
 
 #[derive(Default, Debug)]
 pub(crate) struct RspackExperiments {
   pub(crate) import: Option<Vec<ImportOptions>>,
+  pub(crate) isolated_declarations: Option<IsolatedDeclarationsOptions>,
+  pub(crate) strip_types: bool,
 }
 
 impl From<RawRspackExperiments> for RspackExperiments {
@@ -27,6 +44,8 @@ impl From<RawRspackExperiments> for RspackExperiments {
       import: value
         .import
         .map(|i| i.into_iter().map(|v| v.into()).collect()),
+      isolated_declarations: value.isolated_declarations,
+      strip_types: value.strip_types,
     }
   }
 }
@@ -96,6 +115,43 @@ impl AsRefStrConverter for SwcCompilerOptionsWithAdditional {
   }
 }
 
+impl SwcCompilerOptionsWithAdditional {
+  /// Returns the parser options of the type-strip-only fast path, which is only available when the
+  /// code is TypeScript without JSX, the target is at least ES2022 and no other transform is
+  /// configured. The syntax newer than ES2022 is checked against the target when stripping.
+  pub(crate) fn strip_types_options(&self) -> Option<(TsSyntax, EsVersion, IsModule)> {
+    let rspack_experiments = &self.rspack_experiments;
+    if !rspack_experiments.strip_types
+      || rspack_experiments.import.is_some()
+      || rspack_experiments.isolated_declarations.is_some()
+    {
+      return None;
+    }
+    let config = &self.swc_options.config;
+    let Some(Syntax::Typescript(syntax)) = config.jsc.syntax else {
+      return None;
+    };
+    let target = config.jsc.target?;
+    if syntax.tsx
+      || target < EsVersion::Es2022
+      || config.env.is_some()
+      || config.module.is_some()
+      || config.minify.into_bool()
+      || config.jsc.minify.is_some()
+      || config.jsc.transform.as_ref().is_some()
+      || config.jsc.experimental.plugins.is_some()
+      || !config.jsc.paths.is_empty()
+    {
+      return None;
+    }
+    Some((
+      syntax,
+      target,
+      config.is_module.unwrap_or(IsModule::Bool(true)),
+    ))
+  }
+}
+
 const SOURCE_MAP_INLINE: &str = "inline";
 
 impl TryFrom<&str> for SwcCompilerOptionsWithAdditional {
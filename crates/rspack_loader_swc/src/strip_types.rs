@@ -0,0 +1,757 @@
+//! The fast path of the loader for TypeScript, which only strips the types.
+//!
+//! The types are replaced with whitespace, so the positions of the remaining code are the same as
+//! the source and no source map transform is needed. Syntax that can't be erased, e.g. enums, namespaces and
+//! parameter properties, bails out to the full transform.
+
+use std::sync::Arc;
+
+use rspack_core::rspack_sources::{self, encode_mappings, Mapping, OriginalLocation};
+use rustc_hash::FxHashSet as HashSet;
+use swc_core::atoms::Atom;
+use swc_core::common::{BytePos, FileName, SourceMap, Span, Spanned};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::parser::{
+  parse_file_as_module, parse_file_as_program, parse_file_as_script, Syntax, TsSyntax,
+};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+const MODIFIERS: &[&str] = &[
+  "public",
+  "private",
+  "protected",
+  "readonly",
+  "override",
+  "abstract",
+  "declare",
+];
+
+/// Returns the code with the types stripped, or `None` if the code needs the full transform,
+/// including the code with syntax errors, which are reported by the full transform.
+pub(crate) fn strip_types(
+  resource_path: String,
+  source: &str,
+  syntax: TsSyntax,
+  target: EsVersion,
+  is_module: IsModule,
+) -> Option<String> {
+  let cm = SourceMap::default();
+  let fm = cm.new_source_file(
+    Arc::new(FileName::Real(resource_path.into())),
+    source.into(),
+  );
+  let syntax = Syntax::Typescript(syntax);
+  let mut errors = vec![];
+  let program = match is_module {
+    IsModule::Bool(true) => {
+      parse_file_as_module(&fm, syntax, target, None, &mut errors).map(Program::Module)
+    }
+    IsModule::Bool(false) => {
+      parse_file_as_script(&fm, syntax, target, None, &mut errors).map(Program::Script)
+    }
+    IsModule::Unknown => parse_file_as_program(&fm, syntax, target, None, &mut errors),
+  }
+  .ok()?;
+  if !errors.is_empty() {
+    return None;
+  }
+
+  let mut collector = ValueReferenceCollector::default();
+  program.visit_with(&mut collector);
+  let mut stripper = TypeStripper {
+    src: source,
+    start_pos: fm.start_pos,
+    code: source.as_bytes().to_vec(),
+    value_references: collector.references,
+    type_only_declarations: get_type_only_declarations(&program),
+    target,
+    unsupported: false,
+  };
+  program.visit_with(&mut stripper);
+  if stripper.unsupported {
+    return None;
+  }
+  String::from_utf8(stripper.code).ok()
+}
+
+/// Collects the identifiers referenced as values, used to elide the imports only used as types.
+#[derive(Default)]
+struct ValueReferenceCollector {
+  references: HashSet<Atom>,
+}
+
+impl Visit for ValueReferenceCollector {
+  noop_visit_type!();
+
+  fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+  fn visit_ident(&mut self, n: &Ident) {
+    self.references.insert(n.sym.clone());
+  }
+}
+
+/// Returns the top-level names only declared or imported as types, which are elided from
+/// `export { ... }`.
+fn get_type_only_declarations(program: &Program) -> HashSet<Atom> {
+  let Program::Module(module) = program else {
+    return HashSet::default();
+  };
+  let mut types = HashSet::default();
+  let mut decls = vec![];
+  for item in &module.body {
+    match item {
+      ModuleItem::Stmt(Stmt::Decl(decl)) => decls.push(decl),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => decls.push(&export.decl),
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+        for specifier in &import.specifiers {
+          let local = match specifier {
+            ImportSpecifier::Named(named) if import.type_only || named.is_type_only => &named.local,
+            ImportSpecifier::Default(default) if import.type_only => &default.local,
+            ImportSpecifier::Namespace(namespace) if import.type_only => &namespace.local,
+            _ => continue,
+          };
+          types.insert(local.sym.clone());
+        }
+      }
+      _ => {}
+    }
+  }
+  let mut values = HashSet::default();
+  for decl in decls {
+    match decl {
+      Decl::TsInterface(interface) => {
+        types.insert(interface.id.sym.clone());
+      }
+      Decl::TsTypeAlias(alias) => {
+        types.insert(alias.id.sym.clone());
+      }
+      Decl::Class(class) if !class.declare => {
+        values.insert(class.ident.sym.clone());
+      }
+      Decl::Fn(function) if !function.declare => {
+        values.insert(function.ident.sym.clone());
+      }
+      Decl::Var(var) if !var.declare => {
+        for declarator in &var.decls {
+          if let Pat::Ident(ident) = &declarator.name {
+            values.insert(ident.id.sym.clone());
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+  types.retain(|name| !values.contains(name));
+  types
+}
+
+struct TypeStripper<'a> {
+  src: &'a str,
+  start_pos: BytePos,
+  code: Vec<u8>,
+  value_references: HashSet<Atom>,
+  type_only_declarations: HashSet<Atom>,
+  target: EsVersion,
+  /// The code has syntax that can't be erased.
+  unsupported: bool,
+}
+
+impl TypeStripper<'_> {
+  fn offset(&self, pos: BytePos) -> usize {
+    (pos.0 - self.start_pos.0) as usize
+  }
+
+  /// Replaces the code in `start..end` with whitespace, line breaks are kept.
+  fn erase_range(&mut self, start: usize, end: usize) {
+    for byte in &mut self.code[start..end] {
+      if !matches!(byte, b'\n' | b'\r') {
+        *byte = b' ';
+      }
+    }
+  }
+
+  fn erase(&mut self, span: Span) {
+    self.erase_range(self.offset(span.lo), self.offset(span.hi));
+  }
+
+  /// Erases a statement or a class member, a `;` is kept in its place to keep the code after it from
+  /// joining the code before it, e.g. `a\ninterface B {}\n[c]` is not `a[c]`.
+  fn erase_statement(&mut self, span: Span) {
+    let start = self.keyword_start(self.offset(span.lo), "declare");
+    self.erase_range(start, self.offset(span.hi));
+    self.code[start] = b';';
+  }
+
+  /// Returns the start of `keyword` if it's right before `start`.
+  fn keyword_start(&self, start: usize, keyword: &str) -> usize {
+    let before = self.src[..start].trim_end();
+    if before.ends_with(keyword)
+      && !before[..before.len() - keyword.len()]
+        .ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+    {
+      before.len() - keyword.len()
+    } else {
+      start
+    }
+  }
+
+  /// Erases `char` if it's the next non-whitespace character after `start`, e.g. the `?` of
+  /// optional parameters and the `!` of definite assignments.
+  fn erase_next_char(&mut self, start: usize, char: char) {
+    let rest = &self.src[start..];
+    let trimmed = rest.trim_start();
+    if trimmed.starts_with(char) {
+      let position = start + rest.len() - trimmed.len();
+      self.erase_range(position, position + 1);
+    }
+  }
+
+  /// Erases a removed import or export specifier with the comma around it.
+  fn erase_specifier(&mut self, span: Span) {
+    let start = self.offset(span.lo);
+    let end = self.offset(span.hi);
+    let after = &self.src[end..];
+    let trimmed = after.trim_start();
+    if trimmed.starts_with(',') {
+      self.erase_range(start, end + after.len() - trimmed.len() + 1);
+      return;
+    }
+    let before = self.src[..start].trim_end();
+    if before.ends_with(',') {
+      self.erase_range(before.len() - 1, end);
+    } else {
+      self.erase_range(start, end);
+    }
+  }
+
+  /// Erases the modifiers only available in TypeScript, e.g. `private` and `readonly`, between
+  /// the start of a class member and its key.
+  fn erase_modifiers(&mut self, start: BytePos, key_start: BytePos) {
+    let start = self.offset(start);
+    let end = self.offset(key_start);
+    let mut words = vec![];
+    let mut word_start = None;
+    for (index, char) in self.src[start..end].char_indices() {
+      match (char.is_ascii_alphabetic(), word_start) {
+        (true, None) => word_start = Some(index),
+        (false, Some(word)) => {
+          words.push((start + word, start + index));
+          word_start = None;
+        }
+        _ => {}
+      }
+    }
+    if let Some(word) = word_start {
+      words.push((start + word, end));
+    }
+    for (word_start, word_end) in words {
+      if MODIFIERS.contains(&&self.src[word_start..word_end]) {
+        self.erase_range(word_start, word_end);
+      }
+    }
+  }
+
+  /// Erases declarations only available in TypeScript, returns `true` if `decl` is erased.
+  fn erase_decl(&mut self, decl: &Decl, span: Span) -> bool {
+    let erasable = match decl {
+      Decl::TsInterface(_) | Decl::TsTypeAlias(_) => true,
+      Decl::Class(class) => class.declare,
+      // `function f(): void;` is an overload signature
+      Decl::Fn(function) => function.declare || function.function.body.is_none(),
+      Decl::Var(var) => var.declare,
+      Decl::TsEnum(enum_decl) => enum_decl.declare,
+      Decl::TsModule(module) => module.declare || module.global || is_type_only_namespace(module),
+      Decl::Using(_) => false,
+    };
+    if erasable {
+      self.erase_statement(span);
+    }
+    erasable
+  }
+
+  fn strip_import(&mut self, import: &ImportDecl) {
+    if import.type_only {
+      self.erase_statement(import.span);
+      return;
+    }
+    // same as TypeScript, the imports only used as types are elided
+    let removed = import
+      .specifiers
+      .iter()
+      .filter(|specifier| match specifier {
+        ImportSpecifier::Named(named) => {
+          named.is_type_only || !self.value_references.contains(&named.local.sym)
+        }
+        ImportSpecifier::Default(default) => !self.value_references.contains(&default.local.sym),
+        ImportSpecifier::Namespace(namespace) => {
+          !self.value_references.contains(&namespace.local.sym)
+        }
+      })
+      .collect::<Vec<_>>();
+    if removed.is_empty() {
+      return;
+    }
+    if removed.len() == import.specifiers.len() {
+      self.erase_statement(import.span);
+      return;
+    }
+    for specifier in removed {
+      self.erase_specifier(specifier.span());
+    }
+  }
+
+  fn strip_named_export(&mut self, export: &NamedExport) {
+    if export.type_only {
+      self.erase_statement(export.span);
+      return;
+    }
+    for specifier in &export.specifiers {
+      if let ExportSpecifier::Named(named) = specifier
+        && (named.is_type_only
+          || (export.src.is_none()
+            && matches!(&named.orig, ModuleExportName::Ident(orig) if self.type_only_declarations.contains(&orig.sym))))
+      {
+        self.erase_specifier(named.span);
+      }
+    }
+  }
+}
+
+fn is_type_only_namespace(module: &TsModuleDecl) -> bool {
+  match &module.body {
+    Some(TsNamespaceBody::TsModuleBlock(block)) => block.body.iter().all(|item| {
+      matches!(
+        item,
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsInterface(_) | Decl::TsTypeAlias(_)))
+      ) || matches!(
+        item,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+          decl: Decl::TsInterface(_) | Decl::TsTypeAlias(_),
+          ..
+        }))
+      )
+    }),
+    Some(TsNamespaceBody::TsNamespaceDecl(decl)) => is_type_only_namespace(&TsModuleDecl {
+      span: decl.span,
+      declare: decl.declare,
+      global: decl.global,
+      namespace: true,
+      id: TsModuleName::Ident(decl.id.clone()),
+      body: Some((*decl.body).clone()),
+    }),
+    None => true,
+  }
+}
+
+impl Visit for TypeStripper<'_> {
+  fn visit_module_item(&mut self, n: &ModuleItem) {
+    if self.unsupported {
+      return;
+    }
+    match n {
+      ModuleItem::Stmt(stmt) => self.visit_stmt(stmt),
+      ModuleItem::ModuleDecl(decl) => match decl {
+        ModuleDecl::Import(import) => self.strip_import(import),
+        ModuleDecl::ExportDecl(export) => {
+          if !self.erase_decl(&export.decl, export.span) {
+            export.visit_children_with(self);
+          }
+        }
+        ModuleDecl::ExportNamed(export) => self.strip_named_export(export),
+        ModuleDecl::ExportAll(export) if export.type_only => self.erase_statement(export.span),
+        ModuleDecl::ExportDefaultDecl(export)
+          if matches!(&export.decl, DefaultDecl::TsInterfaceDecl(_))
+            || matches!(&export.decl, DefaultDecl::Fn(function) if function.function.body.is_none()) =>
+        {
+          self.erase_statement(export.span)
+        }
+        ModuleDecl::TsImportEquals(import) if import.is_type_only => {
+          self.erase_statement(import.span)
+        }
+        ModuleDecl::TsNamespaceExport(export) => self.erase_statement(export.span),
+        ModuleDecl::TsImportEquals(_) | ModuleDecl::TsExportAssignment(_) => {
+          self.unsupported = true
+        }
+        _ => decl.visit_children_with(self),
+      },
+    }
+  }
+
+  fn visit_stmt(&mut self, n: &Stmt) {
+    if self.unsupported {
+      return;
+    }
+    if let Stmt::Decl(decl) = n
+      && self.erase_decl(decl, decl.span())
+    {
+      return;
+    }
+    n.visit_children_with(self);
+  }
+
+  fn visit_class(&mut self, n: &Class) {
+    if n.is_abstract {
+      let start = self.offset(n.span.lo);
+      if self.src[start..].starts_with("abstract") {
+        self.erase_range(start, start + "abstract".len());
+      } else {
+        let keyword_start = self.keyword_start(start, "abstract");
+        self.erase_range(keyword_start, start);
+      }
+    }
+    if let (Some(first), Some(last)) = (n.implements.first(), n.implements.last()) {
+      let start = self.offset(first.span.lo);
+      let keyword_start = self.keyword_start(start, "implements");
+      self.erase_range(keyword_start, self.offset(last.span.hi));
+    }
+    n.decorators.visit_with(self);
+    n.super_class.visit_with(self);
+    n.type_params.visit_with(self);
+    n.super_type_params.visit_with(self);
+    n.body.visit_with(self);
+  }
+
+  fn visit_class_member(&mut self, n: &ClassMember) {
+    match n {
+      ClassMember::TsIndexSignature(signature) => self.erase_statement(signature.span),
+      ClassMember::Method(method) if method.function.body.is_none() => {
+        self.erase_statement(method.span)
+      }
+      ClassMember::PrivateMethod(method) if method.function.body.is_none() => {
+        self.erase_statement(method.span)
+      }
+      ClassMember::Constructor(constructor) if constructor.body.is_none() => {
+        self.erase_statement(constructor.span)
+      }
+      ClassMember::ClassProp(prop) if prop.declare || prop.is_abstract => {
+        self.erase_statement(prop.span)
+      }
+      ClassMember::Method(method) => {
+        self.erase_modifiers(method.span.lo, method.key.span().lo);
+        if method.is_optional {
+          self.erase_next_char(self.offset(method.key.span().hi), '?');
+        }
+        method.visit_children_with(self);
+      }
+      ClassMember::PrivateMethod(method) => {
+        self.erase_modifiers(method.span.lo, method.key.span.lo);
+        method.visit_children_with(self);
+      }
+      ClassMember::Constructor(constructor) => {
+        self.erase_modifiers(constructor.span.lo, constructor.key.span().lo);
+        constructor.visit_children_with(self);
+      }
+      ClassMember::ClassProp(prop) => {
+        self.erase_modifiers(prop.span.lo, prop.key.span().lo);
+        if prop.is_optional {
+          self.erase_next_char(self.offset(prop.key.span().hi), '?');
+        }
+        if prop.definite {
+          self.erase_next_char(self.offset(prop.key.span().hi), '!');
+        }
+        prop.visit_children_with(self);
+      }
+      ClassMember::PrivateProp(prop) => {
+        self.erase_modifiers(prop.span.lo, prop.key.span.lo);
+        if prop.is_optional {
+          self.erase_next_char(self.offset(prop.key.span.hi), '?');
+        }
+        if prop.definite {
+          self.erase_next_char(self.offset(prop.key.span.hi), '!');
+        }
+        prop.visit_children_with(self);
+      }
+      ClassMember::AutoAccessor(_) => self.unsupported = true,
+      ClassMember::Empty(_) | ClassMember::StaticBlock(_) => n.visit_children_with(self),
+    }
+  }
+
+  fn visit_param(&mut self, n: &Param) {
+    if let Pat::Ident(ident) = &n.pat
+      && &*ident.id.sym == "this"
+    {
+      self.unsupported = true;
+      return;
+    }
+    n.visit_children_with(self);
+  }
+
+  fn visit_binding_ident(&mut self, n: &BindingIdent) {
+    if n.id.optional {
+      self.erase_next_char(self.offset(n.id.span.hi), '?');
+    }
+    n.type_ann.visit_with(self);
+  }
+
+  fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+    if n.definite
+      && let Pat::Ident(ident) = &n.name
+    {
+      self.erase_next_char(self.offset(ident.id.span.hi), '!');
+    }
+    n.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+    // the `=>` can't be on a new line
+    if let Some(return_type) = &n.return_type
+      && self.src[self.offset(return_type.span.lo)..self.offset(return_type.span.hi)]
+        .contains(['\n', '\r'])
+    {
+      self.unsupported = true;
+      return;
+    }
+    n.visit_children_with(self);
+  }
+
+  fn visit_ts_type_ann(&mut self, n: &TsTypeAnn) {
+    self.erase(n.span);
+  }
+
+  fn visit_ts_type_param_decl(&mut self, n: &TsTypeParamDecl) {
+    self.erase(n.span);
+  }
+
+  fn visit_ts_type_param_instantiation(&mut self, n: &TsTypeParamInstantiation) {
+    self.erase(n.span);
+  }
+
+  fn visit_ts_as_expr(&mut self, n: &TsAsExpr) {
+    n.expr.visit_with(self);
+    self.erase(Span::new(n.expr.span().hi, n.span.hi));
+  }
+
+  fn visit_ts_satisfies_expr(&mut self, n: &TsSatisfiesExpr) {
+    n.expr.visit_with(self);
+    self.erase(Span::new(n.expr.span().hi, n.span.hi));
+  }
+
+  fn visit_ts_const_assertion(&mut self, n: &TsConstAssertion) {
+    n.expr.visit_with(self);
+    self.erase(Span::new(n.expr.span().hi, n.span.hi));
+  }
+
+  fn visit_ts_non_null_expr(&mut self, n: &TsNonNullExpr) {
+    n.expr.visit_with(self);
+    self.erase(Span::new(n.expr.span().hi, n.span.hi));
+  }
+
+  // `<T>value` can't be erased without adding parentheses
+  fn visit_ts_type_assertion(&mut self, _: &TsTypeAssertion) {
+    self.unsupported = true;
+  }
+
+  fn visit_ts_enum_decl(&mut self, _: &TsEnumDecl) {
+    self.unsupported = true;
+  }
+
+  fn visit_ts_module_decl(&mut self, _: &TsModuleDecl) {
+    self.unsupported = true;
+  }
+
+  fn visit_ts_param_prop(&mut self, _: &TsParamProp) {
+    self.unsupported = true;
+  }
+
+  // decorators and `using` declarations may not be supported by the target
+  fn visit_decorator(&mut self, _: &Decorator) {
+    self.unsupported = true;
+  }
+
+  fn visit_using_decl(&mut self, _: &UsingDecl) {
+    self.unsupported = true;
+  }
+
+  // the `v` flag of ES2024 is transformed by the full transform for older targets
+  fn visit_regex(&mut self, n: &Regex) {
+    if self.target < EsVersion::EsNext && n.flags.contains('v') {
+      self.unsupported = true;
+    }
+  }
+}
+
+/// Returns the source map mapping the start of each token of the stripped code to the same
+/// position of the source, the columns are counted in UTF-16 code units. A token is approximated
+/// by a run of identifier characters or a single punctuator, so the mappings inside strings,
+/// comments and template literals are finer than the actual tokens, which is harmless since the
+/// positions are the same.
+pub(crate) fn identity_source_map(
+  resource_path: &str,
+  source: String,
+) -> rspack_sources::SourceMap {
+  let mut mappings = vec![];
+  for (line, content) in source.split('\n').enumerate() {
+    let mut column = 0;
+    let mut in_word = false;
+    for char in content.chars() {
+      let is_word = char.is_alphanumeric() || char == '_' || char == '$';
+      if !char.is_whitespace() && !(is_word && in_word) {
+        mappings.push(Mapping {
+          generated_line: line as u32 + 1,
+          generated_column: column,
+          original: Some(OriginalLocation {
+            source_index: 0,
+            original_line: line as u32 + 1,
+            original_column: column,
+            name_index: None,
+          }),
+        });
+      }
+      in_word = is_word;
+      column += char.len_utf16() as u32;
+    }
+  }
+  rspack_sources::SourceMap::new(
+    encode_mappings(mappings.into_iter()),
+    vec![resource_path.to_string()],
+    vec![source],
+    Vec::<String>::new(),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn strip(source: &str, target: EsVersion) -> Option<String> {
+    strip_types(
+      "test.ts".to_string(),
+      source,
+      TsSyntax::default(),
+      target,
+      IsModule::Bool(true),
+    )
+  }
+
+  /// Removes the whitespace and the repeated `;` left by the erased code.
+  fn normalize(code: &str) -> String {
+    let mut normalized = String::new();
+    for char in code.chars().filter(|char| !char.is_whitespace()) {
+      if char == ';' && normalized.ends_with(';') {
+        continue;
+      }
+      normalized.push(char);
+    }
+    normalized
+  }
+
+  fn assert_stripped(source: &str, expected: &str) {
+    let code = strip(source, EsVersion::Es2022).expect("should strip the types");
+    assert_eq!(code.len(), source.len());
+    assert_eq!(normalize(&code), normalize(expected));
+  }
+
+  #[test]
+  fn should_keep_asi_after_erased_statements() {
+    assert_eq!(
+      strip("let a = b\ninterface I {}\n[c]", EsVersion::Es2022).as_deref(),
+      Some("let a = b\n;             \n[c]")
+    );
+    assert_eq!(
+      strip("let a = b\ndeclare class X {}\n(c)", EsVersion::Es2022).as_deref(),
+      Some("let a = b\n;                 \n(c)")
+    );
+  }
+
+  #[test]
+  fn should_erase_exports_of_imported_types() {
+    assert_stripped(
+      r#"import { type T, U } from "./t";
+import type { V } from "./v";
+export { T, U, V };
+let u: U;"#,
+      r#"import { U } from "./t";
+export { U };
+let u;"#,
+    );
+  }
+
+  #[test]
+  fn should_erase_overloads() {
+    assert_stripped(
+      r#"function f(a: string): string;
+function f(a: number): number;
+function f(a: any) { return a; }
+class C { m(a: string): void; m(a: any) {} }
+export function g(): void;
+export function g() {}"#,
+      r#"; function f(a) { return a; }
+class C { ; m(a) {} }
+; export function g() {}"#,
+    );
+  }
+
+  #[test]
+  fn should_erase_abstract_classes() {
+    assert_stripped(
+      r#"export abstract class A {
+  abstract m(): void;
+  abstract p: string;
+  n(): number { return 1; }
+}"#,
+      r#"export class A { ; n() { return 1; } }"#,
+    );
+  }
+
+  #[test]
+  fn should_erase_declare_fields() {
+    assert_stripped(
+      r#"class A {
+  declare x: string;
+  y!: number;
+  z?: string = "z";
+  private readonly w = 1;
+}"#,
+      r#"class A { ; y; z = "z"; w = 1; }"#,
+    );
+  }
+
+  #[test]
+  fn should_erase_generic_arrows() {
+    assert_stripped(
+      r#"const id = <T,>(value: T): T => value;
+const pair = async <A, B>(a: A, b: B): Promise<[A, B]> => [a, b];"#,
+      r#"const id = (value) => value;
+const pair = async (a, b) => [a, b];"#,
+    );
+  }
+
+  #[test]
+  fn should_handle_multi_line_return_types() {
+    assert_stripped(
+      r#"function f(): {
+  a: string;
+} {
+  return { a: "" };
+}"#,
+      r#"function f() { return { a: "" }; }"#,
+    );
+    // the `=>` of an arrow function can't be on a new line
+    assert_eq!(
+      strip(
+        "const g = (): {\n  a: string;\n} => ({ a: \"\" });",
+        EsVersion::Es2022
+      ),
+      None
+    );
+  }
+
+  #[test]
+  fn should_erase_strings_inside_types() {
+    assert_stripped(
+      r#"let a: "}" | '{ b: 1 }' | `t${string}` = "x";
+type B = { "c;d": "=>" };"#,
+      r#"let a = "x";"#,
+    );
+  }
+
+  #[test]
+  fn should_check_syntax_against_target() {
+    let source = "const r = /[\\p{L}--[a-z]]/v;";
+    assert_eq!(strip(source, EsVersion::Es2022), None);
+    assert_eq!(
+      strip(source, EsVersion::EsNext).as_deref(),
+      Some("const r = /[\\p{L}--[a-z]]/v;")
+    );
+  }
+}
@@ -8,7 +8,7 @@ use std::{
 pub use camino::{Utf8Component, Utf8Components, Utf8Path, Utf8PathBuf, Utf8Prefix};
 use rspack_cacheable::{
   cacheable,
-  with::{AsRefStr, AsRefStrConverter},
+  with::{AsPortable, AsRefStrConverter},
 };
 
 pub trait AssertUtf8 {
@@ -46,7 +46,7 @@ impl<'a> AssertUtf8 for &'a Path {
   }
 }
 
-#[cacheable(with=AsRefStr, hashable)]
+#[cacheable(with=AsPortable, hashable)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ArcPath(Arc<Path>);
 
@@ -408,6 +408,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         presentational_dependencies: vec![],
         code_generation_dependencies: vec![],
         side_effects_bailout: None,
+        optimization_bailouts: vec![],
       }
       .with_empty_diagnostic(),
     )
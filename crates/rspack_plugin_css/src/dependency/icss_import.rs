@@ -0,0 +1,261 @@
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::{
+  AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId, DependencyRange,
+  DependencyTemplate, DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleGraph,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::{
+  miette::{MietteDiagnostic, Severity},
+  Diagnostic, DiagnosticExt, TraceableError,
+};
+use rspack_util::ext::DynHash;
+
+use crate::{
+  parser_and_generator::{CssExport, CssParserAndGenerator},
+  utils::export_locals_convention,
+};
+
+// the max depth of the values re-exported by `@value ... from`, to avoid endless cycles
+const MAX_VALUE_DEPTH: usize = 32;
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssIcssImportReplacement {
+  /// The imported name.
+  pub name: String,
+  pub start: u32,
+  pub end: u32,
+}
+
+/// An `@value a, b as c from "./x.css";` or `:import("./x.css") { c: b; }` of a CSS Modules file,
+/// the references of the imported values are replaced with the values of the imported module.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssIcssImportDependency {
+  id: DependencyId,
+  request: String,
+  names: Vec<String>,
+  replaces: Vec<CssIcssImportReplacement>,
+  range: DependencyRange,
+}
+
+impl CssIcssImportDependency {
+  pub fn new(
+    request: String,
+    names: Vec<String>,
+    replaces: Vec<CssIcssImportReplacement>,
+    range: DependencyRange,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      names,
+      replaces,
+      range,
+    }
+  }
+
+  /// Removes the statement and replaces the references for which `filter` returns `true`, the
+  /// references in removed rule sets are skipped.
+  pub(crate) fn apply_replaces(
+    &self,
+    source: &mut TemplateReplaceSource,
+    compilation: &Compilation,
+    filter: impl Fn(u32, u32) -> bool,
+  ) {
+    let mg = compilation.get_module_graph();
+    source.replace(self.range.start, self.range.end, "", None);
+    for replace in &self.replaces {
+      if filter(replace.start, replace.end)
+        && let Some(value) = resolve_value(&mg, &self.id, &replace.name).value()
+      {
+        source.replace(replace.start, replace.end, value, None);
+      }
+    }
+  }
+}
+
+enum ResolvedValue<'a> {
+  Value(&'a str),
+  NotFound,
+  /// The value is re-exported more than `MAX_VALUE_DEPTH` times, likely in a cycle.
+  TooDeep,
+}
+
+impl<'a> ResolvedValue<'a> {
+  fn value(self) -> Option<&'a str> {
+    match self {
+      ResolvedValue::Value(value) => Some(value),
+      _ => None,
+    }
+  }
+}
+
+/// Returns the value exported as `name` by the module of `dependency_id`, following the values
+/// the module imports from other modules.
+fn resolve_value<'a>(
+  mg: &'a ModuleGraph,
+  dependency_id: &DependencyId,
+  name: &'a str,
+) -> ResolvedValue<'a> {
+  let mut dependency_id = *dependency_id;
+  let mut name = name;
+  for _ in 0..MAX_VALUE_DEPTH {
+    let Some(export) = get_export(mg, &dependency_id, name) else {
+      return ResolvedValue::NotFound;
+    };
+    match (&export.from, export.id) {
+      (Some(_), Some(id)) => {
+        dependency_id = id;
+        name = &export.ident;
+      }
+      _ => return ResolvedValue::Value(&export.ident),
+    }
+  }
+  ResolvedValue::TooDeep
+}
+
+fn get_export<'a>(
+  mg: &'a ModuleGraph,
+  dependency_id: &DependencyId,
+  name: &str,
+) -> Option<&'a CssExport> {
+  let module = mg.get_module_by_dependency_id(dependency_id)?;
+  let parser_and_generator = module
+    .as_normal_module()?
+    .parser_and_generator()
+    .as_any()
+    .downcast_ref::<CssParserAndGenerator>()?;
+  let exports = parser_and_generator.exports.as_ref()?;
+  exports
+    .get(name)
+    .or_else(|| {
+      // the value may only be exported with the names of `exportsConvention`
+      let convention = parser_and_generator.convention.as_ref()?;
+      export_locals_convention(name, convention)
+        .iter()
+        .find_map(|name| exports.get(name))
+    })?
+    .first()
+}
+
+#[cacheable_dyn]
+impl Dependency for CssIcssImportDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CssImport
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssIcssImport
+  }
+
+  fn range(&self) -> Option<&DependencyRange> {
+    Some(&self.range)
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let diagnostics = self
+      .names
+      .iter()
+      .filter(|name| matches!(resolve_value(module_graph, &self.id, name), ResolvedValue::TooDeep))
+      .map(|name| {
+        let title = "CssValueError";
+        let message = format!(
+          "Can't resolve the value '{name}' imported from '{}', it is re-exported more than {MAX_VALUE_DEPTH} times, there may be a cycle",
+          self.request
+        );
+        let source = module_graph
+          .get_parent_module(&self.id)
+          .and_then(|module| module_graph.module_by_identifier(module))
+          .and_then(|module| module.original_source())
+          .map(|source| source.source());
+        if let Some(source) = source {
+          Diagnostic::from(
+            TraceableError::from_file(
+              source.into_owned(),
+              self.range.start as usize,
+              self.range.end as usize,
+              title.to_string(),
+              message,
+            )
+            .with_severity(Severity::Warning)
+            .boxed(),
+          )
+        } else {
+          Diagnostic::from(
+            MietteDiagnostic::new(message)
+              .with_code(title)
+              .with_severity(Severity::Warning)
+              .boxed(),
+          )
+        }
+      })
+      .collect::<Vec<_>>();
+    (!diagnostics.is_empty()).then_some(diagnostics)
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &rspack_core::ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    self
+      .names
+      .iter()
+      .map(|name| ExtendedReferencedExport::Array(vec![name.as_str().into()]))
+      .collect()
+  }
+}
+
+#[cacheable_dyn]
+impl ModuleDependency for CssIcssImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+#[cacheable_dyn]
+impl DependencyTemplate for CssIcssImportDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    self.apply_replaces(source, code_generatable_context.compilation, |_, _| true);
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    hasher: &mut dyn std::hash::Hasher,
+    compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+    // the values are inlined, so the module changes when the imported values change
+    let mg = compilation.get_module_graph();
+    for name in &self.names {
+      resolve_value(&mg, &self.id, name).value().dyn_hash(hasher);
+    }
+  }
+}
+
+impl AsContextDependency for CssIcssImportDependency {}
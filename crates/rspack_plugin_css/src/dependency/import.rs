@@ -5,22 +5,60 @@ use rspack_core::{
   TemplateReplaceSource,
 };
 
+/// The conditions of an `@import`, e.g. `@import url(x) layer(base) supports(display: grid) screen;`,
+/// the content of the imported module is wrapped in the at-rules of them when rendered.
+#[cacheable]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CssImportCondition {
+  /// `Some("")` for an anonymous layer.
+  pub layer: Option<String>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+impl CssImportCondition {
+  pub fn new(layer: Option<&str>, supports: Option<&str>, media: Option<&str>) -> Option<Self> {
+    let supports = supports
+      .map(str::trim)
+      .filter(|supports| !supports.is_empty());
+    let media = media.map(str::trim).filter(|media| !media.is_empty());
+    if layer.is_none() && supports.is_none() && media.is_none() {
+      return None;
+    }
+    Some(Self {
+      layer: layer.map(|layer| layer.trim().to_string()),
+      supports: supports.map(ToString::to_string),
+      media: media.map(ToString::to_string),
+    })
+  }
+}
+
 #[cacheable]
 #[derive(Debug, Clone)]
 pub struct CssImportDependency {
   id: DependencyId,
   request: String,
   range: DependencyRange,
+  condition: Option<CssImportCondition>,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, range: DependencyRange) -> Self {
+  pub fn new(
+    request: String,
+    range: DependencyRange,
+    condition: Option<CssImportCondition>,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       request,
       range,
+      condition,
     }
   }
+
+  pub fn condition(&self) -> Option<&CssImportCondition> {
+    self.condition.as_ref()
+  }
 }
 
 #[cacheable_dyn]
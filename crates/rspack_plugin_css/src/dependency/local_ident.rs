@@ -1,7 +1,7 @@
 use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_core::{
   AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyCategory,
-  DependencyId, DependencyTemplate, DependencyType, ExportNameOrSpec, ExportSpec,
+  DependencyId, DependencyRange, DependencyTemplate, DependencyType, ExportNameOrSpec, ExportSpec,
   ExportsOfExportsSpec, ExportsSpec, RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 use rspack_util::ext::DynHash;
@@ -14,8 +14,7 @@ pub struct CssLocalIdentDependency {
   id: DependencyId,
   local_ident: String,
   convention_names: Vec<String>,
-  start: u32,
-  end: u32,
+  range: DependencyRange,
 }
 
 impl CssLocalIdentDependency {
@@ -24,8 +23,7 @@ impl CssLocalIdentDependency {
       id: DependencyId::new(),
       local_ident,
       convention_names,
-      start,
-      end,
+      range: DependencyRange::new(start, end),
     }
   }
 }
@@ -44,6 +42,10 @@ impl Dependency for CssLocalIdentDependency {
     &DependencyType::CssLocalIdent
   }
 
+  fn range(&self) -> Option<&DependencyRange> {
+    Some(&self.range)
+  }
+
   fn get_exports(&self, _mg: &rspack_core::ModuleGraph) -> Option<ExportsSpec> {
     Some(ExportsSpec {
       exports: ExportsOfExportsSpec::Array(
@@ -76,8 +78,8 @@ impl DependencyTemplate for CssLocalIdentDependency {
     _code_generatable_context: &mut TemplateContext,
   ) {
     source.replace(
-      self.start,
-      self.end,
+      self.range.start,
+      self.range.end,
       &escape_css(&self.local_ident, false),
       None,
     );
@@ -1,5 +1,6 @@
 mod compose;
 mod export;
+mod icss_import;
 mod import;
 mod local_ident;
 mod self_reference;
@@ -7,6 +8,7 @@ mod url;
 
 pub use compose::*;
 pub use export::*;
+pub use icss_import::*;
 pub use import::*;
 pub use local_ident::*;
 pub use self_reference::*;
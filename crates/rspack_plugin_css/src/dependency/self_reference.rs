@@ -32,6 +32,25 @@ impl CssSelfReferenceLocalIdentDependency {
       replaces,
     }
   }
+
+  /// Replaces the references for which `filter` returns `true`, the references in removed rule
+  /// sets are skipped.
+  pub(crate) fn apply_replaces(
+    &self,
+    source: &mut TemplateReplaceSource,
+    filter: impl Fn(u32, u32) -> bool,
+  ) {
+    for replace in &self.replaces {
+      if filter(replace.start, replace.end) {
+        source.replace(
+          replace.start,
+          replace.end,
+          &escape_css(&replace.local_ident, false),
+          None,
+        );
+      }
+    }
+  }
 }
 
 #[cacheable_dyn]
@@ -83,14 +102,7 @@ impl DependencyTemplate for CssSelfReferenceLocalIdentDependency {
     source: &mut TemplateReplaceSource,
     _code_generatable_context: &mut TemplateContext,
   ) {
-    for replace in &self.replaces {
-      source.replace(
-        replace.start,
-        replace.end,
-        &escape_css(&replace.local_ident, false),
-        None,
-      );
-    }
+    self.apply_replaces(source, |_, _| true);
   }
 
   fn dependency_id(&self) -> Option<DependencyId> {
@@ -0,0 +1,196 @@
+use std::sync::LazyLock;
+
+use indexmap::IndexMap;
+use regex::Regex;
+use rustc_hash::FxHashSet;
+
+static VALUE_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"@value\s+([^;{}]+);").expect("Invalid regex"));
+
+static VALUE_FROM_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r#"^([\s\S]+?)\s+from\s+("[^"]*"|'[^']*')$"#).expect("Invalid regex")
+});
+
+static VALUE_DEFINITION_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^([\w-]+)\s*:?\s*([\s\S]*)$").expect("Invalid regex"));
+
+static IMPORTED_NAME_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^([\w-]+)(?:\s+as\s+([\w-]+))?$").expect("Invalid regex"));
+
+static ICSS_IMPORT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r#":import\(\s*("[^"]*"|'[^']*'|[^)\s]+)\s*\)\s*\{([^}]*)\}"#).expect("Invalid regex")
+});
+
+static COMMENT_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"/\*[\s\S]*?\*/").expect("Invalid regex"));
+
+// comments and quoted strings, matched together so that quotes in comments and comment starts in
+// strings are skipped
+static COMMENT_OR_STRING_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r#"/\*[\s\S]*?\*/|"(?:[^"\\\n]|\\[\s\S])*"|'(?:[^'\\\n]|\\[\s\S])*'"#)
+    .expect("Invalid regex")
+});
+
+static IDENT_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"-?[_a-zA-Z][\w-]*").expect("Invalid regex"));
+
+// the at-rules whose preludes can reference values, e.g. `@media small { ... }`
+const VALUE_AT_RULES: &[&str] = &["@media", "@custom-media", "@container", "@supports"];
+
+/// The values of a CSS Modules file, defined by `@value` or imported by `@value ... from` and
+/// `:import()`.
+#[derive(Debug, Default)]
+pub(crate) struct IcssValues {
+  /// The values defined in the file, by name.
+  pub definitions: IndexMap<String, String>,
+  pub imports: Vec<IcssImport>,
+  /// The ranges of the `@value` statements and `:import()` blocks, which are removed.
+  pub statements: Vec<(u32, u32)>,
+}
+
+#[derive(Debug)]
+pub(crate) struct IcssImport {
+  pub request: String,
+  /// The local and the imported names.
+  pub names: Vec<(String, String)>,
+  pub range: (u32, u32),
+}
+
+pub(crate) fn collect_icss_values(source: &str) -> IcssValues {
+  let comments = COMMENT_REGEX
+    .find_iter(source)
+    .map(|comment| (comment.start(), comment.end()))
+    .collect::<Vec<_>>();
+  let in_comment = |pos: usize| {
+    comments
+      .iter()
+      .any(|(start, end)| (*start..*end).contains(&pos))
+  };
+
+  let mut values = IcssValues::default();
+  for captures in VALUE_REGEX.captures_iter(source) {
+    let statement = captures.get(0).expect("should have match");
+    if in_comment(statement.start()) {
+      continue;
+    }
+    let range = (statement.start() as u32, statement.end() as u32);
+    let body = captures[1].trim();
+    if let Some(from) = VALUE_FROM_REGEX.captures(body) {
+      let names = from[1]
+        .trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .filter_map(|name| {
+          let name = IMPORTED_NAME_REGEX.captures(name.trim())?;
+          let imported = name[1].to_string();
+          let local = name
+            .get(2)
+            .map_or(imported.clone(), |local| local.as_str().to_string());
+          Some((local, imported))
+        })
+        .collect();
+      values.imports.push(IcssImport {
+        request: from[2][1..from[2].len() - 1].to_string(),
+        names,
+        range,
+      });
+    } else if let Some(definition) = VALUE_DEFINITION_REGEX.captures(body) {
+      // a value can reference the values defined before it
+      let value = replace_defined_values(definition[2].trim(), &values.definitions);
+      values.definitions.insert(definition[1].to_string(), value);
+    }
+    values.statements.push(range);
+  }
+
+  for captures in ICSS_IMPORT_REGEX.captures_iter(source) {
+    let block = captures.get(0).expect("should have match");
+    if in_comment(block.start()) {
+      continue;
+    }
+    let range = (block.start() as u32, block.end() as u32);
+    let names = captures[2]
+      .split(';')
+      .filter_map(|declaration| {
+        let (local, imported) = declaration.split_once(':')?;
+        Some((local.trim().to_string(), imported.trim().to_string()))
+      })
+      .filter(|(local, imported)| !local.is_empty() && !imported.is_empty())
+      .collect();
+    values.imports.push(IcssImport {
+      request: captures[1]
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string(),
+      names,
+      range,
+    });
+    values.statements.push(range);
+  }
+  values
+}
+
+fn replace_defined_values(value: &str, definitions: &IndexMap<String, String>) -> String {
+  IDENT_REGEX
+    .replace_all(value, |captures: &regex::Captures| {
+      let ident = &captures[0];
+      definitions
+        .get(ident)
+        .cloned()
+        .unwrap_or_else(|| ident.to_string())
+    })
+    .into_owned()
+}
+
+/// Returns the references of `names` in declaration values and the preludes of `@media` and other
+/// at-rules, out of the `statements`, comments and quoted strings.
+pub(crate) fn find_value_references<'a>(
+  source: &'a str,
+  names: &FxHashSet<&str>,
+  statements: &[(u32, u32)],
+) -> Vec<(u32, u32, &'a str)> {
+  let bytes = source.as_bytes();
+  let skipped = COMMENT_OR_STRING_REGEX
+    .find_iter(source)
+    .map(|skipped| (skipped.start(), skipped.end()))
+    .collect::<Vec<_>>();
+  IDENT_REGEX
+    .find_iter(source)
+    .filter(|ident| names.contains(ident.as_str()))
+    .filter(|ident| {
+      let (start, end) = (ident.start(), ident.end());
+      if statements
+        .iter()
+        .any(|(s, e)| (*s as usize..*e as usize).contains(&start))
+        || skipped.iter().any(|(s, e)| (*s..*e).contains(&start))
+      {
+        return false;
+      }
+      // not a part of a class, an id, a function or another ident
+      if start > 0 && matches!(bytes[start - 1], b'.' | b'#' | b'@' | b'$' | b'-' | b'_')
+        || bytes
+          .get(end)
+          .is_some_and(|c| matches!(c, b'(' | b'-' | b'_') || c.is_ascii_alphanumeric())
+      {
+        return false;
+      }
+      let before = &source[..start];
+      let Some(structural) = before.rfind(['{', '}', ';', ':']) else {
+        return is_value_at_rule(before);
+      };
+      if bytes[structural] == b':' {
+        // a declaration value is followed by `;` or `}`, a pseudo-class of a selector by `{`
+        source[end..]
+          .find(['{', '}', ';'])
+          .is_none_or(|next| bytes[end + next] != b'{')
+      } else {
+        is_value_at_rule(&before[structural + 1..])
+      }
+    })
+    .map(|ident| (ident.start() as u32, ident.end() as u32, ident.as_str()))
+    .collect()
+}
+
+fn is_value_at_rule(prelude: &str) -> bool {
+  let prelude = prelude.trim_start();
+  VALUE_AT_RULES
+    .iter()
+    .any(|at_rule| prelude.starts_with(at_rule))
+}
@@ -1,3 +1,6 @@
+mod icss;
+mod rule_set;
+
 use std::{
   borrow::Cow,
   sync::{Arc, LazyLock},
@@ -23,9 +26,12 @@ use rspack_core::{ModuleInitFragments, RuntimeGlobals};
 use rspack_error::{
   miette::Diagnostic, IntoTWithDiagnosticArray, Result, RspackSeverity, TWithDiagnosticArray,
 };
-use rspack_util::ext::DynHash;
+use rspack_util::ext::{AsAny, DynHash};
 use rustc_hash::FxHashSet;
 
+use self::icss::{collect_icss_values, find_value_references, IcssValues};
+use self::rule_set::collect_rule_sets;
+pub use self::rule_set::CssRuleSet;
 use crate::{
   dependency::CssSelfReferenceLocalIdentDependency,
   utils::{css_modules_exports_to_string, LocalIdentOptions},
@@ -36,8 +42,8 @@ use crate::{
 };
 use crate::{
   dependency::{
-    CssComposeDependency, CssExportDependency, CssImportDependency, CssLocalIdentDependency,
-    CssUrlDependency,
+    CssComposeDependency, CssExportDependency, CssIcssImportDependency, CssIcssImportReplacement,
+    CssImportCondition, CssImportDependency, CssLocalIdentDependency, CssUrlDependency,
   },
   utils::{
     css_modules_exports_to_concatenate_module_string, css_parsing_traceable_error, normalize_url,
@@ -87,6 +93,8 @@ pub struct CssParserAndGenerator {
   pub es_module: bool,
   #[cacheable(with=AsOption<AsMap<AsCacheable, AsVec>>)]
   pub exports: Option<CssExports>,
+  #[cacheable(with=AsOption<AsVec>)]
+  pub rule_sets: Option<Vec<CssRuleSet>>,
   pub hot: bool,
 }
 
@@ -156,6 +164,20 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut presentational_dependencies: Vec<Box<dyn DependencyTemplate>> = vec![];
     let mut code_generation_dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
 
+    let mut local_class_positions = vec![];
+
+    let icss_values = if matches!(mode, css_module_lexer::Mode::Local) {
+      collect_icss_values(&source_code)
+    } else {
+      IcssValues::default()
+    };
+    self.add_icss_values(
+      &source_code,
+      &icss_values,
+      &mut dependencies,
+      &mut presentational_dependencies,
+    );
+
     let (deps, warnings) = css_module_lexer::collect_dependencies(&source_code, mode);
     for dependency in deps {
       match dependency {
@@ -183,7 +205,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
           dependencies.push(dep.clone());
           code_generation_dependencies.push(dep);
         }
-        css_module_lexer::Dependency::Import { request, range, .. } => {
+        css_module_lexer::Dependency::Import {
+          request,
+          range,
+          layer,
+          supports,
+          media,
+        } => {
           if request.is_empty() {
             presentational_dependencies.push(Box::new(ConstDependency::new(
               range.start,
@@ -203,6 +231,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
           dependencies.push(Box::new(CssImportDependency::new(
             request.to_string(),
             DependencyRange::new(range.start, range.end),
+            CssImportCondition::new(layer, supports, media),
           )));
         }
         css_module_lexer::Dependency::Replace { content, range } => presentational_dependencies
@@ -214,7 +243,8 @@ impl ParserAndGenerator for CssParserAndGenerator {
           ))),
         css_module_lexer::Dependency::LocalClass { name, range, .. }
         | css_module_lexer::Dependency::LocalId { name, range, .. } => {
-          let (_prefix, name) = name.split_at(1); // split '#' or '.'
+          let (prefix, name) = name.split_at(1); // split '#' or '.'
+          let dependency_is_class = prefix == ".";
           let name = unescape(name);
 
           let local_ident = LocalIdentOptions::new(
@@ -243,6 +273,9 @@ impl ParserAndGenerator for CssParserAndGenerator {
               },
             );
           }
+          if dependency_is_class {
+            local_class_positions.push((range.start, local_ident.clone()));
+          }
           dependencies.push(Box::new(CssLocalIdentDependency::new(
             local_ident,
             convention_names,
@@ -389,6 +422,10 @@ impl ParserAndGenerator for CssParserAndGenerator {
             .expect("should have local_ident_name for module_type css/auto or css/module");
           let convention_names = export_locals_convention(prop, convention);
           let value = REGEX_IS_COMMENTS.replace_all(value, "");
+          let value = icss_values
+            .definitions
+            .get(value.trim())
+            .map_or(&*value, String::as_str);
           for name in convention_names.iter() {
             update_css_exports(
               exports,
@@ -405,6 +442,9 @@ impl ParserAndGenerator for CssParserAndGenerator {
         _ => {}
       }
     }
+    self.rule_sets = (!local_class_positions.is_empty())
+      .then(|| collect_rule_sets(&source_code, &local_class_positions));
+
     for warning in warnings {
       let range = warning.range();
       let error = css_parsing_traceable_error(
@@ -432,6 +472,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
         code_generation_dependencies,
         source,
         side_effects_bailout: None,
+        optimization_bailouts: vec![],
       }
       .with_diagnostic(map_box_diagnostics_to_module_parse_diagnostics(
         diagnostics,
@@ -466,13 +507,30 @@ impl ParserAndGenerator for CssParserAndGenerator {
           data: generate_context.data,
         };
 
+        let mg = compilation.get_module_graph();
+        let unused_rule_sets = self.get_unused_rule_sets(module, generate_context.runtime, &mg);
+        let is_unused = |start: u32, end: u32| {
+          unused_rule_sets
+            .iter()
+            .any(|rule_set| rule_set.start <= start && end <= rule_set.end)
+        };
+
         module.get_dependencies().iter().for_each(|id| {
-          if let Some(dependency) = compilation
-            .get_module_graph()
-            .dependency_by_id(id)
-            .expect("should have dependency")
-            .as_dependency_template()
+          let dependency = mg.dependency_by_id(id).expect("should have dependency");
+          if let Some(range) = dependency.range()
+            && is_unused(range.start, range.end)
+          {
+            return;
+          }
+          if let Some(dependency) =
+            dependency.downcast_ref::<CssSelfReferenceLocalIdentDependency>()
           {
+            dependency.apply_replaces(&mut source, |start, end| !is_unused(start, end));
+          } else if let Some(dependency) = dependency.downcast_ref::<CssIcssImportDependency>() {
+            dependency.apply_replaces(&mut source, compilation, |start, end| {
+              !is_unused(start, end)
+            });
+          } else if let Some(dependency) = dependency.as_dependency_template() {
             dependency.apply(&mut source, &mut context)
           }
         });
@@ -480,9 +538,19 @@ impl ParserAndGenerator for CssParserAndGenerator {
         if let Some(dependencies) = module.get_presentational_dependencies() {
           dependencies
             .iter()
+            .filter(|dependency| {
+              !dependency
+                .as_any()
+                .downcast_ref::<ConstDependency>()
+                .is_some_and(|dependency| is_unused(dependency.start, dependency.end))
+            })
             .for_each(|dependency| dependency.apply(&mut source, &mut context));
         };
 
+        for rule_set in unused_rule_sets {
+          source.replace(rule_set.start, rule_set.end, "", None);
+        }
+
         generate_context.concatenation_scope = context.concatenation_scope.take();
 
         Ok(source.boxed())
@@ -597,6 +665,156 @@ impl ParserAndGenerator for CssParserAndGenerator {
   }
 }
 
+impl CssParserAndGenerator {
+  /// Exports the values defined by `@value` and imported by `@value ... from` and `:import()`,
+  /// removes the statements of them and replaces the references with the values.
+  fn add_icss_values(
+    &mut self,
+    source: &str,
+    values: &IcssValues,
+    dependencies: &mut Vec<Box<dyn Dependency>>,
+    presentational_dependencies: &mut Vec<Box<dyn DependencyTemplate>>,
+  ) {
+    if values.statements.is_empty() {
+      return;
+    }
+    let convention = self
+      .convention
+      .as_ref()
+      .expect("should have local_ident_name for module_type css/auto or css/module");
+    let exports = self.exports.get_or_insert_default();
+
+    for (name, value) in &values.definitions {
+      let convention_names = export_locals_convention(name, convention);
+      for name in convention_names.iter() {
+        update_css_exports(
+          exports,
+          name.to_owned(),
+          CssExport {
+            ident: value.clone(),
+            from: None,
+            id: None,
+          },
+        );
+      }
+      dependencies.push(Box::new(CssExportDependency::new(convention_names)));
+    }
+    for &(start, end) in &values.statements {
+      if !values
+        .imports
+        .iter()
+        .any(|import| import.range == (start, end))
+      {
+        presentational_dependencies.push(Box::new(ConstDependency::new(
+          start,
+          end,
+          "".into(),
+          None,
+        )));
+      }
+    }
+
+    let names = values
+      .definitions
+      .keys()
+      .map(String::as_str)
+      .chain(
+        values
+          .imports
+          .iter()
+          .flat_map(|import| import.names.iter().map(|(local, _)| local.as_str())),
+      )
+      .collect::<FxHashSet<_>>();
+    let mut import_replaces = vec![vec![]; values.imports.len()];
+    for (start, end, name) in find_value_references(source, &names, &values.statements) {
+      if let Some(value) = values.definitions.get(name) {
+        presentational_dependencies.push(Box::new(ConstDependency::new(
+          start,
+          end,
+          value.as_str().into(),
+          None,
+        )));
+      } else if let Some((index, imported)) =
+        values
+          .imports
+          .iter()
+          .enumerate()
+          .find_map(|(index, import)| {
+            import
+              .names
+              .iter()
+              .find(|(local, _)| local == name)
+              .map(|(_, imported)| (index, imported))
+          })
+      {
+        import_replaces[index].push(CssIcssImportReplacement {
+          name: imported.clone(),
+          start,
+          end,
+        });
+      }
+    }
+
+    for (import, replaces) in values.imports.iter().zip(import_replaces) {
+      let dep = CssIcssImportDependency::new(
+        import.request.clone(),
+        import
+          .names
+          .iter()
+          .map(|(_, imported)| imported.clone())
+          .collect(),
+        replaces,
+        DependencyRange::new(import.range.0, import.range.1),
+      );
+      for (local, imported) in &import.names {
+        let convention_names = export_locals_convention(local, convention);
+        for name in convention_names.iter() {
+          update_css_exports(
+            exports,
+            name.to_owned(),
+            CssExport {
+              ident: imported.clone(),
+              from: Some(import.request.clone()),
+              id: Some(*dep.id()),
+            },
+          );
+        }
+        dependencies.push(Box::new(CssExportDependency::new(convention_names)));
+      }
+      dependencies.push(Box::new(dep));
+    }
+  }
+
+  /// Returns the rule sets to remove, whose selectors all have a local class that is not used,
+  /// the local classes composed by used classes are used as well.
+  fn get_unused_rule_sets(
+    &self,
+    module: &dyn Module,
+    runtime: Option<&RuntimeSpec>,
+    mg: &ModuleGraph,
+  ) -> Vec<&CssRuleSet> {
+    let (Some(exports), Some(rule_sets)) = (&self.exports, &self.rule_sets) else {
+      return vec![];
+    };
+    let used_idents = get_used_exports(exports, module.identifier(), runtime, mg)
+      .into_values()
+      .flatten()
+      .filter(|export| export.from.is_none())
+      .map(|export| export.ident.as_str())
+      .collect::<FxHashSet<_>>();
+    rule_sets
+      .iter()
+      .filter(|rule_set| {
+        rule_set.selectors.iter().all(|classes| {
+          classes
+            .iter()
+            .any(|class| !used_idents.contains(class.as_str()))
+        })
+      })
+      .collect()
+  }
+}
+
 fn get_used_exports<'a>(
   exports: &'a CssExports,
   identifier: ModuleIdentifier,
@@ -0,0 +1,250 @@
+use std::ops::Range;
+
+use rspack_cacheable::cacheable;
+
+/// A rule set of a CSS Modules file that only matches elements with local classes, it's removed
+/// when each of its selectors has a local class that is not used.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct CssRuleSet {
+  pub start: u32,
+  pub end: u32,
+  /// The local idents of the classes in each selector.
+  pub selectors: Vec<Vec<String>>,
+}
+
+// the at-rules whose blocks contain rule sets
+const GROUPING_AT_RULES: &[&str] = &[
+  "media",
+  "supports",
+  "layer",
+  "container",
+  "scope",
+  "document",
+  "-moz-document",
+  "starting-style",
+];
+
+/// Returns the rule sets, including the ones nested in `@media` and other grouping at-rules, whose
+/// selectors all have local classes and no `:global`, `local_classes` are the positions of the
+/// local classes and their local idents.
+///
+/// Only the classes outside parentheses are required by a selector, the ones in `:not()`, `:is()`,
+/// `:where()` and other functional pseudo-classes may not match an element at all.
+pub(crate) fn collect_rule_sets(source: &str, local_classes: &[(u32, String)]) -> Vec<CssRuleSet> {
+  let mut scanner = Scanner {
+    source,
+    bytes: source.as_bytes(),
+    pos: 0,
+    rule_sets: vec![],
+  };
+  scanner.scan_rule_list();
+  std::mem::take(&mut scanner.rule_sets)
+    .into_iter()
+    .filter_map(|(start, prelude_end, end)| {
+      let selectors = scanner
+        .split_selectors(start, prelude_end)
+        .into_iter()
+        .map(|(selector_start, selector_end)| {
+          if source[selector_start..selector_end].contains(":global") {
+            return None;
+          }
+          let nested = scanner.parenthesized_ranges(selector_start, selector_end);
+          let classes = local_classes
+            .iter()
+            .filter(|(pos, _)| {
+              let pos = *pos as usize;
+              (selector_start..selector_end).contains(&pos)
+                && !nested.iter().any(|range| range.contains(&pos))
+            })
+            .map(|(_, local_ident)| local_ident.clone())
+            .collect::<Vec<_>>();
+          (!classes.is_empty()).then_some(classes)
+        })
+        .collect::<Option<Vec<_>>>()?;
+      Some(CssRuleSet {
+        start: start as u32,
+        end: end as u32,
+        selectors,
+      })
+    })
+    .collect()
+}
+
+struct Scanner<'a> {
+  source: &'a str,
+  bytes: &'a [u8],
+  pos: usize,
+  /// The start, the end of the prelude and the end of the rule sets.
+  rule_sets: Vec<(usize, usize, usize)>,
+}
+
+impl Scanner<'_> {
+  fn peek(&self) -> Option<u8> {
+    self.bytes.get(self.pos).copied()
+  }
+
+  /// Skips a comment, a string or an escaped character, returns `false` if there is none.
+  fn skip_trivia(&mut self) -> bool {
+    match self.peek() {
+      Some(b'/') if self.bytes.get(self.pos + 1) == Some(&b'*') => {
+        self.pos = self.source[self.pos + 2..]
+          .find("*/")
+          .map_or(self.bytes.len(), |end| self.pos + 2 + end + 2);
+      }
+      Some(quote @ (b'"' | b'\'')) => {
+        self.pos += 1;
+        while let Some(c) = self.peek() {
+          self.pos += if c == b'\\' { 2 } else { 1 };
+          if c == quote || c == b'\n' {
+            break;
+          }
+        }
+      }
+      Some(b'\\') => self.pos += 2,
+      _ => return false,
+    }
+    self.pos = self.pos.min(self.bytes.len());
+    true
+  }
+
+  /// Moves to the next `{`, `;` or `}` that is not in parentheses or brackets.
+  fn scan_prelude(&mut self) {
+    let mut depth = 0usize;
+    while let Some(c) = self.peek() {
+      if self.skip_trivia() {
+        continue;
+      }
+      match c {
+        b'(' | b'[' => depth += 1,
+        b')' | b']' => depth = depth.saturating_sub(1),
+        b'{' | b';' | b'}' if depth == 0 => return,
+        _ => {}
+      }
+      self.pos += 1;
+    }
+  }
+
+  /// Moves to the end of the block starting at the current `{`.
+  fn skip_block(&mut self) {
+    let mut depth = 0usize;
+    while let Some(c) = self.peek() {
+      if self.skip_trivia() {
+        continue;
+      }
+      self.pos += 1;
+      match c {
+        b'{' => depth += 1,
+        b'}' => {
+          depth -= 1;
+          if depth == 0 {
+            return;
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn scan_rule_list(&mut self) {
+    while let Some(c) = self.peek() {
+      if c.is_ascii_whitespace() || c == b';' {
+        self.pos += 1;
+        continue;
+      }
+      if self.skip_trivia() {
+        continue;
+      }
+      match c {
+        b'}' => {
+          self.pos += 1;
+          return;
+        }
+        b'@' => {
+          let name_start = self.pos + 1;
+          self.scan_prelude();
+          let name = self.source[name_start..self.pos]
+            .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+          if self.peek() == Some(b'{') {
+            if GROUPING_AT_RULES.contains(&name.as_str()) {
+              self.pos += 1;
+              self.scan_rule_list();
+            } else {
+              self.skip_block();
+            }
+          }
+        }
+        _ => {
+          let start = self.pos;
+          self.scan_prelude();
+          if self.peek() == Some(b'{') {
+            let prelude_end = self.pos;
+            self.skip_block();
+            self.rule_sets.push((start, prelude_end, self.pos));
+          }
+        }
+      }
+    }
+  }
+
+  /// Returns the ranges in parentheses of a selector.
+  fn parenthesized_ranges(&mut self, start: usize, end: usize) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut range_start = start;
+    let mut depth = 0usize;
+    self.pos = start;
+    while self.pos < end {
+      if self.skip_trivia() {
+        continue;
+      }
+      match self.bytes[self.pos] {
+        b'(' => {
+          if depth == 0 {
+            range_start = self.pos;
+          }
+          depth += 1;
+        }
+        b')' if depth > 0 => {
+          depth -= 1;
+          if depth == 0 {
+            ranges.push(range_start..self.pos);
+          }
+        }
+        _ => {}
+      }
+      self.pos += 1;
+    }
+    if depth > 0 {
+      ranges.push(range_start..end);
+    }
+    ranges
+  }
+
+  /// Splits the prelude of a rule set at the commas between the selectors.
+  fn split_selectors(&mut self, start: usize, end: usize) -> Vec<(usize, usize)> {
+    let mut selectors = vec![];
+    let mut selector_start = start;
+    let mut depth = 0usize;
+    self.pos = start;
+    while self.pos < end {
+      if self.skip_trivia() {
+        continue;
+      }
+      match self.bytes[self.pos] {
+        b'(' | b'[' => depth += 1,
+        b')' | b']' => depth = depth.saturating_sub(1),
+        b',' if depth == 0 => {
+          selectors.push((selector_start, self.pos));
+          selector_start = self.pos + 1;
+        }
+        _ => {}
+      }
+      self.pos += 1;
+    }
+    selectors.push((selector_start, end));
+    selectors
+  }
+}
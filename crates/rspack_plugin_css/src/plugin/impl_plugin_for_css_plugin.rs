@@ -8,7 +8,7 @@ use rayon::prelude::*;
 use rspack_collections::DatabaseItem;
 use rspack_core::rspack_sources::{BoxSource, CachedSource, ReplaceSource};
 use rspack_core::{
-  get_css_chunk_filename_template,
+  get_css_chunk_filename_template, get_css_hot_update_chunk_filename_template,
   rspack_sources::{ConcatSource, RawStringSource, Source, SourceExt},
   Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
   SourceType,
@@ -22,12 +22,14 @@ use rspack_core::{
 use rspack_error::{Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::plugin_hook;
-use rspack_plugin_runtime::is_enabled_for_chunk;
+use rspack_plugin_runtime::{chunk_has_css, is_enabled_for_chunk};
+use rspack_util::infallible::ResultInfallibleExt;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+use crate::dependency::CssImportCondition;
 use crate::parser_and_generator::{CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator};
 use crate::runtime::CssLoadingRuntimeModule;
-use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER;
+use crate::utils::{AUTO_PUBLIC_PATH_PLACEHOLDER, HMR_CSS_MODULE_MARKER};
 use crate::{plugin::CssPluginInner, CssPlugin};
 
 struct CssModuleDebugInfo<'a> {
@@ -67,7 +69,8 @@ impl CssPlugin {
   ) -> Result<(BoxSource, Vec<Diagnostic>)> {
     let (ordered_css_modules, conflicts) =
       Self::get_ordered_chunk_css_modules(chunk, compilation, css_import_modules, css_modules);
-    let source = Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
+    let (source, mut diagnostics) =
+      Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules, output_path)?;
 
     let content = source.source();
     let len = AUTO_PUBLIC_PATH_PLACEHOLDER.len();
@@ -85,7 +88,6 @@ impl CssPlugin {
     } else {
       source.boxed()
     };
-    let mut diagnostics = vec![];
     if let Some(conflicts) = conflicts {
       diagnostics.extend(conflicts.into_iter().map(|conflict| {
         let chunk = compilation.chunk_by_ukey.expect_get(&conflict.chunk);
@@ -122,7 +124,14 @@ impl CssPlugin {
     compilation: &Compilation,
     chunk: &Chunk,
     ordered_css_modules: &[&dyn Module],
-  ) -> rspack_error::Result<ConcatSource> {
+    output_path: &str,
+  ) -> rspack_error::Result<(ConcatSource, Vec<Diagnostic>)> {
+    let mut import_conditions =
+      Self::get_import_conditions(compilation, chunk, ordered_css_modules);
+    let mut diagnostics = vec![];
+    let hot = ordered_css_modules
+      .iter()
+      .any(|module| Self::is_hot_module(*module));
     let module_sources = ordered_css_modules
       .iter()
       .map(|module| {
@@ -131,11 +140,30 @@ impl CssPlugin {
           .code_generation_results
           .get(module_id, Some(chunk.runtime()));
 
-        Ok(
-          code_gen_result
-            .get(&SourceType::Css)
-            .map(|source| (CssModuleDebugInfo { module: *module }, source)),
-        )
+        let conditions = import_conditions.remove(module_id).unwrap_or_default();
+        Ok(code_gen_result.get(&SourceType::Css).map(|source| {
+          let source = if module.source_types().contains(&SourceType::CssImport) {
+            let (source, dropped) =
+              Self::render_external_import_with_conditions(source, &conditions);
+            if dropped {
+              diagnostics.push(
+                Diagnostic::warn(
+                  "Dropped import conditions".into(),
+                  format!(
+                    "The conditions of the outer @imports of {} are dropped, an external @import can only have the conditions of the @import it's imported by",
+                    module.readable_identifier(&compilation.options.context)
+                  ),
+                )
+                .with_file(Some(output_path.to_owned().into()))
+                .with_chunk(Some(chunk.ukey().as_u32())),
+              );
+            }
+            source
+          } else {
+            Self::render_module_with_conditions(source, &conditions)
+          };
+          (CssModuleDebugInfo { module: *module }, source)
+        }))
       })
       .collect::<Result<Vec<_>>>()?;
 
@@ -148,9 +176,14 @@ impl CssPlugin {
       .fold(
         ConcatSource::default,
         |mut acc, (debug_info, cur_source)| {
+          if hot
+            && let Some(marker) = Self::render_module_hmr_marker(compilation, debug_info.module)
+          {
+            acc.add(RawStringSource::from(marker));
+          }
           let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
           acc.add(start);
-          acc.add(cur_source.clone());
+          acc.add(cur_source);
           acc.add(RawStringSource::from_static("\n"));
           acc.add(end);
           acc
@@ -161,7 +194,149 @@ impl CssPlugin {
         acc
       });
 
-    Ok(source)
+    Ok((source, diagnostics))
+  }
+
+  /// Renders the module once for each chain of `@import` conditions, wrapped in `@layer`,
+  /// `@supports` and `@media` from the outermost `@import`.
+  fn render_module_with_conditions(
+    source: &BoxSource,
+    conditions: &[Vec<CssImportCondition>],
+  ) -> BoxSource {
+    if let [chain] = conditions
+      && chain.is_empty()
+    {
+      return source.clone();
+    }
+    let mut result = ConcatSource::default();
+    for chain in conditions {
+      let mut wrapped = source.clone();
+      for condition in chain.iter().rev() {
+        if let Some(media) = &condition.media {
+          wrapped = wrap_in_at_rule(format!("@media {media}"), wrapped);
+        }
+        if let Some(supports) = &condition.supports {
+          wrapped = wrap_in_at_rule(format!("@supports ({supports})"), wrapped);
+        }
+        if let Some(layer) = &condition.layer {
+          let at_rule = if layer.is_empty() {
+            "@layer".to_string()
+          } else {
+            format!("@layer {layer}")
+          };
+          wrapped = wrap_in_at_rule(at_rule, wrapped);
+        }
+      }
+      result.add(wrapped);
+      result.add(RawStringSource::from_static("\n"));
+    }
+    result.boxed()
+  }
+
+  /// The `@import` of an external stylesheet can't be nested in at-rules, so the conditions of the
+  /// innermost `@import` are added to it instead, returns whether the conditions of any outer
+  /// `@import` are dropped.
+  fn render_external_import_with_conditions(
+    source: &BoxSource,
+    conditions: &[Vec<CssImportCondition>],
+  ) -> (BoxSource, bool) {
+    let code = source.source();
+    let import = code.trim_end().trim_end_matches(';');
+    let mut result = String::new();
+    let mut dropped = false;
+    for chain in conditions {
+      dropped |= chain.len() > 1;
+      result += import;
+      if let Some(condition) = chain.last() {
+        if let Some(layer) = &condition.layer {
+          if layer.is_empty() {
+            result += " layer";
+          } else {
+            result += &format!(" layer({layer})");
+          }
+        }
+        if let Some(supports) = &condition.supports {
+          result += &format!(" supports({supports})");
+        }
+        if let Some(media) = &condition.media {
+          result += &format!(" {media}");
+        }
+      }
+      result += ";\n";
+    }
+    (RawStringSource::from(result).boxed(), dropped)
+  }
+
+  fn is_hot_module(module: &dyn Module) -> bool {
+    module
+      .as_normal_module()
+      .and_then(|module| {
+        module
+          .parser_and_generator()
+          .as_any()
+          .downcast_ref::<CssParserAndGenerator>()
+      })
+      .is_some_and(|parser_and_generator| parser_and_generator.hot)
+  }
+
+  /// Marks each module with its id when HMR is enabled, so the runtime can split the stylesheet
+  /// of a chunk and replace the rules of the updated modules only. All modules of the chunk are
+  /// marked, including the external `@import`s, otherwise their rules would be lost when the
+  /// stylesheet is split.
+  fn render_module_hmr_marker(compilation: &Compilation, module: &dyn Module) -> Option<String> {
+    let module_id =
+      ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())?;
+    Some(format!(
+      "/* {HMR_CSS_MODULE_MARKER} {} */\n",
+      serde_json::to_string(module_id).expect("should json stringify module id")
+    ))
+  }
+
+  /// Renders the modules updated in a hot update chunk, the runtime requests the stylesheet of
+  /// every updated chunk with CSS, so an empty one is rendered when only other modules are updated.
+  fn render_hot_update(
+    &self,
+    compilation: &Compilation,
+    mg: &ModuleGraph,
+    chunk: &Chunk,
+    css_import_modules: Vec<&dyn Module>,
+    css_modules: Vec<&dyn Module>,
+  ) -> Result<Option<RenderManifestEntry>> {
+    let chunk_id = chunk.id(&compilation.chunk_ids_artifact);
+    let has_css = !css_import_modules.is_empty()
+      || !css_modules.is_empty()
+      || compilation.chunk_by_ukey.iter().any(|(ukey, current)| {
+        !matches!(current.kind(), ChunkKind::HotUpdate)
+          && current.id(&compilation.chunk_ids_artifact) == chunk_id
+          && chunk_has_css(ukey, compilation)
+      });
+    if !has_css {
+      return Ok(None);
+    }
+    let output_path = compilation
+      .get_path(
+        &get_css_hot_update_chunk_filename_template(&compilation.options.output),
+        PathData::default()
+          .chunk_id_optional(chunk_id.map(|id| id.as_str()))
+          .runtime(chunk.runtime().as_str()),
+      )
+      .always_ok();
+    // the order conflicts are reported by the chunk itself
+    let (source, _) = self.render_chunk(
+      compilation,
+      mg,
+      chunk,
+      &output_path,
+      css_import_modules,
+      css_modules,
+    )?;
+    Ok(Some(RenderManifestEntry {
+      source,
+      filename: output_path,
+      has_filename: false,
+      info: AssetInfo::default(),
+      auxiliary: false,
+    }))
   }
 
   fn render_module_debug_info(
@@ -200,6 +375,15 @@ impl CssPlugin {
   }
 }
 
+fn wrap_in_at_rule(at_rule: String, source: BoxSource) -> BoxSource {
+  ConcatSource::new([
+    RawStringSource::from(format!("{at_rule} {{\n")).boxed(),
+    source,
+    RawStringSource::from_static("\n}").boxed(),
+  ])
+  .boxed()
+}
+
 #[plugin_hook(CompilerCompilation for CssPlugin)]
 async fn compilation(
   &self,
@@ -215,6 +399,10 @@ async fn compilation(
     DependencyType::CssCompose,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::CssIcssImport,
+    params.normal_module_factory.clone(),
+  );
   compilation.set_dependency_factory(
     DependencyType::CssSelfReferenceLocalIdent,
     Arc::new(SelfModuleFactory {}),
@@ -251,6 +439,9 @@ fn runtime_requirements_in_tree(
     runtime_requirements_mut.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
     runtime_requirements_mut.insert(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY);
     runtime_requirements_mut.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+    if runtime_requirements.contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS) {
+      runtime_requirements_mut.insert(RuntimeGlobals::GET_FULL_HASH);
+    }
     compilation.add_runtime_module(chunk_ukey, Box::<CssLoadingRuntimeModule>::default())?;
   }
 
@@ -276,6 +467,7 @@ async fn content_hash(
     .collect::<Vec<_>>();
   let (ordered_modules, _) =
     Self::get_ordered_chunk_css_modules(chunk, compilation, css_import_modules, css_modules);
+  let mut import_conditions = Self::get_import_conditions(compilation, chunk, &ordered_modules);
   let mut hasher = hashes
     .entry(SourceType::Css)
     .or_insert_with(|| RspackHash::from(&compilation.options.output));
@@ -288,12 +480,16 @@ async fn content_hash(
           .code_generation_results
           .get_hash(&m.identifier(), Some(chunk.runtime())),
         ChunkGraph::get_module_id(&compilation.module_ids_artifact, m.identifier()),
+        import_conditions
+          .remove(&m.identifier())
+          .unwrap_or_default(),
       )
     })
-    .for_each(|(current, id)| {
+    .for_each(|(current, id, conditions)| {
       if let Some(current) = current {
         current.hash(&mut hasher);
         id.hash(&mut hasher);
+        conditions.hash(&mut hasher);
       }
     });
 
@@ -309,9 +505,6 @@ async fn render_manifest(
   diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<()> {
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
-  if matches!(chunk.kind(), ChunkKind::HotUpdate) {
-    return Ok(());
-  }
   let module_graph = compilation.get_module_graph();
   let css_import_modules = compilation
     .chunk_graph
@@ -321,6 +514,16 @@ async fn render_manifest(
     .chunk_graph
     .get_chunk_modules_iterable_by_source_type(chunk_ukey, SourceType::Css, &module_graph)
     .collect::<Vec<_>>();
+  if matches!(chunk.kind(), ChunkKind::HotUpdate) {
+    manifest.extend(self.render_hot_update(
+      compilation,
+      &module_graph,
+      chunk,
+      css_import_modules,
+      css_modules,
+    )?);
+    return Ok(());
+  }
   if css_import_modules.is_empty() && css_modules.is_empty() {
     return Ok(());
   }
@@ -425,6 +628,7 @@ impl Plugin for CssPlugin {
           .expect("should have CssGeneratorOptions");
         Box::new(CssParserAndGenerator {
           exports: None,
+          rule_sets: None,
           convention: None,
           local_ident_name: None,
           exports_only: g.exports_only.expect("should have exports_only"),
@@ -445,6 +649,7 @@ impl Plugin for CssPlugin {
           .expect("should have CssModuleGeneratorOptions");
         Box::new(CssParserAndGenerator {
           exports: None,
+          rule_sets: None,
           convention: Some(
             g.exports_convention
               .expect("should have exports_convention"),
@@ -472,6 +677,7 @@ impl Plugin for CssPlugin {
           .expect("should have CssAutoGeneratorOptions");
         Box::new(CssParserAndGenerator {
           exports: None,
+          rule_sets: None,
           convention: Some(
             g.exports_convention
               .expect("should have exports_convention"),
@@ -2,11 +2,14 @@
 mod impl_plugin_for_css_plugin;
 use std::cmp::{self, Reverse};
 
-use rspack_collections::{DatabaseItem, IdentifierSet};
-use rspack_core::{Chunk, Compilation, Module};
+use indexmap::IndexSet;
+use rspack_collections::{DatabaseItem, IdentifierMap, IdentifierSet};
+use rspack_core::{Chunk, ChunkGraph, ChunkKind, Compilation, Module, ModuleGraph, RuntimeSpec};
 use rspack_core::{ChunkUkey, ModuleIdentifier};
 use rspack_hook::plugin;
 
+use crate::dependency::{CssImportCondition, CssImportDependency};
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct CssPlugin;
@@ -19,6 +22,129 @@ pub struct CssOrderConflicts {
 }
 
 impl CssPlugin {
+  /// Returns the conditions each of the modules is imported with in the chunk, each item is the
+  /// conditions of a chain of `@import`s leading to the module from the outermost, and an empty
+  /// item means the module is imported without conditions, e.g. by a JavaScript module.
+  ///
+  /// Only the `@import`s of modules in the chunk groups of the chunk and active in its runtime are
+  /// taken into account, the `@import`s of other entries don't apply to the stylesheet.
+  pub(crate) fn get_import_conditions(
+    compilation: &Compilation,
+    chunk: &Chunk,
+    modules: &[&dyn Module],
+  ) -> IdentifierMap<Vec<Vec<CssImportCondition>>> {
+    struct Collector<'a, 'b> {
+      module_graph: &'a ModuleGraph<'b>,
+      chunk_graph: &'a ChunkGraph,
+      runtime: &'a RuntimeSpec,
+      chunks: Vec<ChunkUkey>,
+      visiting: IdentifierSet,
+      // only the chains of modules not involved in a cycle are cached, as the chains of a module in
+      // a cycle depend on where the cycle is entered
+      cache: IdentifierMap<IndexSet<Vec<CssImportCondition>>>,
+    }
+
+    impl Collector<'_, '_> {
+      fn collect(
+        &mut self,
+        module: &ModuleIdentifier,
+      ) -> (IndexSet<Vec<CssImportCondition>>, bool) {
+        if let Some(chains) = self.cache.get(module) {
+          return (chains.clone(), true);
+        }
+        if !self.visiting.insert(*module) {
+          return (IndexSet::from_iter([vec![]]), false);
+        }
+        let mut cacheable = true;
+        let mut chains = IndexSet::new();
+        for connection in self.module_graph.get_incoming_connections(module) {
+          if !connection.is_active(self.module_graph, Some(self.runtime)) {
+            continue;
+          }
+          let dependency = self
+            .module_graph
+            .dependency_by_id(&connection.dependency_id)
+            .and_then(|dependency| dependency.downcast_ref::<CssImportDependency>());
+          match (dependency, connection.original_module_identifier) {
+            (Some(dependency), Some(origin_module)) => {
+              if !self
+                .chunks
+                .iter()
+                .any(|chunk| self.chunk_graph.is_module_in_chunk(&origin_module, *chunk))
+              {
+                continue;
+              }
+              let (origin_chains, origin_cacheable) = self.collect(&origin_module);
+              cacheable &= origin_cacheable;
+              for mut chain in origin_chains {
+                if let Some(condition) = dependency.condition() {
+                  chain.push(condition.clone());
+                }
+                chains.insert(chain);
+              }
+            }
+            _ => {
+              chains.insert(vec![]);
+            }
+          }
+        }
+        self.visiting.remove(module);
+        if chains.is_empty() {
+          chains.insert(vec![]);
+        }
+        if cacheable {
+          self.cache.insert(*module, chains.clone());
+        }
+        (chains, cacheable)
+      }
+    }
+
+    let module_graph = compilation.get_module_graph();
+    let mut collector = Collector {
+      module_graph: &module_graph,
+      chunk_graph: &compilation.chunk_graph,
+      runtime: chunk.runtime(),
+      chunks: Self::get_chunk_group_chunks(compilation, chunk),
+      visiting: IdentifierSet::default(),
+      cache: IdentifierMap::default(),
+    };
+    modules
+      .iter()
+      .map(|module| {
+        let module = module.identifier();
+        let mut chains = collector.collect(&module).0.into_iter().collect::<Vec<_>>();
+        chains.sort();
+        (module, chains)
+      })
+      .collect()
+  }
+
+  /// Returns the chunks of the chunk groups the chunk belongs to, a hot update chunk belongs to no
+  /// chunk group, so the chunk groups of the chunk it updates are used instead.
+  fn get_chunk_group_chunks(compilation: &Compilation, chunk: &Chunk) -> Vec<ChunkUkey> {
+    let chunks = if matches!(chunk.kind(), ChunkKind::HotUpdate) {
+      let chunk_id = chunk.id(&compilation.chunk_ids_artifact);
+      compilation
+        .chunk_by_ukey
+        .values()
+        .filter(|current| {
+          !matches!(current.kind(), ChunkKind::HotUpdate)
+            && current.id(&compilation.chunk_ids_artifact) == chunk_id
+        })
+        .collect::<Vec<_>>()
+    } else {
+      vec![chunk]
+    };
+    chunks
+      .into_iter()
+      .flat_map(|chunk| chunk.groups())
+      .map(|group| compilation.chunk_group_by_ukey.expect_get(group))
+      .flat_map(|group| group.chunks.iter().copied())
+      .collect::<IndexSet<_>>()
+      .into_iter()
+      .collect()
+  }
+
   pub(crate) fn get_ordered_chunk_css_modules<'a>(
     chunk: &Chunk,
     compilation: &Compilation,
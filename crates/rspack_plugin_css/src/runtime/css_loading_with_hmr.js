@@ -1,10 +1,90 @@
 var oldTags = [];
 var newTags = [];
+var moduleUpdates = [];
+var removedCssModules = [];
+var moduleTags = {};
+// keep in sync with HMR_CSS_MODULE_MARKER in utils.rs
+var moduleMarkerRegExp = /\/\* webpack\/css-module: (.+?) \*\/\n/;
+// returns null when there are rules that don't belong to any marked module, the stylesheet can't
+// be split without losing them
+var splitCssModules = function (css) {
+	var parts = css.split(moduleMarkerRegExp);
+	if (parts[0].trim()) return null;
+	var modules = [];
+	for (var i = 1; i < parts.length; i += 2) {
+		modules.push([JSON.parse(parts[i]), parts[i + 1]]);
+	}
+	return modules;
+};
+// the urls in a <style> are resolved against the document instead of the stylesheet
+var rebaseUrls = function (css, base) {
+	return css.replace(
+		/url\(\s*(['"]?)([^'")]+)\1\s*\)/g,
+		function (match, quote, url) {
+			if (/^([a-z][a-z0-9+.-]*:|\/|#)/i.test(url)) return match;
+			return "url(" + quote + new URL(url, base).href + quote + ")";
+		}
+	);
+};
+var fetchStylesheet = function (chunkId, url) {
+	return fetch(url).then(function (response) {
+		if (!response.ok) {
+			var error = new Error(
+				"Loading css hot update chunk " +
+					chunkId +
+					" failed.\n(" +
+					response.status +
+					": " +
+					url +
+					")"
+			);
+			error.name = "ChunkLoadError";
+			error.type = "missing";
+			error.request = url;
+			throw error;
+		}
+		return response.text();
+	});
+};
+var setModuleStyle = function (link, moduleId, css, base) {
+	var style = moduleTags[moduleId];
+	if (!style) {
+		style = moduleTags[moduleId] = document.createElement("style");
+		if (__webpack_require__.nc) {
+			style.setAttribute("nonce", __webpack_require__.nc);
+		}
+		style.setAttribute("data-webpack", uniqueName + ":css-module-" + moduleId);
+		link.parentNode.insertBefore(style, link);
+	}
+	style.textContent = rebaseUrls(css, base);
+};
 var applyHandler = function (options) {
 	return {
-		dispose: function () {},
+		dispose: function () {
+			removedCssModules.forEach(function (moduleId) {
+				var style = moduleTags[moduleId];
+				if (style && style.parentNode) style.parentNode.removeChild(style);
+				delete moduleTags[moduleId];
+			});
+			removedCssModules.length = 0;
+		},
 		apply: function () {
 			var moduleIds = [];
+			while (moduleUpdates.length) {
+				var update = moduleUpdates.shift();
+				var link = update[0];
+				if (update[1]) {
+					// replace the stylesheet of the chunk with a <style> for each module, in the same order
+					update[1].forEach(function (module) {
+						setModuleStyle(link, module[0], module[1], link.href);
+					});
+					link.setAttribute("data-webpack-css-modules", 1);
+					link.disabled = true;
+				}
+				update[2].forEach(function (module) {
+					setModuleStyle(link, module[0], module[1], update[3]);
+				});
+			}
 			newTags.forEach(function (info) {
 				info[1].sheet.disabled = false;
 			});
@@ -28,6 +108,51 @@ var cssTextKey = function (link) {
 		return r.cssText
 	}).join();
 };
+// reloads the whole stylesheet of a chunk that can't be split into modules
+var reloadStylesheet = function (chunkId, url, oldTag, updatedModulesList) {
+	return new Promise(function (resolve, reject) {
+		var link = loadStylesheet(
+			chunkId,
+			url + (url.indexOf("?") < 0 ? "?" : "&") + "hmr=" + Date.now(),
+			function (event) {
+				if (event.type !== "load") {
+					var error = new Error();
+					var errorType = event && event.type;
+					var realSrc = event && event.target && event.target.src;
+					error.message =
+						"Loading css hot update chunk " +
+						chunkId +
+						" failed.\n(" +
+						errorType +
+						": " +
+						realSrc +
+						")";
+					error.name = "ChunkLoadError";
+					error.type = errorType;
+					error.request = realSrc;
+					reject(error);
+				} else {
+					try {
+						if (cssTextKey(oldTag) == cssTextKey(link)) {
+							if (link.parentNode) link.parentNode.removeChild(link);
+							return resolve();
+						}
+					} catch (e) {}
+					var factories = {};
+					loadCssChunkData(factories, link, chunkId);
+					Object.keys(factories).forEach(function(id) {
+					    (updatedModulesList.push(id));
+					});
+					link.sheet.disabled = true;
+					oldTags.push(oldTag);
+					newTags.push([chunkId, link]);
+					resolve();
+				}
+			},
+			oldTag
+		);
+	});
+};
 __webpack_require__.hmrC.css = function (
 	chunkIds,
 	removedChunks,
@@ -37,53 +162,35 @@ __webpack_require__.hmrC.css = function (
 	updatedModulesList
 ) {
 	applyHandlers.push(applyHandler);
+	removedModules.forEach(function (moduleId) {
+		removedCssModules.push(moduleId);
+	});
 	chunkIds.forEach(function (chunkId) {
 		var filename = __webpack_require__.k(chunkId);
 		var url = __webpack_require__.p + filename;
 		var oldTag = loadStylesheet(chunkId, url);
 		if (!oldTag) return;
+		var updateUrl = __webpack_require__.p + __webpack_require__.hk(chunkId);
+		var isSplit = oldTag.getAttribute("data-webpack-css-modules");
 		promises.push(
-			new Promise(function (resolve, reject) {
-				var link = loadStylesheet(
-					chunkId,
-					url + (url.indexOf("?") < 0 ? "?" : "&") + "hmr=" + Date.now(),
-					function (event) {
-						if (event.type !== "load") {
-							var error = new Error();
-							var errorType = event && event.type;
-							var realSrc = event && event.target && event.target.src;
-							error.message =
-								"Loading css hot update chunk " +
-								chunkId +
-								" failed.\n(" +
-								errorType +
-								": " +
-								realSrc +
-								")";
-							error.name = "ChunkLoadError";
-							error.type = errorType;
-							error.request = realSrc;
-							reject(error);
-						} else {
-							try {
-								if (cssTextKey(oldTag) == cssTextKey(link)) {
-									if (link.parentNode) link.parentNode.removeChild(link);
-									return resolve();
-								}
-							} catch (e) {}
-							var factories = {};
-							loadCssChunkData(factories, link, chunkId);
-							Object.keys(factories).forEach(function(id) {
-							    (updatedModulesList.push(id));
-							});
-							link.sheet.disabled = true;
-							oldTags.push(oldTag);
-							newTags.push([chunkId, link]);
-							resolve();
-						}
-					},
-					oldTag
-				);
+			Promise.all([
+				isSplit ? null : fetchStylesheet(chunkId, oldTag.href),
+				fetchStylesheet(chunkId, updateUrl)
+			]).then(function (stylesheets) {
+				var chunkModules = stylesheets[0] && splitCssModules(stylesheets[0]);
+				var updatedModules = splitCssModules(stylesheets[1]);
+				if (
+					(stylesheets[0] && !(chunkModules && chunkModules.length)) ||
+					!updatedModules
+				) {
+					return reloadStylesheet(chunkId, url, oldTag, updatedModulesList);
+				}
+				moduleUpdates.push([
+					oldTag,
+					chunkModules,
+					updatedModules,
+					new URL(updateUrl, document.baseURI).href
+				]);
 			})
 		);
 	});
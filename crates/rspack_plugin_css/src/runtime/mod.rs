@@ -3,12 +3,14 @@ use std::borrow::Cow;
 use cow_utils::CowUtils;
 use rspack_collections::Identifier;
 use rspack_core::{
-  basic_function, compile_boolean_matcher, impl_runtime_module,
+  basic_function, compile_boolean_matcher, get_css_hot_update_chunk_filename_template,
+  impl_runtime_module,
   rspack_sources::{BoxSource, ConcatSource, RawStringSource, SourceExt},
-  BooleanMatcher, ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
-  RuntimeModuleStage,
+  BooleanMatcher, ChunkUkey, Compilation, CrossOriginLoading, PathData, RuntimeGlobals,
+  RuntimeModule, RuntimeModuleStage,
 };
 use rspack_plugin_runtime::{chunk_has_css, get_chunk_runtime_requirements, stringify_chunks};
+use rspack_util::infallible::ResultInfallibleExt;
 use rustc_hash::FxHashSet as HashSet;
 
 #[impl_runtime_module]
@@ -179,6 +181,20 @@ installedChunks[chunkId] = 0;
       }
 
       if with_hmr {
+        let hot_update_filename = compilation
+          .get_path(
+            &get_css_hot_update_chunk_filename_template(&compilation.options.output),
+            PathData::default()
+              .hash(format!("' + {}() + '", RuntimeGlobals::GET_FULL_HASH).as_str())
+              .id("' + chunkId + '")
+              .runtime(chunk.runtime().as_str()),
+          )
+          .always_ok();
+        source.add(RawStringSource::from(format!(
+          "{} = function (chunkId) {{\n\treturn '{}';\n}};\n",
+          RuntimeGlobals::GET_CHUNK_UPDATE_CSS_FILENAME,
+          hot_update_filename
+        )));
         source.add(RawStringSource::from_static(include_str!(
           "./css_loading_with_hmr.js"
         )));
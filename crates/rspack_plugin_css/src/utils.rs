@@ -27,6 +27,8 @@ use rustc_hash::FxHashSet as HashSet;
 use crate::parser_and_generator::CssExport;
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_CSS_AUTO_PUBLIC_PATH__";
+// keep in sync with the marker in runtime/css_loading_with_hmr.js
+pub const HMR_CSS_MODULE_MARKER: &str = "webpack/css-module:";
 pub static LEADING_DIGIT_REGEX: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"^((-?[0-9])|--)").expect("Invalid regexp"));
 pub static PREFIX_UNDERSCORE_REGEX: LazyLock<Regex> =
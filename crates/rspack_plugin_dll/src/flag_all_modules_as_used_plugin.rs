@@ -73,6 +73,7 @@ async fn succeed_module(
   // see: https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/FlagAllModulesAsUsedPlugin.js#L43-L47
   module.set_factory_meta(FactoryMeta {
     side_effect_free: Some(false),
+    side_effects_source: None,
   });
 
   let module_concatenation_bailout = module
@@ -7,7 +7,7 @@ use hot_module_replacement::HotModuleReplacementRuntimeModule;
 use rspack_collections::{DatabaseItem, IdentifierSet, UkeyMap};
 use rspack_core::{
   chunk_graph_chunk::ChunkId,
-  collect_changed_modules,
+  collect_changed_modules, get_css_hot_update_chunk_filename_template,
   rspack_sources::{RawStringSource, SourceExt},
   ApplyContext, AssetInfo, Chunk, ChunkKind, ChunkUkey, Compilation,
   CompilationAdditionalTreeRuntimeRequirements, CompilationAsset, CompilationParams,
@@ -268,9 +268,15 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         let filename = if entry.has_filename {
           entry.filename.to_string()
         } else {
+          // the stylesheet of the updated css modules is emitted besides the script
+          let filename_template = if entry.filename.ends_with(".css") {
+            get_css_hot_update_chunk_filename_template(&compilation.options.output)
+          } else {
+            compilation.options.output.hot_update_chunk_filename.clone()
+          };
           compilation
             .get_path(
-              &compilation.options.output.hot_update_chunk_filename,
+              &filename_template,
               PathData::default()
                 .chunk_id_optional(
                   hot_update_chunk
@@ -1,4 +1,4 @@
-use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPreset};
+use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPortable};
 use rspack_core::{
   module_raw, AffectType, AsContextDependency, Compilation, Dependency, DependencyCategory,
   DependencyId, DependencyTemplate, DependencyType, ModuleDependency, RuntimeSpec, TemplateContext,
@@ -10,7 +10,7 @@ use rspack_util::atom::Atom;
 #[derive(Debug, Clone)]
 pub struct AMDRequireItemDependency {
   id: DependencyId,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   request: Atom,
   range: (u32, u32),
   optional: bool,
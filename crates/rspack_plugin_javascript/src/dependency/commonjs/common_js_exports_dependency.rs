@@ -3,11 +3,11 @@ use rspack_cacheable::{
   with::{AsPreset, AsVec},
 };
 use rspack_core::{
-  property_access, AsContextDependency, AsModuleDependency, Compilation, Dependency,
-  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, ExportNameOrSpec,
-  ExportSpec, ExportsOfExportsSpec, ExportsSpec, InitFragmentExt, InitFragmentKey,
-  InitFragmentStage, ModuleGraph, NormalInitFragment, RuntimeGlobals, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource, UsedName,
+  property_access, to_normal_comment, AsContextDependency, AsModuleDependency, Compilation,
+  Dependency, DependencyCategory, DependencyId, DependencyTemplate, DependencyType,
+  ExportNameOrSpec, ExportSpec, ExportsOfExportsSpec, ExportsSpec, InitFragmentExt,
+  InitFragmentKey, InitFragmentStage, ModuleGraph, NormalInitFragment, RuntimeGlobals, RuntimeSpec,
+  TemplateContext, TemplateReplaceSource, UsedName,
 };
 use swc_core::atoms::Atom;
 
@@ -56,6 +56,8 @@ pub struct CommonJsExportsDependency {
   id: DependencyId,
   range: (u32, u32),
   value_range: Option<(u32, u32)>,
+  /// Range of the assignment statement which is safe to be removed when the export is unused
+  assignment_range: Option<(u32, u32)>,
   base: ExportsBase,
   #[cacheable(with=AsVec<AsPreset>)]
   names: Vec<Atom>,
@@ -65,6 +67,7 @@ impl CommonJsExportsDependency {
   pub fn new(
     range: (u32, u32),
     value_range: Option<(u32, u32)>,
+    assignment_range: Option<(u32, u32)>,
     base: ExportsBase,
     names: Vec<Atom>,
   ) -> Self {
@@ -72,6 +75,7 @@ impl CommonJsExportsDependency {
       id: DependencyId::new(),
       range,
       value_range,
+      assignment_range,
       base,
       names,
     }
@@ -170,6 +174,17 @@ impl DependencyTemplate for CommonJsExportsDependency {
           ),
           None,
         )
+      } else if let Some(assignment_range) = self.assignment_range {
+        // the assigned value is side effect free, remove the whole assignment
+        source.replace(
+          assignment_range.0,
+          assignment_range.1,
+          &to_normal_comment(&format!(
+            "unused exports{}",
+            property_access(self.names.iter(), 0)
+          )),
+          None,
+        );
       } else {
         init_fragments.push(
           NormalInitFragment::new(
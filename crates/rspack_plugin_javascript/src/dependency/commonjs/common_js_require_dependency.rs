@@ -1,11 +1,16 @@
-use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsOption, AsPreset, AsVec, Skip},
+};
 use rspack_core::{
-  module_id, Compilation, DependencyLocation, DependencyRange, RuntimeSpec, SharedSourceMap,
+  create_exports_object_referenced, module_id, Compilation, DependencyLocation, DependencyRange,
+  ExtendedReferencedExport, ModuleGraph, ReferencedExport, RuntimeSpec, SharedSourceMap,
 };
 use rspack_core::{AsContextDependency, Dependency, DependencyCategory};
 use rspack_core::{DependencyId, DependencyTemplate};
 use rspack_core::{DependencyType, ModuleDependency};
 use rspack_core::{TemplateContext, TemplateReplaceSource};
+use swc_core::atoms::Atom;
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -15,6 +20,8 @@ pub struct CommonJsRequireDependency {
   optional: bool,
   range: DependencyRange,
   range_expr: Option<DependencyRange>,
+  #[cacheable(with=AsOption<AsVec<AsVec<AsPreset>>>)]
+  referenced_exports: Option<Vec<Vec<Atom>>>,
   #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
 }
@@ -33,9 +40,16 @@ impl CommonJsRequireDependency {
       optional,
       range,
       range_expr,
+      referenced_exports: None,
       source_map,
     }
   }
+
+  /// Narrow the referenced exports to the properties accessed on the result of `require()`,
+  /// the whole exports object is referenced if not set
+  pub fn set_referenced_exports(&mut self, referenced_exports: Vec<Vec<Atom>>) {
+    self.referenced_exports = Some(referenced_exports);
+  }
 }
 
 #[cacheable_dyn]
@@ -60,6 +74,21 @@ impl Dependency for CommonJsRequireDependency {
     self.range_expr.as_ref()
   }
 
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    let Some(referenced_exports) = &self.referenced_exports else {
+      return create_exports_object_referenced();
+    };
+    // properties are accessed on the exports object directly, so they can't be mangled
+    referenced_exports
+      .iter()
+      .map(|names| ExtendedReferencedExport::Export(ReferencedExport::new(names.clone(), false)))
+      .collect()
+  }
+
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::True
   }
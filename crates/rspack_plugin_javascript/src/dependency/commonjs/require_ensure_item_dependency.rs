@@ -1,4 +1,4 @@
-use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPreset};
+use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPortable};
 use rspack_core::{
   AffectType, AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory,
   DependencyId, DependencyRange, DependencyType, ModuleDependency,
@@ -9,7 +9,7 @@ use rspack_util::atom::Atom;
 #[derive(Debug, Clone)]
 pub struct RequireEnsureItemDependency {
   id: DependencyId,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   request: Atom,
   range: DependencyRange,
 }
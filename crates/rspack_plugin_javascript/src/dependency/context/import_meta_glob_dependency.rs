@@ -0,0 +1,229 @@
+use itertools::Itertools;
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsOption, AsPreset},
+};
+use rspack_core::{
+  module_raw, property_access, AsModuleDependency, Compilation, ContextDependency,
+  ContextElementDependency, ContextMode, ContextOptions, Dependency, DependencyCategory,
+  DependencyId, DependencyRange, DependencyTemplate, DependencyType, ModuleGraph, RuntimeSpec,
+  TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::Diagnostic;
+use rspack_util::{atom::Atom, json_stringify};
+use rustc_hash::FxHashMap;
+
+use super::create_resource_identifier_for_context_dependency;
+
+/// `import.meta.glob(patterns, options)`, the matched files are collected by a context module
+/// and rendered as an object whose keys are the paths of the files.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct ImportMetaGlobDependency {
+  id: DependencyId,
+  options: ContextOptions,
+  range: DependencyRange,
+  resource_identifier: String,
+  optional: bool,
+  critical: Option<Diagnostic>,
+  /// Prefix of the keys, the path from the importer to the context directory
+  key_prefix: String,
+  /// Export to pick from the matched modules, set by the `import` option
+  #[cacheable(with=AsOption<AsPreset>)]
+  import: Option<Atom>,
+}
+
+impl ImportMetaGlobDependency {
+  pub fn new(
+    options: ContextOptions,
+    range: DependencyRange,
+    optional: bool,
+    key_prefix: String,
+    import: Option<Atom>,
+  ) -> Self {
+    let resource_identifier = create_resource_identifier_for_context_dependency(None, &options);
+    Self {
+      options,
+      range,
+      resource_identifier,
+      optional,
+      id: DependencyId::new(),
+      critical: None,
+      key_prefix,
+      import,
+    }
+  }
+
+  /// Keys and requests of the modules in the context module, sorted by keys
+  fn get_entries(&self, module_graph: &ModuleGraph) -> Vec<(String, String)> {
+    let Some(context_module) = module_graph.module_identifier_by_dependency_id(&self.id) else {
+      return vec![];
+    };
+    // a file may be matched by several alternative requests, e.g. `./a.js` and `./a`,
+    // prefer the longest one which contains the extension
+    let mut requests = FxHashMap::default();
+    for connection in module_graph.get_outgoing_connections(context_module) {
+      let Some(dep) = module_graph
+        .dependency_by_id(&connection.dependency_id)
+        .and_then(|dep| dep.downcast_ref::<ContextElementDependency>())
+      else {
+        continue;
+      };
+      let request = requests
+        .entry(*connection.module_identifier())
+        .or_insert(dep.user_request.as_str());
+      if dep.user_request.len() > request.len() {
+        *request = dep.user_request.as_str();
+      }
+    }
+    requests
+      .into_values()
+      .map(|request| {
+        (
+          format!("{}{}", self.key_prefix, request.trim_start_matches("./")),
+          request.to_string(),
+        )
+      })
+      .sorted()
+      .collect()
+  }
+}
+
+#[cacheable_dyn]
+impl Dependency for ImportMetaGlobDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ImportMetaGlob
+  }
+
+  fn range(&self) -> Option<&DependencyRange> {
+    Some(&self.range)
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+
+  fn get_diagnostics(&self, _module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    if let Some(critical) = self.critical() {
+      return Some(vec![critical.clone()]);
+    }
+    None
+  }
+}
+
+impl ContextDependency for ImportMetaGlobDependency {
+  fn request(&self) -> &str {
+    &self.options.request
+  }
+
+  fn options(&self) -> &ContextOptions {
+    &self.options
+  }
+
+  fn get_context(&self) -> Option<&str> {
+    None
+  }
+
+  fn resource_identifier(&self) -> &str {
+    &self.resource_identifier
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.options.request = request;
+  }
+
+  fn get_optional(&self) -> bool {
+    self.optional
+  }
+
+  fn type_prefix(&self) -> rspack_core::ContextTypePrefix {
+    rspack_core::ContextTypePrefix::Normal
+  }
+
+  fn critical(&self) -> &Option<Diagnostic> {
+    &self.critical
+  }
+
+  fn critical_mut(&mut self) -> &mut Option<Diagnostic> {
+    &mut self.critical
+  }
+}
+
+#[cacheable_dyn]
+impl DependencyTemplate for ImportMetaGlobDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+
+    let context = module_raw(
+      compilation,
+      runtime_requirements,
+      &self.id,
+      &self.options.request,
+      self.optional,
+    );
+    let eager = matches!(self.options.mode, ContextMode::Sync);
+    let import = self
+      .import
+      .as_ref()
+      .map(|import| property_access([import], 0))
+      .unwrap_or_default();
+    let properties = self
+      .get_entries(&compilation.get_module_graph())
+      .into_iter()
+      .map(|(key, request)| {
+        let request = json_stringify(&request);
+        let value = if eager {
+          format!("ctx({request}){import}")
+        } else if import.is_empty() {
+          format!("function () {{ return ctx({request}); }}")
+        } else {
+          format!(
+            "function () {{ return ctx({request}).then(function (m) {{ return m{import}; }}); }}"
+          )
+        };
+        format!("{}: {value}", json_stringify(&key))
+      })
+      .join(",\n");
+    source.replace(
+      self.range.start,
+      self.range.end,
+      &format!("(function (ctx) {{ return {{\n{properties}\n}}; }})({context})"),
+      None,
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    hasher: &mut dyn std::hash::Hasher,
+    compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+    // the rendered object changes when files are added to or removed from the context module
+    for (key, request) in self.get_entries(&compilation.get_module_graph()) {
+      hasher.write(key.as_bytes());
+      hasher.write(request.as_bytes());
+    }
+  }
+}
+
+impl AsModuleDependency for ImportMetaGlobDependency {}
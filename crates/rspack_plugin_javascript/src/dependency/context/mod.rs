@@ -1,12 +1,14 @@
 mod common_js_require_context_dependency;
 mod import_context_dependency;
 mod import_meta_context_dependency;
+mod import_meta_glob_dependency;
 mod require_context_dependency;
 mod require_resolve_context_dependency;
 
 pub use common_js_require_context_dependency::CommonJsRequireContextDependency;
 pub use import_context_dependency::ImportContextDependency;
 pub use import_meta_context_dependency::ImportMetaContextDependency;
+pub use import_meta_glob_dependency::ImportMetaGlobDependency;
 use itertools::Itertools;
 pub use require_context_dependency::RequireContextDependency;
 pub use require_resolve_context_dependency::RequireResolveContextDependency;
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use indexmap::{IndexMap, IndexSet};
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsOption, AsPreset, AsVec, Skip},
+  with::{AsOption, AsPortable, AsPreset, AsVec, Skip},
 };
 use rspack_collections::IdentifierSet;
 use rspack_core::{
@@ -43,7 +43,7 @@ pub struct ESMExportImportedSpecifierDependency {
   pub ids: Vec<Atom>,
   #[cacheable(with=AsOption<AsPreset>)]
   pub name: Option<Atom>,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   pub request: Atom,
   pub export_all: bool,
   pub source_order: i32,
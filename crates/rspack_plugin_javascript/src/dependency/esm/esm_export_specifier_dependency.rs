@@ -6,8 +6,9 @@ use rspack_collections::IdentifierSet;
 use rspack_core::{
   AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyCategory,
   DependencyId, DependencyLocation, DependencyRange, DependencyTemplate, DependencyType,
-  ESMExportInitFragment, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
-  RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource, UsedName,
+  ESMExportInitFragment, EvaluatedInlinableValue, ExportNameOrSpec, ExportSpec,
+  ExportsOfExportsSpec, ExportsSpec, ModuleGraph, RuntimeSpec, SharedSourceMap, TemplateContext,
+  TemplateReplaceSource, UsedName,
 };
 use swc_core::ecma::atoms::Atom;
 
@@ -23,6 +24,7 @@ pub struct ESMExportSpecifierDependency {
   pub name: Atom,
   #[cacheable(with=AsPreset)]
   pub value: Atom, // id
+  inlinable: Option<EvaluatedInlinableValue>,
 }
 
 impl ESMExportSpecifierDependency {
@@ -30,16 +32,26 @@ impl ESMExportSpecifierDependency {
     name: Atom,
     value: Atom,
     range: DependencyRange,
+    inlinable: Option<EvaluatedInlinableValue>,
     source_map: Option<SharedSourceMap>,
   ) -> Self {
     Self {
       name,
       value,
       range,
+      inlinable,
       source_map,
       id: DependencyId::new(),
     }
   }
+
+  pub fn inlinable(&self) -> Option<&EvaluatedInlinableValue> {
+    self.inlinable.as_ref()
+  }
+
+  pub fn set_inlinable(&mut self, inlinable: EvaluatedInlinableValue) {
+    self.inlinable = Some(inlinable);
+  }
 }
 
 #[cacheable_dyn]
@@ -61,8 +73,17 @@ impl Dependency for ESMExportSpecifierDependency {
   }
 
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
+    let export = if let Some(inlinable) = &self.inlinable {
+      ExportNameOrSpec::ExportSpec(ExportSpec {
+        name: self.name.clone(),
+        inlinable: Some(inlinable.clone()),
+        ..Default::default()
+      })
+    } else {
+      ExportNameOrSpec::String(self.name.clone())
+    };
     Some(ExportsSpec {
-      exports: ExportsOfExportsSpec::Array(vec![ExportNameOrSpec::String(self.name.clone())]),
+      exports: ExportsOfExportsSpec::Array(vec![export]),
       priority: Some(1),
       can_mangle: None,
       terminal_binding: Some(true),
@@ -82,7 +103,12 @@ impl Dependency for ESMExportSpecifierDependency {
   }
 
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
-    rspack_core::AffectType::False
+    // the value is inlined into the referencing modules
+    if self.inlinable.is_some() {
+      rspack_core::AffectType::True
+    } else {
+      rspack_core::AffectType::False
+    }
   }
 }
 
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsPreset, Skip},
+  with::{AsPortable, Skip},
 };
 use rspack_collections::IdentifierSet;
 use rspack_core::Compilation;
@@ -15,8 +15,8 @@ use rspack_core::{
   AwaitDependenciesInitFragment, BuildMetaDefaultObject, ConditionalInitFragment, ConnectionState,
   Dependency, DependencyCategory, DependencyCondition, DependencyId, DependencyTemplate,
   DependencyType, ErrorSpan, ExportInfoProvided, ExportsType, ExtendedReferencedExport,
-  ImportAttributes, InitFragmentExt, InitFragmentKey, InitFragmentStage, ModuleDependency,
-  ProvidedExports, RuntimeCondition, TemplateContext, TemplateReplaceSource,
+  ImportAttributes, ImportPhase, InitFragmentExt, InitFragmentKey, InitFragmentStage,
+  ModuleDependency, ProvidedExports, RuntimeCondition, TemplateContext, TemplateReplaceSource,
 };
 use rspack_core::{ModuleGraph, RuntimeSpec};
 use rspack_error::miette::{MietteDiagnostic, Severity};
@@ -65,7 +65,7 @@ pub mod import_emitted_runtime {
 #[cacheable]
 #[derive(Debug, Clone)]
 pub struct ESMImportSideEffectDependency {
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   pub request: Atom,
   pub source_order: i32,
   pub id: DependencyId,
@@ -74,6 +74,7 @@ pub struct ESMImportSideEffectDependency {
   pub dependency_type: DependencyType,
   pub export_all: bool,
   attributes: Option<ImportAttributes>,
+  phase: ImportPhase,
   resource_identifier: String,
   #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
@@ -89,6 +90,7 @@ impl ESMImportSideEffectDependency {
     dependency_type: DependencyType,
     export_all: bool,
     attributes: Option<ImportAttributes>,
+    phase: ImportPhase,
     source_map: Option<SharedSourceMap>,
   ) -> Self {
     let resource_identifier =
@@ -102,6 +104,7 @@ impl ESMImportSideEffectDependency {
       dependency_type,
       export_all,
       attributes,
+      phase,
       resource_identifier,
       source_map,
     }
@@ -163,7 +166,11 @@ pub fn esm_import_dependency_apply<T: ModuleDependency>(
   let module_key = ref_module
     .map(|i| i.as_str())
     .unwrap_or(module_dependency.request());
-  let key = format!("ESM import {}", module_key);
+  let key = if module_dependency.get_phase().is_defer() {
+    format!("ESM import defer {}", module_key)
+  } else {
+    format!("ESM import {}", module_key)
+  };
 
   // The import emitted map is consumed by ESMAcceptDependency which enabled by HotModuleReplacementPlugin
   if let Some(import_emitted_map) = import_emitted_runtime::get_map() {
@@ -427,6 +434,10 @@ impl Dependency for ESMImportSideEffectDependency {
     self.attributes.as_ref()
   }
 
+  fn get_phase(&self) -> ImportPhase {
+    self.phase
+  }
+
   fn get_module_evaluation_side_effects_state(
     &self,
     module_graph: &ModuleGraph,
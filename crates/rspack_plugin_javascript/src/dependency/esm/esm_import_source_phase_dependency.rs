@@ -0,0 +1,211 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsPortable, AsPreset, Skip},
+};
+use rspack_core::{
+  module_id, AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId,
+  DependencyLocation, DependencyRange, DependencyTemplate, DependencyType, ErrorSpan,
+  ExtendedReferencedExport, ImportAttributes, ImportPhase, InitFragmentExt, InitFragmentKey,
+  InitFragmentStage, ModuleDependency, ModuleGraph, ModuleType, NormalInitFragment, RuntimeGlobals,
+  RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::{
+  miette::{MietteDiagnostic, Severity},
+  Diagnostic, DiagnosticExt, TraceableError,
+};
+use swc_core::ecma::atoms::Atom;
+
+use super::create_resource_identifier_for_esm_dependency;
+
+/// `import source x from "./module.wasm"`, the compiled `WebAssembly.Module` is bound to `x`
+/// without instantiating or evaluating the imported module.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct ESMImportSourcePhaseDependency {
+  id: DependencyId,
+  #[cacheable(with=AsPortable)]
+  request: Atom,
+  #[cacheable(with=AsPreset)]
+  name: Atom,
+  range: DependencyRange,
+  range_src: DependencyRange,
+  attributes: Option<ImportAttributes>,
+  resource_identifier: String,
+  #[cacheable(with=Skip)]
+  source_map: Option<SharedSourceMap>,
+}
+
+impl ESMImportSourcePhaseDependency {
+  pub fn new(
+    request: Atom,
+    name: Atom,
+    range: DependencyRange,
+    range_src: DependencyRange,
+    attributes: Option<ImportAttributes>,
+    source_map: Option<SharedSourceMap>,
+  ) -> Self {
+    let resource_identifier =
+      create_resource_identifier_for_esm_dependency(&request, attributes.as_ref());
+    Self {
+      id: DependencyId::new(),
+      request,
+      name,
+      range,
+      range_src,
+      attributes,
+      resource_identifier,
+      source_map,
+    }
+  }
+}
+
+#[cacheable_dyn]
+impl Dependency for ESMImportSourcePhaseDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn loc(&self) -> Option<DependencyLocation> {
+    self.range.to_loc(self.source_map.as_ref())
+  }
+
+  fn range(&self) -> Option<&DependencyRange> {
+    Some(&self.range)
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::EsmImportSource
+  }
+
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    self.attributes.as_ref()
+  }
+
+  fn get_phase(&self) -> ImportPhase {
+    ImportPhase::Source
+  }
+
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    vec![]
+  }
+
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let module = module_graph.get_module_by_dependency_id(&self.id)?;
+    if matches!(module.module_type(), ModuleType::WasmAsync) {
+      return None;
+    }
+    let parent_module_identifier = module_graph.get_parent_module(&self.id)?;
+    let parent_module = module_graph.module_by_identifier(parent_module_identifier)?;
+    let title = "ESModulesLinkingError";
+    let message = format!(
+      "Source phase import of '{}' is only supported for WebAssembly modules (type 'webassembly/async'), but the module type is '{}'",
+      self.request,
+      module.module_type()
+    );
+    let diagnostic = if let Some(source) = parent_module.original_source().map(|s| s.source()) {
+      Diagnostic::from(
+        TraceableError::from_file(
+          source.into_owned(),
+          self.range.start as usize,
+          self.range.end as usize,
+          title.to_string(),
+          message,
+        )
+        .with_severity(Severity::Error)
+        .boxed(),
+      )
+    } else {
+      Diagnostic::from(
+        MietteDiagnostic::new(message)
+          .with_code(title)
+          .with_severity(Severity::Error)
+          .boxed(),
+      )
+    };
+    Some(vec![diagnostic
+      .with_hide_stack(Some(true))
+      .with_module_identifier(Some(*parent_module_identifier))])
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+}
+
+#[cacheable_dyn]
+impl ModuleDependency for ESMImportSourcePhaseDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn source_span(&self) -> Option<ErrorSpan> {
+    Some(ErrorSpan::new(self.range_src.start, self.range_src.end))
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+}
+
+#[cacheable_dyn]
+impl DependencyTemplate for ESMImportSourcePhaseDependency {
+  fn apply(
+    &self,
+    _source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      init_fragments,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    runtime_requirements.insert(RuntimeGlobals::COMPILE_WASM);
+    let module_id = module_id(compilation, &self.id, &self.request, false);
+    let content = format!(
+      "/* ESM import source */var {} = await {}({module_id});\n",
+      self.name,
+      RuntimeGlobals::COMPILE_WASM
+    );
+    init_fragments.push(
+      NormalInitFragment::new(
+        content,
+        InitFragmentStage::StageAsyncESMImports,
+        0,
+        InitFragmentKey::ESMImport(format!("ESM import source {}", self.name)),
+        None,
+      )
+      .boxed(),
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
+
+impl AsContextDependency for ESMImportSourcePhaseDependency {}
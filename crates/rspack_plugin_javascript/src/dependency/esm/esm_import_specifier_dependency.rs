@@ -1,6 +1,6 @@
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsOption, AsPreset, AsVec, Skip},
+  with::{AsOption, AsPortable, AsPreset, AsVec, Skip},
 };
 use rspack_collections::IdentifierSet;
 use rspack_core::{
@@ -8,8 +8,9 @@ use rspack_core::{
   get_exports_type, AsContextDependency, Compilation, ConnectionState, Dependency,
   DependencyCategory, DependencyCondition, DependencyId, DependencyLocation, DependencyRange,
   DependencyTemplate, DependencyType, ExportPresenceMode, ExportsType, ExtendedReferencedExport,
-  ImportAttributes, JavascriptParserOptions, ModuleDependency, ModuleGraph, ReferencedExport,
-  RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource, UsedByExports,
+  ImportAttributes, ImportPhase, JavascriptParserOptions, ModuleDependency, ModuleGraph,
+  ReferencedExport, RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource,
+  UsedByExports,
 };
 use rspack_core::{property_access, ModuleReferenceOptions};
 use rspack_error::Diagnostic;
@@ -23,7 +24,7 @@ use super::{create_resource_identifier_for_esm_dependency, esm_import_dependency
 #[derive(Debug, Clone)]
 pub struct ESMImportSpecifierDependency {
   id: DependencyId,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   request: Atom,
   #[cacheable(with=AsPreset)]
   name: Atom,
@@ -41,6 +42,7 @@ pub struct ESMImportSpecifierDependency {
   resource_identifier: String,
   export_presence_mode: ExportPresenceMode,
   attributes: Option<ImportAttributes>,
+  phase: ImportPhase,
   #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
   pub namespace_object_as_context: bool,
@@ -61,6 +63,7 @@ impl ESMImportSpecifierDependency {
     export_presence_mode: ExportPresenceMode,
     referenced_properties_in_destructuring: Option<HashSet<Atom>>,
     attributes: Option<ImportAttributes>,
+    phase: ImportPhase,
     source_map: Option<SharedSourceMap>,
   ) -> Self {
     let resource_identifier =
@@ -81,6 +84,7 @@ impl ESMImportSpecifierDependency {
       namespace_object_as_context: false,
       referenced_properties_in_destructuring,
       attributes,
+      phase,
       resource_identifier,
       source_map,
     }
@@ -117,6 +121,39 @@ impl ESMImportSpecifierDependency {
     }
   }
 
+  /// Returns the code of the referenced export when its value is inlined into this module,
+  /// only available when `optimization.inlineExports` is enabled.
+  pub fn get_inlined_code<'a>(&self, module_graph: &'a ModuleGraph) -> Option<&'a Atom> {
+    if self.call {
+      return None;
+    }
+    let (name, properties) = self.get_ids(module_graph).split_first()?;
+    let module = module_graph.module_identifier_by_dependency_id(&self.id)?;
+    let parent_module = module_graph.get_parent_module(&self.id)?;
+    if !matches!(
+      get_exports_type(module_graph, &self.id, parent_module),
+      ExportsType::Namespace
+    ) {
+      return None;
+    }
+    let export_info = module_graph
+      .get_exports_info(module)
+      .get_export_info_without_mut_module_graph(module_graph, name);
+    if let Some(inlinable) = export_info.inlinable(module_graph) {
+      return inlinable.code(properties);
+    }
+    // follow reexports to the module that declares the export
+    let target = export_info.get_target(module_graph)?;
+    let [name] = target.export.as_deref()? else {
+      return None;
+    };
+    module_graph
+      .get_exports_info(&target.module)
+      .get_export_info_without_mut_module_graph(module_graph, name)
+      .inlinable(module_graph)?
+      .code(properties)
+  }
+
   pub fn create_export_presence_mode(options: &JavascriptParserOptions) -> ExportPresenceMode {
     options
       .import_exports_presence
@@ -156,6 +193,25 @@ impl DependencyTemplate for ESMImportSpecifierDependency {
       return;
     }
 
+    if let Some(code) = self.get_inlined_code(&module_graph) {
+      let ids = self.get_ids(&module_graph);
+      let value = format!(
+        "{}/* inlined export {} */ {code}",
+        if code.starts_with('(') && !(self.shorthand || self.asi_safe) {
+          ";"
+        } else {
+          ""
+        },
+        property_access(ids, 0)
+      );
+      if self.shorthand {
+        source.insert(self.range.end, &format!(": {value}"), None);
+      } else {
+        source.replace(self.range.start, self.range.end, &value, None)
+      }
+      return;
+    }
+
     let used = reference_mgm.is_some();
     if reference_mgm.is_some() && !used {
       // TODO do this by PureExpressionDependency.
@@ -232,10 +288,13 @@ impl DependencyTemplate for ESMImportSpecifierDependency {
 
   fn update_hash(
     &self,
-    _hasher: &mut dyn std::hash::Hasher,
-    _compilation: &Compilation,
+    hasher: &mut dyn std::hash::Hasher,
+    compilation: &Compilation,
     _runtime: Option<&RuntimeSpec>,
   ) {
+    if let Some(code) = self.get_inlined_code(&compilation.get_module_graph()) {
+      hasher.write(code.as_bytes());
+    }
   }
 }
 
@@ -261,10 +320,18 @@ impl Dependency for ESMImportSpecifierDependency {
     self.attributes.as_ref()
   }
 
+  fn get_phase(&self) -> ImportPhase {
+    self.phase
+  }
+
   fn set_used_by_exports(&mut self, used_by_exports: Option<UsedByExports>) {
     self.used_by_exports = used_by_exports;
   }
 
+  fn get_used_by_exports(&self) -> Option<&UsedByExports> {
+    self.used_by_exports.as_ref()
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Esm
   }
@@ -314,6 +381,11 @@ impl Dependency for ESMImportSpecifierDependency {
     module_graph: &ModuleGraph,
     _runtime: Option<&RuntimeSpec>,
   ) -> Vec<ExtendedReferencedExport> {
+    // the export is not accessed at runtime
+    if self.get_inlined_code(module_graph).is_some() {
+      return vec![];
+    }
+
     let mut ids = self.get_ids(module_graph);
     // namespace import
     if ids.is_empty() {
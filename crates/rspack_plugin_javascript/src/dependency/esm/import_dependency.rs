@@ -1,6 +1,6 @@
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsOption, AsPreset, AsVec},
+  with::{AsOption, AsPortable, AsPreset, AsVec},
 };
 use rspack_core::{
   create_exports_object_referenced, module_namespace_promise, Compilation, DependencyRange,
@@ -60,7 +60,7 @@ pub fn create_import_dependency_referenced_exports(
 #[derive(Debug, Clone)]
 pub struct ImportDependency {
   id: DependencyId,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   pub request: Atom,
   pub range: DependencyRange,
   #[cacheable(with=AsOption<AsVec<AsPreset>>)]
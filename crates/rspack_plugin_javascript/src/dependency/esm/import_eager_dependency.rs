@@ -1,6 +1,6 @@
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsOption, AsPreset, AsVec},
+  with::{AsOption, AsPortable, AsPreset, AsVec},
 };
 use rspack_core::{
   module_namespace_promise, AsContextDependency, Compilation, Dependency, DependencyCategory,
@@ -18,7 +18,7 @@ use super::{
 #[derive(Debug, Clone)]
 pub struct ImportEagerDependency {
   id: DependencyId,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   request: Atom,
   range: DependencyRange,
   #[cacheable(with=AsOption<AsVec<AsPreset>>)]
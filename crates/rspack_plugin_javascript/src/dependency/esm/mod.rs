@@ -4,6 +4,7 @@ mod esm_export_header_dependency;
 mod esm_export_imported_specifier_dependency;
 mod esm_export_specifier_dependency;
 mod esm_import_dependency;
+mod esm_import_source_phase_dependency;
 mod esm_import_specifier_dependency;
 mod external_module_dependency;
 mod import_dependency;
@@ -22,6 +23,7 @@ pub use self::esm_export_specifier_dependency::ESMExportSpecifierDependency;
 pub use self::esm_import_dependency::esm_import_dependency_apply;
 pub use self::esm_import_dependency::import_emitted_runtime;
 pub use self::esm_import_dependency::ESMImportSideEffectDependency;
+pub use self::esm_import_source_phase_dependency::ESMImportSourcePhaseDependency;
 pub use self::esm_import_specifier_dependency::ESMImportSpecifierDependency;
 pub use self::external_module_dependency::ExternalModuleDependency;
 pub use self::import_dependency::ImportDependency;
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsPreset, AsVec, Skip},
+  with::{AsPortable, AsPreset, AsVec, Skip},
 };
 use rspack_core::{
   create_exports_object_referenced, module_raw, Compilation, DependencyLocation, DependencyRange,
@@ -18,7 +18,7 @@ use swc_core::atoms::Atom;
 #[derive(Debug, Clone)]
 pub struct ProvideDependency {
   id: DependencyId,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   request: Atom,
   identifier: String,
   #[cacheable(with=AsVec<AsPreset>)]
@@ -1,4 +1,4 @@
-use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPreset};
+use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPortable};
 use rspack_core::{
   module_id, AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId,
   DependencyRange, DependencyTemplate, DependencyType, ModuleDependency, RuntimeSpec,
@@ -10,7 +10,7 @@ use swc_core::ecma::atoms::Atom;
 #[derive(Debug, Clone)]
 pub struct ImportMetaHotDeclineDependency {
   id: DependencyId,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   request: Atom,
   range: DependencyRange,
 }
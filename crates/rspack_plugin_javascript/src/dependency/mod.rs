@@ -6,6 +6,7 @@ mod export_info_dependency;
 mod hmr;
 mod is_included_dependency;
 mod module_argument_dependency;
+mod pure_calls_dependency;
 mod pure_expression_dependency;
 mod url;
 mod worker;
@@ -18,6 +19,7 @@ pub use self::export_info_dependency::*;
 pub use self::hmr::*;
 pub use self::is_included_dependency::*;
 pub use self::module_argument_dependency::*;
+pub use self::pure_calls_dependency::*;
 pub use self::pure_expression_dependency::*;
 pub use self::url::*;
 pub use self::worker::*;
@@ -0,0 +1,61 @@
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_collections::IdentifierSet;
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, AsModuleDependency, ConnectionState, Dependency,
+  DependencyId, ModuleGraph, PureCallee,
+};
+
+/// Top-level calls of a module which are free of side effects as long as all `callees` are pure.
+///
+/// The module is treated as side effect free only after `InferPureFunctionsPlugin`
+/// proved that the callees are pure.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct PureCallsDependency {
+  id: DependencyId,
+  callees: Vec<PureCallee>,
+  pure: bool,
+}
+
+impl PureCallsDependency {
+  pub fn new(callees: Vec<PureCallee>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      callees,
+      pure: false,
+    }
+  }
+
+  pub fn callees(&self) -> &[PureCallee] {
+    &self.callees
+  }
+
+  pub fn set_pure(&mut self, pure: bool) {
+    self.pure = pure;
+  }
+}
+
+#[cacheable_dyn]
+impl Dependency for PureCallsDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn get_module_evaluation_side_effects_state(
+    &self,
+    _module_graph: &ModuleGraph,
+    _module_chain: &mut IdentifierSet,
+  ) -> ConnectionState {
+    ConnectionState::Bool(!self.pure)
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::False
+  }
+}
+
+impl AsModuleDependency for PureCallsDependency {}
+
+impl AsContextDependency for PureCallsDependency {}
+
+impl AsDependencyTemplate for PureCallsDependency {}
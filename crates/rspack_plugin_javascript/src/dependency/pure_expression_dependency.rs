@@ -35,9 +35,8 @@ impl PureExpressionDependency {
     runtime: Option<&RuntimeSpec>,
   ) -> RuntimeCondition {
     match self.used_by_exports {
-      Some(UsedByExports::Bool(true)) => {
-        unreachable!()
-      }
+      // the expression relies on a function that turned out to be impure, see `InferPureFunctionsPlugin`
+      Some(UsedByExports::Bool(true)) => RuntimeCondition::Boolean(true),
       Some(UsedByExports::Bool(false)) => RuntimeCondition::Boolean(false),
       Some(UsedByExports::Set(ref set)) => {
         let module_graph = compilation.get_module_graph();
@@ -68,6 +67,10 @@ impl Dependency for PureExpressionDependency {
     self.used_by_exports = used_by_exports;
   }
 
+  fn get_used_by_exports(&self) -> Option<&UsedByExports> {
+    self.used_by_exports.as_ref()
+  }
+
   fn get_module_evaluation_side_effects_state(
     &self,
     _module_graph: &ModuleGraph,
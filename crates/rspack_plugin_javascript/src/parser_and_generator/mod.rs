@@ -21,10 +21,13 @@ use swc_core::ecma::ast;
 use swc_core::ecma::parser::{lexer::Lexer, EsSyntax, Syntax};
 use swc_node_comments::SwcComments;
 
-use crate::dependency::ESMCompatibilityDependency;
+use crate::dependency::{ESMCompatibilityDependency, PureCallsDependency};
 use crate::visitors::{scan_dependencies, swc_visitor::resolver};
 use crate::visitors::{semicolon, ScanDependenciesResult};
-use crate::{BoxJavascriptParserPlugin, SideEffectsFlagPluginVisitor, SyntaxContextInfo};
+use crate::{
+  collect_pure_functions, BoxJavascriptParserPlugin, PureCalleeResolver,
+  SideEffectsFlagPluginVisitor, SyntaxContextInfo,
+};
 
 #[cacheable]
 #[derive(Default)]
@@ -126,6 +129,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
             presentational_dependencies: vec![],
             code_generation_dependencies: vec![],
             side_effects_bailout: None,
+            optimization_bailouts: vec![],
           }
           .with_diagnostic(map_box_diagnostics_to_module_parse_diagnostics(
             diagnostics,
@@ -192,11 +196,11 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
     let unresolved_mark = ast.get_context().unresolved_mark;
 
     let ScanDependenciesResult {
-      dependencies,
+      mut dependencies,
       blocks,
       presentational_dependencies,
       mut warning_diagnostics,
-      ..
+      optimization_bailouts,
     } = match ast.visit(|program, _| {
       scan_dependencies(
         cm.clone(),
@@ -226,15 +230,37 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
     diagnostics.append(&mut warning_diagnostics);
     let mut side_effects_bailout = None;
 
+    let pure_callee_resolver = compiler_options.optimization.infer_pure_functions.then(|| {
+      ast.visit(|program, context| {
+        let unresolved_ctxt = SyntaxContext::empty().apply_mark(context.unresolved_mark);
+        let resolver = PureCalleeResolver::new(program.get_inner_program());
+        build_info.pure_functions.get_or_insert_default().functions = collect_pure_functions(
+          program.get_inner_program(),
+          unresolved_ctxt,
+          program.comments.as_ref().map(|c| c as &dyn Comments),
+          &resolver,
+        );
+        resolver
+      })
+    });
+
     if compiler_options.optimization.side_effects.is_true() {
       ast.transform(|program, context| {
         let unresolved_ctxt = SyntaxContext::empty().apply_mark(context.unresolved_mark);
         let mut visitor = SideEffectsFlagPluginVisitor::new(
           SyntaxContextInfo::new(unresolved_ctxt),
           program.comments.as_ref().map(|c| c as &dyn Comments),
-        );
+        )
+        .with_pure_callee_resolver(pure_callee_resolver.as_ref());
         program.visit_with(&mut visitor);
         build_meta.side_effect_free = Some(visitor.side_effects_item.is_none());
+        // The module is only free of side effects when the functions called at the top level are pure,
+        // which is checked by `InferPureFunctionsPlugin` after all modules are built
+        if visitor.side_effects_item.is_none() && !visitor.pure_callees.is_empty() {
+          dependencies.push(Box::new(PureCallsDependency::new(std::mem::take(
+            &mut visitor.pure_callees,
+          ))));
+        }
         // Take the item from visitor is safe, because the field is only used in this place
         side_effects_bailout = visitor
           .side_effects_item
@@ -257,6 +283,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         presentational_dependencies,
         code_generation_dependencies: vec![],
         side_effects_bailout,
+        optimization_bailouts,
       }
       .with_diagnostic(map_box_diagnostics_to_module_parse_diagnostics(
         diagnostics,
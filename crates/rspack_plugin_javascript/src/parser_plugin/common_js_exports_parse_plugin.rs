@@ -1,9 +1,9 @@
 use rspack_core::{
   BuildMetaDefaultObject, BuildMetaExportsType, DependencyRange, RuntimeGlobals,
-  RuntimeRequirementsDependency, SpanExt,
+  RuntimeRequirementsDependency, SharedSourceMap, SpanExt,
 };
 use swc_core::atoms::Atom;
-use swc_core::common::Spanned;
+use swc_core::common::{Mark, Span, Spanned, SyntaxContext};
 use swc_core::ecma::ast::{
   AssignExpr, AssignTarget, CallExpr, PropOrSpread, SimpleAssignTarget, UnaryExpr,
 };
@@ -13,6 +13,7 @@ use swc_core::ecma::ast::{Expr, Lit, Prop, PropName, ThisExpr, UnaryOp};
 use super::JavascriptParserPlugin;
 use crate::dependency::{CommonJsExportRequireDependency, CommonJsExportsDependency};
 use crate::dependency::{CommonJsSelfReferenceDependency, ExportsBase, ModuleDecoratorDependency};
+use crate::is_pure_expression;
 use crate::utils::eval::{self, BasicEvaluatedExpression};
 use crate::visitors::expr_like::ExprLike;
 use crate::visitors::{
@@ -110,6 +111,16 @@ fn is_falsy_literal(expr: &Expr) -> bool {
   }
 }
 
+fn exports_base_name(base: ExportsBase) -> &'static str {
+  if base.is_exports() {
+    EXPORTS_NAME
+  } else if base.is_module_exports() {
+    "module.exports"
+  } else {
+    "this"
+  }
+}
+
 fn is_lit_truthy_literal(lit: &Lit) -> bool {
   match lit {
     Lit::Str(str) => !str.value.is_empty(),
@@ -165,12 +176,24 @@ impl JavascriptParser<'_> {
   }
 
   // can't scan `__esModule` value
-  fn bailout(&mut self) {
+  fn bailout(&mut self, reason: &str, span: Span) {
     if matches!(self.parser_exports_state, Some(true)) {
       self.build_meta.exports_type = BuildMetaExportsType::Unset;
       self.build_meta.default_object = BuildMetaDefaultObject::False;
     }
     self.parser_exports_state = Some(false);
+    self.bailout_hint(reason, span);
+  }
+
+  // surface the reason in `optimizationBailout` of stats
+  fn bailout_hint(&mut self, reason: &str, span: Span) {
+    let source_map: SharedSourceMap = self.source_map.clone();
+    let range: DependencyRange = span.into();
+    let message = match range.to_loc(Some(&source_map)) {
+      Some(loc) => format!("CommonJS bailout: {reason} at {loc}"),
+      None => format!("CommonJS bailout: {reason}"),
+    };
+    self.optimization_bailouts.push(message);
   }
 
   // `__esModule` is false
@@ -256,7 +279,17 @@ impl JavascriptParser<'_> {
   }
 }
 
-pub struct CommonJsExportsParserPlugin;
+pub struct CommonJsExportsParserPlugin {
+  unresolved_context: SyntaxContext,
+}
+
+impl CommonJsExportsParserPlugin {
+  pub fn new(unresolved_mark: Mark) -> Self {
+    Self {
+      unresolved_context: SyntaxContext::empty().apply_mark(unresolved_mark),
+    }
+  }
+}
 
 impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
   fn identifier(
@@ -273,7 +306,7 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
       } else {
         RuntimeGlobals::NODE_MODULE_DECORATOR
       };
-      parser.bailout();
+      parser.bailout("module is used directly", ident.span());
       parser
         .dependencies
         .push(Box::new(ModuleDecoratorDependency::new(
@@ -282,7 +315,7 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
         )));
       Some(true)
     } else if !parser.is_esm && parser.is_exports_ident(ident) {
-      parser.bailout();
+      parser.bailout("exports is used directly", ident.span());
       parser
         .dependencies
         .push(Box::new(CommonJsSelfReferenceDependency::new(
@@ -305,7 +338,7 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
     if parser.is_esm {
       None
     } else if parser.is_top_level_this(expr) {
-      parser.bailout();
+      parser.bailout("this is used directly", expr.span());
       parser
         .dependencies
         .push(Box::new(CommonJsSelfReferenceDependency::new(
@@ -331,7 +364,10 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
         get_member_expression_info(parser, expr, Some(is_module_exports_start))
       {
         if remaining.is_empty() {
-          parser.bailout();
+          parser.bailout(
+            &format!("{} is used directly", exports_base_name(base)),
+            expr.span(),
+          );
         }
         parser
           .dependencies
@@ -429,15 +465,30 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
       // exports.a = 1;
       // module.exports.a = 1;
       // this.a = 1;
-      parser
-        .dependencies
-        .push(Box::new(CommonJsExportsDependency::new(
+      let is_statement_level = parser.is_statement_level_expression(assign_expr.span());
+      let dependencies_len = parser.dependencies.len();
+      let presentational_dependencies_len = parser.presentational_dependencies.len();
+      let blocks_len = parser.blocks.len();
+      parser.walk_expression(&assign_expr.right);
+      // `exports.a = function () {}` can be removed entirely when `a` is unused,
+      // as long as the value is side effect free and not rendered by other dependencies
+      let assignment_range = (is_statement_level
+        && dependencies_len == parser.dependencies.len()
+        && presentational_dependencies_len == parser.presentational_dependencies.len()
+        && blocks_len == parser.blocks.len()
+        && is_pure_expression(&assign_expr.right, self.unresolved_context, parser.comments))
+      .then(|| (assign_expr.span.real_lo(), assign_expr.span.real_hi()));
+      // keep the export before the dependencies of the value, as if it was added before the walk
+      parser.dependencies.insert(
+        dependencies_len,
+        Box::new(CommonJsExportsDependency::new(
           (left_expr.span().real_lo(), left_expr.span().real_hi()),
           None,
+          assignment_range,
           base,
           remaining.to_owned(),
-        )));
-      parser.walk_expression(&assign_expr.right);
+        )),
+      );
       Some(true)
     };
 
@@ -469,7 +520,10 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
           // module.exports()
           // this()
           if remaining.is_empty() {
-            parser.bailout();
+            parser.bailout(
+              &format!("{}() is called directly", exports_base_name(base)),
+              expr.span(),
+            );
           }
 
           // exports.a.b()
@@ -531,6 +585,7 @@ impl JavascriptParserPlugin for CommonJsExportsParserPlugin {
           .push(Box::new(CommonJsExportsDependency::new(
             (call_expr.span.real_lo(), call_expr.span.real_hi()),
             Some((arg2.span().real_lo(), arg2.span().real_hi())),
+            None,
             base,
             vec![str.value.clone()],
           )));
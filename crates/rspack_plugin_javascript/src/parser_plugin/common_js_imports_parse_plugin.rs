@@ -1,10 +1,14 @@
 use rspack_core::{
-  ConstDependency, ContextDependency, ContextMode, DependencyCategory, DependencyRange, SpanExt,
+  ConstDependency, ContextDependency, ContextMode, Dependency, DependencyCategory, DependencyRange,
+  SpanExt,
 };
 use rspack_core::{ContextNameSpaceObject, ContextOptions};
 use rspack_error::{DiagnosticExt, Severity};
+use swc_core::atoms::Atom;
 use swc_core::common::{Span, Spanned};
-use swc_core::ecma::ast::{CallExpr, Expr, Ident, MemberExpr, UnaryExpr};
+use swc_core::ecma::ast::{
+  CallExpr, Expr, Ident, Lit, MemberExpr, UnaryExpr, VarDecl, VarDeclKind, VarDeclarator,
+};
 
 use super::JavascriptParserPlugin;
 use crate::dependency::{
@@ -15,7 +19,7 @@ use crate::dependency::{
 use crate::utils::eval::{self, BasicEvaluatedExpression};
 use crate::visitors::{
   context_reg_exp, create_context_dependency, create_traceable_error, expr_matcher, expr_name,
-  JavascriptParser,
+  JavascriptParser, TagInfoData,
 };
 use crate::visitors::{extract_require_call_info, is_require_call_start};
 
@@ -86,6 +90,46 @@ fn create_require_resolve_context_dependency(
   RequireResolveContextDependency::new(options, range, parser.in_try)
 }
 
+const REQUIRE_VARIABLE_TAG: &str = "_identifier__require_variable_tag__";
+
+#[derive(Debug, Clone)]
+struct RequireVariableData {
+  // range of `require()` which initializes the variable
+  range: (u32, u32),
+}
+
+/// Get the members accessed before the first optional chain, `a.b?.c` => `[a, b]`
+fn get_non_optional_members(members: &[Atom], members_optionals: &[bool]) -> Vec<Atom> {
+  members
+    .iter()
+    .zip(members_optionals)
+    .take_while(|(_, optional)| !**optional)
+    .map(|(member, _)| member.clone())
+    .collect()
+}
+
+/// Record the properties referenced on a variable initialized by `require()`,
+/// empty `names` means the whole exports object is referenced
+fn add_require_variable_reference(parser: &mut JavascriptParser, names: Vec<Atom>) {
+  let Some(tag_info_id) = parser.current_tag_info else {
+    return;
+  };
+  let Some(data) = parser
+    .definitions_db
+    .expect_get_tag_info(tag_info_id)
+    .data
+    .clone()
+  else {
+    return;
+  };
+  let data = RequireVariableData::downcast(data);
+  match parser.require_variable_references.get_mut(&data.range) {
+    Some(Some(references)) if !names.is_empty() => references.push(names),
+    Some(references) => *references = None,
+    None => {}
+  }
+}
+
 pub struct CommonJsImportsParserPlugin;
 
 impl CommonJsImportsParserPlugin {
@@ -203,13 +247,22 @@ impl CommonJsImportsParserPlugin {
   ) -> Option<bool> {
     param.is_string().then(|| {
       let range_expr: DependencyRange = param.range().into();
-      let dep = CommonJsRequireDependency::new(
+      let mut dep = CommonJsRequireDependency::new(
         param.string().to_string(),
         range_expr,
         Some(span.into()),
         parser.in_try,
         Some(parser.source_map.clone()),
       );
+      // const { a, b } = require('./a');
+      if let Some(properties) = parser.destructuring_assignment_properties_for(&span) {
+        dep.set_referenced_exports(
+          properties
+            .into_iter()
+            .map(|property| vec![Atom::from(property)])
+            .collect(),
+        );
+      }
       parser.dependencies.push(Box::new(dep));
       true
     })
@@ -348,6 +401,97 @@ impl CommonJsImportsParserPlugin {
 }
 
 impl JavascriptParserPlugin for CommonJsImportsParserPlugin {
+  // const lib = require('./lib');
+  // lib.a;
+  fn pre_declarator(
+    &self,
+    parser: &mut JavascriptParser,
+    declarator: &VarDeclarator,
+    declaration: &VarDecl,
+  ) -> Option<bool> {
+    if declaration.kind != VarDeclKind::Const {
+      return None;
+    }
+    let ident = declarator.name.as_ident()?;
+    let call_expr = declarator.init.as_ref()?.as_call()?;
+    if !call_expr
+      .callee
+      .as_expr()
+      .is_some_and(|expr| expr_matcher::is_require(&**expr))
+      || !parser.is_unresolved_ident(expr_name::REQUIRE)
+      || call_expr.args.len() != 1
+      || call_expr.args[0].spread.is_some()
+      || !matches!(&*call_expr.args[0].expr, Expr::Lit(Lit::Str(_)))
+    {
+      return None;
+    }
+    let range: DependencyRange = call_expr.span.into();
+    let range = (range.start, range.end);
+    parser
+      .require_variable_references
+      .insert(range, Some(vec![]));
+    parser.tag_variable(
+      ident.sym.to_string(),
+      REQUIRE_VARIABLE_TAG,
+      Some(RequireVariableData { range }),
+    );
+    Some(true)
+  }
+
+  fn member_chain(
+    &self,
+    parser: &mut JavascriptParser,
+    _expr: &MemberExpr,
+    for_name: &str,
+    members: &[Atom],
+    members_optionals: &[bool],
+    _member_ranges: &[Span],
+  ) -> Option<bool> {
+    if for_name != REQUIRE_VARIABLE_TAG {
+      return None;
+    }
+    let names = get_non_optional_members(members, members_optionals);
+    add_require_variable_reference(parser, names);
+    Some(true)
+  }
+
+  fn call_member_chain(
+    &self,
+    parser: &mut JavascriptParser,
+    call_expr: &CallExpr,
+    for_name: &str,
+    members: &[Atom],
+    members_optionals: &[bool],
+    _member_ranges: &[Span],
+  ) -> Option<bool> {
+    if for_name != REQUIRE_VARIABLE_TAG {
+      return None;
+    }
+    let mut names = get_non_optional_members(members, members_optionals);
+    // `lib.a()` is called with `lib` as `this`, which may access other exports
+    if names.len() == members.len() {
+      names.pop();
+    }
+    add_require_variable_reference(parser, names);
+    parser.walk_expr_or_spread(&call_expr.args);
+    Some(true)
+  }
+
+  fn finish(&self, parser: &mut JavascriptParser) -> Option<bool> {
+    if parser.require_variable_references.is_empty() {
+      return None;
+    }
+    for dep in parser.dependencies.iter_mut() {
+      if let Some(dep) = dep.downcast_mut::<CommonJsRequireDependency>()
+        && let Some(range) = dep.range().map(|range| (range.start, range.end))
+        && let Some(Some(references)) = parser.require_variable_references.get(&range)
+      {
+        dep.set_referenced_exports(references.clone());
+      }
+    }
+    None
+  }
+
   fn can_rename(&self, parser: &mut JavascriptParser, str: &str) -> Option<bool> {
     if str == expr_name::REQUIRE && parser.is_unresolved_ident(str) {
       Some(true)
@@ -512,6 +656,26 @@ impl JavascriptParserPlugin for CommonJsImportsParserPlugin {
     if for_name == expr_name::REQUIRE {
       return self.require_as_expression_handler(parser, ident);
     }
+    if for_name == REQUIRE_VARIABLE_TAG {
+      // const { a } = lib;
+      let names = parser
+        .destructuring_assignment_properties_for(&ident.span())
+        .map(|properties| {
+          properties
+            .into_iter()
+            .map(|property| vec![Atom::from(property)])
+            .collect::<Vec<_>>()
+        });
+      match names {
+        Some(names) if !names.is_empty() => {
+          for names in names {
+            add_require_variable_reference(parser, names);
+          }
+        }
+        _ => add_require_variable_reference(parser, vec![]),
+      }
+      return Some(true);
+    }
     None
   }
 }
@@ -1,8 +1,12 @@
 use itertools::Itertools;
-use rspack_core::{BoxDependency, ConstDependency, DependencyRange, DependencyType, SpanExt};
+use rspack_core::{
+  BoxDependency, ConstDependency, DependencyRange, DependencyType, EvaluatedInlinableValue,
+  ImportPhase, SpanExt,
+};
 use swc_core::atoms::Atom;
 use swc_core::common::comments::CommentKind;
-use swc_core::common::Spanned;
+use swc_core::common::{Mark, Spanned, SyntaxContext};
+use swc_core::ecma::ast::{Pat, Program, VarDecl, VarDeclKind, VarDeclarator};
 
 use super::esm_import_dependency_parser_plugin::{ESMSpecifierData, ESM_SPECIFIER_TAG};
 use super::{
@@ -14,15 +18,77 @@ use crate::dependency::{
   ESMExportImportedSpecifierDependency, ESMExportSpecifierDependency,
   ESMImportSideEffectDependency,
 };
+use crate::utils::inlinable_exports::{collect_inlinable_declarations, render_evaluated_primitive};
 use crate::utils::object_properties::get_attributes;
 use crate::visitors::{
   ExportDefaultDeclaration, ExportDefaultExpression, ExportImport, ExportLocal, JavascriptParser,
   TagInfoData,
 };
 
-pub struct ESMExportDependencyParserPlugin;
+pub struct ESMExportDependencyParserPlugin {
+  unresolved_context: SyntaxContext,
+  inline_exports: bool,
+}
+
+impl ESMExportDependencyParserPlugin {
+  pub fn new(unresolved_mark: Mark, inline_exports: bool) -> Self {
+    Self {
+      unresolved_context: SyntaxContext::empty().apply_mark(unresolved_mark),
+      inline_exports,
+    }
+  }
+}
 
 impl JavascriptParserPlugin for ESMExportDependencyParserPlugin {
+  fn program(&self, parser: &mut JavascriptParser, ast: &Program) -> Option<bool> {
+    if self.inline_exports
+      && let Program::Module(module) = ast
+    {
+      parser.inlinable_declarations =
+        collect_inlinable_declarations(module, self.unresolved_context);
+    }
+    None
+  }
+
+  fn declarator(
+    &self,
+    parser: &mut JavascriptParser,
+    declarator: &VarDeclarator,
+    declaration: &VarDecl,
+  ) -> Option<bool> {
+    // constants whose value is only known to the parser, e.g. `process.env.NODE_ENV !== "production"`
+    // with `DefinePlugin`, the exports referencing them are updated in `finish`
+    if self.inline_exports
+      && matches!(declaration.kind, VarDeclKind::Const)
+      && let Pat::Ident(ident) = &declarator.name
+      && let Some(init) = &declarator.init
+      && !parser.inlinable_declarations.contains_key(&ident.id.sym)
+      && parser.is_module_scope()
+      && let Some(code) = render_evaluated_primitive(&parser.evaluate_expression(init))
+    {
+      parser.inlinable_declarations.insert(
+        ident.id.sym.clone(),
+        EvaluatedInlinableValue::Primitive(code),
+      );
+    }
+    None
+  }
+
+  fn finish(&self, parser: &mut JavascriptParser) -> Option<bool> {
+    if !self.inline_exports {
+      return None;
+    }
+    for dep in parser.dependencies.iter_mut() {
+      if let Some(dep) = dep.downcast_mut::<ESMExportSpecifierDependency>()
+        && dep.inlinable().is_none()
+        && let Some(inlinable) = parser.inlinable_declarations.get(&dep.value)
+      {
+        dep.set_inlinable(inlinable.clone());
+      }
+    }
+    None
+  }
+
   fn export(&self, parser: &mut JavascriptParser, statement: ExportLocal) -> Option<bool> {
     let dep = ESMExportHeaderDependency::new(
       statement.span().into(),
@@ -51,6 +117,7 @@ impl JavascriptParserPlugin for ESMExportDependencyParserPlugin {
       DependencyType::EsmExport,
       matches!(statement, ExportImport::All(_)),
       statement.get_with_obj().map(get_attributes),
+      ImportPhase::Evaluation,
       Some(parser.source_map.clone()),
     );
     parser.dependencies.push(Box::new(side_effect_dep));
@@ -94,6 +161,7 @@ impl JavascriptParserPlugin for ESMExportDependencyParserPlugin {
         export_name.clone(),
         local_id.clone(),
         statement.span().into(),
+        parser.inlinable_declarations.get(local_id).cloned(),
         Some(parser.source_map.clone()),
       ))
     };
@@ -1,15 +1,22 @@
-use rspack_core::{ConstDependency, Dependency, DependencyType, ImportAttributes, SpanExt};
+use rspack_core::{
+  ConstDependency, Dependency, DependencyType, ImportAttributes, ImportPhase, SpanExt,
+};
 use swc_core::atoms::Atom;
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{
   AssignExpr, AssignOp, AssignTarget, AssignTargetPat, Callee, MemberExpr, OptChainBase,
 };
-use swc_core::ecma::ast::{Expr, Ident, ImportDecl};
+use swc_core::ecma::ast::{Expr, Ident, ImportDecl, ImportPhase as AstImportPhase};
 
 use super::{InnerGraphPlugin, JavascriptParserPlugin};
-use crate::dependency::{ESMImportSideEffectDependency, ESMImportSpecifierDependency};
+use crate::dependency::{
+  ESMImportSideEffectDependency, ESMImportSourcePhaseDependency, ESMImportSpecifierDependency,
+};
 use crate::utils::object_properties::get_attributes;
-use crate::visitors::{collect_destructuring_assignment_properties, JavascriptParser, TagInfoData};
+use crate::visitors::{
+  collect_destructuring_assignment_properties, create_traceable_error, JavascriptParser,
+  TagInfoData,
+};
 
 fn get_non_optional_part<'a>(members: &'a [Atom], members_optionals: &[bool]) -> &'a [Atom] {
   let mut i = 0;
@@ -47,6 +54,77 @@ fn get_non_optional_member_chain_from_member(member: &MemberExpr, mut count: i32
   get_non_optional_member_chain_from_expr(&member.obj, count)
 }
 
+fn get_import_phase(import_decl: &ImportDecl) -> ImportPhase {
+  match import_decl.phase {
+    AstImportPhase::Evaluation => ImportPhase::Evaluation,
+    AstImportPhase::Source => ImportPhase::Source,
+    AstImportPhase::Defer => ImportPhase::Defer,
+  }
+}
+
+fn push_import_phase_error(parser: &mut JavascriptParser, message: String, span: Span) {
+  parser.errors.push(Box::new(create_traceable_error(
+    "JavaScript parsing error".into(),
+    message,
+    parser.source_file,
+    span.into(),
+  )));
+}
+
+/// `import source x from "./module.wasm"`, the statement is replaced by the compiled module
+fn handle_source_phase_import(
+  parser: &mut JavascriptParser,
+  import_decl: &ImportDecl,
+  source: &str,
+) -> Option<bool> {
+  let [swc_core::ecma::ast::ImportSpecifier::Default(specifier)] =
+    import_decl.specifiers.as_slice()
+  else {
+    push_import_phase_error(
+      parser,
+      "Source phase imports must have exactly one default binding, e.g. `import source x from \"./module.wasm\"`".into(),
+      import_decl.span,
+    );
+    return Some(true);
+  };
+  if !parser.compiler_options.experiments.top_level_await {
+    push_import_phase_error(
+      parser,
+      "Source phase imports require top-level-await (set experiments.topLevelAwait: true to enable it)".into(),
+      import_decl.span,
+    );
+    return Some(true);
+  }
+  // the module is compiled asynchronously
+  parser.build_meta.has_top_level_await = true;
+  parser.build_info.module_concatenation_bailout = Some("import source".into());
+  let attributes = import_decl.with.as_ref().map(|obj| get_attributes(obj));
+  parser
+    .dependencies
+    .push(Box::new(ESMImportSourcePhaseDependency::new(
+      source.into(),
+      specifier.local.sym.clone(),
+      import_decl.span.into(),
+      import_decl.src.span.into(),
+      attributes,
+      Some(parser.source_map.clone()),
+    )));
+  parser
+    .presentational_dependencies
+    .push(Box::new(ConstDependency::new(
+      import_decl.span.real_lo(),
+      import_decl.span.real_hi(),
+      if parser.is_asi_position(import_decl.span_lo()) {
+        ";".into()
+      } else {
+        "".into()
+      },
+      None,
+    )));
+  parser.unset_asi_position(import_decl.span_hi());
+  Some(true)
+}
+
 pub struct ESMImportDependencyParserPlugin;
 
 pub const ESM_SPECIFIER_TAG: &str = "_identifier__esm_specifier_tag__";
@@ -58,6 +136,7 @@ pub struct ESMSpecifierData {
   pub ids: Vec<Atom>,
   pub source_order: i32,
   pub attributes: Option<ImportAttributes>,
+  pub phase: ImportPhase,
 }
 
 impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
@@ -67,6 +146,25 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
     import_decl: &ImportDecl,
     source: &str,
   ) -> Option<bool> {
+    let phase = get_import_phase(import_decl);
+    if phase.is_source() {
+      return handle_source_phase_import(parser, import_decl, source);
+    }
+    if phase.is_defer() {
+      if !matches!(
+        import_decl.specifiers.as_slice(),
+        [swc_core::ecma::ast::ImportSpecifier::Namespace(_)]
+      ) {
+        push_import_phase_error(
+          parser,
+          "Deferred imports only support a namespace binding, e.g. `import defer * as ns from \"./module\"`".into(),
+          import_decl.span,
+        );
+      }
+      // the imported module is evaluated on the first access of the namespace,
+      // which can't be expressed in a concatenated module
+      parser.build_info.module_concatenation_bailout = Some("import defer".into());
+    }
     parser.last_esm_import_order += 1;
     let attributes = import_decl.with.as_ref().map(|obj| get_attributes(obj));
     let dependency = ESMImportSideEffectDependency::new(
@@ -77,6 +175,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       DependencyType::EsmImport,
       false,
       attributes,
+      phase,
       Some(parser.source_map.clone()),
     );
     parser.dependencies.push(Box::new(dependency));
@@ -105,6 +204,11 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
     id: Option<&Atom>,
     name: &Atom,
   ) -> Option<bool> {
+    let phase = get_import_phase(statement);
+    // the binding of a source phase import is declared by ESMImportSourcePhaseDependency
+    if phase.is_source() {
+      return Some(true);
+    }
     parser.tag_variable::<ESMSpecifierData>(
       name.to_string(),
       ESM_SPECIFIER_TAG,
@@ -114,6 +218,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
         ids: id.map(|id| vec![id.clone()]).unwrap_or_default(),
         source_order: parser.last_esm_import_order,
         attributes: statement.with.as_ref().map(|obj| get_attributes(obj)),
+        phase,
       }),
     );
     Some(true)
@@ -145,6 +250,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       ESMImportSpecifierDependency::create_export_presence_mode(parser.javascript_options),
       parser.properties_in_destructuring.remove(&ident.sym),
       settings.attributes,
+      settings.phase,
       Some(parser.source_map.clone()),
     );
     let dep_id = *dep.id();
@@ -212,6 +318,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       ESMImportSpecifierDependency::create_export_presence_mode(parser.javascript_options),
       None,
       settings.attributes,
+      settings.phase,
       Some(parser.source_map.clone()),
     );
     let dep_id = *dep.id();
@@ -276,6 +383,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       ESMImportSpecifierDependency::create_export_presence_mode(parser.javascript_options),
       None,
       settings.attributes,
+      settings.phase,
       Some(parser.source_map.clone()),
     );
     let dep_id = *dep.id();
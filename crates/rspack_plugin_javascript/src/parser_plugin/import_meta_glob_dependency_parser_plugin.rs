@@ -0,0 +1,406 @@
+use rspack_core::{
+  ContextMode, ContextNameSpaceObject, ContextOptions, DependencyCategory, SpanExt,
+};
+use rspack_paths::Utf8Path;
+use rspack_regex::RspackRegex;
+use sugar_path::SugarPath;
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Expr, Lit, ObjectLit, PropName};
+
+use super::JavascriptParserPlugin;
+use crate::dependency::ImportMetaGlobDependency;
+use crate::utils::eval::{self, BasicEvaluatedExpression};
+use crate::utils::object_properties::{
+  get_bool_by_obj_prop, get_literal_str_by_obj_prop, get_value_by_obj_prop,
+};
+use crate::visitors::{create_traceable_error, expr_name, quote_meta, JavascriptParser};
+
+/// A glob pattern split into the static directory and the segments to be matched
+#[derive(Debug, PartialEq, Eq)]
+struct GlobPattern {
+  negated: bool,
+  /// Pattern starts with `/`, which is resolved from the root of the project
+  from_root: bool,
+  base: Vec<String>,
+  rest: Vec<String>,
+}
+
+fn is_magic_segment(segment: &str) -> bool {
+  segment.contains(['*', '?', '{', '['])
+}
+
+fn parse_glob_pattern(pattern: &str) -> Option<GlobPattern> {
+  let (negated, pattern) = match pattern.strip_prefix('!') {
+    Some(pattern) => (true, pattern),
+    None => (false, pattern),
+  };
+  let from_root = pattern.starts_with('/');
+  let segments = pattern
+    .split('/')
+    .filter(|segment| !segment.is_empty() && *segment != ".")
+    .collect::<Vec<_>>();
+  // a pattern without magic characters matches the file itself
+  let static_len = segments
+    .iter()
+    .position(|segment| is_magic_segment(segment))
+    .unwrap_or(segments.len().saturating_sub(1));
+
+  let mut base: Vec<String> = vec![];
+  for segment in &segments[..static_len] {
+    if *segment == ".." && base.last().is_some_and(|last| last != "..") {
+      base.pop();
+    } else {
+      base.push(segment.to_string());
+    }
+  }
+  let rest = segments[static_len..]
+    .iter()
+    .map(|segment| segment.to_string())
+    .collect::<Vec<_>>();
+  if rest.is_empty()
+    || rest.iter().any(|segment| segment == "..")
+    || (from_root && base.first().is_some_and(|segment| segment == ".."))
+  {
+    return None;
+  }
+  Some(GlobPattern {
+    negated,
+    from_root,
+    base,
+    rest,
+  })
+}
+
+/// Convert glob segments to a regexp source, both `/` and `\` are accepted as the separator
+fn glob_to_reg_exp(segments: &[String]) -> String {
+  let mut res = String::new();
+  for (index, segment) in segments.iter().enumerate() {
+    let is_last = index == segments.len() - 1;
+    if segment == "**" {
+      res.push_str(if is_last { ".*" } else { r"(?:[^\\/]*[\\/])*" });
+      continue;
+    }
+    let mut braces = 0;
+    let mut chars = segment.chars().peekable();
+    while let Some(c) = chars.next() {
+      match c {
+        '*' => res.push_str(r"[^\\/]*"),
+        '?' => res.push_str(r"[^\\/]"),
+        '{' => {
+          braces += 1;
+          res.push_str("(?:");
+        }
+        '}' if braces > 0 => {
+          braces -= 1;
+          res.push(')');
+        }
+        ',' if braces > 0 => res.push('|'),
+        '[' => {
+          res.push('[');
+          if chars.next_if_eq(&'!').is_some() {
+            res.push('^');
+          }
+          for c in chars.by_ref() {
+            if c == ']' {
+              break;
+            }
+            if c == '\\' || c == '[' {
+              res.push('\\');
+            }
+            res.push(c);
+          }
+          res.push(']');
+        }
+        c => res.push_str(&quote_meta(c.encode_utf8(&mut [0; 4]))),
+      }
+    }
+    if !is_last {
+      res.push_str(r"[\\/]");
+    }
+  }
+  res
+}
+
+fn get_literal_string(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() && tpl.quasis.len() == 1 => {
+      tpl.quasis.first().map(|el| el.raw.to_string())
+    }
+    _ => None,
+  }
+}
+
+fn get_query(obj: &ObjectLit) -> Option<String> {
+  let query = match get_value_by_obj_prop(obj, "query") {
+    None => return Some(String::new()),
+    Some(Expr::Lit(Lit::Str(str))) => str.value.to_string(),
+    Some(Expr::Object(query)) => {
+      let mut params = vec![];
+      for prop in &query.props {
+        let kv = prop.as_prop().and_then(|p| p.as_key_value())?;
+        let key = match &kv.key {
+          PropName::Ident(ident) => ident.sym.to_string(),
+          PropName::Str(str) => str.value.to_string(),
+          _ => return None,
+        };
+        let value = match kv.value.as_lit()? {
+          Lit::Str(str) => str.value.to_string(),
+          Lit::Num(num) => num.value.to_string(),
+          Lit::Bool(bool) => bool.value.to_string(),
+          _ => return None,
+        };
+        params.push(format!("{key}={value}"));
+      }
+      params.join("&")
+    }
+    Some(_) => return None,
+  };
+  if query.is_empty() || query.starts_with('?') {
+    Some(query)
+  } else {
+    Some(format!("?{query}"))
+  }
+}
+
+fn create_import_meta_glob_dependency(
+  node: &CallExpr,
+  parser: &mut JavascriptParser,
+) -> Option<ImportMetaGlobDependency> {
+  let patterns = node.args.first().filter(|arg| arg.spread.is_none())?;
+  let patterns = match &*patterns.expr {
+    Expr::Array(array) => array
+      .elems
+      .iter()
+      .map(|el| {
+        el.as_ref()
+          .filter(|el| el.spread.is_none())
+          .and_then(|el| get_literal_string(&el.expr))
+      })
+      .collect::<Option<Vec<_>>>()?,
+    expr => vec![get_literal_string(expr)?],
+  };
+  let patterns = patterns
+    .iter()
+    .map(|pattern| parse_glob_pattern(pattern))
+    .collect::<Option<Vec<_>>>()?;
+
+  let (eager, import, query) = match node.args.get(1) {
+    Some(arg) => {
+      let obj = arg.expr.as_object()?;
+      let eager = get_bool_by_obj_prop(obj, "eager").is_some_and(|bool| bool.value);
+      let import = get_literal_str_by_obj_prop(obj, "import").map(|str| str.value.clone());
+      (eager, import, get_query(obj)?)
+    }
+    None => (false, None, String::new()),
+  };
+
+  let (negated, positive): (Vec<_>, Vec<_>) = patterns.iter().partition(|pattern| pattern.negated);
+  let first = positive.first()?;
+  let from_root = first.from_root;
+  if positive
+    .iter()
+    .any(|pattern| pattern.from_root != from_root)
+  {
+    return None;
+  }
+  // the context module is created for the common directory of all patterns
+  let mut base = first.base.clone();
+  for pattern in &positive[1..] {
+    let len = base
+      .iter()
+      .zip(&pattern.base)
+      .take_while(|(a, b)| a == b)
+      .count();
+    base.truncate(len);
+  }
+  if positive
+    .iter()
+    .any(|pattern| pattern.base[base.len()..].iter().any(|s| s == ".."))
+  {
+    return None;
+  }
+  let recursive = positive.iter().any(|pattern| {
+    pattern.base.len() > base.len()
+      || pattern.rest.len() > 1
+      || pattern.rest.iter().any(|segment| segment.contains("**"))
+  });
+  let reg_exp = positive
+    .iter()
+    .map(|pattern| glob_to_reg_exp(&[&pattern.base[base.len()..], &pattern.rest[..]].concat()))
+    .collect::<Vec<_>>()
+    .join("|");
+  let reg_exp = RspackRegex::new(&format!(r"^\./(?:{reg_exp})$")).ok()?;
+
+  let root = parser.compiler_options.context.as_path();
+  let importer_dir = parser
+    .resource_data
+    .resource_path
+    .as_deref()
+    .and_then(Utf8Path::parent);
+  // negated patterns are matched against absolute paths of files
+  let exclude = negated
+    .iter()
+    .map(|pattern| {
+      let dir = if pattern.from_root {
+        root
+      } else {
+        importer_dir?
+      };
+      let mut dir = dir.as_std_path().to_path_buf();
+      dir.extend(&pattern.base);
+      Some(format!(
+        r"{}[\\/]{}",
+        quote_meta(&dir.normalize().to_string_lossy()),
+        glob_to_reg_exp(&pattern.rest)
+      ))
+    })
+    .collect::<Option<Vec<_>>>()?;
+  let exclude = if exclude.is_empty() {
+    None
+  } else {
+    Some(RspackRegex::new(&format!("^(?:{})$", exclude.join("|"))).ok()?)
+  };
+
+  let joined = base.join("/");
+  let (context, key_prefix) = if from_root {
+    let context = if joined.is_empty() {
+      root.to_string()
+    } else {
+      root.join(&joined).to_string()
+    };
+    (context, format!("/{joined}/").replace("//", "/"))
+  } else if joined.is_empty() {
+    (".".to_string(), "./".to_string())
+  } else if base[0] == ".." {
+    (joined.clone(), format!("{joined}/"))
+  } else {
+    (format!("./{joined}"), format!("./{joined}/"))
+  };
+
+  let context_options = ContextOptions {
+    mode: if eager {
+      ContextMode::Sync
+    } else {
+      ContextMode::Lazy
+    },
+    recursive,
+    reg_exp: Some(reg_exp),
+    include: None,
+    exclude,
+    category: DependencyCategory::Esm,
+    request: format!("{context}{query}"),
+    context,
+    namespace_object: ContextNameSpaceObject::Strict,
+    group_options: None,
+    replaces: Vec::new(),
+    start: node.span().real_lo(),
+    end: node.span().real_hi(),
+    referenced_exports: import.clone().map(|import| vec![import]),
+    attributes: None,
+  };
+  Some(ImportMetaGlobDependency::new(
+    context_options,
+    node.span.into(),
+    parser.in_try,
+    key_prefix,
+    import,
+  ))
+}
+
+pub struct ImportMetaGlobDependencyParserPlugin;
+
+impl JavascriptParserPlugin for ImportMetaGlobDependencyParserPlugin {
+  fn evaluate_identifier(
+    &self,
+    _parser: &mut JavascriptParser,
+    ident: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    if ident == expr_name::IMPORT_META_GLOB {
+      Some(eval::evaluate_to_identifier(
+        expr_name::IMPORT_META_GLOB.to_string(),
+        expr_name::IMPORT_META.to_string(),
+        Some(true),
+        start,
+        end,
+      ))
+    } else {
+      None
+    }
+  }
+
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != expr_name::IMPORT_META_GLOB {
+      return None;
+    }
+    if expr.args.is_empty() || expr.args.len() > 2 {
+      return None;
+    }
+    if let Some(dep) = create_import_meta_glob_dependency(expr, parser) {
+      parser.dependencies.push(Box::new(dep));
+    } else {
+      parser.warning_diagnostics.push(Box::new(
+        create_traceable_error(
+          "Unsupported import.meta.glob".into(),
+          "import.meta.glob() only accepts literal glob patterns sharing a common base directory, and an object literal of options".into(),
+          parser.source_file,
+          expr.span.into(),
+        )
+        .with_severity(rspack_error::RspackSeverity::Warn),
+      ));
+    }
+    Some(true)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{glob_to_reg_exp, parse_glob_pattern, GlobPattern};
+
+  fn to_strings(segments: &[&str]) -> Vec<String> {
+    segments.iter().map(|s| s.to_string()).collect()
+  }
+
+  #[test]
+  fn should_parse_glob_pattern() {
+    assert_eq!(
+      parse_glob_pattern("./dir/../modules/**/*.js"),
+      Some(GlobPattern {
+        negated: false,
+        from_root: false,
+        base: to_strings(&["modules"]),
+        rest: to_strings(&["**", "*.js"]),
+      })
+    );
+    assert_eq!(
+      parse_glob_pattern("!/src/a.js"),
+      Some(GlobPattern {
+        negated: true,
+        from_root: true,
+        base: to_strings(&["src"]),
+        rest: to_strings(&["a.js"]),
+      })
+    );
+    assert_eq!(parse_glob_pattern("./*/../a.js"), None);
+  }
+
+  #[test]
+  fn should_convert_glob_to_reg_exp() {
+    let reg = |glob: &str| {
+      let pattern = parse_glob_pattern(glob).expect("should parse glob");
+      regex::Regex::new(&format!(r"^\./{}$", glob_to_reg_exp(&pattern.rest)))
+        .expect("should be valid regexp")
+    };
+    assert!(reg("*.js").is_match("./a.js"));
+    assert!(!reg("*.js").is_match("./a/b.js"));
+    assert!(reg("**/*.js").is_match("./a.js"));
+    assert!(reg("**/*.js").is_match("./a/b/c.js"));
+    assert!(reg("*.{js,ts}").is_match("./a.ts"));
+    assert!(!reg("*.{js,ts}").is_match("./a.css"));
+    assert!(reg("[!b].js").is_match("./a.js"));
+    assert!(!reg("[!b].js").is_match("./b.js"));
+    assert!(reg("a?.js").is_match("./ab.js"));
+  }
+}
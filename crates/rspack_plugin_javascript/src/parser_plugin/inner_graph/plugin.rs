@@ -1,4 +1,4 @@
-use rspack_core::{Dependency, SpanExt, UsedByExports};
+use rspack_core::{Dependency, PureCallee, PureCondition, SpanExt, UsedByExports};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::{
   atoms::Atom,
@@ -11,8 +11,9 @@ use crate::{
   dependency::PureExpressionDependency,
   is_pure_class, is_pure_class_member, is_pure_expression, is_pure_function,
   parser_plugin::{JavascriptParserPlugin, DEFAULT_STAR_JS_WORD},
+  pure_condition_marker,
   visitors::{JavascriptParser, Statement, TagInfoData, TopLevelScope},
-  ClassExt,
+  ClassExt, PureCalleeResolver, PurityChecker,
 };
 
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
@@ -58,6 +59,7 @@ pub enum InnerGraphMapUsage {
 
 pub struct InnerGraphPlugin {
   unresolved_context: SyntaxContext,
+  infer_pure_functions: bool,
 }
 
 pub static TOP_LEVEL_SYMBOL: &str = "inner graph top level symbol";
@@ -74,12 +76,34 @@ impl TopLevelSymbol {
 }
 
 impl InnerGraphPlugin {
-  pub fn new(unresolved_mark: Mark) -> Self {
+  pub fn new(unresolved_mark: Mark, infer_pure_functions: bool) -> Self {
     Self {
       unresolved_context: SyntaxContext::empty().apply_mark(unresolved_mark),
+      infer_pure_functions,
     }
   }
 
+  /// Returns the callees when the expression is only free of side effects if the
+  /// top-level functions and imports it calls are pure.
+  fn pure_callees(&self, parser: &JavascriptParser, expr: &Expr) -> Option<Vec<PureCallee>> {
+    let resolver = parser.inner_graph.pure_callee_resolver.as_ref()?;
+    let mut checker = PurityChecker::new(self.unresolved_context, parser.comments, resolver);
+    (checker.expr(expr) && !checker.callees.is_empty()).then_some(checker.callees)
+  }
+
+  /// The code of the symbol can only be dropped when all callees are pure, the marker flows
+  /// into the usage of everything the symbol references and is checked by `InferPureFunctionsPlugin`.
+  fn add_pure_condition(parser: &mut JavascriptParser, name: &Atom, callees: Vec<PureCallee>) {
+    let marker = pure_condition_marker(name);
+    parser
+      .inner_graph
+      .add_usage(name.clone(), InnerGraphMapUsage::Value(marker.clone()));
+    parser
+      .inner_graph
+      .pure_conditions
+      .push(PureCondition { marker, callees });
+  }
+
   pub fn for_each_expression(parser: &mut JavascriptParser, for_name: &str) {
     if !parser.inner_graph.is_enabled() || for_name != TOP_LEVEL_SYMBOL {
       return;
@@ -312,9 +336,12 @@ impl JavascriptParserPlugin for InnerGraphPlugin {
   fn program(
     &self,
     parser: &mut crate::visitors::JavascriptParser,
-    _ast: &swc_core::ecma::ast::Program,
+    ast: &swc_core::ecma::ast::Program,
   ) -> Option<bool> {
     parser.inner_graph.enable();
+    if self.infer_pure_functions {
+      parser.inner_graph.pure_callee_resolver = Some(PureCalleeResolver::new(ast));
+    }
 
     None
   }
@@ -326,6 +353,15 @@ impl JavascriptParserPlugin for InnerGraphPlugin {
 
     Self::infer_dependency_usage(parser);
 
+    if self.infer_pure_functions {
+      let conditions = std::mem::take(&mut parser.inner_graph.pure_conditions);
+      parser
+        .build_info
+        .pure_functions
+        .get_or_insert_default()
+        .conditions = conditions;
+    }
+
     None
   }
 
@@ -436,6 +472,20 @@ impl JavascriptParserPlugin for InnerGraphPlugin {
           .statement_pure_part
           .insert(export_span, expr.span());
       }
+    } else if let ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) = export_decl
+      && let Some(callees) = self.pure_callees(parser, expr)
+    {
+      let variable = Self::tag_top_level_symbol(parser, &DEFAULT_STAR_JS_WORD);
+      let export_span = export_decl.span();
+      parser
+        .inner_graph
+        .statement_with_top_level_symbol
+        .insert(export_span, variable);
+      parser
+        .inner_graph
+        .statement_pure_part
+        .insert(export_span, expr.span());
+      Self::add_pure_condition(parser, &DEFAULT_STAR_JS_WORD, callees);
     }
 
     None
@@ -480,6 +530,14 @@ impl JavascriptParserPlugin for InnerGraphPlugin {
         if !unwrapped_init.is_fn_expr() && !unwrapped_init.is_arrow() && !unwrapped_init.is_lit() {
           parser.inner_graph.pure_declarators.insert(decl.span());
         }
+      } else if let Some(callees) = self.pure_callees(parser, init) {
+        let v = Self::tag_top_level_symbol(parser, name);
+        parser
+          .inner_graph
+          .decl_with_top_level_symbol
+          .insert(decl.span(), v);
+        parser.inner_graph.pure_declarators.insert(decl.span());
+        Self::add_pure_condition(parser, name, callees);
       }
     }
 
@@ -1,6 +1,6 @@
 use std::collections::hash_map::Entry;
 
-use rspack_core::UsedByExports;
+use rspack_core::{PureCondition, UsedByExports};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::{common::Span, ecma::atoms::Atom};
 
@@ -10,6 +10,7 @@ use crate::{
     InnerGraphMapSetValue, InnerGraphMapUsage, InnerGraphMapValue,
   },
   visitors::JavascriptParser,
+  PureCalleeResolver,
 };
 
 pub type UsageCallback = Box<dyn Fn(&mut JavascriptParser, Option<UsedByExports>)>;
@@ -26,6 +27,9 @@ pub struct InnerGraphState {
   pub(crate) class_with_top_level_symbol: HashMap<Span, TopLevelSymbol>,
   pub(crate) decl_with_top_level_symbol: HashMap<Span, TopLevelSymbol>,
   pub(crate) pure_declarators: HashSet<Span>,
+  /// Only available when `optimization.inferPureFunctions` is enabled.
+  pub(crate) pure_callee_resolver: Option<PureCalleeResolver>,
+  pub(crate) pure_conditions: Vec<PureCondition>,
 }
 
 impl InnerGraphState {
@@ -12,6 +12,7 @@ mod esm_import_dependency_parser_plugin;
 mod esm_top_level_this_plugin;
 mod exports_info_api_plugin;
 mod import_meta_context_dependency_parser_plugin;
+mod import_meta_glob_dependency_parser_plugin;
 mod import_meta_plugin;
 mod import_parser_plugin;
 mod initialize_evaluating;
@@ -45,10 +46,13 @@ pub(crate) use self::compatibility_plugin::CompatibilityPlugin;
 pub(crate) use self::drive::JavaScriptParserPluginDrive;
 pub(crate) use self::esm_detection_parser_plugin::ESMDetectionParserPlugin;
 pub(crate) use self::esm_export_dependency_parser_plugin::ESMExportDependencyParserPlugin;
-pub(crate) use self::esm_import_dependency_parser_plugin::ESMImportDependencyParserPlugin;
+pub(crate) use self::esm_import_dependency_parser_plugin::{
+  ESMImportDependencyParserPlugin, ESMSpecifierData, ESM_SPECIFIER_TAG,
+};
 pub(crate) use self::esm_top_level_this_plugin::ESMTopLevelThisParserPlugin;
 pub(crate) use self::exports_info_api_plugin::ExportsInfoApiPlugin;
 pub(crate) use self::import_meta_context_dependency_parser_plugin::ImportMetaContextDependencyParserPlugin;
+pub(crate) use self::import_meta_glob_dependency_parser_plugin::ImportMetaGlobDependencyParserPlugin;
 pub(crate) use self::import_meta_plugin::{ImportMetaDisabledPlugin, ImportMetaPlugin};
 pub(crate) use self::import_parser_plugin::ImportParserPlugin;
 pub(crate) use self::initialize_evaluating::InitializeEvaluating;
@@ -216,41 +216,52 @@ impl<'a> FlagDependencyExportsState<'a> {
     dep_id: DependencyId,
   ) {
     for export_name_or_spec in exports {
-      let (name, can_mangle, terminal_binding, exports, from, from_export, priority, hidden) =
-        match export_name_or_spec {
-          ExportNameOrSpec::String(name) => (
-            name.clone(),
-            global_export_info.can_mangle,
-            global_export_info.terminal_binding,
-            None::<&Vec<ExportNameOrSpec>>,
-            global_export_info.from.cloned(),
-            None::<&rspack_core::Nullable<Vec<Atom>>>,
-            global_export_info.priority,
-            false,
-          ),
-          ExportNameOrSpec::ExportSpec(spec) => (
-            spec.name.clone(),
-            match spec.can_mangle {
-              Some(v) => Some(v),
-              None => global_export_info.can_mangle,
-            },
-            spec
-              .terminal_binding
-              .unwrap_or(global_export_info.terminal_binding),
-            spec.exports.as_ref(),
-            if spec.from.is_some() {
-              spec.from.clone()
-            } else {
-              global_export_info.from.cloned()
-            },
-            spec.export.as_ref(),
-            match spec.priority {
-              Some(v) => Some(v),
-              None => global_export_info.priority,
-            },
-            spec.hidden.unwrap_or(false),
-          ),
-        };
+      let (
+        name,
+        can_mangle,
+        terminal_binding,
+        inlinable,
+        exports,
+        from,
+        from_export,
+        priority,
+        hidden,
+      ) = match export_name_or_spec {
+        ExportNameOrSpec::String(name) => (
+          name.clone(),
+          global_export_info.can_mangle,
+          global_export_info.terminal_binding,
+          None,
+          None::<&Vec<ExportNameOrSpec>>,
+          global_export_info.from.cloned(),
+          None::<&rspack_core::Nullable<Vec<Atom>>>,
+          global_export_info.priority,
+          false,
+        ),
+        ExportNameOrSpec::ExportSpec(spec) => (
+          spec.name.clone(),
+          match spec.can_mangle {
+            Some(v) => Some(v),
+            None => global_export_info.can_mangle,
+          },
+          spec
+            .terminal_binding
+            .unwrap_or(global_export_info.terminal_binding),
+          spec.inlinable.as_ref(),
+          spec.exports.as_ref(),
+          if spec.from.is_some() {
+            spec.from.clone()
+          } else {
+            global_export_info.from.cloned()
+          },
+          spec.export.as_ref(),
+          match spec.priority {
+            Some(v) => Some(v),
+            None => global_export_info.priority,
+          },
+          spec.hidden.unwrap_or(false),
+        ),
+      };
       let export_info = exports_info.get_export_info(self.mg, &name);
       if let Some(provided) = export_info.provided(self.mg)
         && matches!(
@@ -272,6 +283,13 @@ impl<'a> FlagDependencyExportsState<'a> {
         self.changed = true;
       }
 
+      if let Some(inlinable) = inlinable
+        && export_info.inlinable(self.mg) != Some(inlinable)
+      {
+        export_info.set_inlinable(self.mg, Some(inlinable.clone()));
+        self.changed = true;
+      }
+
       if let Some(exports) = exports {
         let nested_exports_info = export_info.create_nested_exports_info(self.mg);
         self.merge_exports(
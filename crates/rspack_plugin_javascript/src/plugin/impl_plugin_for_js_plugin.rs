@@ -33,6 +33,10 @@ async fn compilation(
     DependencyType::EsmImportSpecifier,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::EsmImportSource,
+    params.normal_module_factory.clone(),
+  );
   compilation.set_dependency_factory(
     DependencyType::EsmExport,
     params.normal_module_factory.clone(),
@@ -94,6 +98,10 @@ async fn compilation(
     DependencyType::ImportMetaContext,
     params.context_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::ImportMetaGlob,
+    params.context_module_factory.clone(),
+  );
   // ImportPlugin
   compilation.set_dependency_factory(
     DependencyType::DynamicImport,
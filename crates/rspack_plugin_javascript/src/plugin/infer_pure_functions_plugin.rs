@@ -0,0 +1,694 @@
+use std::rc::Rc;
+
+use rspack_collections::IdentifierMap;
+use rspack_core::{
+  ApplyContext, Compilation, CompilationOptimizeDependencies, CompilerOptions, DependencyId,
+  DependencyType, Logger, ModuleGraph, ModuleIdentifier, Plugin, PluginContext, PureCallee,
+  PureFunction, UsedByExports,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::atoms::Atom;
+use swc_core::common::comments::Comments;
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::*;
+
+use super::side_effects_flag_plugin::{is_pure_class, is_pure_expression, is_pure_pat};
+use crate::dependency::PureCallsDependency;
+use crate::parser_plugin::DEFAULT_STAR_JS_WORD;
+use crate::utils::inlinable_exports::collect_reassigned_bindings;
+
+/// Marker added to the `UsedByExports` of dependencies derived from the top-level symbol `name`,
+/// when the symbol is only free of side effects if the functions it calls are pure.
+pub fn pure_condition_marker(name: &Atom) -> Atom {
+  format!("*pure condition {name}*").into()
+}
+
+/// Maps the callees of top-level code to the top-level functions, classes and imports of a module.
+#[derive(Debug, Default)]
+pub struct PureCalleeResolver {
+  top_level_ctxt: Option<SyntaxContext>,
+  locals: HashSet<Atom>,
+  /// The top-level functions and classes that are reassigned, which can't be resolved.
+  reassigned: HashSet<Atom>,
+  imports: HashMap<Atom, PureCallee>,
+}
+
+impl PureCalleeResolver {
+  pub fn new(program: &Program) -> Self {
+    let mut resolver = Self::default();
+    let Program::Module(module) = program else {
+      return resolver;
+    };
+    for item in &module.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+          if import_decl.type_only || !matches!(import_decl.phase, ImportPhase::Evaluation) {
+            continue;
+          }
+          for specifier in &import_decl.specifiers {
+            let (local, id) = match specifier {
+              ImportSpecifier::Named(named) if !named.is_type_only => {
+                let id = match &named.imported {
+                  Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+                  Some(ModuleExportName::Str(str)) => str.value.clone(),
+                  None => named.local.sym.clone(),
+                };
+                (&named.local, id)
+              }
+              ImportSpecifier::Default(default) => (&default.local, "default".into()),
+              _ => continue,
+            };
+            resolver.top_level_ctxt = Some(local.ctxt);
+            resolver.imports.insert(
+              local.sym.clone(),
+              PureCallee::Imported {
+                request: import_decl.src.value.clone(),
+                ids: vec![id],
+              },
+            );
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
+        | ModuleItem::Stmt(Stmt::Decl(decl)) => {
+          for ident in function_like_decl_idents(decl) {
+            resolver.add_local(ident);
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default_decl)) => {
+          match &export_default_decl.decl {
+            DefaultDecl::Fn(FnExpr {
+              ident: Some(ident), ..
+            })
+            | DefaultDecl::Class(ClassExpr {
+              ident: Some(ident), ..
+            }) => resolver.add_local(ident),
+            _ => {}
+          }
+        }
+        _ => {}
+      }
+    }
+    if let Some(top_level_ctxt) = resolver.top_level_ctxt {
+      for (sym, ctxt) in collect_reassigned_bindings(module) {
+        if ctxt == top_level_ctxt && resolver.locals.remove(&sym) {
+          resolver.reassigned.insert(sym);
+        }
+      }
+    }
+    resolver
+  }
+
+  fn add_local(&mut self, ident: &Ident) {
+    self.top_level_ctxt = Some(ident.ctxt);
+    self.locals.insert(ident.sym.clone());
+  }
+
+  /// Only identifiers bound at the top level can be resolved, bindings that shadow
+  /// a top-level function or import have a different syntax context.
+  pub fn resolve(&self, ident: &Ident) -> Option<PureCallee> {
+    if self.top_level_ctxt != Some(ident.ctxt) {
+      return None;
+    }
+    if self.locals.contains(&ident.sym) {
+      return Some(PureCallee::Local(ident.sym.clone()));
+    }
+    self.imports.get(&ident.sym).cloned()
+  }
+}
+
+fn function_like_decl_idents(decl: &Decl) -> Vec<&Ident> {
+  match decl {
+    Decl::Fn(fn_decl) => vec![&fn_decl.ident],
+    Decl::Class(class_decl) => vec![&class_decl.ident],
+    Decl::Var(var_decl) if var_decl.kind == VarDeclKind::Const => var_decl
+      .decls
+      .iter()
+      .filter_map(
+        |declarator| match (&declarator.name, declarator.init.as_deref()) {
+          (Pat::Ident(ident), Some(Expr::Fn(_) | Expr::Arrow(_) | Expr::Class(_))) => {
+            Some(&ident.id)
+          }
+          _ => None,
+        },
+      )
+      .collect(),
+    _ => vec![],
+  }
+}
+
+/// Checks code that is free of side effects as long as the functions it calls are pure,
+/// the calls to top-level functions and imports are collected into `callees`.
+pub struct PurityChecker<'a> {
+  unresolved_ctxt: SyntaxContext,
+  comments: Option<&'a dyn Comments>,
+  resolver: &'a PureCalleeResolver,
+  pub callees: Vec<PureCallee>,
+  in_constructor: bool,
+}
+
+impl<'a> PurityChecker<'a> {
+  pub fn new(
+    unresolved_ctxt: SyntaxContext,
+    comments: Option<&'a dyn Comments>,
+    resolver: &'a PureCalleeResolver,
+  ) -> Self {
+    Self {
+      unresolved_ctxt,
+      comments,
+      resolver,
+      callees: vec![],
+      in_constructor: false,
+    }
+  }
+
+  pub fn expr(&mut self, expr: &Expr) -> bool {
+    if is_pure_expression(expr, self.unresolved_ctxt, self.comments) {
+      return true;
+    }
+    match expr {
+      Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+      })
+      | Expr::New(NewExpr {
+        callee,
+        args: Some(args),
+        ..
+      }) => self.call(callee, args),
+      Expr::New(NewExpr {
+        callee, args: None, ..
+      }) => self.call(callee, &[]),
+      Expr::Call(CallExpr {
+        callee: Callee::Super(_),
+        args,
+        ..
+      }) if self.in_constructor => self.args(args),
+      Expr::Assign(AssignExpr {
+        op: AssignOp::Assign,
+        left: AssignTarget::Simple(SimpleAssignTarget::Member(member)),
+        right,
+        ..
+      }) if self.in_constructor && member.obj.is_this() => {
+        self.member_prop(&member.prop) && self.expr(right)
+      }
+      Expr::Paren(paren) => self.expr(&paren.expr),
+      Expr::Seq(seq) => seq.exprs.iter().all(|expr| self.expr(expr)),
+      Expr::Bin(bin) => self.expr(&bin.left) && self.expr(&bin.right),
+      Expr::Cond(cond) => self.expr(&cond.test) && self.expr(&cond.cons) && self.expr(&cond.alt),
+      Expr::Unary(unary) if unary.op != UnaryOp::Delete => self.expr(&unary.arg),
+      Expr::Tpl(tpl) => tpl.exprs.iter().all(|expr| self.expr(expr)),
+      Expr::Array(array) => array.elems.iter().all(|elem| match elem {
+        Some(ExprOrSpread { spread: None, expr }) => self.expr(expr),
+        Some(_) => false,
+        None => true,
+      }),
+      Expr::Object(object) => object.props.iter().all(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+          Prop::Shorthand(_) => true,
+          Prop::KeyValue(key_value) => {
+            self.prop_name(&key_value.key) && self.expr(&key_value.value)
+          }
+          Prop::Method(method) => self.prop_name(&method.key),
+          _ => false,
+        },
+        PropOrSpread::Spread(_) => false,
+      }),
+      _ => false,
+    }
+  }
+
+  fn call(&mut self, callee: &Expr, args: &[ExprOrSpread]) -> bool {
+    let Expr::Ident(ident) = callee else {
+      return false;
+    };
+    let Some(callee) = self.resolver.resolve(ident) else {
+      return false;
+    };
+    self.callees.push(callee);
+    self.args(args)
+  }
+
+  fn args(&mut self, args: &[ExprOrSpread]) -> bool {
+    args
+      .iter()
+      .all(|arg| arg.spread.is_none() && self.expr(&arg.expr))
+  }
+
+  fn prop_name(&mut self, key: &PropName) -> bool {
+    match key {
+      PropName::Computed(computed) => self.expr(&computed.expr),
+      _ => true,
+    }
+  }
+
+  fn member_prop(&mut self, prop: &MemberProp) -> bool {
+    match prop {
+      MemberProp::Computed(computed) => self.expr(&computed.expr),
+      _ => true,
+    }
+  }
+
+  fn stmts(&mut self, stmts: &[Stmt]) -> bool {
+    stmts.iter().all(|stmt| self.stmt(stmt))
+  }
+
+  fn stmt(&mut self, stmt: &Stmt) -> bool {
+    match stmt {
+      Stmt::Return(ReturnStmt { arg, .. }) => arg.as_ref().is_none_or(|arg| self.expr(arg)),
+      Stmt::Expr(ExprStmt { expr, .. }) => self.expr(expr),
+      Stmt::Decl(Decl::Var(var_decl)) => var_decl.decls.iter().all(|declarator| {
+        is_pure_pat(&declarator.name, self.unresolved_ctxt, self.comments)
+          && declarator.init.as_ref().is_none_or(|init| self.expr(init))
+      }),
+      Stmt::Decl(Decl::Fn(_)) => true,
+      Stmt::Decl(Decl::Class(class_decl)) => {
+        is_pure_class(&class_decl.class, self.unresolved_ctxt, self.comments)
+      }
+      Stmt::If(if_stmt) => {
+        self.expr(&if_stmt.test)
+          && self.stmt(&if_stmt.cons)
+          && if_stmt.alt.as_ref().is_none_or(|alt| self.stmt(alt))
+      }
+      Stmt::Block(block) => self.stmts(&block.stmts),
+      Stmt::Empty(_) => true,
+      _ => false,
+    }
+  }
+
+  fn function(&mut self, function: &Function) -> bool {
+    if !function.decorators.is_empty()
+      || !function
+        .params
+        .iter()
+        .all(|param| is_pure_pat(&param.pat, self.unresolved_ctxt, self.comments))
+    {
+      return false;
+    }
+    function
+      .body
+      .as_ref()
+      .is_some_and(|body| self.stmts(&body.stmts))
+  }
+
+  fn arrow(&mut self, arrow: &ArrowExpr) -> bool {
+    if !arrow
+      .params
+      .iter()
+      .all(|param| is_pure_pat(param, self.unresolved_ctxt, self.comments))
+    {
+      return false;
+    }
+    match &*arrow.body {
+      BlockStmtOrExpr::BlockStmt(block) => self.stmts(&block.stmts),
+      BlockStmtOrExpr::Expr(expr) => self.expr(expr),
+    }
+  }
+
+  /// Both evaluating the class and constructing an instance of it must be free of side effects.
+  fn class(&mut self, class: &Class) -> bool {
+    if !class.decorators.is_empty() {
+      return false;
+    }
+    if let Some(super_class) = &class.super_class
+      && !self.call(super_class, &[])
+    {
+      return false;
+    }
+    class.body.iter().all(|member| match member {
+      ClassMember::Constructor(constructor) => {
+        let params_pure = constructor.params.iter().all(|param| match param {
+          ParamOrTsParamProp::Param(param) => {
+            is_pure_pat(&param.pat, self.unresolved_ctxt, self.comments)
+          }
+          ParamOrTsParamProp::TsParamProp(_) => false,
+        });
+        self.in_constructor = true;
+        let body_pure = constructor
+          .body
+          .as_ref()
+          .is_some_and(|body| self.stmts(&body.stmts));
+        self.in_constructor = false;
+        params_pure && body_pure
+      }
+      ClassMember::Method(method) => self.prop_name(&method.key),
+      ClassMember::PrivateMethod(_) | ClassMember::Empty(_) => true,
+      ClassMember::ClassProp(prop) => {
+        prop.decorators.is_empty()
+          && self.prop_name(&prop.key)
+          && prop.value.as_ref().is_none_or(|value| self.expr(value))
+      }
+      ClassMember::PrivateProp(prop) => {
+        prop.decorators.is_empty() && prop.value.as_ref().is_none_or(|value| self.expr(value))
+      }
+      ClassMember::StaticBlock(block) => self.stmts(&block.body.stmts),
+      ClassMember::AutoAccessor(_) | ClassMember::TsIndexSignature(_) => false,
+    })
+  }
+}
+
+enum Candidate<'a> {
+  Function(&'a Function),
+  Arrow(&'a ArrowExpr),
+  Class(&'a Class),
+}
+
+fn decl_candidates<'a>(decl: &'a Decl, candidates: &mut Vec<(Atom, Candidate<'a>)>) {
+  match decl {
+    Decl::Fn(fn_decl) => candidates.push((
+      fn_decl.ident.sym.clone(),
+      Candidate::Function(&fn_decl.function),
+    )),
+    Decl::Class(class_decl) => candidates.push((
+      class_decl.ident.sym.clone(),
+      Candidate::Class(&class_decl.class),
+    )),
+    Decl::Var(var_decl) if var_decl.kind == VarDeclKind::Const => {
+      for declarator in &var_decl.decls {
+        let Pat::Ident(ident) = &declarator.name else {
+          continue;
+        };
+        let candidate = match declarator.init.as_deref() {
+          Some(Expr::Fn(fn_expr)) => Candidate::Function(&fn_expr.function),
+          Some(Expr::Arrow(arrow)) => Candidate::Arrow(arrow),
+          Some(Expr::Class(class_expr)) => Candidate::Class(&class_expr.class),
+          _ => continue,
+        };
+        candidates.push((ident.id.sym.clone(), candidate));
+      }
+    }
+    _ => {}
+  }
+}
+
+/// Infers the top-level functions and classes of the module that are free of side effects,
+/// assuming that the functions they call are pure as well.
+pub fn collect_pure_functions(
+  program: &Program,
+  unresolved_ctxt: SyntaxContext,
+  comments: Option<&dyn Comments>,
+  resolver: &PureCalleeResolver,
+) -> Vec<PureFunction> {
+  let Program::Module(module) = program else {
+    return vec![];
+  };
+
+  let mut exports: HashMap<Atom, Vec<Atom>> = HashMap::default();
+  let mut candidates = vec![];
+  for item in &module.body {
+    match item {
+      ModuleItem::Stmt(Stmt::Decl(decl)) => decl_candidates(decl, &mut candidates),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+        for ident in function_like_decl_idents(decl) {
+          exports
+            .entry(ident.sym.clone())
+            .or_default()
+            .push(ident.sym.clone());
+        }
+        decl_candidates(decl, &mut candidates);
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default_decl)) => {
+        let (ident, candidate) = match &export_default_decl.decl {
+          DefaultDecl::Fn(fn_expr) => (&fn_expr.ident, Candidate::Function(&fn_expr.function)),
+          DefaultDecl::Class(class_expr) => {
+            (&class_expr.ident, Candidate::Class(&class_expr.class))
+          }
+          DefaultDecl::TsInterfaceDecl(_) => continue,
+        };
+        let name = ident
+          .as_ref()
+          .map(|ident| ident.sym.clone())
+          .unwrap_or_else(|| DEFAULT_STAR_JS_WORD.clone());
+        exports
+          .entry(name.clone())
+          .or_default()
+          .push("default".into());
+        candidates.push((name, candidate));
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+        expr: box Expr::Ident(ident),
+        ..
+      })) => {
+        exports
+          .entry(ident.sym.clone())
+          .or_default()
+          .push("default".into());
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+        specifiers,
+        src: None,
+        type_only: false,
+        ..
+      })) => {
+        for specifier in specifiers {
+          let ExportSpecifier::Named(ExportNamedSpecifier {
+            orig: ModuleExportName::Ident(orig),
+            exported,
+            is_type_only: false,
+            ..
+          }) = specifier
+          else {
+            continue;
+          };
+          let exported = match exported {
+            Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+            Some(ModuleExportName::Str(str)) => str.value.clone(),
+            None => orig.sym.clone(),
+          };
+          exports.entry(orig.sym.clone()).or_default().push(exported);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  candidates
+    .into_iter()
+    .filter(|(name, _)| !resolver.reassigned.contains(name))
+    .filter_map(|(name, candidate)| {
+      let mut checker = PurityChecker::new(unresolved_ctxt, comments, resolver);
+      let is_pure = match candidate {
+        Candidate::Function(function) => checker.function(function),
+        Candidate::Arrow(arrow) => checker.arrow(arrow),
+        Candidate::Class(class) => checker.class(class),
+      };
+      is_pure.then(|| PureFunction {
+        exports: exports.remove(&name).unwrap_or_default(),
+        name,
+        callees: checker.callees,
+      })
+    })
+    .collect()
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct InferPureFunctionsPlugin;
+
+type FunctionKey = (ModuleIdentifier, Atom);
+
+#[plugin_hook(CompilationOptimizeDependencies for InferPureFunctionsPlugin)]
+fn optimize_dependencies(&self, compilation: &mut Compilation) -> Result<Option<bool>> {
+  let logger = compilation.get_logger("rspack.InferPureFunctionsPlugin");
+  let start = logger.time("infer pure functions");
+  let module_graph = compilation.get_module_graph();
+
+  let mut functions: HashMap<FunctionKey, &[PureCallee]> = HashMap::default();
+  let mut exports: HashMap<FunctionKey, Atom> = HashMap::default();
+  let mut modules = vec![];
+  for (module_identifier, module) in module_graph.modules() {
+    let Some(info) = module
+      .build_info()
+      .and_then(|build_info| build_info.pure_functions.as_ref())
+    else {
+      continue;
+    };
+    for function in &info.functions {
+      functions.insert(
+        (module_identifier, function.name.clone()),
+        &function.callees,
+      );
+      for export in &function.exports {
+        exports.insert((module_identifier, export.clone()), function.name.clone());
+      }
+    }
+    modules.push(module_identifier);
+  }
+
+  let resolve_callee = |module: &ModuleIdentifier, callee: &PureCallee| -> Option<FunctionKey> {
+    match callee {
+      PureCallee::Local(name) => Some((*module, name.clone())),
+      PureCallee::Imported { request, ids } => {
+        let target = resolve_imported_function(&module_graph, module, request, ids)?;
+        exports.get(&target).map(|name| (target.0, name.clone()))
+      }
+    }
+  };
+
+  // Start from assuming every candidate is pure and drop the ones calling impure functions,
+  // so mutually recursive functions are pure as long as nothing else in the cycle is impure.
+  let resolved: HashMap<&FunctionKey, Option<Vec<FunctionKey>>> = functions
+    .iter()
+    .map(|(key, callees)| {
+      let targets = callees
+        .iter()
+        .map(|callee| resolve_callee(&key.0, callee))
+        .collect::<Option<Vec<_>>>();
+      (key, targets)
+    })
+    .collect();
+  let mut pure: HashSet<&FunctionKey> = functions.keys().collect();
+  loop {
+    let impure: Vec<&FunctionKey> = pure
+      .iter()
+      .filter(|key| {
+        !resolved[*key]
+          .as_ref()
+          .is_some_and(|targets| targets.iter().all(|target| pure.contains(target)))
+      })
+      .copied()
+      .collect();
+    if impure.is_empty() {
+      break;
+    }
+    for key in impure {
+      pure.remove(key);
+    }
+  }
+
+  let is_pure_callee = |module: &ModuleIdentifier, callee: &PureCallee| {
+    resolve_callee(module, callee).is_some_and(|key| pure.contains(&key))
+  };
+
+  let mut failed_markers: IdentifierMap<HashSet<Atom>> = IdentifierMap::default();
+  let mut pure_calls: Vec<(DependencyId, bool)> = vec![];
+  for module_identifier in modules {
+    let module = module_graph
+      .module_by_identifier(&module_identifier)
+      .expect("should have module");
+    let info = module
+      .build_info()
+      .and_then(|build_info| build_info.pure_functions.as_ref())
+      .expect("should have pure functions info");
+    let markers: HashSet<Atom> = info
+      .conditions
+      .iter()
+      .filter(|condition| {
+        !condition
+          .callees
+          .iter()
+          .all(|callee| is_pure_callee(&module_identifier, callee))
+      })
+      .map(|condition| condition.marker.clone())
+      .collect();
+    if !markers.is_empty() {
+      failed_markers.insert(module_identifier, markers);
+    }
+    for dependency_id in module.get_dependencies() {
+      if let Some(dependency) = module_graph
+        .dependency_by_id(dependency_id)
+        .and_then(|dependency| dependency.downcast_ref::<PureCallsDependency>())
+      {
+        let is_pure = dependency
+          .callees()
+          .iter()
+          .all(|callee| is_pure_callee(&module_identifier, callee));
+        pure_calls.push((*dependency_id, is_pure));
+      }
+    }
+  }
+  let pure_functions_count = pure.len();
+  let total_functions_count = functions.len();
+
+  // The code of a top-level symbol whose condition failed is always kept,
+  // together with the code it references.
+  let mut impure_dependencies: Vec<DependencyId> = vec![];
+  for (module_identifier, markers) in &failed_markers {
+    let module = module_graph
+      .module_by_identifier(module_identifier)
+      .expect("should have module");
+    impure_dependencies.extend(module.get_dependencies().iter().filter(|dependency_id| {
+      matches!(
+        module_graph
+          .dependency_by_id(dependency_id)
+          .and_then(|dependency| dependency.get_used_by_exports()),
+        Some(UsedByExports::Set(set)) if set.iter().any(|name| markers.contains(name))
+      )
+    }));
+  }
+
+  let mut module_graph = compilation.get_module_graph_mut();
+  for dependency_id in impure_dependencies {
+    if let Some(dependency) = module_graph.dependency_by_id_mut(&dependency_id) {
+      dependency.set_used_by_exports(Some(UsedByExports::Bool(true)));
+    }
+  }
+  for (dependency_id, is_pure) in pure_calls {
+    if let Some(dependency) = module_graph
+      .dependency_by_id_mut(&dependency_id)
+      .and_then(|dependency| dependency.downcast_mut::<PureCallsDependency>())
+    {
+      dependency.set_pure(is_pure);
+    }
+  }
+
+  logger.time_end(start);
+  logger.log(format!(
+    "{pure_functions_count} of {total_functions_count} functions are pure"
+  ));
+  Ok(None)
+}
+
+/// Resolves `import { ids[0] } from "request"` in `module` to the module and the export
+/// that actually defines it, following reexports.
+fn resolve_imported_function(
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+  request: &str,
+  ids: &[Atom],
+) -> Option<FunctionKey> {
+  let [name] = ids else {
+    return None;
+  };
+  let target = module_graph
+    .module_by_identifier(module)?
+    .get_dependencies()
+    .iter()
+    .find_map(|dependency_id| {
+      let dependency = module_graph.dependency_by_id(dependency_id)?;
+      if matches!(dependency.dependency_type(), DependencyType::EsmImport)
+        && dependency.as_module_dependency()?.request() == request
+      {
+        module_graph.module_identifier_by_dependency_id(dependency_id)
+      } else {
+        None
+      }
+    })?;
+  let export_info = module_graph
+    .get_exports_info(target)
+    .get_export_info_without_mut_module_graph(module_graph, name);
+  match export_info.get_target_with_filter(module_graph, Rc::new(|_, _| true)) {
+    Some(resolved) => match resolved.export.as_deref() {
+      Some([name]) => Some((resolved.module, name.clone())),
+      _ => None,
+    },
+    None => Some((*target, name.clone())),
+  }
+}
+
+impl Plugin for InferPureFunctionsPlugin {
+  fn name(&self) -> &'static str {
+    "InferPureFunctionsPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .optimize_dependencies
+      .tap(optimize_dependencies::new(self));
+    Ok(())
+  }
+}
@@ -10,20 +10,80 @@ use rspack_core::concatenated_module::{
   is_esm_dep_like, ConcatenatedInnerModule, ConcatenatedModule, RootModuleContext,
 };
 use rspack_core::{
-  filter_runtime, merge_runtime, ApplyContext, Compilation, CompilationOptimizeChunkModules,
-  CompilerOptions, ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger, Module,
-  ModuleExt, ModuleGraph, ModuleGraphModule, ModuleIdentifier, Plugin, PluginContext,
-  ProvidedExports, RuntimeCondition, RuntimeSpec, SourceType,
+  filter_runtime, is_deferred_import, merge_runtime, ApplyContext, BuildMetaExportsType,
+  Compilation, CompilationOptimizeChunkModules, CompilerOptions, DependencyType,
+  ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger, Module, ModuleExt,
+  ModuleGraph, ModuleGraphModule, ModuleIdentifier, Plugin, PluginContext, ProvidedExports,
+  RuntimeCondition, RuntimeSpec, SourceType,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::itoa;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+use crate::dependency::ModuleArgumentDependency;
+
 fn format_bailout_reason(msg: &str) -> String {
   format!("ModuleConcatenation bailout: {}", msg)
 }
 
+fn is_commonjs_module(module: &dyn Module) -> bool {
+  module.module_type().is_js_like()
+    && module.build_meta().is_some_and(|meta| {
+      matches!(
+        meta.exports_type,
+        BuildMetaExportsType::Flagged | BuildMetaExportsType::Dynamic
+      )
+    })
+}
+
+/// CommonJS modules can't be the root of a concatenated module, but they can be inlined into
+/// one when their export shape is statically known and they don't rely on the `module` object.
+/// The concatenated module is in strict mode, so sloppy mode modules are never inlined.
+fn get_commonjs_inline_bailout_reason(
+  module: &dyn Module,
+  module_graph: &ModuleGraph,
+) -> Option<Cow<'static, str>> {
+  if !module.build_info().is_some_and(|info| info.strict) {
+    return Some("CommonJS module is not in strict mode".into());
+  }
+  if let Some(bailout) = module
+    .build_info()
+    .and_then(|info| info.module_concatenation_bailout.as_deref())
+  {
+    return Some(format!("CommonJS module uses {bailout}").into());
+  }
+  let uses_module_object = module.get_dependencies().iter().any(|dep_id| {
+    module_graph.dependency_by_id(dep_id).is_some_and(|dep| {
+      matches!(
+        dep.dependency_type(),
+        DependencyType::ModuleDecorator
+          | DependencyType::ModuleHotAccept
+          | DependencyType::ModuleHotDecline
+      )
+    })
+  }) || module
+    .get_presentational_dependencies()
+    .is_some_and(|deps| {
+      deps.iter().any(|dep| {
+        dep
+          .as_any()
+          .downcast_ref::<ModuleArgumentDependency>()
+          .is_some()
+      })
+    });
+  if uses_module_object {
+    return Some("CommonJS module uses the module object".into());
+  }
+  if !matches!(
+    module_graph.get_provided_exports(module.identifier()),
+    ProvidedExports::Vec(_)
+  ) {
+    return Some("CommonJS module has a dynamic export shape".into());
+  }
+  None
+}
+
 #[derive(Clone, Debug)]
 enum Warning {
   Id(ModuleIdentifier),
@@ -432,6 +492,35 @@ impl ModuleConcatenationPlugin {
       return Some(problem);
     }
 
+    // the evaluation of a deferred module is postponed to the first access of its namespace,
+    // so it can't be evaluated in the scope of a concatenated module
+    let mut deferred_importers = incoming_connections_from_modules
+      .iter()
+      .filter(|(_, connections)| {
+        connections
+          .iter()
+          .any(|connection| is_deferred_import(compilation, &connection.dependency_id))
+      })
+      .map(|(origin_module, _)| {
+        module_graph
+          .module_by_identifier(origin_module)
+          .expect("should have module")
+          .readable_identifier(&compilation.options.context)
+          .to_string()
+      })
+      .collect::<Vec<_>>();
+    if !deferred_importers.is_empty() {
+      deferred_importers.sort();
+      let problem = Warning::Problem(format!(
+        "Module {} is referenced by `import defer` from these modules: {}",
+        module_readable_identifier,
+        deferred_importers.join(", ")
+      ));
+      statistics.incorrect_module_dependency += 1;
+      failure_cache.insert(*module_id, problem.clone());
+      return Some(problem);
+    }
+
     if let Some(runtime) = runtime
       && runtime.len() > 1
     {
@@ -753,8 +842,19 @@ impl ModuleConcatenationPlugin {
           .expect("should have module")
           .get_concatenation_bailout_reason(&module_graph, &compilation.chunk_graph)
         {
+          let module = &**m.expect("should have module");
+          if !is_commonjs_module(module) {
+            bailout_reason.push(reason);
+            return (false, false, module_id, bailout_reason);
+          }
+          if let Some(inline_reason) = get_commonjs_inline_bailout_reason(module, &module_graph) {
+            bailout_reason.push(reason);
+            bailout_reason.push(inline_reason);
+            return (false, false, module_id, bailout_reason);
+          }
+          // inlined into a concatenated module with the interop of CommonJS modules
           bailout_reason.push(reason);
-          return (false, false, module_id, bailout_reason);
+          can_be_root = false;
         }
 
         let m = module_graph.module_by_identifier(&module_id);
@@ -764,6 +864,33 @@ impl ModuleConcatenationPlugin {
           return (false, false, module_id, bailout_reason);
         }
 
+        // a concatenated module evaluates its external modules up front, which would break the
+        // postponed evaluation of the modules it imports with `import defer`
+        let mut deferred_requests = m
+          .expect("should have module")
+          .get_dependencies()
+          .iter()
+          .filter(|dep_id| is_deferred_import(compilation, dep_id))
+          .filter_map(|dep_id| {
+            module_graph
+              .dependency_by_id(dep_id)
+              .and_then(|dep| dep.as_module_dependency())
+              .map(|dep| dep.user_request().to_string())
+          })
+          .collect::<Vec<_>>();
+        if !deferred_requests.is_empty() {
+          deferred_requests.sort();
+          deferred_requests.dedup();
+          bailout_reason.push(
+            format!(
+              "Module uses `import defer` for these requests: {}",
+              deferred_requests.join(", ")
+            )
+            .into(),
+          );
+          return (false, false, module_id, bailout_reason);
+        }
+
         if !m
           .and_then(|m| m.build_info())
           .expect("should have build info")
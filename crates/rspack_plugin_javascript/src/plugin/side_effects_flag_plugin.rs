@@ -18,7 +18,8 @@ use rspack_core::SideEffectsOptimizeArtifact;
 use rspack_core::{
   BoxModule, Compilation, CompilationOptimizeDependencies, ConnectionState, FactoryMeta,
   ModuleFactoryCreateData, ModuleGraph, ModuleIdentifier, NormalModuleCreateData,
-  NormalModuleFactoryModule, Plugin, ResolvedExportInfoTarget, SideEffectsBailoutItemWithSpan,
+  NormalModuleFactoryModule, Plugin, PureCallee, ResolvedExportInfoTarget,
+  SideEffectsBailoutItemWithSpan, SideEffectsFlagSource,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
@@ -32,6 +33,7 @@ use swc_core::ecma::utils::{ExprCtx, ExprExt};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
 use crate::dependency::{ESMExportImportedSpecifierDependency, ESMImportSpecifierDependency};
+use crate::{PureCalleeResolver, PurityChecker};
 
 #[derive(Clone, Debug)]
 enum SideEffects {
@@ -89,6 +91,9 @@ pub struct SideEffectsFlagPluginVisitor<'a> {
   pub side_effects_item: Option<SideEffectsBailoutItemWithSpan>,
   is_top_level: bool,
   comments: Option<&'a dyn Comments>,
+  pure_callee_resolver: Option<&'a PureCalleeResolver>,
+  /// Functions called at the top level, the module is only free of side effects if they are pure.
+  pub pure_callees: Vec<PureCallee>,
 }
 
 impl Debug for SideEffectsFlagPluginVisitor<'_> {
@@ -97,6 +102,7 @@ impl Debug for SideEffectsFlagPluginVisitor<'_> {
       .field("unresolved_ctxt", &self.unresolved_ctxt)
       .field("side_effects_span", &self.side_effects_item)
       .field("is_top_level", &self.is_top_level)
+      .field("pure_callees", &self.pure_callees)
       .finish()
   }
 }
@@ -119,8 +125,51 @@ impl<'a> SideEffectsFlagPluginVisitor<'a> {
       side_effects_item: None,
       is_top_level: true,
       comments,
+      pure_callee_resolver: None,
+      pure_callees: vec![],
     }
   }
+
+  pub fn with_pure_callee_resolver(mut self, resolver: Option<&'a PureCalleeResolver>) -> Self {
+    self.pure_callee_resolver = resolver;
+    self
+  }
+
+  /// Returns `true` if the expression is pure, or is pure as long as the functions it calls are,
+  /// in which case the callees are collected into `pure_callees`.
+  fn is_conditionally_pure_expression(&mut self, expr: &Expr) -> bool {
+    if is_pure_expression(expr, self.unresolved_ctxt, self.comments) {
+      return true;
+    }
+    let Some(resolver) = self.pure_callee_resolver else {
+      return false;
+    };
+    let mut checker = PurityChecker::new(self.unresolved_ctxt, self.comments, resolver);
+    if !checker.expr(expr) {
+      return false;
+    }
+    self.pure_callees.extend(checker.callees);
+    true
+  }
+
+  fn is_conditionally_pure_decl(&mut self, decl: &Decl) -> bool {
+    if is_pure_decl(decl, self.unresolved_ctxt, self.comments) {
+      return true;
+    }
+    let (Some(resolver), Decl::Var(var)) = (self.pure_callee_resolver, decl) else {
+      return false;
+    };
+    let mut checker = PurityChecker::new(self.unresolved_ctxt, self.comments, resolver);
+    let pure = var
+      .decls
+      .iter()
+      .all(|decl| decl.init.as_ref().is_none_or(|init| checker.expr(init)));
+    if !pure {
+      return false;
+    }
+    self.pure_callees.extend(checker.callees);
+    true
+  }
 }
 
 impl Visit for SideEffectsFlagPluginVisitor<'_> {
@@ -143,7 +192,7 @@ impl Visit for SideEffectsFlagPluginVisitor<'_> {
             decl.visit_with(self);
           }
           ModuleDecl::ExportDefaultExpr(expr) => {
-            if !is_pure_expression(&expr.expr, self.unresolved_ctxt, self.comments) {
+            if !self.is_conditionally_pure_expression(&expr.expr) {
               self.side_effects_item = Some(SideEffectsBailoutItemWithSpan::new(
                 expr.span,
                 String::from("ExportDefaultExpr"),
@@ -176,7 +225,7 @@ impl Visit for SideEffectsFlagPluginVisitor<'_> {
   }
 
   fn visit_export_decl(&mut self, node: &ExportDecl) {
-    if !is_pure_decl(&node.decl, self.unresolved_ctxt, self.comments) {
+    if !self.is_conditionally_pure_decl(&node.decl) {
       self.side_effects_item = Some(SideEffectsBailoutItemWithSpan::new(
         node.decl.span(),
         String::from("Decl"),
@@ -298,7 +347,7 @@ impl SideEffectsFlagPluginVisitor<'_> {
         }
       }
       Stmt::Expr(stmt) => {
-        if !is_pure_expression(&stmt.expr, self.unresolved_ctxt, self.comments) {
+        if !self.is_conditionally_pure_expression(&stmt.expr) {
           self.side_effects_item = Some(SideEffectsBailoutItemWithSpan::new(
             stmt.span(),
             String::from("Statement"),
@@ -314,7 +363,7 @@ impl SideEffectsFlagPluginVisitor<'_> {
         }
       }
       Stmt::Decl(stmt) => {
-        if !is_pure_decl(stmt, self.unresolved_ctxt, self.comments) {
+        if !self.is_conditionally_pure_decl(stmt) {
           self.side_effects_item = Some(SideEffectsBailoutItemWithSpan::new(
             stmt.span(),
             String::from("Statement"),
@@ -637,6 +686,7 @@ async fn nmf_module(
   if let Some(has_side_effects) = create_data.side_effects {
     module.set_factory_meta(FactoryMeta {
       side_effect_free: Some(!has_side_effects),
+      side_effects_source: Some(SideEffectsFlagSource::ModuleRule),
     });
     return Ok(());
   }
@@ -658,6 +708,12 @@ async fn nmf_module(
   let has_side_effects = get_side_effects_from_package_json(side_effects, relative_path.as_path());
   module.set_factory_meta(FactoryMeta {
     side_effect_free: Some(!has_side_effects),
+    side_effects_source: Some(SideEffectsFlagSource::PackageJson(
+      package_path
+        .join("package.json")
+        .to_string_lossy()
+        .into_owned(),
+    )),
   });
   Ok(())
 }
@@ -0,0 +1,318 @@
+use rspack_core::EvaluatedInlinableValue;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::atoms::Atom;
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::{
+  AssignExpr, AssignOp, AssignTarget, CallExpr, Callee, Decl, Expr, ForHead, Function, Id, Lit,
+  MemberExpr, MemberProp, Module, ModuleDecl, ModuleItem, Number, Pat, SimpleAssignTarget, Stmt,
+  UnaryExpr, UnaryOp, UpdateExpr, VarDecl, VarDeclKind,
+};
+use swc_core::ecma::utils::find_pat_ids;
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use super::eval::BasicEvaluatedExpression;
+
+/// Longer strings are not inlined, as repeating them at every use site costs more than the
+/// property access on the exports object.
+const MAX_INLINABLE_STRING_LENGTH: usize = 16;
+
+/// Collects the top-level bindings of the module whose value is known at build time:
+/// `const` bindings initialized with a primitive literal, and TypeScript enums compiled to
+/// `var E = function(E) { E[E["A"] = 0] = "A"; return E; }({})` that are never mutated.
+pub fn collect_inlinable_declarations(
+  module: &Module,
+  unresolved_ctxt: SyntaxContext,
+) -> HashMap<Atom, EvaluatedInlinableValue> {
+  let mut declarations = HashMap::default();
+  let mut enums = vec![];
+  for item in &module.body {
+    let var = match item {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => var,
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+        Decl::Var(var) => var,
+        _ => continue,
+      },
+      _ => continue,
+    };
+    collect_var_decl(var, unresolved_ctxt, &mut declarations, &mut enums);
+  }
+
+  if !enums.is_empty() {
+    let mut visitor = MutatedBindings {
+      properties: true,
+      ..Default::default()
+    };
+    module.visit_with(&mut visitor);
+    for (id, members) in enums {
+      if !visitor.mutated.contains(&id) {
+        declarations.insert(id.0, EvaluatedInlinableValue::Enum(members));
+      }
+    }
+  }
+  declarations
+}
+
+fn collect_var_decl(
+  var: &VarDecl,
+  unresolved_ctxt: SyntaxContext,
+  declarations: &mut HashMap<Atom, EvaluatedInlinableValue>,
+  enums: &mut Vec<(Id, Vec<(Atom, Atom)>)>,
+) {
+  for declarator in &var.decls {
+    let (Pat::Ident(ident), Some(init)) = (&declarator.name, &declarator.init) else {
+      continue;
+    };
+    if matches!(var.kind, VarDeclKind::Const)
+      && let Some(code) = evaluate_primitive(init, unresolved_ctxt)
+    {
+      declarations.insert(
+        ident.id.sym.clone(),
+        EvaluatedInlinableValue::Primitive(code),
+      );
+    } else if let Some(members) = evaluate_enum(init) {
+      enums.push((ident.id.to_id(), members));
+    }
+  }
+}
+
+/// Renders the primitive literal as code, which can be put in any expression position.
+fn evaluate_primitive(expr: &Expr, unresolved_ctxt: SyntaxContext) -> Option<Atom> {
+  match expr {
+    Expr::Paren(paren) => evaluate_primitive(&paren.expr, unresolved_ctxt),
+    Expr::Lit(Lit::Null(_)) => Some("null".into()),
+    Expr::Lit(Lit::Bool(b)) => Some(if b.value { "true" } else { "false" }.into()),
+    Expr::Lit(Lit::Num(num)) if num.value.is_finite() => Some(render_number(num)),
+    Expr::Lit(Lit::Str(str)) if str.value.chars().count() <= MAX_INLINABLE_STRING_LENGTH => {
+      serde_json::to_string(&*str.value).ok().map(Into::into)
+    }
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Minus,
+      arg: box Expr::Lit(Lit::Num(num)),
+      ..
+    }) if num.value.is_finite() => Some(format!("(-{})", render_number(num)).into()),
+    Expr::Unary(UnaryExpr {
+      op: UnaryOp::Void,
+      arg: box Expr::Lit(Lit::Num(_)),
+      ..
+    }) => Some("(void 0)".into()),
+    Expr::Ident(ident) if ident.sym == "undefined" && ident.ctxt == unresolved_ctxt => {
+      Some("(void 0)".into())
+    }
+    _ => None,
+  }
+}
+
+/// Renders the value of an expression the parser evaluated to a primitive, e.g.
+/// `process.env.NODE_ENV !== "production"` with the values of `DefinePlugin`.
+pub fn render_evaluated_primitive(evaluated: &BasicEvaluatedExpression) -> Option<Atom> {
+  if evaluated.could_have_side_effects() {
+    return None;
+  }
+  if evaluated.is_null() {
+    Some("null".into())
+  } else if evaluated.is_undefined() {
+    Some("(void 0)".into())
+  } else if evaluated.is_bool() {
+    Some(if evaluated.bool() { "true" } else { "false" }.into())
+  } else if evaluated.is_number() && evaluated.number().is_finite() {
+    let number = evaluated.number();
+    if number.is_sign_negative() {
+      Some(format!("(-{})", -number).into())
+    } else {
+      Some(number.to_string().into())
+    }
+  } else if evaluated.is_string()
+    && evaluated.string().chars().count() <= MAX_INLINABLE_STRING_LENGTH
+  {
+    serde_json::to_string(evaluated.string())
+      .ok()
+      .map(Into::into)
+  } else {
+    None
+  }
+}
+
+fn render_number(num: &Number) -> Atom {
+  num
+    .raw
+    .clone()
+    .unwrap_or_else(|| num.value.to_string().into())
+}
+
+/// Returns the members of `function(E) { E[E["A"] = 0] = "A"; E["B"] = "b"; return E; }({})`,
+/// which is how TypeScript enums are compiled. Members with a value that can't be inlined are
+/// skipped, any other statement in the function bails out.
+fn evaluate_enum(expr: &Expr) -> Option<Vec<(Atom, Atom)>> {
+  let Expr::Call(CallExpr {
+    callee: Callee::Expr(callee),
+    args,
+    ..
+  }) = expr
+  else {
+    return None;
+  };
+  let Expr::Fn(fn_expr) = callee.unwrap_parens() else {
+    return None;
+  };
+  let [arg] = args.as_slice() else {
+    return None;
+  };
+  if arg.spread.is_some() || !arg.expr.as_object().is_some_and(|obj| obj.props.is_empty()) {
+    return None;
+  }
+  let Function {
+    params,
+    body: Some(body),
+    is_async: false,
+    is_generator: false,
+    ..
+  } = &*fn_expr.function
+  else {
+    return None;
+  };
+  let [param] = params.as_slice() else {
+    return None;
+  };
+  let Pat::Ident(param) = &param.pat else {
+    return None;
+  };
+  let param = param.id.to_id();
+
+  let (last, stmts) = body.stmts.split_last()?;
+  let Stmt::Return(ret) = last else {
+    return None;
+  };
+  if !ret
+    .arg
+    .as_deref()
+    .and_then(Expr::as_ident)
+    .is_some_and(|ident| ident.to_id() == param)
+  {
+    return None;
+  }
+
+  let mut members = vec![];
+  for stmt in stmts {
+    let Stmt::Expr(expr_stmt) = stmt else {
+      return None;
+    };
+    let (key, value) = enum_member_assignment(&expr_stmt.expr, &param)?;
+    if let Some(code) = evaluate_primitive(value, SyntaxContext::empty()) {
+      members.push((key, code));
+    }
+  }
+  Some(members)
+}
+
+/// Matches `E[E["A"] = value] = "A"` and `E["A"] = value`.
+fn enum_member_assignment<'a>(expr: &'a Expr, param: &Id) -> Option<(Atom, &'a Expr)> {
+  let (member, value) = enum_assignment(expr, param)?;
+  if let MemberProp::Computed(computed) = &member.prop
+    && let Some((reverse, value)) = enum_assignment(&computed.expr, param)
+  {
+    return Some((member_key(&reverse.prop)?, value));
+  }
+  Some((member_key(&member.prop)?, value))
+}
+
+fn enum_assignment<'a>(expr: &'a Expr, param: &Id) -> Option<(&'a MemberExpr, &'a Expr)> {
+  let Expr::Assign(AssignExpr {
+    op: AssignOp::Assign,
+    left: AssignTarget::Simple(SimpleAssignTarget::Member(member)),
+    right,
+    ..
+  }) = expr.unwrap_parens()
+  else {
+    return None;
+  };
+  member
+    .obj
+    .as_ident()
+    .is_some_and(|obj| &obj.to_id() == param)
+    .then_some((member, &**right))
+}
+
+fn member_key(prop: &MemberProp) -> Option<Atom> {
+  match prop {
+    MemberProp::Ident(ident) => Some(ident.sym.clone()),
+    MemberProp::Computed(computed) => match &*computed.expr {
+      Expr::Lit(Lit::Str(str)) => Some(str.value.clone()),
+      _ => None,
+    },
+    MemberProp::PrivateName(_) => None,
+  }
+}
+
+/// Returns the bindings of `module` that are reassigned, e.g. `foo = bar`, `foo++` or
+/// `[foo] = bar`.
+pub fn collect_reassigned_bindings(module: &Module) -> HashSet<Id> {
+  let mut visitor = MutatedBindings::default();
+  module.visit_with(&mut visitor);
+  visitor.mutated
+}
+
+/// Collects the bindings that are reassigned, or whose properties are assigned or deleted
+/// when `properties` is set.
+#[derive(Default)]
+struct MutatedBindings {
+  mutated: HashSet<Id>,
+  properties: bool,
+}
+
+impl MutatedBindings {
+  fn add_target(&mut self, expr: &Expr) {
+    match expr {
+      Expr::Ident(ident) => {
+        self.mutated.insert(ident.to_id());
+      }
+      Expr::Member(member) if self.properties => self.add_target(&member.obj),
+      Expr::Paren(paren) => self.add_target(&paren.expr),
+      _ => {}
+    }
+  }
+}
+
+impl Visit for MutatedBindings {
+  noop_visit_type!();
+
+  fn visit_assign_expr(&mut self, node: &AssignExpr) {
+    match &node.left {
+      AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => {
+        self.mutated.insert(ident.id.to_id());
+      }
+      AssignTarget::Simple(SimpleAssignTarget::Member(member)) if self.properties => {
+        self.add_target(&member.obj)
+      }
+      AssignTarget::Pat(pat) => self.mutated.extend(find_pat_ids::<_, Id>(pat)),
+      _ => {}
+    }
+    node.visit_children_with(self);
+  }
+
+  fn visit_for_head(&mut self, node: &ForHead) {
+    if let ForHead::Pat(pat) = node {
+      self.mutated.extend(find_pat_ids::<_, Id>(pat));
+    }
+    node.visit_children_with(self);
+  }
+
+  fn visit_pat(&mut self, node: &Pat) {
+    // member expressions in destructuring assignments
+    if let Pat::Expr(expr) = node {
+      self.add_target(expr);
+    }
+    node.visit_children_with(self);
+  }
+
+  fn visit_update_expr(&mut self, node: &UpdateExpr) {
+    self.add_target(&node.arg);
+    node.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, node: &UnaryExpr) {
+    if matches!(node.op, UnaryOp::Delete) {
+      self.add_target(&node.arg);
+    }
+    node.visit_children_with(self);
+  }
+}
@@ -1,4 +1,5 @@
 pub mod eval;
+pub mod inlinable_exports;
 pub mod mangle_exports;
 pub mod object_properties;
 
@@ -16,7 +16,9 @@ use swc_core::common::Mark;
 use swc_core::common::{comments::Comments, BytePos, SourceFile, SourceMap};
 use swc_core::ecma::atoms::Atom;
 
-pub use self::context_dependency_helper::{create_context_dependency, ContextModuleScanResult};
+pub use self::context_dependency_helper::{
+  create_context_dependency, quote_meta, ContextModuleScanResult,
+};
 pub use self::parser::{
   estree::*, AllowedMemberTypes, CallExpressionInfo, CallHooksName, ExportedVariableInfo,
   JavascriptParser, MemberExpressionInfo, RootName, TagInfoData, TopLevelScope,
@@ -29,6 +31,7 @@ pub struct ScanDependenciesResult {
   pub blocks: Vec<Box<AsyncDependenciesBlock>>,
   pub presentational_dependencies: Vec<BoxDependencyTemplate>,
   pub warning_diagnostics: Vec<Box<dyn Diagnostic + Send + Sync>>,
+  pub optimization_bailouts: Vec<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -88,6 +91,7 @@ pub fn scan_dependencies(
       blocks: parser.blocks,
       presentational_dependencies: parser.presentational_dependencies,
       warning_diagnostics: parser.warning_diagnostics,
+      optimization_bailouts: parser.optimization_bailouts,
     })
   } else {
     Err(parser.errors)
@@ -13,7 +13,7 @@ use bitflags::bitflags;
 pub use call_hooks_name::CallHooksName;
 use rspack_core::{
   AdditionalData, AsyncDependenciesBlock, BoxDependency, BuildInfo, BuildMeta, DependencyTemplate,
-  JavascriptParserOptions, ModuleIdentifier, ModuleLayer, ResourceData,
+  EvaluatedInlinableValue, JavascriptParserOptions, ModuleIdentifier, ModuleLayer, ResourceData,
 };
 use rspack_core::{CompilerOptions, JavascriptParserUrl, ModuleType, SpanExt};
 use rspack_error::miette::Diagnostic;
@@ -242,6 +242,13 @@ pub struct JavascriptParser<'parser> {
   pub(crate) prev_statement: Option<StatementPath>,
   pub(crate) current_tag_info: Option<TagInfoId>,
   pub(crate) local_modules: Vec<LocalModule>,
+  // properties accessed on `const x = require('...')` keyed by the range of `require()`,
+  // `None` means `x` is used as a whole
+  pub(crate) require_variable_references: FxHashMap<(u32, u32), Option<Vec<Vec<Atom>>>>,
+  // reasons why exports of the module can't be analyzed statically
+  pub(crate) optimization_bailouts: Vec<String>,
+  // top-level bindings whose value is known at build time, only collected when `optimization.inlineExports` is enabled
+  pub(crate) inlinable_declarations: FxHashMap<Atom, EvaluatedInlinableValue>,
   // ===== scope info =======
   pub(crate) in_try: bool,
   pub(crate) in_short_hand: bool,
@@ -300,6 +307,9 @@ impl<'parser> JavascriptParser<'parser> {
       plugins.push(Box::new(
         parser_plugin::ImportMetaContextDependencyParserPlugin,
       ));
+      plugins.push(Box::new(
+        parser_plugin::ImportMetaGlobDependencyParserPlugin,
+      ));
       if let Some(true) = javascript_options.import_meta {
         plugins.push(Box::new(parser_plugin::ImportMetaPlugin));
       } else {
@@ -307,7 +317,12 @@ impl<'parser> JavascriptParser<'parser> {
       }
 
       plugins.push(Box::new(parser_plugin::ESMImportDependencyParserPlugin));
-      plugins.push(Box::new(parser_plugin::ESMExportDependencyParserPlugin));
+      plugins.push(Box::new(
+        parser_plugin::ESMExportDependencyParserPlugin::new(
+          unresolved_mark,
+          compiler_options.optimization.inline_exports,
+        ),
+      ));
     }
 
     if compiler_options.amd.is_some() && (module_type.is_js_auto() || module_type.is_js_dynamic()) {
@@ -321,7 +336,9 @@ impl<'parser> JavascriptParser<'parser> {
     if module_type.is_js_auto() || module_type.is_js_dynamic() {
       plugins.push(Box::new(parser_plugin::CommonJsImportsParserPlugin));
       plugins.push(Box::new(parser_plugin::CommonJsPlugin));
-      plugins.push(Box::new(parser_plugin::CommonJsExportsParserPlugin));
+      plugins.push(Box::new(parser_plugin::CommonJsExportsParserPlugin::new(
+        unresolved_mark,
+      )));
       if compiler_options.node.is_some() {
         plugins.push(Box::new(parser_plugin::NodeStuffPlugin));
       }
@@ -352,6 +369,7 @@ impl<'parser> JavascriptParser<'parser> {
     if compiler_options.optimization.inner_graph {
       plugins.push(Box::new(parser_plugin::InnerGraphPlugin::new(
         unresolved_mark,
+        compiler_options.optimization.infer_pure_functions,
       )));
     }
     plugins.append(parser_plugins);
@@ -399,6 +417,9 @@ impl<'parser> JavascriptParser<'parser> {
       additional_data,
       parse_meta,
       local_modules: Default::default(),
+      require_variable_references: Default::default(),
+      optimization_bailouts: Default::default(),
+      inlinable_declarations: Default::default(),
     }
   }
 
@@ -450,6 +471,15 @@ impl<'parser> JavascriptParser<'parser> {
     curr_path.span() == expr_span
   }
 
+  /// Whether the statements being walked are the top-level statements of the module, and not
+  /// in a nested block or function.
+  pub fn is_module_scope(&self) -> bool {
+    self
+      .definitions_db
+      .expect_get_scope(self.definitions)
+      .is_root()
+  }
+
   pub fn get_mut_variable_info(&mut self, name: &str) -> Option<&mut VariableInfo> {
     let id = self.definitions_db.get(self.definitions, name)?;
     Some(self.definitions_db.expect_get_mut_variable(id))
@@ -1,11 +1,14 @@
 use std::borrow::Cow;
 
+use rspack_core::{DependenciesBlock, DependencyType, GuardedDependencies, ImportPhase};
+use swc_core::atoms::Atom;
 use swc_core::common::Spanned;
 use swc_core::ecma::ast::{
   ArrayLit, ArrayPat, ArrowExpr, AssignExpr, AssignPat, AssignTarget, AssignTargetPat, AwaitExpr,
   GetterProp, Param, SetterProp, SimpleAssignTarget,
 };
 use swc_core::ecma::ast::{BinExpr, BlockStmt, BlockStmtOrExpr, CallExpr, Callee, CatchClause};
+use swc_core::ecma::ast::{BinaryOp, Lit, ThrowStmt, UnaryExpr, UpdateExpr};
 use swc_core::ecma::ast::{Class, ClassExpr, ClassMember, CondExpr, DefaultDecl};
 use swc_core::ecma::ast::{DoWhileStmt, ExportDefaultDecl, Expr};
 use swc_core::ecma::ast::{ExprOrSpread, ExprStmt, MemberExpr, MemberProp, VarDeclOrExpr};
@@ -16,20 +19,28 @@ use swc_core::ecma::ast::{ModuleDecl, ModuleItem, ObjectPat, ObjectPatProp, Stmt
 use swc_core::ecma::ast::{OptChainExpr, Pat, ThisExpr, UnaryOp};
 use swc_core::ecma::ast::{Prop, PropName, PropOrSpread, RestPat, ReturnStmt, SeqExpr, TaggedTpl};
 use swc_core::ecma::ast::{SwitchCase, SwitchStmt, Tpl, TryStmt, VarDecl, YieldExpr};
-use swc_core::ecma::ast::{ThrowStmt, UnaryExpr, UpdateExpr};
 
 use super::estree::{ClassDeclOrExpr, MaybeNamedClassDecl, MaybeNamedFunctionDecl, Statement};
 use super::{
-  AllowedMemberTypes, CallHooksName, JavascriptParser, MemberExpressionInfo, RootName,
+  AllowedMemberTypes, CallHooksName, JavascriptParser, MemberExpressionInfo, RootName, TagInfoData,
   TopLevelScope,
 };
-use crate::parser_plugin::{is_logic_op, JavascriptParserPlugin};
+use crate::parser_plugin::{
+  is_logic_op, ESMSpecifierData, JavascriptParserPlugin, ESM_SPECIFIER_TAG,
+};
 use crate::visitors::scope_info::{FreeName, VariableInfo};
 
 fn warp_ident_to_pat(ident: Ident) -> Pat {
   Pat::Ident(ident.into())
 }
 
+/// A branch condition that only reads an imported export, e.g. `IS_DEV`, `!IS_DEV` or `flags.IS_DEV`.
+struct ImportGuard {
+  request: Atom,
+  ids: Vec<Atom>,
+  negated: bool,
+}
+
 impl JavascriptParser<'_> {
   fn in_block_scope<F>(&mut self, f: F)
   where
@@ -48,6 +59,103 @@ impl JavascriptParser<'_> {
     self.in_tagged_template_tag = old_in_tagged_template_tag;
   }
 
+  fn get_import_guard(&mut self, test: &Expr) -> Option<ImportGuard> {
+    if !self.compiler_options.optimization.inline_exports {
+      return None;
+    }
+    match test {
+      Expr::Paren(paren) => self.get_import_guard(&paren.expr),
+      Expr::Unary(UnaryExpr {
+        op: UnaryOp::Bang,
+        arg,
+        ..
+      }) => self.get_import_guard(arg).map(|guard| ImportGuard {
+        negated: !guard.negated,
+        ..guard
+      }),
+      Expr::Ident(ident) => {
+        let settings =
+          ESMSpecifierData::downcast(self.get_tag_data(&ident.sym, ESM_SPECIFIER_TAG)?);
+        // a namespace object is always truthy
+        (matches!(settings.phase, ImportPhase::Evaluation) && !settings.ids.is_empty()).then(|| {
+          ImportGuard {
+            request: settings.source,
+            ids: settings.ids,
+            negated: false,
+          }
+        })
+      }
+      Expr::Member(MemberExpr { obj, prop, .. }) => {
+        let obj = obj.as_ident()?;
+        let prop = match prop {
+          MemberProp::Ident(ident) => ident.sym.clone(),
+          MemberProp::Computed(computed) => match &*computed.expr {
+            Expr::Lit(Lit::Str(str)) => str.value.clone(),
+            _ => return None,
+          },
+          MemberProp::PrivateName(_) => return None,
+        };
+        let settings = ESMSpecifierData::downcast(self.get_tag_data(&obj.sym, ESM_SPECIFIER_TAG)?);
+        if !matches!(settings.phase, ImportPhase::Evaluation) {
+          return None;
+        }
+        let mut ids = settings.ids;
+        ids.push(prop);
+        Some(ImportGuard {
+          request: settings.source,
+          ids,
+          negated: false,
+        })
+      }
+      _ => None,
+    }
+  }
+
+  /// Walks a branch that is taken when the condition `guard` is `truthy`, and records the
+  /// dependencies created in it, so they are only built once the branch is known to be live.
+  fn walk_guarded_branch<F>(&mut self, guard: Option<&ImportGuard>, truthy: bool, f: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    let Some(guard) = guard else {
+      return f(self);
+    };
+    let dependencies_start = self.dependencies.len();
+    let blocks_start = self.blocks.len();
+    f(self);
+    let dependencies = self
+      .dependencies
+      .get(dependencies_start..)
+      .unwrap_or_default()
+      .iter()
+      .filter(|dep| {
+        // imported bindings are resolved through the import statement, which is never guarded
+        (dep.as_module_dependency().is_some() || dep.as_context_dependency().is_some())
+          && !matches!(dep.dependency_type(), DependencyType::EsmImportSpecifier)
+      })
+      .map(|dep| *dep.id())
+      .chain(
+        self
+          .blocks
+          .get(blocks_start..)
+          .unwrap_or_default()
+          .iter()
+          .flat_map(|block| block.get_dependencies().iter().copied()),
+      )
+      .collect::<Vec<_>>();
+    if !dependencies.is_empty() {
+      self
+        .build_info
+        .guarded_dependencies
+        .push(GuardedDependencies {
+          request: guard.request.clone(),
+          ids: guard.ids.clone(),
+          truthy: truthy != guard.negated,
+          dependencies,
+        });
+    }
+  }
+
   pub fn in_class_scope<'a, I, F>(&mut self, has_this: bool, params: I, f: F)
   where
     F: FnOnce(&mut Self),
@@ -282,9 +390,14 @@ impl JavascriptParser<'_> {
       }
     } else {
       self.walk_expression(&stmt.test);
-      self.walk_nested_statement(&stmt.cons);
+      let guard = self.get_import_guard(&stmt.test);
+      self.walk_guarded_branch(guard.as_ref(), true, |this| {
+        this.walk_nested_statement(&stmt.cons)
+      });
       if let Some(alt) = &stmt.alt {
-        self.walk_nested_statement(alt);
+        self.walk_guarded_branch(guard.as_ref(), false, |this| {
+          this.walk_nested_statement(alt)
+        });
       }
     }
   }
@@ -651,8 +764,13 @@ impl JavascriptParser<'_> {
       }
     } else {
       self.walk_expression(&expr.test);
-      self.walk_expression(&expr.cons);
-      self.walk_expression(&expr.alt);
+      let guard = self.get_import_guard(&expr.test);
+      self.walk_guarded_branch(guard.as_ref(), true, |this| {
+        this.walk_expression(&expr.cons)
+      });
+      self.walk_guarded_branch(guard.as_ref(), false, |this| {
+        this.walk_expression(&expr.alt)
+      });
     }
   }
 
@@ -1032,7 +1150,16 @@ impl JavascriptParser<'_> {
           self.walk_expression(&expr.right);
         }
       } else {
-        self.walk_left_right_expression(expr)
+        self.walk_expression(&expr.left);
+        let guard = match expr.op {
+          BinaryOp::LogicalAnd | BinaryOp::LogicalOr => self.get_import_guard(&expr.left),
+          _ => None,
+        };
+        self.walk_guarded_branch(
+          guard.as_ref(),
+          matches!(expr.op, BinaryOp::LogicalAnd),
+          |this| this.walk_expression(&expr.right),
+        );
       }
     } else if self
       .plugin_drive
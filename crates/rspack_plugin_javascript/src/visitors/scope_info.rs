@@ -380,4 +380,9 @@ impl ScopeInfo {
       .filter(|(_, &info_id)| info_id != VariableInfo::TOMBSTONE)
       .map(|(name, info_id)| (name.as_str(), info_id))
   }
+
+  /// Whether this is the outermost scope, i.e. the module or script scope.
+  pub fn is_root(&self) -> bool {
+    self.stack.len() == 1
+  }
 }
@@ -132,6 +132,7 @@ impl ParserAndGenerator for JsonParserAndGenerator {
         code_generation_dependencies: vec![],
         source: box_source,
         side_effects_bailout: None,
+        optimization_bailouts: vec![],
       }
       .with_diagnostic(diagnostics),
     )
@@ -1,4 +1,4 @@
-use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPreset};
+use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPortable};
 use rspack_core::{
   AsContextDependency, Dependency, InitFragmentExt, InitFragmentKey, InitFragmentStage,
   NormalInitFragment,
@@ -13,7 +13,7 @@ use swc_core::ecma::atoms::Atom;
 #[derive(Debug, Clone)]
 pub struct ModernModuleReexportStarExternalDependency {
   id: DependencyId,
-  #[cacheable(with=AsPreset)]
+  #[cacheable(with=AsPortable)]
   request: Atom,
   target_request: ExternalRequest,
   external_type: ExternalType,
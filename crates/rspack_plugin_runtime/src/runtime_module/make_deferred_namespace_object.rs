@@ -0,0 +1,33 @@
+use rspack_collections::Identifier;
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawStringSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct MakeDeferredNamespaceObjectRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for MakeDeferredNamespaceObjectRuntimeModule {
+  fn default() -> Self {
+    Self::with_default(Identifier::from(
+      "webpack/runtime/make_deferred_namespace_object",
+    ))
+  }
+}
+
+impl RuntimeModule for MakeDeferredNamespaceObjectRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    Ok(
+      RawStringSource::from_static(include_str!("runtime/make_deferred_namespace_object.js"))
+        .boxed(),
+    )
+  }
+}
@@ -26,6 +26,7 @@ mod has_own_property;
 mod import_scripts_chunk_loading;
 mod jsonp_chunk_loading;
 mod load_script;
+mod make_deferred_namespace_object;
 mod make_namespace_object;
 mod module_chunk_loading;
 mod node_module_decorator;
@@ -70,6 +71,7 @@ pub use has_own_property::HasOwnPropertyRuntimeModule;
 pub use import_scripts_chunk_loading::ImportScriptsChunkLoadingRuntimeModule;
 pub use jsonp_chunk_loading::JsonpChunkLoadingRuntimeModule;
 pub use load_script::LoadScriptRuntimeModule;
+pub use make_deferred_namespace_object::MakeDeferredNamespaceObjectRuntimeModule;
 pub use make_namespace_object::MakeNamespaceObjectRuntimeModule;
 pub use module_chunk_loading::ModuleChunkLoadingRuntimeModule;
 pub use node_module_decorator::NodeModuleDecoratorRuntimeModule;
@@ -0,0 +1,42 @@
+// create a namespace object which evaluates the module on first access
+// mode: the mode of __webpack_require__.t to create the namespace of non-esm module,
+// the exports of module are used as the namespace when mode is undefined
+__webpack_require__.z = function(moduleId, mode) {
+	var ns;
+	var init = function() {
+		if(!ns) {
+			ns = __webpack_require__(moduleId);
+			if(mode !== undefined) ns = __webpack_require__.t(ns, mode);
+		}
+		return ns;
+	};
+	return new Proxy(Object.create(null), {
+		get: function(_, key) {
+			if(key === Symbol.toStringTag) return "Deferred Module";
+			// a deferred namespace is never thenable
+			if(key === "then" || typeof key === "symbol") return undefined;
+			return init()[key];
+		},
+		has: function(_, key) {
+			if(key === Symbol.toStringTag) return true;
+			if(key === "then" || typeof key === "symbol") return false;
+			return key in init();
+		},
+		ownKeys: function() {
+			return Reflect.ownKeys(init()).filter(function(key) {
+				return key !== "then" && typeof key !== "symbol";
+			}).concat([Symbol.toStringTag]);
+		},
+		getOwnPropertyDescriptor: function(_, key) {
+			if(key === Symbol.toStringTag) {
+				return { value: "Deferred Module", writable: false, enumerable: false, configurable: true };
+			}
+			if(typeof key === "symbol" || key === "then" || !(key in init())) return undefined;
+			return { value: init()[key], writable: true, enumerable: true, configurable: true };
+		},
+		set: function() { return false; },
+		defineProperty: function() { return false; },
+		deleteProperty: function() { return false; },
+		setPrototypeOf: function() { return false; }
+	});
+};
@@ -24,9 +24,10 @@ use crate::runtime_module::{
   ESMModuleDecoratorRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
   GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
   GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HasOwnPropertyRuntimeModule,
-  LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule,
-  NonceRuntimeModule, OnChunkLoadedRuntimeModule, PublicPathRuntimeModule,
-  RelativeUrlRuntimeModule, RuntimeIdRuntimeModule, SystemContextRuntimeModule,
+  LoadScriptRuntimeModule, MakeDeferredNamespaceObjectRuntimeModule,
+  MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NonceRuntimeModule,
+  OnChunkLoadedRuntimeModule, PublicPathRuntimeModule, RelativeUrlRuntimeModule,
+  RuntimeIdRuntimeModule, SystemContextRuntimeModule,
 };
 
 static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
@@ -44,6 +45,7 @@ static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
     RuntimeGlobals::GET_FULL_HASH,
     RuntimeGlobals::GLOBAL,
     RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+    RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT,
     RuntimeGlobals::MODULE_CACHE,
     RuntimeGlobals::MODULE_FACTORIES,
     RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY,
@@ -56,6 +58,7 @@ static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
     RuntimeGlobals::ASYNC_MODULE,
     // RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
+    RuntimeGlobals::COMPILE_WASM,
     RuntimeGlobals::SHARE_SCOPE_MAP,
     RuntimeGlobals::INITIALIZE_SHARING,
     RuntimeGlobals::LOAD_SCRIPT,
@@ -444,6 +447,12 @@ fn runtime_requirements_in_tree(
           MakeNamespaceObjectRuntimeModule::default().boxed(),
         )?;
       }
+      RuntimeGlobals::MAKE_DEFERRED_NAMESPACE_OBJECT => {
+        compilation.add_runtime_module(
+          chunk_ukey,
+          MakeDeferredNamespaceObjectRuntimeModule::default().boxed(),
+        )?;
+      }
       RuntimeGlobals::COMPAT_GET_DEFAULT_EXPORT => {
         compilation.add_runtime_module(
           chunk_ukey,
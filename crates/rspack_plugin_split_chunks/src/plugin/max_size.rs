@@ -1,14 +1,14 @@
 use std::sync::LazyLock;
-use std::{borrow::Cow, hash::Hash};
+use std::{borrow::Cow, collections::VecDeque, hash::Hash};
 
 use rayon::prelude::*;
 use regex::Regex;
-use rspack_collections::{DatabaseItem, UkeyMap};
+use rspack_collections::{DatabaseItem, IdentifierMap, IdentifierSet, UkeyMap};
 use rspack_core::incremental::Mutation;
 use rspack_core::{
-  ChunkUkey, Compilation, CompilerOptions, Module, ModuleIdentifier, DEFAULT_DELIMITER,
+  ChunkUkey, Compilation, CompilerOptions, Module, ModuleIdentifier, SourceType, DEFAULT_DELIMITER,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_util::identifier::make_paths_relative;
 
@@ -76,66 +76,134 @@ fn request_to_id(req: &str) -> String {
   res
 }
 
-fn deterministic_grouping_for_modules(
+/// The module that can't be placed before the selected module in all chunk groups, and the
+/// selected module.
+type CssOrderConflict = (ModuleIdentifier, ModuleIdentifier);
+
+fn is_css_module(module: &dyn Module) -> bool {
+  module
+    .source_types()
+    .iter()
+    .any(|ty| matches!(ty, SourceType::Css | SourceType::CssImport))
+}
+
+/// Returns the CSS modules of the chunk in the order they are applied, which is merged from the
+/// orders of the chunk groups of the chunk, and the pairs of modules that the chunk groups order
+/// differently.
+fn get_css_modules_in_order(
   compilation: &Compilation,
   chunk: &ChunkUkey,
-  allow_max_size: &SplitChunkSizes,
-  min_size: &SplitChunkSizes,
-  delimiter: &str,
-) -> Vec<Group> {
-  let mut results: Vec<Group> = Default::default();
-  let module_graph = compilation.get_module_graph();
-  let items = compilation
-    .chunk_graph
-    .get_chunk_modules(chunk, &module_graph);
-  let context = compilation.options.context.as_ref();
+  modules: &[ModuleIdentifier],
+) -> (Vec<ModuleIdentifier>, Vec<CssOrderConflict>) {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+  let mut lists = chunk
+    .get_sorted_groups_iter(&compilation.chunk_group_by_ukey)
+    .map(|group| {
+      let group = compilation.chunk_group_by_ukey.expect_get(group);
+      let mut indexed_modules = modules
+        .iter()
+        .filter_map(|module| {
+          group
+            .module_post_order_index(module)
+            .map(|index| (index, *module))
+        })
+        .collect::<Vec<_>>();
+      // the imported modules are applied before the importing ones
+      indexed_modules.sort_by_key(|(index, _)| *index);
+      indexed_modules
+        .into_iter()
+        .map(|(_, module)| module)
+        .collect::<VecDeque<_>>()
+    })
+    .filter(|list| !list.is_empty())
+    .collect::<Vec<_>>();
 
-  let nodes = items.into_iter().map(|module| {
-    let module: &dyn Module = &**module;
-    let name: String = if let Some(name_for_condition) = module.name_for_condition() {
-      make_paths_relative(context, &name_for_condition)
-    } else {
-      let path = make_paths_relative(context, module.identifier().as_str());
-      REPLACE_MODULE_IDENTIFIER_REG
-        .replace_all(&path, "")
-        .to_string()
-    };
-    let key = format!(
-      "{}{}{}",
-      name,
-      delimiter,
-      hash_filename(&name, &compilation.options)
-    );
-    GroupItem {
-      module: module.identifier(),
-      size: get_size(module, compilation),
-      key: request_to_id(&key),
+  let mut ordered_modules = Vec::with_capacity(modules.len());
+  let mut conflicts = vec![];
+  while let Some(first) = lists.iter().find_map(|list| list.front().copied()) {
+    // a module can be selected when it's not after other modules in any chunk group
+    let selected = lists
+      .iter()
+      .filter_map(|list| list.front())
+      .copied()
+      .find(|module| {
+        lists
+          .iter()
+          .all(|list| list.front() == Some(module) || !list.contains(module))
+      })
+      .unwrap_or_else(|| {
+        if let Some(blocking) = lists
+          .iter()
+          .find(|list| list.front() != Some(&first) && list.contains(&first))
+          .and_then(|list| list.front())
+        {
+          conflicts.push((*blocking, first));
+        }
+        first
+      });
+    for list in &mut lists {
+      if let Some(index) = list.iter().position(|module| *module == selected) {
+        list.remove(index);
+      }
     }
-  });
+    lists.retain(|list| !list.is_empty());
+    ordered_modules.push(selected);
+  }
 
-  let mut initial_nodes = nodes
-    .into_iter()
-    .filter_map(|node| {
-      // The Module itself is already bigger than `allow_max_size`, we will create a chunk
-      // just for it.
-      if node.size.bigger_than(allow_max_size) && !node.size.smaller_than(min_size) {
-        tracing::trace!(
-          "Module({}) itself {:?} is already bigger than `allow_max_size` {:?}",
-          node.module,
-          node.size,
-          allow_max_size
-        );
-        let key = node.key.clone();
-        results.push(Group::new(vec![node], Some(key), vec![]));
-        None
-      } else {
-        Some(node)
-      }
-    })
-    .collect::<Vec<_>>();
+  // the modules that are not in any chunk group keep their original order
+  let ordered_set = ordered_modules.iter().copied().collect::<IdentifierSet>();
+  ordered_modules.extend(
+    modules
+      .iter()
+      .filter(|module| !ordered_set.contains(*module))
+      .copied(),
+  );
+  (ordered_modules, conflicts)
+}
 
-  initial_nodes.sort_by(|a, b| a.key.cmp(&b.key));
+fn order_conflict_diagnostic(
+  compilation: &Compilation,
+  chunk: &ChunkUkey,
+  (failed_module, selected_module): &CssOrderConflict,
+) -> Diagnostic {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+  let module_graph = compilation.get_module_graph();
+  let readable_identifier = |module: &ModuleIdentifier| {
+    module_graph
+      .module_by_identifier(module)
+      .map(|module| {
+        module
+          .readable_identifier(&compilation.options.context)
+          .to_string()
+      })
+      .unwrap_or_else(|| module.to_string())
+  };
+  Diagnostic::warn(
+    "Conflicting order".into(),
+    format!(
+      "chunk {}\nConflicting order between {} and {}, the chunks split by `maxSize` can't keep \
+       the order of the CSS modules in all chunk groups",
+      chunk
+        .name()
+        .or_else(|| chunk
+          .id(&compilation.chunk_ids_artifact)
+          .map(|id| id.as_str()))
+        .unwrap_or_default(),
+      readable_identifier(failed_module),
+      readable_identifier(selected_module)
+    ),
+  )
+  .with_chunk(Some(chunk.ukey().as_u32()))
+}
 
+/// Splits the nodes into consecutive groups which are not bigger than `allow_max_size` if possible,
+/// at the positions where the keys of the adjacent nodes are least similar.
+fn split_by_max_size(
+  initial_nodes: Vec<GroupItem>,
+  allow_max_size: &SplitChunkSizes,
+  min_size: &SplitChunkSizes,
+  results: &mut Vec<Group>,
+) {
   if !initial_nodes.is_empty() {
     let similarities = get_similarities(&initial_nodes);
     let initial_group = Group::new(initial_nodes, None, similarities);
@@ -235,11 +303,111 @@ fn deterministic_grouping_for_modules(
       }
     }
   }
+}
+
+fn deterministic_grouping_for_modules(
+  compilation: &Compilation,
+  chunk: &ChunkUkey,
+  allow_max_size: &SplitChunkSizes,
+  min_size: &SplitChunkSizes,
+  delimiter: &str,
+) -> (Vec<Group>, Vec<CssOrderConflict>) {
+  let mut results: Vec<Group> = Default::default();
+  let module_graph = compilation.get_module_graph();
+  let items = compilation
+    .chunk_graph
+    .get_chunk_modules(chunk, &module_graph);
+  let context = compilation.options.context.as_ref();
+
+  // The rules of CSS modules are applied in the order of the chunks, so the CSS modules keep
+  // their order and are only split into consecutive groups, instead of being grouped by path.
+  let mut css_modules = items
+    .iter()
+    .filter(|module| is_css_module(&***module))
+    .map(|module| module.identifier())
+    .collect::<Vec<_>>();
+  css_modules.sort_unstable();
+  let (css_order, conflicts) = if css_modules.is_empty() {
+    (None, vec![])
+  } else {
+    let (ordered_modules, conflicts) = get_css_modules_in_order(compilation, chunk, &css_modules);
+    let css_order = ordered_modules
+      .into_iter()
+      .enumerate()
+      .map(|(index, module)| (module, index))
+      .collect::<IdentifierMap<_>>();
+    (Some(css_order), conflicts)
+  };
+
+  let nodes = items.into_iter().map(|module| {
+    let module: &dyn Module = &**module;
+    let name: String = if let Some(name_for_condition) = module.name_for_condition() {
+      make_paths_relative(context, &name_for_condition)
+    } else {
+      let path = make_paths_relative(context, module.identifier().as_str());
+      REPLACE_MODULE_IDENTIFIER_REG
+        .replace_all(&path, "")
+        .to_string()
+    };
+    let key = format!(
+      "{}{}{}",
+      name,
+      delimiter,
+      hash_filename(&name, &compilation.options)
+    );
+    GroupItem {
+      module: module.identifier(),
+      size: get_size(module, compilation),
+      key: request_to_id(&key),
+    }
+  });
+
+  let (css_nodes, nodes): (Vec<_>, Vec<_>) = nodes.partition(|node| {
+    css_order
+      .as_ref()
+      .is_some_and(|css_order| css_order.contains_key(&node.module))
+  });
+
+  let mut initial_nodes = nodes
+    .into_iter()
+    .filter_map(|node| {
+      // The Module itself is already bigger than `allow_max_size`, we will create a chunk
+      // just for it.
+      if node.size.bigger_than(allow_max_size) && !node.size.smaller_than(min_size) {
+        tracing::trace!(
+          "Module({}) itself {:?} is already bigger than `allow_max_size` {:?}",
+          node.module,
+          node.size,
+          allow_max_size
+        );
+        let key = node.key.clone();
+        results.push(Group::new(vec![node], Some(key), vec![]));
+        None
+      } else {
+        Some(node)
+      }
+    })
+    .collect::<Vec<_>>();
+
+  initial_nodes.sort_by(|a, b| a.key.cmp(&b.key));
+
+  split_by_max_size(initial_nodes, allow_max_size, min_size, &mut results);
 
   // lexically ordering
   results.sort_unstable_by(|a, b| a.nodes[0].key.cmp(&b.nodes[0].key));
 
-  results
+  if let Some(css_order) = &css_order {
+    let mut css_nodes = css_nodes;
+    css_nodes.sort_by_key(|node| css_order.get(&node.module).copied());
+    let mut css_results = vec![];
+    split_by_max_size(css_nodes, allow_max_size, min_size, &mut css_results);
+    // the groups of CSS modules are already in order and placed before the other groups, as the
+    // new chunks are inserted before the original chunk in the same order
+    css_results.append(&mut results);
+    results = css_results;
+  }
+
+  (results, conflicts)
 }
 
 struct ChunkWithSizeInfo<'a> {
@@ -359,6 +527,7 @@ impl SplitChunksPlugin {
     .into_iter()
     .flatten();
 
+    let mut diagnostics = vec![];
     let infos_with_results = chunks_with_size_info
       .filter_map(|info| {
         let ChunkWithSizeInfo {
@@ -367,7 +536,7 @@ impl SplitChunksPlugin {
           min_size,
           automatic_name_delimiter,
         } = &info;
-        let results = deterministic_grouping_for_modules(
+        let (results, conflicts) = deterministic_grouping_for_modules(
           compilation_ref,
           chunk,
           allow_max_size,
@@ -383,9 +552,16 @@ impl SplitChunksPlugin {
           return None;
         }
 
+        diagnostics.extend(
+          conflicts
+            .iter()
+            .map(|conflict| order_conflict_diagnostic(compilation_ref, chunk, conflict)),
+        );
+
         Some((info, results))
       })
       .collect::<Vec<_>>();
+    compilation.extend_diagnostics(diagnostics);
 
     infos_with_results.into_iter().for_each(|(info, results)| {
       let last_index = results.len() - 1;
@@ -0,0 +1,95 @@
+mod virtual_fs;
+
+use std::sync::Arc;
+
+use rspack_core::{
+  ApplyContext, Compilation, CompilationParams, CompilerOptions, CompilerThisCompilation, Plugin,
+  PluginContext,
+};
+use rspack_error::Result;
+use rspack_fs::ReadableFileSystem;
+use rspack_hook::{plugin, plugin_hook};
+
+pub use self::virtual_fs::{VirtualFileSystem, VirtualModules};
+
+/// Provide modules whose source only lives in memory.
+///
+/// The input filesystem of compiler should be created by
+/// `VirtualModulesPlugin::input_filesystem` so that virtual modules can be
+/// resolved and read. After virtual modules are updated through
+/// `VirtualModulesPlugin::modules`, call `Compiler::rebuild` to rebuild them,
+/// the changed virtual modules are added to `modified_files` and `removed_files`
+/// of the next compilation. In watch mode, wait for `VirtualModules::changed`
+/// to know when to rebuild.
+///
+/// ```ignore
+/// let plugin = VirtualModulesPlugin::new(VirtualModules::default());
+/// let modules = plugin.modules();
+/// let input_filesystem = plugin.input_filesystem(Arc::new(NativeFileSystem));
+/// modules.write_module("/project/src/virtual.js", "export default 1");
+/// // create compiler with `plugin` and `input_filesystem` and build
+/// modules.write_module("/project/src/virtual.js", "export default 2");
+/// compiler.rebuild(Default::default(), Default::default()).await?;
+/// ```
+#[plugin]
+#[derive(Debug)]
+pub struct VirtualModulesPlugin {
+  modules: VirtualModules,
+}
+
+impl VirtualModulesPlugin {
+  pub fn new(modules: VirtualModules) -> Self {
+    Self::new_inner(modules)
+  }
+
+  /// The virtual modules store, which can be used to update virtual modules at runtime
+  pub fn modules(&self) -> VirtualModules {
+    self.modules.clone()
+  }
+
+  /// Create the input filesystem which reads virtual modules over the `base` filesystem
+  pub fn input_filesystem(
+    &self,
+    base: Arc<dyn ReadableFileSystem>,
+  ) -> Arc<dyn ReadableFileSystem + Send + Sync> {
+    Arc::new(VirtualFileSystem::new(base, self.modules.clone()))
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for VirtualModulesPlugin)]
+async fn this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let (modified_files, removed_files) = self.modules.take_changes();
+  // all modules are built in the first compilation, the virtual modules written before it are not
+  // changes of the compilation
+  if compilation.records.is_none() {
+    return Ok(());
+  }
+  compilation
+    .removed_files
+    .retain(|path| !modified_files.contains(path));
+  compilation
+    .modified_files
+    .retain(|path| !removed_files.contains(path));
+  compilation.modified_files.extend(modified_files);
+  compilation.removed_files.extend(removed_files);
+  Ok(())
+}
+
+impl Plugin for VirtualModulesPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.VirtualModulesPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .this_compilation
+      .tap(this_compilation::new(self));
+    Ok(())
+  }
+}
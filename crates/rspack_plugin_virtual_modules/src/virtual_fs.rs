@@ -0,0 +1,281 @@
+use std::{
+  pin::pin,
+  sync::{Arc, Mutex},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use rspack_fs::{FileMetadata, ReadableFileSystem, Result};
+use rspack_paths::{ArcPath, Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::Notify;
+
+fn current_time() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .expect("should get current time")
+    .as_millis() as u64
+}
+
+fn new_metadata(is_file: bool, size: u64, time: u64) -> FileMetadata {
+  FileMetadata {
+    is_file,
+    is_directory: !is_file,
+    is_symlink: false,
+    atime_ms: time,
+    mtime_ms: time,
+    ctime_ms: time,
+    size,
+  }
+}
+
+#[derive(Debug)]
+struct VirtualFile {
+  content: Vec<u8>,
+  metadata: FileMetadata,
+}
+
+#[derive(Debug, Default)]
+struct VirtualModulesInner {
+  files: HashMap<Utf8PathBuf, VirtualFile>,
+  /// Paths changed since the last compilation
+  modified: HashSet<Utf8PathBuf>,
+  removed: HashSet<Utf8PathBuf>,
+}
+
+/// The store of virtual modules shared by the plugin and the filesystem.
+///
+/// Virtual modules are files which only live in memory, their paths should be absolute.
+#[derive(Debug, Default, Clone)]
+pub struct VirtualModules {
+  inner: Arc<Mutex<VirtualModulesInner>>,
+  notify: Arc<Notify>,
+}
+
+impl VirtualModules {
+  /// Create or update a virtual module
+  pub fn write_module(&self, path: impl Into<Utf8PathBuf>, content: impl Into<Vec<u8>>) {
+    let path = path.into();
+    let content = content.into();
+    {
+      let mut inner = self.inner.lock().expect("should get lock");
+      let metadata = new_metadata(true, content.len() as u64, current_time());
+      inner.removed.remove(&path);
+      inner.modified.insert(path.clone());
+      inner.files.insert(path, VirtualFile { content, metadata });
+    }
+    self.notify.notify_waiters();
+  }
+
+  /// Remove a virtual module, return false if the module does not exist
+  pub fn remove_module(&self, path: &Utf8Path) -> bool {
+    {
+      let mut inner = self.inner.lock().expect("should get lock");
+      if inner.files.remove(path).is_none() {
+        return false;
+      }
+      inner.modified.remove(path);
+      inner.removed.insert(path.to_path_buf());
+    }
+    self.notify.notify_waiters();
+    true
+  }
+
+  pub fn contains(&self, path: &Utf8Path) -> bool {
+    let inner = self.inner.lock().expect("should get lock");
+    inner.files.contains_key(path)
+  }
+
+  /// Wait until there are virtual modules written or removed since the last compilation,
+  /// it can be used to trigger a rebuild in watch mode.
+  ///
+  /// It returns immediately if the changes are not taken by a compilation yet, so the changes made
+  /// while the previous compilation is running are not missed.
+  pub async fn changed(&self) {
+    loop {
+      let mut notified = pin!(self.notify.notified());
+      // register before checking the changes, so that a change made in between wakes it up
+      notified.as_mut().enable();
+      if self.has_changes() {
+        return;
+      }
+      notified.await;
+    }
+  }
+
+  fn has_changes(&self) -> bool {
+    let inner = self.inner.lock().expect("should get lock");
+    !inner.modified.is_empty() || !inner.removed.is_empty()
+  }
+
+  /// Take the paths modified and removed since the last call
+  pub fn take_changes(&self) -> (HashSet<ArcPath>, HashSet<ArcPath>) {
+    let mut inner = self.inner.lock().expect("should get lock");
+    let modified = std::mem::take(&mut inner.modified)
+      .into_iter()
+      .map(|path| ArcPath::from(path.as_std_path()))
+      .collect();
+    let removed = std::mem::take(&mut inner.removed)
+      .into_iter()
+      .map(|path| ArcPath::from(path.as_std_path()))
+      .collect();
+    (modified, removed)
+  }
+
+  fn read(&self, path: &Utf8Path) -> Option<Vec<u8>> {
+    let inner = self.inner.lock().expect("should get lock");
+    inner.files.get(path).map(|file| file.content.clone())
+  }
+
+  /// Get the metadata of a virtual module or a directory containing virtual modules
+  fn metadata(&self, path: &Utf8Path) -> Option<FileMetadata> {
+    let inner = self.inner.lock().expect("should get lock");
+    if let Some(file) = inner.files.get(path) {
+      return Some(file.metadata.clone());
+    }
+    inner
+      .files
+      .iter()
+      .filter(|(file_path, _)| file_path.starts_with(path))
+      .map(|(_, file)| file.metadata.mtime_ms)
+      .max()
+      .map(|time| new_metadata(false, 0, time))
+  }
+}
+
+/// Input filesystem which reads virtual modules first and falls back to the base filesystem.
+///
+/// Directories containing virtual modules are considered to exist, so that the
+/// resolver can find virtual modules in directories not existing on disk.
+#[derive(Debug)]
+pub struct VirtualFileSystem {
+  base: Arc<dyn ReadableFileSystem>,
+  modules: VirtualModules,
+}
+
+impl VirtualFileSystem {
+  pub fn new(base: Arc<dyn ReadableFileSystem>, modules: VirtualModules) -> Self {
+    Self { base, modules }
+  }
+}
+
+#[async_trait::async_trait]
+impl ReadableFileSystem for VirtualFileSystem {
+  fn read(&self, path: &Utf8Path) -> Result<Vec<u8>> {
+    match self.modules.read(path) {
+      Some(content) => Ok(content),
+      None => self.base.read(path),
+    }
+  }
+
+  fn metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    // virtual modules take precedence over files on disk
+    if self.modules.contains(path) {
+      if let Some(metadata) = self.modules.metadata(path) {
+        return Ok(metadata);
+      }
+    }
+    self
+      .base
+      .metadata(path)
+      .or_else(|err| self.modules.metadata(path).ok_or(err))
+  }
+
+  fn symlink_metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    if self.modules.contains(path) {
+      return self.metadata(path);
+    }
+    self
+      .base
+      .symlink_metadata(path)
+      .or_else(|err| self.modules.metadata(path).ok_or(err))
+  }
+
+  fn canonicalize(&self, path: &Utf8Path) -> Result<Utf8PathBuf> {
+    self.base.canonicalize(path).or_else(|err| {
+      self
+        .modules
+        .metadata(path)
+        .map(|_| path.to_path_buf())
+        .ok_or(err)
+    })
+  }
+
+  async fn async_read(&self, file: &Utf8Path) -> Result<Vec<u8>> {
+    match self.modules.read(file) {
+      Some(content) => Ok(content),
+      None => self.base.async_read(file).await,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{sync::Arc, time::Duration};
+
+  use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
+  use rspack_paths::Utf8Path;
+
+  use super::{VirtualFileSystem, VirtualModules};
+
+  #[test]
+  fn should_read_virtual_modules() {
+    let modules = VirtualModules::default();
+    let fs = VirtualFileSystem::new(Arc::new(MemoryFileSystem::default()), modules.clone());
+
+    modules.write_module("/src/virtual/a.js", "export default 1");
+    assert_eq!(
+      fs.read(Utf8Path::new("/src/virtual/a.js")).unwrap(),
+      "export default 1".as_bytes()
+    );
+    assert!(
+      fs.metadata(Utf8Path::new("/src/virtual/a.js"))
+        .unwrap()
+        .is_file
+    );
+    assert!(
+      fs.metadata(Utf8Path::new("/src/virtual"))
+        .unwrap()
+        .is_directory
+    );
+    assert!(fs.metadata(Utf8Path::new("/src/virtual/b.js")).is_err());
+
+    let (modified, removed) = modules.take_changes();
+    assert_eq!(modified.len(), 1);
+    assert!(removed.is_empty());
+
+    assert!(modules.remove_module(Utf8Path::new("/src/virtual/a.js")));
+    assert!(fs.read(Utf8Path::new("/src/virtual/a.js")).is_err());
+    assert!(fs.metadata(Utf8Path::new("/src/virtual")).is_err());
+    let (modified, removed) = modules.take_changes();
+    assert!(modified.is_empty());
+    assert_eq!(removed.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn should_wait_for_changes_not_built() {
+    let modules = VirtualModules::default();
+    let wait = || tokio::time::timeout(Duration::from_millis(100), modules.changed());
+
+    assert!(wait().await.is_err());
+
+    // nothing is waiting when the module is written, the change should not be lost
+    modules.write_module("/src/virtual/a.js", "export default 1");
+    assert!(wait().await.is_ok());
+    assert!(wait().await.is_ok());
+
+    // the changes are taken by a compilation
+    modules.take_changes();
+    assert!(wait().await.is_err());
+
+    let waiting = {
+      let modules = modules.clone();
+      tokio::spawn(async move { modules.changed().await })
+    };
+    tokio::task::yield_now().await;
+    assert!(modules.remove_module(Utf8Path::new("/src/virtual/a.js")));
+    tokio::time::timeout(Duration::from_millis(100), waiting)
+      .await
+      .expect("should be notified")
+      .expect("should not panic");
+  }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use rspack_core::{Compiler, CompilerOptions, EntryOptions, Mode, PluginExt};
+use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
+use rspack_paths::Utf8Path;
+use rspack_plugin_entry::EntryPlugin;
+use rspack_plugin_javascript::JsPlugin;
+use rspack_plugin_runtime::RuntimePlugin;
+use rspack_plugin_virtual_modules::{VirtualModules, VirtualModulesPlugin};
+
+fn create_compiler(modules: VirtualModules, output_fs: Arc<MemoryFileSystem>) -> Compiler {
+  let options = CompilerOptions::builder()
+    .context("/project")
+    .mode(Mode::Development)
+    .build();
+  let plugin = VirtualModulesPlugin::new(modules);
+  let input_fs = plugin.input_filesystem(Arc::new(MemoryFileSystem::default()));
+  let plugins = vec![
+    plugin.boxed(),
+    EntryPlugin::new(
+      "/project".into(),
+      "./src/index.js".to_string(),
+      EntryOptions {
+        name: Some("main".to_string()),
+        ..Default::default()
+      },
+    )
+    .boxed(),
+    JsPlugin::default().boxed(),
+    RuntimePlugin::default().boxed(),
+  ];
+  Compiler::new(
+    String::new(),
+    options,
+    plugins,
+    vec![],
+    Some(output_fs),
+    Some(Arc::new(MemoryFileSystem::default())),
+    Some(input_fs),
+    None,
+    None,
+  )
+}
+
+fn read_output(fs: &MemoryFileSystem) -> String {
+  let content = ReadableFileSystem::read(fs, Utf8Path::new("/project/dist/main.js"))
+    .expect("should emit main.js");
+  String::from_utf8(content).expect("should be utf8")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_rebuild_written_virtual_modules() {
+  let modules = VirtualModules::default();
+  modules.write_module(
+    "/project/src/index.js",
+    "import value from './virtual.js';\nconsole.log(value);",
+  );
+  modules.write_module("/project/src/virtual.js", "export default 'virtual_first';");
+  let output_fs = Arc::new(MemoryFileSystem::default());
+  let mut compiler = create_compiler(modules.clone(), output_fs.clone());
+
+  compiler.build().await.expect("should build");
+  assert_eq!(compiler.compilation.get_errors().count(), 0);
+  assert!(read_output(&output_fs).contains("virtual_first"));
+
+  modules.write_module(
+    "/project/src/virtual.js",
+    "export default 'virtual_second';",
+  );
+  compiler
+    .rebuild(Default::default(), Default::default())
+    .await
+    .expect("should rebuild");
+  assert_eq!(compiler.compilation.get_errors().count(), 0);
+  let output = read_output(&output_fs);
+  assert!(output.contains("virtual_second"));
+  assert!(!output.contains("virtual_first"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_rebuild_removed_virtual_modules() {
+  let modules = VirtualModules::default();
+  modules.write_module(
+    "/project/src/index.js",
+    "import value from './virtual.js';\nconsole.log(value);",
+  );
+  modules.write_module("/project/src/virtual.js", "export default 'virtual_first';");
+  let output_fs = Arc::new(MemoryFileSystem::default());
+  let mut compiler = create_compiler(modules.clone(), output_fs.clone());
+
+  compiler.build().await.expect("should build");
+  assert_eq!(compiler.compilation.get_errors().count(), 0);
+
+  assert!(modules.remove_module(Utf8Path::new("/project/src/virtual.js")));
+  compiler
+    .rebuild(Default::default(), Default::default())
+    .await
+    .expect("should rebuild");
+  assert!(compiler
+    .compilation
+    .get_errors()
+    .any(|error| error.message().contains("./virtual.js")));
+  assert!(!read_output(&output_fs).contains("virtual_first"));
+}
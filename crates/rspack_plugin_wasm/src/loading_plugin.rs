@@ -28,7 +28,9 @@ fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if runtime_requirements
+    .intersects(RuntimeGlobals::INSTANTIATE_WASM | RuntimeGlobals::COMPILE_WASM)
+  {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
       chunk_ukey,
@@ -36,6 +38,7 @@ fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
         format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
         true,
         *chunk_ukey,
+        *runtime_requirements,
       )
       .boxed(),
     )?;
@@ -84,7 +87,9 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if runtime_requirements
+    .intersects(RuntimeGlobals::INSTANTIATE_WASM | RuntimeGlobals::COMPILE_WASM)
+  {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
       chunk_ukey,
@@ -96,6 +101,7 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
         },
         false,
         *chunk_ukey,
+        *runtime_requirements,
       )
       .boxed(),
     )?;
@@ -103,6 +103,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
         code_generation_dependencies: vec![],
         source,
         side_effects_bailout: None,
+        optimization_bailouts: vec![],
       }
       .with_diagnostic(diagnostic),
     )
@@ -326,7 +327,7 @@ fn render_import_stmt(import_var: &str, module_id: &ModuleId) -> String {
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
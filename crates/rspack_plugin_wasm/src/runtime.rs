@@ -1,12 +1,14 @@
 use cow_utils::CowUtils;
-use rspack_collections::Identifier;
+use rspack_collections::{Identifier, IdentifierSet};
 use rspack_core::rspack_sources::{BoxSource, RawStringSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, ChunkGraph, ChunkUkey, Compilation,
+  ModuleType, NormalModuleSource, PathData, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
 };
 use rspack_util::infallible::ResultInfallibleExt as _;
-use rspack_util::itoa;
+use rspack_util::{itoa, json_stringify};
+
+use crate::hash_for_source;
 
 #[impl_runtime_module]
 #[derive(Debug)]
@@ -15,6 +17,7 @@ pub struct AsyncWasmLoadingRuntimeModule {
   generate_load_binary_code: String,
   supports_streaming: bool,
   chunk: ChunkUkey,
+  runtime_requirements: RuntimeGlobals,
 }
 
 impl AsyncWasmLoadingRuntimeModule {
@@ -22,16 +25,65 @@ impl AsyncWasmLoadingRuntimeModule {
     generate_load_binary_code: String,
     supports_streaming: bool,
     chunk: ChunkUkey,
+    runtime_requirements: RuntimeGlobals,
   ) -> Self {
     Self::with_default(
       Identifier::from("webpack/runtime/async_wasm_loading"),
       generate_load_binary_code,
       supports_streaming,
       chunk,
+      runtime_requirements,
     )
   }
 }
 
+/// Content hashes of the wasm modules in the chunks referenced by the chunk that are imported by
+/// `import source`, keyed by module ids
+fn get_source_phase_wasm_hashes(compilation: &Compilation, chunk: &ChunkUkey) -> String {
+  let module_graph = compilation.get_module_graph();
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+  let mut modules = IdentifierSet::default();
+  for chunk in chunk.get_all_referenced_chunks(&compilation.chunk_group_by_ukey) {
+    modules.extend(
+      compilation
+        .chunk_graph
+        .get_chunk_modules_identifier(&chunk)
+        .iter()
+        .copied(),
+    );
+  }
+  let mut hashes = modules
+    .into_iter()
+    .filter_map(|identifier| {
+      let module = module_graph.module_by_identifier(&identifier)?;
+      if *module.module_type() != ModuleType::WasmAsync {
+        return None;
+      }
+      let is_source_phase = module_graph
+        .get_incoming_connections(&identifier)
+        .any(|connection| {
+          module_graph
+            .dependency_by_id(&connection.dependency_id)
+            .is_some_and(|dep| dep.get_phase().is_source())
+        });
+      if !is_source_phase {
+        return None;
+      }
+      let module_id = ChunkGraph::get_module_id(&compilation.module_ids_artifact, identifier)?;
+      let NormalModuleSource::BuiltSucceed(source) = module.as_normal_module()?.source() else {
+        return None;
+      };
+      Some(format!(
+        "{}: {}",
+        json_stringify(module_id),
+        json_stringify(&hash_for_source(source))
+      ))
+    })
+    .collect::<Vec<_>>();
+  hashes.sort();
+  format!("{{\n{}\n}}", hashes.join(",\n"))
+}
+
 impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
   fn name(&self) -> Identifier {
     self.id
@@ -60,19 +112,32 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
           .runtime(chunk.runtime().as_str()),
       )
       .always_ok();
-    Ok(
-      RawStringSource::from(get_async_wasm_loading(
-        &self
-          .generate_load_binary_code
-          .cow_replace("$PATH", &format!("\"{}\"", path))
-          .cow_replace(
-            "$IMPORT_META_NAME",
-            compilation.options.output.import_meta_name.as_str(),
-          ),
+    let req = self
+      .generate_load_binary_code
+      .cow_replace("$PATH", &format!("\"{}\"", path))
+      .cow_replace(
+        "$IMPORT_META_NAME",
+        compilation.options.output.import_meta_name.as_str(),
+      )
+      .into_owned();
+    let mut source = String::new();
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::INSTANTIATE_WASM)
+    {
+      source.push_str(&get_async_wasm_loading(&req, self.supports_streaming));
+    }
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::COMPILE_WASM)
+    {
+      source.push_str(&get_async_wasm_compiling(
+        &req,
         self.supports_streaming,
-      ))
-      .boxed(),
-    )
+        &get_source_phase_wasm_hashes(compilation, &self.chunk),
+      ));
+    }
+    Ok(RawStringSource::from(source).boxed())
   }
 
   fn stage(&self) -> RuntimeModuleStage {
@@ -129,3 +194,53 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     )
   }
 }
+
+fn get_async_wasm_compiling(req: &str, supports_streaming: bool, hashes: &str) -> String {
+  let fallback_code = r#"
+          .then(function(x) { return x.arrayBuffer();})
+          .then(function(bytes) { return WebAssembly.compile(bytes);});
+"#;
+
+  let streaming_code = r#"
+      return req.then(function(res) {
+        if (typeof WebAssembly.compileStreaming === "function") {
+          return WebAssembly.compileStreaming(res)
+            .catch(function(e) {
+              if(res.headers.get("Content-Type") !== "application/wasm") {
+                console.warn("`WebAssembly.compileStreaming` failed because your server does not serve wasm with `application/wasm` MIME type. Falling back to `WebAssembly.compile` which is slower. Original error:\n", e);
+                return fallback();
+              }
+              throw e;
+            });
+        }
+        return fallback();
+      });
+"#;
+
+  if supports_streaming {
+    format!(
+      r#"
+    var wasmModuleHashes = {hashes};
+    __webpack_require__.vs = function(wasmModuleId) {{
+      var wasmModuleHash = wasmModuleHashes[wasmModuleId];
+      var req = {req};
+      var fallback = function() {{
+        return req{fallback_code}
+      }}
+      {streaming_code}
+    }};
+"#
+    )
+  } else {
+    let req = req.trim_end_matches(';');
+    format!(
+      r#"
+    var wasmModuleHashes = {hashes};
+    __webpack_require__.vs = function(wasmModuleId) {{
+      var wasmModuleHash = wasmModuleHashes[wasmModuleId];
+      return {req}{fallback_code}
+    }};
+      "#
+    )
+  }
+}
@@ -62,6 +62,15 @@ impl FSError {
     lower_case_error_content.contains("no such file")
       || lower_case_error_content.contains("file not exists")
   }
+  pub fn is_already_exists(&self) -> bool {
+    if matches!(self.kind(), ErrorKind::AlreadyExists) {
+      return true;
+    }
+    let error_content = self.inner.to_string();
+    let lower_case_error_content = error_content.cow_to_lowercase();
+    lower_case_error_content.contains("eexist")
+      || lower_case_error_content.contains("already exists")
+  }
   pub fn kind(&self) -> ErrorKind {
     match &self.inner {
       rspack_fs::Error::Io(e) => e.kind(),
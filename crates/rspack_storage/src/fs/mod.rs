@@ -19,6 +19,8 @@ pub trait FileSystem: std::fmt::Debug + Sync + Send {
   async fn metadata(&self, path: &Utf8Path) -> FSResult<FileMetadata>;
   async fn remove_file(&self, path: &Utf8Path) -> FSResult<()>;
   async fn move_file(&self, from: &Utf8Path, to: &Utf8Path) -> FSResult<()>;
+  /// Create a file with the data atomically, fail if the file exists
+  async fn create_new_file(&self, path: &Utf8Path, data: &[u8]) -> FSResult<()>;
 }
 
 #[derive(Debug)]
@@ -228,6 +230,17 @@ impl FileSystem for BridgeFileSystem {
     }
     Ok(())
   }
+
+  async fn create_new_file(&self, path: &Utf8Path, data: &[u8]) -> FSResult<()> {
+    self
+      .ensure_dir(path.parent().expect("should have parent"))
+      .await?;
+    self
+      .0
+      .create_new_file(path, data)
+      .await
+      .map_err(|e| FSError::from_fs_error(path, FSOperation::Write, e))
+  }
 }
 
 #[cfg(test)]
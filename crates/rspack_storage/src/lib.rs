@@ -1,12 +1,18 @@
 mod error;
 mod fs;
 mod pack;
+mod remote;
 
 use std::sync::Arc;
 
-pub use error::Result;
+pub use error::{Error, Result};
 pub use fs::{BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, Reader, Writer};
-pub use pack::{PackStorage, PackStorageOptions};
+pub use pack::{
+  PackCompression, PackInfo, PackStorage, PackStorageOptions, ScopeInfo, StorageInfo, VersionInfo,
+};
+pub use remote::{
+  HttpRemoteStore, HttpRemoteStoreOptions, RemoteStorage, RemoteStorageOptions, RemoteStore,
+};
 use tokio::sync::oneshot::Receiver;
 
 type ItemKey = Vec<u8>;
@@ -19,6 +25,10 @@ pub trait Storage: std::fmt::Debug + Sync + Send {
   fn set(&self, scope: &'static str, key: Vec<u8>, value: Vec<u8>);
   fn remove(&self, scope: &'static str, key: &[u8]);
   fn trigger_save(&self) -> Result<Receiver<Result<()>>>;
+  /// Take the errors which don't break the storage, such as failing to pull from a remote store
+  fn take_warnings(&self) -> Vec<Error> {
+    vec![]
+  }
 }
 
 pub type ArcStorage = Arc<dyn Storage>;
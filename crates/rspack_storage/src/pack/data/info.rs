@@ -0,0 +1,89 @@
+use rspack_paths::Utf8PathBuf;
+
+use super::current_time;
+
+/// Pack file recorded in scope meta
+#[derive(Debug, Clone)]
+pub struct PackInfo {
+  pub bucket: usize,
+  pub path: Utf8PathBuf,
+  pub hash: String,
+  pub size: usize,
+  pub generation: usize,
+  /// Whether the pack file matches the hash in scope meta,
+  /// `None` if the pack is not verified
+  pub valid: Option<bool>,
+}
+
+/// Scope status read from scope meta and scope directory
+#[derive(Debug, Clone)]
+pub struct ScopeInfo {
+  pub name: String,
+  pub path: Utf8PathBuf,
+  pub bucket_size: usize,
+  pub pack_size: usize,
+  pub generation: usize,
+  pub packs: Vec<PackInfo>,
+  /// Files in scope directory which are not referenced by scope meta
+  pub unused_files: Vec<Utf8PathBuf>,
+  pub unused_size: u64,
+}
+
+impl ScopeInfo {
+  pub fn size(&self) -> usize {
+    self.packs.iter().map(|pack| pack.size).sum()
+  }
+
+  pub fn invalid_packs(&self) -> impl Iterator<Item = &PackInfo> {
+    self.packs.iter().filter(|pack| pack.valid == Some(false))
+  }
+
+  /// Packs which have not been rewritten for more than `generations` saves
+  pub fn stale_packs(&self, generations: usize) -> impl Iterator<Item = &PackInfo> {
+    self
+      .packs
+      .iter()
+      .filter(move |pack| self.generation.saturating_sub(pack.generation) > generations)
+  }
+}
+
+/// Other version directory under the cache root
+#[derive(Debug, Clone)]
+pub struct VersionInfo {
+  pub name: String,
+  pub path: Utf8PathBuf,
+  pub size: u64,
+  /// `None` if the directory has no root meta
+  pub expire_time: Option<u64>,
+  /// The last time the version was loaded, `None` if it's not recorded
+  pub access_time: Option<u64>,
+}
+
+impl VersionInfo {
+  pub fn is_expired(&self) -> bool {
+    self
+      .expire_time
+      .is_none_or(|expire_time| current_time() > expire_time)
+  }
+}
+
+/// Storage status of current version
+#[derive(Debug, Clone)]
+pub struct StorageInfo {
+  pub path: Utf8PathBuf,
+  pub expire_time: u64,
+  pub scopes: Vec<ScopeInfo>,
+  /// Directories in storage root which are not recorded in root meta
+  pub unused_scopes: Vec<Utf8PathBuf>,
+  pub versions: Vec<VersionInfo>,
+}
+
+impl StorageInfo {
+  pub fn is_expired(&self) -> bool {
+    current_time() > self.expire_time
+  }
+
+  pub fn size(&self) -> usize {
+    self.scopes.iter().map(|scope| scope.size()).sum()
+  }
+}
@@ -39,6 +39,10 @@ impl RootMeta {
   pub fn get_path(dir: &Utf8Path) -> Utf8PathBuf {
     dir.join("storage_meta")
   }
+  /// The file recording the last time the version was loaded
+  pub fn get_access_path(dir: &Utf8Path) -> Utf8PathBuf {
+    dir.join(".storage_access")
+  }
 }
 
 pub fn current_time() -> u64 {
@@ -1,9 +1,11 @@
+mod info;
 mod meta;
 mod options;
 mod pack;
 mod scope;
 
+pub use info::{PackInfo, ScopeInfo, StorageInfo, VersionInfo};
 pub use meta::{current_time, PackFileMeta, RootMeta, RootMetaFrom, ScopeMeta};
-pub use options::{PackOptions, RootOptions};
+pub use options::{PackCompression, PackOptions, RootOptions};
 pub use pack::{Pack, PackContents, PackGenerations, PackKeys};
 pub use scope::{PackScope, RootMetaState};
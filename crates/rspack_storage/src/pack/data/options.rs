@@ -1,9 +1,22 @@
 use rspack_paths::Utf8PathBuf;
 
+/// Compression of pack contents
+///
+/// Pack files are self-described, so packs written with different compressions
+/// can be read by the same storage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PackCompression {
+  #[default]
+  None,
+  /// Deflate with the compression level from 0 to 10
+  Deflate(u8),
+}
+
 #[derive(Debug)]
 pub struct PackOptions {
   pub bucket_size: usize,
   pub pack_size: usize,
+  pub compression: PackCompression,
 }
 
 #[derive(Debug)]
@@ -11,4 +24,8 @@ pub struct RootOptions {
   pub root: Utf8PathBuf,
   pub expire: u64,
   pub clean: bool,
+  /// The max size of all versions in root, when exceeded the least recently
+  /// used versions are removed first, and then the least recently written
+  /// packs of current version
+  pub max_size: Option<u64>,
 }
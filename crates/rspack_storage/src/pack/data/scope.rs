@@ -95,7 +95,7 @@ impl ScopePacksState {
 
 #[derive(Debug)]
 pub struct PackScope {
-  pub name: &'static str,
+  pub name: String,
   pub path: Utf8PathBuf,
   pub options: Arc<PackOptions>,
   pub meta: ScopeMetaState,
@@ -104,9 +104,9 @@ pub struct PackScope {
 }
 
 impl PackScope {
-  pub fn new(name: &'static str, path: Utf8PathBuf, options: Arc<PackOptions>) -> Self {
+  pub fn new(name: &str, path: Utf8PathBuf, options: Arc<PackOptions>) -> Self {
     Self {
-      name,
+      name: name.to_string(),
       path,
       options,
       meta: ScopeMetaState::Pending,
@@ -115,7 +115,7 @@ impl PackScope {
     }
   }
 
-  pub fn empty(name: &'static str, path: Utf8PathBuf, options: Arc<PackOptions>) -> Self {
+  pub fn empty(name: &str, path: Utf8PathBuf, options: Arc<PackOptions>) -> Self {
     let mut scope = Self::new(name, path, options);
     scope.clear();
     scope
@@ -1,15 +1,19 @@
 mod queue;
 
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use futures::future::join_all;
+use itertools::Itertools;
 use pollster::block_on;
 use queue::TaskQueue;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tokio::sync::oneshot::Receiver;
 use tokio::sync::{oneshot, Mutex};
 
-use super::data::{PackOptions, PackScope, RootMeta, RootMetaState, RootOptions};
+use super::data::{
+  PackOptions, PackScope, RootMeta, RootMetaState, RootOptions, ScopeInfo, StorageInfo,
+};
 use super::strategy::{ScopeStrategy, WriteScopeResult};
 use super::ScopeUpdates;
 use crate::error::{Error, ErrorType, ValidateResult};
@@ -44,6 +48,12 @@ impl ScopeManager {
   }
 
   pub fn save(&self, updates: ScopeUpdates) -> Result<Receiver<Result<()>>> {
+    self.save_with_clean(updates, self.root_options.clean)
+  }
+
+  /// Save the updates and remove unused files if `clean` is enabled, the files are
+  /// removed while the write lock is still held
+  fn save_with_clean(&self, updates: ScopeUpdates, clean: bool) -> Result<Receiver<Result<()>>> {
     let pack_options = self.pack_options.clone();
     let strategy = self.strategy.clone();
     let scopes = self.scopes.clone();
@@ -76,7 +86,16 @@ impl ScopeManager {
     let strategy = self.strategy.clone();
     let scopes = self.scopes.clone();
     let root_meta = self.root_meta.clone();
-    let root_options = self.root_options.clone();
+    let root_options = if clean == self.root_options.clean {
+      self.root_options.clone()
+    } else {
+      Arc::new(RootOptions {
+        root: self.root_options.root.clone(),
+        expire: self.root_options.expire,
+        clean,
+        max_size: self.root_options.max_size,
+      })
+    };
     let (tx, rx) = oneshot::channel();
     self.queue.add_task(Box::pin(async move {
       let mut scopes_lock = scopes.lock().await;
@@ -110,7 +129,7 @@ impl ScopeManager {
       .clear();
   }
 
-  pub async fn load(&self, name: &'static str) -> Result<ItemPairs> {
+  pub async fn load(&self, name: &str) -> Result<ItemPairs> {
     self
       .scopes
       .lock()
@@ -175,7 +194,92 @@ impl ScopeManager {
     }
   }
 
-  async fn validate_scope(&self, name: &'static str) -> Result<ValidateResult> {
+  /// Read the status of all scopes recorded in root meta without loading them,
+  /// pack hashes are checked if `verify` is enabled.
+  pub async fn inspect(&self, verify: bool) -> Result<Option<StorageInfo>> {
+    let Some(root_meta) = self.strategy.read_root_meta().await? else {
+      return Ok(None);
+    };
+
+    let mut scopes: Vec<ScopeInfo> = vec![];
+    for name in root_meta
+      .scopes
+      .iter()
+      .filter(|name| !name.is_empty())
+      .sorted()
+    {
+      let mut scope = PackScope::new(
+        name,
+        self.strategy.get_path(name),
+        self.pack_options.clone(),
+      );
+      scopes.push(self.strategy.inspect_scope(&mut scope, verify).await?);
+    }
+
+    Ok(Some(
+      self
+        .strategy
+        .inspect_root(&root_meta, scopes, &self.root_options)
+        .await?,
+    ))
+  }
+
+  /// Rewrite all scopes recorded in root meta into full packs and remove
+  /// unused files, invalid scopes are dropped.
+  ///
+  /// Each scope is rewritten with the bucket size and pack size in its own meta,
+  /// so that the scopes saved with different options are kept.
+  pub async fn compact(&self) -> Result<()> {
+    let Some(info) = self.inspect(false).await? else {
+      return Ok(());
+    };
+
+    let mut updates = ScopeUpdates::default();
+    for scope in info.scopes {
+      let options = Arc::new(PackOptions {
+        bucket_size: scope.bucket_size,
+        pack_size: scope.pack_size,
+        compression: self.pack_options.compression,
+      });
+      // validate the scope with its own options
+      self.scopes.lock().await.insert(
+        scope.name.clone(),
+        PackScope::new(
+          &scope.name,
+          self.strategy.get_path(&scope.name),
+          options.clone(),
+        ),
+      );
+      let items = match self.load(&scope.name).await {
+        Ok(items) => items,
+        Err(e) if e.is_validate() => vec![],
+        Err(e) => return Err(e),
+      };
+      // write all items to an empty scope so that all packs are recreated
+      self.scopes.lock().await.insert(
+        scope.name.clone(),
+        PackScope::empty(&scope.name, self.strategy.get_path(&scope.name), options),
+      );
+      updates.insert(
+        Cow::Owned(scope.name),
+        items
+          .into_iter()
+          .map(|(key, value)| (key.as_ref().clone(), Some(value.as_ref().clone())))
+          .collect(),
+      );
+    }
+
+    // replaced pack files should be removed even if `clean` is disabled
+    self.save_with_clean(updates, true)?.await.map_err(|e| {
+      Error::from_reason(
+        Some(ErrorType::Save),
+        None,
+        format!("compact has been canceled: {e}"),
+      )
+    })?
+  }
+
+  async fn validate_scope(&self, name: &str) -> Result<ValidateResult> {
     let root_meta_guard = self.root_meta.lock().await;
     // no root, no scope
     let Some(root_meta) = root_meta_guard.expect_value() else {
@@ -251,7 +355,22 @@ async fn save_scopes(
 ) -> Result<ScopeMap> {
   scopes.retain(|_, scope| scope.loaded());
 
-  strategy.before_all(&mut scopes).await?;
+  let res = write_scopes(&mut scopes, root_meta, strategy, root_options).await;
+  // release the write lock even if saving failed,
+  // otherwise other processes have to wait until it is stale
+  strategy.release_lock().await?;
+  res?;
+
+  Ok(scopes.into_iter().collect())
+}
+
+async fn write_scopes(
+  scopes: &mut ScopeMap,
+  root_meta: &RootMeta,
+  strategy: &dyn ScopeStrategy,
+  root_options: &RootOptions,
+) -> Result<()> {
+  strategy.before_all(scopes).await?;
 
   join_all(
     scopes
@@ -262,6 +381,8 @@ async fn save_scopes(
   .into_iter()
   .collect::<Result<Vec<_>>>()?;
 
+  strategy.evict(scopes, root_options).await?;
+
   let changed = join_all(scopes.values_mut().map(|scope| async move {
     let mut res = WriteScopeResult::default();
     if scope.loaded() {
@@ -279,12 +400,18 @@ async fn save_scopes(
     acc
   });
 
-  strategy.write_root_meta(root_meta).await?;
+  // the lock may be taken over by another process when this process is paused,
+  // files are only published while it is held
+  strategy.check_lock().await?;
   strategy.merge_changed(changed).await?;
-  strategy.after_all(&mut scopes).await?;
-  strategy.clean(root_meta, &scopes, root_options).await?;
+  // root meta is written at last, scopes are not visible to other processes
+  // until all their files are ready
+  strategy.check_lock().await?;
+  strategy.write_root_meta(root_meta).await?;
+  strategy.after_all(scopes).await?;
+  strategy.clean(root_meta, scopes, root_options).await?;
 
-  Ok(scopes.into_iter().collect())
+  Ok(())
 }
 
 #[cfg(test)]
@@ -298,7 +425,7 @@ mod tests {
   use crate::{
     error::Result,
     pack::{
-      data::{PackOptions, RootOptions},
+      data::{PackCompression, PackOptions, RootOptions},
       manager::ScopeManager,
       strategy::SplitPackStrategy,
     },
@@ -336,10 +463,12 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 500,
+      compression: PackCompression::None,
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -348,6 +477,7 @@ mod tests {
       fs.clone(),
       Some(1),
       Some(2),
+      1000,
     ));
     let manager = ScopeManager::new(root_options, pack_options, strategy);
 
@@ -392,10 +522,12 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 500,
+      compression: PackCompression::None,
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -404,6 +536,7 @@ mod tests {
       fs.clone(),
       Some(1),
       Some(2),
+      1000,
     ));
     let manager = ScopeManager::new(root_options, pack_options, strategy);
 
@@ -476,11 +609,13 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       // different bucket size
       bucket_size: 100,
       pack_size: 500,
+      compression: PackCompression::None,
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -489,6 +624,7 @@ mod tests {
       fs.clone(),
       Some(1),
       Some(2),
+      1000,
     ));
     let manager = ScopeManager::new(root_options.clone(), pack_options.clone(), strategy.clone());
     // should report error when invalid failed
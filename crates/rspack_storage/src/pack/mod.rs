@@ -3,10 +3,13 @@ mod manager;
 mod strategy;
 
 use std::{
+  borrow::Cow,
   path::PathBuf,
   sync::{Arc, Mutex},
 };
 
+pub use data::PackCompression;
+pub use data::{PackInfo, ScopeInfo, StorageInfo, VersionInfo};
 use data::{PackOptions, RootOptions};
 use manager::ScopeManager;
 use rspack_paths::AssertUtf8;
@@ -16,7 +19,8 @@ use tokio::sync::oneshot::Receiver;
 
 use crate::{error::Result, FileSystem, ItemKey, ItemPairs, ItemValue, Storage};
 
-pub type ScopeUpdates = HashMap<&'static str, ScopeUpdate>;
+/// The updates of scopes, the names of scopes read from root meta are owned
+pub type ScopeUpdates = HashMap<Cow<'static, str>, ScopeUpdate>;
 #[derive(Debug)]
 pub struct PackStorage {
   pub manager: ScopeManager,
@@ -29,11 +33,15 @@ pub struct PackStorageOptions {
   pub fs: Arc<dyn FileSystem>,
   pub bucket_size: usize,
   pub pack_size: usize,
+  pub compression: PackCompression,
   pub expire: u64,
+  pub max_size: Option<u64>,
   pub version: String,
   pub clean: bool,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
+  /// Locks not refreshed within this time are considered to be left by killed processes
+  pub lock_timeout: u64,
 }
 
 impl PackStorage {
@@ -44,10 +52,12 @@ impl PackStorage {
           root: options.root.clone().assert_utf8(),
           expire: options.expire,
           clean: options.clean,
+          max_size: options.max_size,
         }),
         Arc::new(PackOptions {
           bucket_size: options.bucket_size,
           pack_size: options.pack_size,
+          compression: options.compression,
         }),
         Arc::new(SplitPackStrategy::new(
           options.root.join(&options.version).assert_utf8(),
@@ -55,11 +65,25 @@ impl PackStorage {
           options.fs,
           options.fresh_generation,
           options.release_generation,
+          options.lock_timeout,
         )),
       ),
       updates: Default::default(),
     }
   }
+
+  /// Get the status of current version, return `None` if nothing has been saved.
+  ///
+  /// Pack files are checked with the hashes in scope meta if `verify` is enabled.
+  pub async fn inspect(&self, verify: bool) -> Result<Option<StorageInfo>> {
+    self.manager.inspect(verify).await
+  }
+
+  /// Repack all saved scopes and remove unused files, expired versions and
+  /// invalid scopes in place.
+  pub async fn compact(&self) -> Result<()> {
+    self.manager.compact().await
+  }
 }
 
 #[async_trait::async_trait]
@@ -69,12 +93,12 @@ impl Storage for PackStorage {
   }
   fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
     let mut updates = self.updates.lock().expect("should get lock");
-    let scope_update = updates.entry(scope).or_default();
+    let scope_update = updates.entry(Cow::Borrowed(scope)).or_default();
     scope_update.insert(key, Some(value));
   }
   fn remove(&self, scope: &'static str, key: &[u8]) {
     let mut updates = self.updates.lock().expect("should get lock");
-    let scope_update = updates.entry(scope).or_default();
+    let scope_update = updates.entry(Cow::Borrowed(scope)).or_default();
     scope_update.insert(key.to_vec(), None);
   }
   fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
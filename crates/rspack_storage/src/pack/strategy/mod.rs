@@ -6,8 +6,8 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 pub use split::SplitPackStrategy;
 
 use super::data::{
-  Pack, PackContents, PackFileMeta, PackGenerations, PackKeys, PackOptions, PackScope, RootMeta,
-  RootOptions,
+  Pack, PackCompression, PackContents, PackFileMeta, PackGenerations, PackKeys, PackOptions,
+  PackScope, RootMeta, RootOptions, ScopeInfo, StorageInfo,
 };
 use crate::{
   error::{Result, ValidateResult},
@@ -26,6 +26,8 @@ pub trait ScopeStrategy:
   + ScopeReadStrategy
   + ScopeWriteStrategy
   + ScopeValidateStrategy
+  + InspectStrategy
+  + EvictStrategy
   + std::fmt::Debug
   + Sync
   + Send
@@ -44,6 +46,11 @@ pub trait RootStrategy {
     scopes: &HashMap<String, PackScope>,
     root_options: &RootOptions,
   ) -> Result<()>;
+  /// Fail if the write lock acquired by `before_all` has been lost, nothing should be
+  /// published then as another process may be writing the storage
+  async fn check_lock(&self) -> Result<()>;
+  /// Release the write lock acquired by `before_load` or `before_all`
+  async fn release_lock(&self) -> Result<()>;
 }
 
 #[derive(Debug, Default)]
@@ -74,7 +81,7 @@ pub trait PackWriteStrategy {
     options: &PackOptions,
     packs: Vec<(PackFileMeta, Pack)>,
   ) -> Result<UpdatePacksResult>;
-  async fn write_pack(&self, pack: &Pack) -> Result<()>;
+  async fn write_pack(&self, pack: &Pack, compression: PackCompression) -> Result<()>;
 }
 
 #[async_trait]
@@ -116,3 +123,26 @@ pub trait ScopeWriteStrategy {
   async fn merge_changed(&self, changed: WriteScopeResult) -> Result<()>;
   async fn after_all(&self, scopes: &mut HashMap<String, PackScope>) -> Result<()>;
 }
+
+#[async_trait]
+pub trait InspectStrategy {
+  async fn inspect_scope(&self, scope: &mut PackScope, verify: bool) -> Result<ScopeInfo>;
+  async fn inspect_root(
+    &self,
+    root_meta: &RootMeta,
+    scopes: Vec<ScopeInfo>,
+    root_options: &RootOptions,
+  ) -> Result<StorageInfo>;
+}
+
+#[async_trait]
+pub trait EvictStrategy {
+  /// Remove the least recently used versions and the least recently written packs
+  /// before writing scopes, until the size of cache root fits the `max_size` of root
+  /// options, versions being used by other processes are kept
+  async fn evict(
+    &self,
+    scopes: &mut HashMap<String, PackScope>,
+    root_options: &RootOptions,
+  ) -> Result<()>;
+}
@@ -0,0 +1,94 @@
+use miniz_oxide::{deflate::compress_to_vec, inflate::decompress_to_vec_with_limit};
+
+use crate::pack::data::PackCompression;
+
+/// Compressed pack files start with a header line like `@deflate 1024`,
+/// the number is the length of compressed contents.
+const HEADER_PREFIX: &str = "@";
+
+pub fn is_header_line(line: &str) -> bool {
+  line.starts_with(HEADER_PREFIX)
+}
+
+pub fn create_header_line(compression: PackCompression, compressed_length: usize) -> String {
+  let name = match compression {
+    PackCompression::None => unreachable!("should not create header for uncompressed pack"),
+    PackCompression::Deflate(_) => "deflate",
+  };
+  format!("{HEADER_PREFIX}{name} {compressed_length}")
+}
+
+/// Parse the header line, return the compression and the length of compressed contents
+pub fn parse_header_line(line: &str) -> Result<(PackCompression, usize), String> {
+  let (name, length) = line
+    .trim_start_matches(HEADER_PREFIX)
+    .split_once(" ")
+    .ok_or_else(|| format!("invalid pack header `{line}`"))?;
+  let compression = match name {
+    "deflate" => PackCompression::Deflate(0),
+    _ => return Err(format!("unsupported pack compression `{name}`")),
+  };
+  let length = length
+    .parse::<usize>()
+    .map_err(|e| format!("parse compressed length failed: {e}"))?;
+  Ok((compression, length))
+}
+
+/// Compress the contents, return `None` if the compressed data is not smaller
+pub fn compress(compression: PackCompression, data: &[u8]) -> Option<Vec<u8>> {
+  let compressed = match compression {
+    PackCompression::None => return None,
+    PackCompression::Deflate(level) => compress_to_vec(data, level.min(10)),
+  };
+  (compressed.len() < data.len()).then_some(compressed)
+}
+
+pub fn decompress(
+  compression: PackCompression,
+  data: &[u8],
+  length: usize,
+) -> Result<Vec<u8>, String> {
+  let decompressed = match compression {
+    PackCompression::None => data.to_vec(),
+    PackCompression::Deflate(_) => decompress_to_vec_with_limit(data, length)
+      .map_err(|e| format!("decompress pack contents failed: {e}"))?,
+  };
+  if decompressed.len() != length {
+    return Err(format!(
+      "decompressed length {} not match {length}",
+      decompressed.len()
+    ));
+  }
+  Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{compress, create_header_line, decompress, is_header_line, parse_header_line};
+  use crate::pack::data::PackCompression;
+
+  #[test]
+  fn should_compress_contents() {
+    let data = "val_mock_".repeat(100).into_bytes();
+    assert!(compress(PackCompression::None, &data).is_none());
+    let compressed = compress(PackCompression::Deflate(1), &data).expect("should compress");
+    assert!(compressed.len() < data.len());
+    assert_eq!(
+      decompress(PackCompression::Deflate(0), &compressed, data.len()).expect("should decompress"),
+      data
+    );
+    assert!(decompress(PackCompression::Deflate(0), &compressed, data.len() - 1).is_err());
+    // incompressible data is stored as is
+    assert!(compress(PackCompression::Deflate(1), b"a").is_none());
+
+    let header = create_header_line(PackCompression::Deflate(6), 1024);
+    assert!(is_header_line(&header));
+    assert!(!is_header_line("10 10"));
+    assert_eq!(
+      parse_header_line(&header).expect("should parse header"),
+      (PackCompression::Deflate(0), 1024)
+    );
+    assert!(parse_header_line("@lz4 1024").is_err());
+    assert!(parse_header_line("@deflate").is_err());
+  }
+}
@@ -0,0 +1,276 @@
+use async_trait::async_trait;
+use itertools::Itertools;
+use rspack_paths::Utf8Path;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::{lock::try_lock_unused_version, SplitPackStrategy};
+use crate::{
+  error::Result,
+  pack::{
+    data::{PackScope, RootOptions},
+    strategy::EvictStrategy,
+  },
+};
+
+#[async_trait]
+impl EvictStrategy for SplitPackStrategy {
+  async fn evict(
+    &self,
+    scopes: &mut HashMap<String, PackScope>,
+    root_options: &RootOptions,
+  ) -> Result<()> {
+    let Some(max_size) = root_options.max_size else {
+      return Ok(());
+    };
+
+    let mut total_size = 0;
+    // (scope name, bucket id, pack name, generation, size)
+    let mut packs = vec![];
+    for (name, scope) in scopes.iter() {
+      let meta = scope.meta.expect_value();
+      let scope_packs = scope.packs.expect_value();
+      for (bucket_id, (pack_metas, bucket_packs)) in meta.packs.iter().zip(scope_packs).enumerate()
+      {
+        for (pack_meta, pack) in pack_metas.iter().zip(bucket_packs) {
+          // unwritten packs are estimated by the size before compression
+          let size = if pack_meta.wrote {
+            self.fs.metadata(&pack.path).await?.size
+          } else {
+            pack_meta.size as u64
+          };
+          total_size += size;
+          packs.push((
+            name.clone(),
+            bucket_id,
+            pack_meta.name.clone(),
+            pack_meta.generation,
+            size,
+          ));
+        }
+      }
+    }
+
+    // other versions are removed from the least recently used one, the versions
+    // never loaded since the access time is recorded are used when they are saved
+    let versions = self
+      .get_versions(root_options)
+      .await?
+      .into_iter()
+      .sorted_by_key(|version| {
+        version
+          .access_time
+          .or_else(|| {
+            version
+              .expire_time
+              .map(|expire_time| expire_time.saturating_sub(root_options.expire))
+          })
+          .unwrap_or_default()
+      })
+      .collect_vec();
+    total_size += versions.iter().map(|version| version.size).sum::<u64>();
+    for version in versions {
+      if total_size <= max_size {
+        return Ok(());
+      }
+      if self.try_remove_version(&version.path).await? {
+        total_size -= version.size;
+      }
+    }
+
+    // packs of current version are removed from the least recently written one
+    let mut evicted: HashMap<String, HashSet<(usize, String)>> = HashMap::default();
+    for (scope_name, bucket_id, pack_name, _, size) in packs
+      .into_iter()
+      .sorted_by_key(|(_, _, _, generation, _)| *generation)
+    {
+      if total_size <= max_size {
+        break;
+      }
+      evicted
+        .entry(scope_name)
+        .or_default()
+        .insert((bucket_id, pack_name));
+      total_size -= size;
+    }
+
+    for (scope_name, evicted_packs) in evicted {
+      let scope = scopes.get_mut(&scope_name).expect("should have scope");
+      let mut scope_packs = scope.packs.take_value().expect("should have scope packs");
+      let meta = scope.meta.expect_value_mut();
+      for (bucket_id, (pack_metas, bucket_packs)) in meta
+        .packs
+        .iter_mut()
+        .zip(scope_packs.iter_mut())
+        .enumerate()
+      {
+        let (remain_metas, remain_packs): (Vec<_>, Vec<_>) = std::mem::take(pack_metas)
+          .into_iter()
+          .zip(std::mem::take(bucket_packs))
+          .filter(|(pack_meta, pack)| {
+            if !evicted_packs.contains(&(bucket_id, pack_meta.name.clone())) {
+              return true;
+            }
+            if pack_meta.wrote {
+              scope.removed.insert(pack.path.clone());
+            }
+            false
+          })
+          .unzip();
+        *pack_metas = remain_metas;
+        *bucket_packs = remain_packs;
+      }
+      scope.packs.set_value(scope_packs);
+    }
+
+    Ok(())
+  }
+}
+
+impl SplitPackStrategy {
+  /// Remove the directory of another version unless it is being loaded or saved by
+  /// another process, the write lock of the version is held while removing, so no
+  /// process can start to load or save it in the meantime
+  async fn try_remove_version(&self, path: &Utf8Path) -> Result<bool> {
+    if !try_lock_unused_version(path, &self.lock_owner, self.lock_timeout, self.fs.clone()).await? {
+      return Ok(false);
+    }
+    self.fs.remove_dir(path).await?;
+    Ok(true)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_paths::Utf8Path;
+
+  use crate::{
+    error::Result,
+    pack::{
+      data::{current_time, PackCompression, PackOptions, PackScope, RootMeta, RootOptions},
+      strategy::{
+        split::{
+          lock::{create_lock_owner, write_read_lock},
+          util::test_pack_utils::{
+            clean_strategy, count_scope_packs, create_strategies, mock_updates, save_scope,
+            UpdateVal,
+          },
+        },
+        EvictStrategy, ScopeWriteStrategy, SplitPackStrategy,
+      },
+    },
+  };
+
+  async fn mock_version(
+    root: &Utf8Path,
+    name: &str,
+    expire_time: u64,
+    strategy: &SplitPackStrategy,
+  ) -> Result<()> {
+    let dir = root.join(name);
+    strategy.fs.ensure_dir(&dir).await?;
+    let mut writer = strategy.fs.write_file(&RootMeta::get_path(&dir)).await?;
+    writer.write_line(&expire_time.to_string()).await?;
+    writer.write_line("scope_name").await?;
+    writer.flush().await?;
+    let mut writer = strategy.fs.write_file(&dir.join("pack")).await?;
+    writer.write_all(&[0; 10000]).await?;
+    writer.flush().await?;
+    Ok(())
+  }
+
+  async fn test_evict(root: &Utf8Path, strategy: &SplitPackStrategy) -> Result<()> {
+    let options = Arc::new(PackOptions {
+      bucket_size: 1,
+      pack_size: 500,
+      compression: PackCompression::None,
+    });
+    let mut scope = PackScope::empty(
+      "scope_name",
+      strategy.get_path("scope_name"),
+      options.clone(),
+    );
+    strategy
+      .update_scope(
+        &mut scope,
+        mock_updates(0, 50, 40, UpdateVal::Value("val".into())),
+      )
+      .await?;
+    save_scope(&mut scope, strategy).await?;
+    strategy
+      .update_scope(
+        &mut scope,
+        mock_updates(50, 60, 40, UpdateVal::Value("val".into())),
+      )
+      .await?;
+    let pack_count = count_scope_packs(&scope);
+    assert!(pack_count > 2);
+
+    let now = current_time();
+    mock_version(root, "old_version", now - 1000, strategy).await?;
+    mock_version(root, "older_version", now - 2000, strategy).await?;
+
+    let mut scopes = [("scope_name".to_string(), scope)].into_iter().collect();
+    let mut root_options = RootOptions {
+      root: root.to_path_buf(),
+      expire: 1000,
+      clean: true,
+      max_size: None,
+    };
+    strategy.evict(&mut scopes, &root_options).await?;
+    assert!(strategy.fs.exists(&root.join("older_version")).await?);
+
+    // remove the least recently used version, the older version is loaded recently
+    let mut writer = strategy
+      .fs
+      .write_file(&RootMeta::get_access_path(&root.join("older_version")))
+      .await?;
+    writer.write_line(&now.to_string()).await?;
+    writer.flush().await?;
+    root_options.max_size = Some(20000);
+    strategy.evict(&mut scopes, &root_options).await?;
+    assert!(!strategy.fs.exists(&root.join("old_version")).await?);
+    assert!(strategy.fs.exists(&root.join("older_version")).await?);
+    assert_eq!(count_scope_packs(&scopes["scope_name"]), pack_count);
+
+    // versions being read by other processes are not removed,
+    // remove packs of current version instead
+    let reader = create_lock_owner();
+    write_read_lock(&root.join("older_version"), &reader, strategy.fs.clone()).await?;
+    // about 1000 bytes are left for current version
+    root_options.max_size = Some(11000);
+    strategy.evict(&mut scopes, &root_options).await?;
+    assert!(strategy.fs.exists(&root.join("older_version")).await?);
+    let scope = &scopes["scope_name"];
+    assert!(count_scope_packs(scope) < pack_count);
+    assert!(!scope.removed.is_empty());
+    // the packs wrote in latest generation remain
+    let meta = scope.meta.expect_value();
+    assert!(meta
+      .packs
+      .iter()
+      .flatten()
+      .all(|pack_meta| pack_meta.generation == meta.generation));
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn should_evict_by_size() {
+    for strategy in create_strategies("evict") {
+      clean_strategy(&strategy).await;
+      let root = strategy
+        .root
+        .parent()
+        .expect("should have parent")
+        .to_path_buf();
+      let _ = strategy.fs.remove_dir(&root.join("old_version")).await;
+      let _ = strategy.fs.remove_dir(&root.join("older_version")).await;
+
+      let _ = test_evict(&root, &strategy)
+        .await
+        .map_err(|e| panic!("{}", e));
+    }
+  }
+}
@@ -5,6 +5,7 @@ use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tokio::task::JoinError;
 
+use super::lock::{release_write_lock, try_lock_unused_version};
 use crate::{
   fs::{BatchFSError, FSError, FSOperation},
   pack::data::{current_time, PackScope, RootMeta, RootOptions, ScopeMeta},
@@ -341,9 +342,17 @@ pub async fn remove_unused_scopes(
   .map(|_| ())
 }
 
+/// Remove the directory of another version if it is expired, or it is left without
+/// root meta by a failed process
+///
+/// The write lock of the version is acquired before checking, the versions being loaded
+/// or saved by other processes are skipped, e.g. a version saved for the first time has
+/// no root meta until all its files are written.
 async fn try_remove_version(
   version: &str,
   dir: &Utf8Path,
+  owner: &str,
+  timeout: u64,
   fs: Arc<dyn FileSystem>,
 ) -> BatchHandleFileResult<()> {
   // do not remove hidden dirs and lock files
@@ -356,35 +365,44 @@ async fn try_remove_version(
     return Ok(());
   }
 
-  // remove unknown directories
-  let meta = RootMeta::get_path(dir);
-  if !fs.exists(&meta).await? {
-    fs.remove_dir(dir).await?;
+  if !try_lock_unused_version(dir, owner, timeout, fs.clone()).await? {
     return Ok(());
   }
 
-  // remove direcotires of expired versions
-  let mut reader = fs.read_file(&meta).await?;
-  let expire_time = reader.read_line().await?.parse::<u64>().map_err(|e| {
-    FSError::from_message(
-      &meta,
-      FSOperation::Read,
-      format!("parse option meta failed: {}", e),
-    )
-  })?;
-  let current = current_time();
+  let meta = RootMeta::get_path(dir);
+  let expired = if fs.exists(&meta).await? {
+    let mut reader = fs.read_file(&meta).await?;
+    let expire_time = reader.read_line().await?.parse::<u64>().map_err(|e| {
+      FSError::from_message(
+        &meta,
+        FSOperation::Read,
+        format!("parse option meta failed: {}", e),
+      )
+    });
+    match expire_time {
+      Ok(expire_time) => current_time() > expire_time,
+      Err(e) => {
+        release_write_lock(dir, owner, fs).await?;
+        return Err(e.into());
+      }
+    }
+  } else {
+    true
+  };
 
-  if current > expire_time {
+  if expired {
     fs.remove_dir(dir).await?;
-    Ok(())
   } else {
-    Ok(())
+    release_write_lock(dir, owner, fs).await?;
   }
+  Ok(())
 }
 
 pub async fn remove_expired_versions(
   root: &Utf8Path,
   root_options: &RootOptions,
+  owner: Arc<String>,
+  timeout: u64,
   fs: Arc<dyn FileSystem>,
 ) -> BatchHandleFileResult<()> {
   let dirs = fs.read_dir(&root_options.root).await?;
@@ -394,8 +412,9 @@ pub async fn remove_expired_versions(
       None
     } else {
       let fs = fs.clone();
+      let owner = owner.clone();
       Some(tokio::spawn(async move {
-        try_remove_version(&version, &version_dir, fs).await
+        try_remove_version(&version, &version_dir, &owner, timeout, fs).await
       }))
     }
   });
@@ -0,0 +1,335 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::{future::join_all, TryFutureExt};
+use itertools::Itertools;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashSet as HashSet;
+
+use super::{handle_file::walk_dir, util::get_indexed_packs, SplitPackStrategy};
+use crate::{
+  error::{Error, ErrorType, Result},
+  pack::{
+    data::{
+      PackInfo, PackScope, RootMeta, RootOptions, ScopeInfo, ScopeMeta, StorageInfo, VersionInfo,
+    },
+    strategy::{InspectStrategy, PackReadStrategy, ScopeReadStrategy},
+  },
+  FileSystem,
+};
+
+#[async_trait]
+impl InspectStrategy for SplitPackStrategy {
+  async fn inspect_scope(&self, scope: &mut PackScope, verify: bool) -> Result<ScopeInfo> {
+    self.ensure_packs(scope).await?;
+
+    let (_, pack_list) = get_indexed_packs(scope, None);
+    let tasks = pack_list.iter().map(|(pack_meta, pack)| {
+      let strategy = self.clone();
+      let path = pack.path.to_owned();
+      let hash = pack_meta.hash.to_owned();
+      tokio::spawn(async move {
+        if !verify {
+          return None;
+        }
+        // unreadable packs are treated as invalid packs
+        let Ok(Some(keys)) = strategy.read_pack_keys(&path).await else {
+          return Some(false);
+        };
+        match strategy
+          .get_pack_hash(&path, &keys, &Default::default())
+          .await
+        {
+          Ok(res) => Some(hash == res),
+          Err(_) => Some(false),
+        }
+      })
+      .map_err(|e| {
+        Error::from_error(
+          Some(ErrorType::Validate),
+          Some(scope.name.as_str()),
+          e.into(),
+        )
+      })
+    });
+    let validate_results = join_all(tasks)
+      .await
+      .into_iter()
+      .collect::<Result<Vec<_>>>()?;
+
+    let meta = scope.meta.expect_value();
+    let packs = get_indexed_packs(scope, None)
+      .0
+      .into_iter()
+      .zip(pack_list)
+      .zip(validate_results)
+      .map(|(((bucket, _), (pack_meta, pack)), valid)| PackInfo {
+        bucket,
+        path: pack.path.clone(),
+        hash: pack_meta.hash.clone(),
+        size: pack_meta.size,
+        generation: pack_meta.generation,
+        valid,
+      })
+      .collect_vec();
+
+    let mut used_files = packs
+      .iter()
+      .map(|pack| pack.path.clone())
+      .collect::<HashSet<_>>();
+    used_files.insert(ScopeMeta::get_path(&scope.path));
+    let (unused_files, unused_size) =
+      get_files_with_size(&scope.path, &used_files, self.fs.clone()).await?;
+
+    Ok(ScopeInfo {
+      name: scope.name.clone(),
+      path: scope.path.clone(),
+      bucket_size: meta.bucket_size,
+      pack_size: meta.pack_size,
+      generation: meta.generation,
+      packs,
+      unused_files,
+      unused_size,
+    })
+  }
+
+  async fn inspect_root(
+    &self,
+    root_meta: &RootMeta,
+    scopes: Vec<ScopeInfo>,
+    root_options: &RootOptions,
+  ) -> Result<StorageInfo> {
+    let mut unused_scopes = vec![];
+    for name in self
+      .fs
+      .read_dir(&self.root)
+      .await?
+      .difference(&root_meta.scopes)
+      .filter(|name| !name.starts_with("."))
+      .sorted()
+    {
+      let path = self.root.join(name);
+      if self.fs.metadata(&path).await?.is_directory {
+        unused_scopes.push(path);
+      }
+    }
+
+    let versions = self.get_versions(root_options).await?;
+
+    Ok(StorageInfo {
+      path: self.root.as_ref().clone(),
+      expire_time: root_meta.expire_time,
+      scopes,
+      unused_scopes,
+      versions,
+    })
+  }
+}
+
+impl SplitPackStrategy {
+  /// Get the other versions in cache root except current version
+  pub(super) async fn get_versions(&self, root_options: &RootOptions) -> Result<Vec<VersionInfo>> {
+    let mut versions = vec![];
+    if !self.fs.exists(&root_options.root).await? {
+      return Ok(versions);
+    }
+    for name in self
+      .fs
+      .read_dir(&root_options.root)
+      .await?
+      .into_iter()
+      .sorted()
+    {
+      let path = root_options.root.join(&name);
+      // skip hidden dirs, lock files, current version and temp root
+      if name.starts_with(".")
+        || name.contains(".lock")
+        || path == *self.root
+        || self.temp_root.starts_with(&path)
+      {
+        continue;
+      }
+      if !self.fs.metadata(&path).await?.is_directory {
+        continue;
+      }
+      let meta_path = RootMeta::get_path(&path);
+      let expire_time = if self.fs.exists(&meta_path).await? {
+        let mut reader = self.fs.read_file(&meta_path).await?;
+        reader.read_line().await?.parse::<u64>().ok()
+      } else {
+        None
+      };
+      let access_path = RootMeta::get_access_path(&path);
+      let access_time = if self.fs.exists(&access_path).await? {
+        let mut reader = self.fs.read_file(&access_path).await?;
+        reader.read_line().await?.parse::<u64>().ok()
+      } else {
+        None
+      };
+      let (_, size) = get_files_with_size(&path, &HashSet::default(), self.fs.clone()).await?;
+      versions.push(VersionInfo {
+        name,
+        path,
+        size,
+        expire_time,
+        access_time,
+      });
+    }
+
+    Ok(versions)
+  }
+}
+
+/// Get the files under `dir` except `excludes` and their total size
+async fn get_files_with_size(
+  dir: &Utf8Path,
+  excludes: &HashSet<Utf8PathBuf>,
+  fs: Arc<dyn FileSystem>,
+) -> Result<(Vec<Utf8PathBuf>, u64)> {
+  if !fs.exists(dir).await? {
+    return Ok((vec![], 0));
+  }
+  let files = walk_dir(dir, fs.clone())
+    .await?
+    .into_iter()
+    .filter(|file| !excludes.contains(file))
+    .sorted()
+    .collect_vec();
+  let mut size = 0;
+  for file in &files {
+    size += fs.metadata(file).await?.size;
+  }
+  Ok((files, size))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_paths::{Utf8Path, Utf8PathBuf};
+  use rustc_hash::FxHashSet as HashSet;
+
+  use crate::{
+    error::Result,
+    pack::{
+      data::{PackCompression, PackOptions, PackScope, RootMeta, RootOptions},
+      strategy::{
+        split::util::test_pack_utils::{
+          clean_strategy, create_strategies, flush_file_mtime, mock_updates, save_scope, UpdateVal,
+        },
+        InspectStrategy, RootStrategy, ScopeReadStrategy, ScopeWriteStrategy, SplitPackStrategy,
+      },
+    },
+  };
+
+  async fn test_inspect_scope(
+    scope_path: Utf8PathBuf,
+    strategy: &SplitPackStrategy,
+    options: Arc<PackOptions>,
+  ) -> Result<()> {
+    let mut scope = PackScope::new("scope_name", scope_path.clone(), options.clone());
+    let info = strategy.inspect_scope(&mut scope, true).await?;
+    assert_eq!(info.bucket_size, 10);
+    assert_eq!(info.pack_size, 100);
+    assert_eq!(info.generation, 1);
+    assert!(!info.packs.is_empty());
+    assert_eq!(info.invalid_packs().count(), 0);
+    assert_eq!(info.stale_packs(0).count(), 0);
+    assert!(info.unused_files.is_empty());
+
+    // pack files are modified and an unknown file is added
+    let modified = info.packs[0].path.clone();
+    flush_file_mtime(&modified, strategy.fs.clone()).await?;
+    let mut writer = strategy.fs.write_file(&scope_path.join("unknown")).await?;
+    writer.write_all(b"unknown").await?;
+    writer.flush().await?;
+
+    let mut scope = PackScope::new("scope_name", scope_path.clone(), options.clone());
+    let info = strategy.inspect_scope(&mut scope, true).await?;
+    assert_eq!(
+      info.invalid_packs().map(|p| &p.path).collect::<Vec<_>>(),
+      vec![&modified]
+    );
+    assert_eq!(info.unused_files, vec![scope_path.join("unknown")]);
+    assert_eq!(info.unused_size, 7);
+
+    // skip verification
+    let mut scope = PackScope::new("scope_name", scope_path, options);
+    let info = strategy.inspect_scope(&mut scope, false).await?;
+    assert!(info.packs.iter().all(|p| p.valid.is_none()));
+    Ok(())
+  }
+
+  async fn test_inspect_root(root: &Utf8Path, strategy: &SplitPackStrategy) -> Result<()> {
+    let root_meta = RootMeta::new(HashSet::from_iter(["scope_name".to_string()]), 1000);
+    strategy.write_root_meta(&root_meta).await?;
+    strategy
+      .fs
+      .ensure_dir(&strategy.get_path("removed_scope"))
+      .await?;
+    strategy
+      .fs
+      .ensure_dir(&root.join("old_version/some_scope"))
+      .await?;
+
+    let info = strategy
+      .inspect_root(
+        &root_meta,
+        vec![],
+        &RootOptions {
+          root: root.to_path_buf(),
+          expire: 1000,
+          clean: true,
+          max_size: None,
+        },
+      )
+      .await?;
+    assert!(!info.is_expired());
+    assert_eq!(info.unused_scopes, vec![strategy.get_path("removed_scope")]);
+    let old_version = info
+      .versions
+      .iter()
+      .find(|version| version.name == "old_version")
+      .expect("should have old version");
+    assert!(old_version.expire_time.is_none());
+    assert!(old_version.is_expired());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn should_inspect_storage() {
+    for strategy in create_strategies("inspect_storage") {
+      clean_strategy(&strategy).await;
+      let root = strategy
+        .root
+        .parent()
+        .expect("should have parent")
+        .to_path_buf();
+      let _ = strategy.fs.remove_dir(&root.join("old_version")).await;
+
+      let scope_path = strategy.get_path("scope_name");
+      let pack_options = Arc::new(PackOptions {
+        bucket_size: 10,
+        pack_size: 100,
+        compression: PackCompression::None,
+      });
+      let mut mock_scope = PackScope::empty("scope_name", scope_path.clone(), pack_options.clone());
+      let updates = mock_updates(0, 100, 30, UpdateVal::Value("val".to_string()));
+      strategy
+        .update_scope(&mut mock_scope, updates)
+        .await
+        .expect("should update scope");
+      save_scope(&mut mock_scope, &strategy)
+        .await
+        .expect("should write scope");
+
+      let _ = test_inspect_scope(scope_path, &strategy, pack_options)
+        .await
+        .map_err(|e| panic!("{}", e));
+      let _ = test_inspect_root(&root, &strategy)
+        .await
+        .map_err(|e| panic!("{}", e));
+    }
+  }
+}
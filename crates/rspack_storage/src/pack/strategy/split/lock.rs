@@ -0,0 +1,535 @@
+//! Lock files to share one cache root between processes.
+//!
+//! - `write.lock` is held by the only process saving the storage, other
+//!   processes wait for it before saving or recovering the storage.
+//! - `read.{owner}.lock` is written by processes loading the storage, files
+//!   which may still be read by them are not removed.
+//!
+//! Lock files record their owner and the time they were refreshed, a lock
+//! which has not been refreshed within the lock timeout is considered to be
+//! left by a killed process and can be taken over. Locks are refreshed by a
+//! `LockKeeper` for the whole time they are held.
+
+use std::{
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+  time::Duration,
+};
+
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use tokio::{sync::Mutex, task::JoinHandle};
+
+use crate::{
+  fs::{FSError, FSOperation},
+  pack::data::current_time,
+  FileSystem,
+};
+
+type HandleFileResult<T> = Result<T, FSError>;
+
+const WRITE_LOCK: &str = "write.lock";
+const READ_LOCK_PREFIX: &str = "read.";
+const LOCK_SUFFIX: &str = ".lock";
+const RETRY_INTERVAL: u64 = 50;
+
+#[derive(Debug)]
+struct LockInfo {
+  owner: String,
+  time: u64,
+}
+
+impl LockInfo {
+  fn is_stale(&self, timeout: u64) -> bool {
+    current_time().saturating_sub(self.time) > timeout
+  }
+}
+
+/// Create an owner id which is unique among processes and storages of the same process
+pub fn create_lock_owner() -> String {
+  static COUNTER: AtomicUsize = AtomicUsize::new(0);
+  format!(
+    "{}_{}_{}",
+    std::process::id(),
+    current_time(),
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+  )
+}
+
+fn get_read_lock_path(root: &Utf8Path, owner: &str) -> Utf8PathBuf {
+  root.join(format!("{READ_LOCK_PREFIX}{owner}{LOCK_SUFFIX}"))
+}
+
+async fn read_lock_info(
+  path: &Utf8Path,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<Option<LockInfo>> {
+  if !fs.exists(path).await? {
+    return Ok(None);
+  }
+  let mut reader = match fs.read_file(path).await {
+    Ok(reader) => reader,
+    // released by the owner just now
+    Err(e) if e.is_not_found() => return Ok(None),
+    Err(e) => return Err(e),
+  };
+  let owner = reader.read_line().await.unwrap_or_default();
+  let time = match reader
+    .read_line()
+    .await
+    .ok()
+    .and_then(|line| line.parse::<u64>().ok())
+  {
+    Some(time) => time,
+    // the lock file is being written or broken by a killed process,
+    // it is stale if it has not been modified within the timeout
+    None => match fs.metadata(path).await {
+      Ok(meta) => meta.mtime_ms,
+      Err(e) if e.is_not_found() => return Ok(None),
+      Err(e) => return Err(e),
+    },
+  };
+  Ok(Some(LockInfo { owner, time }))
+}
+
+fn get_lock_contents(owner: &str, time: u64) -> String {
+  format!("{owner}\n{time}\n")
+}
+
+/// Write the lock file to a temporary file and rename it,
+/// so that a lock file is never read with incomplete contents
+async fn publish_lock(
+  path: &Utf8Path,
+  owner: &str,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<()> {
+  let temp_path = Utf8PathBuf::from(format!("{path}.{owner}"));
+  let mut writer = fs.write_file(&temp_path).await?;
+  writer.write_line(owner).await?;
+  writer.write_line(&current_time().to_string()).await?;
+  writer.flush().await?;
+  fs.move_file(&temp_path, path).await?;
+  Ok(())
+}
+
+/// Acquire the write lock of `root`, wait until the lock is released or stale
+/// if it is held by another process
+///
+/// The lock file is created exclusively, so only one of the processes acquiring
+/// the lock at the same time can get it.
+pub async fn acquire_write_lock(
+  root: &Utf8Path,
+  owner: &str,
+  timeout: u64,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<()> {
+  fs.ensure_dir(root).await?;
+  loop {
+    match try_acquire_write_lock(root, owner, timeout, fs.clone()).await {
+      Ok(true) => return Ok(()),
+      Ok(false) => tokio::time::sleep(Duration::from_millis(RETRY_INTERVAL)).await,
+      // the directory is removed by another process in the meantime
+      Err(e) if e.is_not_found() => fs.ensure_dir(root).await?,
+      Err(e) => return Err(e),
+    }
+  }
+}
+
+/// Try to acquire the write lock of `root` without waiting, return false if the lock
+/// is held by another process
+pub async fn try_acquire_write_lock(
+  root: &Utf8Path,
+  owner: &str,
+  timeout: u64,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<bool> {
+  let path = root.join(WRITE_LOCK);
+  loop {
+    match fs
+      .create_new_file(&path, get_lock_contents(owner, current_time()).as_bytes())
+      .await
+    {
+      Ok(()) => return Ok(true),
+      Err(e) if e.is_already_exists() => {}
+      Err(e) => return Err(e),
+    }
+    let Some(info) = read_lock_info(&path, fs.clone()).await? else {
+      continue;
+    };
+    if info.owner == owner {
+      return Ok(true);
+    }
+    if !info.is_stale(timeout) {
+      return Ok(false);
+    }
+    remove_stale_lock(&path, owner, timeout, fs.clone()).await?;
+  }
+}
+
+/// Remove a stale lock left by a killed process
+///
+/// Several processes may find the stale lock at the same time, the lock is moved
+/// away before removing, and a fresh lock created by another process in the meantime
+/// is restored exclusively, so it is never removed or overwritten.
+async fn remove_stale_lock(
+  path: &Utf8Path,
+  owner: &str,
+  timeout: u64,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<()> {
+  let stale_path = Utf8PathBuf::from(format!("{path}.{owner}.stale"));
+  match fs.move_file(path, &stale_path).await {
+    Ok(()) => {}
+    // moved away by another process
+    Err(e) if e.is_not_found() => return Ok(()),
+    Err(e) => return Err(e),
+  }
+  let Some(info) = read_lock_info(&stale_path, fs.clone()).await? else {
+    return Ok(());
+  };
+  if !info.is_stale(timeout) {
+    match fs
+      .create_new_file(path, get_lock_contents(&info.owner, info.time).as_bytes())
+      .await
+    {
+      Ok(()) => {}
+      // the owner finds out that the lock is taken over when refreshing it
+      Err(e) if e.is_already_exists() => {}
+      Err(e) => return Err(e),
+    }
+  }
+  fs.remove_file(&stale_path).await
+}
+
+/// Refresh the time of the write lock to tell other processes that the owner is alive,
+/// fail if the lock has been taken over by another process
+///
+/// A missing lock is moved away by another process which considers it to be stale, it is
+/// never recreated, as that could overwrite the lock created by the new owner.
+pub async fn refresh_write_lock(
+  root: &Utf8Path,
+  owner: &str,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<()> {
+  let path = root.join(WRITE_LOCK);
+  match read_lock_info(&path, fs.clone()).await? {
+    Some(info) if info.owner == owner => publish_lock(&path, owner, fs).await,
+    Some(_) => Err(FSError::from_message(
+      &path,
+      FSOperation::Write,
+      "write lock has been taken over by another process".to_string(),
+    )),
+    None => Err(FSError::from_message(
+      &path,
+      FSOperation::Write,
+      "write lock has been removed by another process".to_string(),
+    )),
+  }
+}
+
+pub async fn release_write_lock(
+  root: &Utf8Path,
+  owner: &str,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<()> {
+  let path = root.join(WRITE_LOCK);
+  if read_lock_info(&path, fs.clone())
+    .await?
+    .is_some_and(|info| info.owner == owner)
+  {
+    fs.remove_file(&path).await?;
+  }
+  Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LockKind {
+  Write,
+  Read,
+}
+
+/// Refresh a lock periodically until it is stopped, so that the lock is never
+/// considered to be stale by other processes while it is held
+///
+/// Refreshing stops when the lock is lost, e.g. taken over by another process after
+/// this process has been paused for longer than the timeout, the owner should check
+/// it by `check` before publishing any files.
+#[derive(Debug)]
+pub struct LockKeeper {
+  path: Utf8PathBuf,
+  active: Arc<Mutex<bool>>,
+  lost: Arc<Mutex<Option<String>>>,
+  handle: JoinHandle<()>,
+}
+
+impl LockKeeper {
+  pub fn new(
+    kind: LockKind,
+    root: Arc<Utf8PathBuf>,
+    owner: Arc<String>,
+    timeout: u64,
+    fs: Arc<dyn FileSystem>,
+  ) -> Self {
+    let path = match kind {
+      LockKind::Write => root.join(WRITE_LOCK),
+      LockKind::Read => get_read_lock_path(&root, &owner),
+    };
+    let active = Arc::new(Mutex::new(true));
+    let lost = Arc::new(Mutex::new(None));
+    let task_active = active.clone();
+    let task_lost = lost.clone();
+    let handle = tokio::spawn(async move {
+      let interval = Duration::from_millis((timeout / 3).max(1));
+      loop {
+        tokio::time::sleep(interval).await;
+        // refreshing is finished before the keeper is stopped,
+        // so a released lock is never written again
+        let active = task_active.lock().await;
+        if !*active {
+          break;
+        }
+        let res = match kind {
+          LockKind::Write => refresh_write_lock(&root, &owner, fs.clone()).await,
+          LockKind::Read => write_read_lock(&root, &owner, fs.clone()).await,
+        };
+        if let Err(e) = res {
+          *task_lost.lock().await = Some(e.to_string());
+          break;
+        }
+      }
+    });
+    Self {
+      path,
+      active,
+      lost,
+      handle,
+    }
+  }
+
+  /// Fail if the lock could not be refreshed, another process may hold it now
+  pub async fn check(&self) -> HandleFileResult<()> {
+    match &*self.lost.lock().await {
+      Some(reason) => Err(FSError::from_message(
+        &self.path,
+        FSOperation::Write,
+        format!("lock has been lost: {reason}"),
+      )),
+      None => Ok(()),
+    }
+  }
+
+  pub async fn stop(self) {
+    *self.active.lock().await = false;
+    self.handle.abort();
+  }
+}
+
+impl Drop for LockKeeper {
+  // the lock of a dropped storage becomes stale after the timeout
+  fn drop(&mut self) {
+    self.handle.abort();
+  }
+}
+
+pub async fn write_read_lock(
+  root: &Utf8Path,
+  owner: &str,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<()> {
+  publish_lock(&get_read_lock_path(root, owner), owner, fs).await
+}
+
+pub async fn remove_read_lock(
+  root: &Utf8Path,
+  owner: &str,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<()> {
+  fs.remove_file(&get_read_lock_path(root, owner)).await
+}
+
+/// Check if other processes are reading the storage, stale read locks are removed
+pub async fn has_other_readers(
+  root: &Utf8Path,
+  owner: &str,
+  timeout: u64,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<bool> {
+  if !fs.exists(root).await? {
+    return Ok(false);
+  }
+  let mut res = false;
+  for name in fs.read_dir(root).await? {
+    if !name.starts_with(READ_LOCK_PREFIX) || !name.ends_with(LOCK_SUFFIX) {
+      continue;
+    }
+    let path = root.join(&name);
+    let Some(info) = read_lock_info(&path, fs.clone()).await? else {
+      continue;
+    };
+    if info.owner == owner {
+      continue;
+    }
+    if info.is_stale(timeout) {
+      fs.remove_file(&path).await?;
+    } else {
+      res = true;
+    }
+  }
+  Ok(res)
+}
+
+/// Acquire the write lock of another version to remove it, return false if the version
+/// is being loaded or saved by another process
+///
+/// The lock is held until the directory is removed or `release_write_lock` is called,
+/// so no process can start to load or save the version in the meantime.
+pub async fn try_lock_unused_version(
+  root: &Utf8Path,
+  owner: &str,
+  timeout: u64,
+  fs: Arc<dyn FileSystem>,
+) -> HandleFileResult<bool> {
+  if !try_acquire_write_lock(root, owner, timeout, fs.clone()).await? {
+    return Ok(false);
+  }
+  if has_other_readers(root, owner, timeout, fs.clone()).await? {
+    release_write_lock(root, owner, fs).await?;
+    return Ok(false);
+  }
+  Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::Utf8PathBuf;
+
+  use super::{
+    acquire_write_lock, create_lock_owner, has_other_readers, refresh_write_lock,
+    release_write_lock, try_acquire_write_lock, write_read_lock, LockKeeper, LockKind,
+  };
+  use crate::{fs::FSResult, BridgeFileSystem, FileSystem};
+
+  async fn test_write_lock(fs: Arc<dyn FileSystem>) -> FSResult<()> {
+    let root = Utf8PathBuf::from("/lock/write");
+    let owner = create_lock_owner();
+    let other = create_lock_owner();
+    assert_ne!(owner, other);
+
+    acquire_write_lock(&root, &owner, 1000, fs.clone()).await?;
+    // reentrant for the same owner
+    acquire_write_lock(&root, &owner, 1000, fs.clone()).await?;
+    refresh_write_lock(&root, &owner, fs.clone()).await?;
+    assert!(refresh_write_lock(&root, &other, fs.clone()).await.is_err());
+
+    // wait until the lock is stale and take it over
+    acquire_write_lock(&root, &other, 100, fs.clone()).await?;
+    assert!(refresh_write_lock(&root, &owner, fs.clone()).await.is_err());
+
+    // only the owner can release the lock
+    release_write_lock(&root, &owner, fs.clone()).await?;
+    assert!(fs.exists(&root.join("write.lock")).await?);
+    release_write_lock(&root, &other, fs.clone()).await?;
+    assert!(!fs.exists(&root.join("write.lock")).await?);
+
+    // only one of the processes acquiring the lock at the same time gets it
+    let owners = [create_lock_owner(), create_lock_owner()];
+    let (a, b) = tokio::join!(
+      acquire_write_lock(&root, &owners[0], 1000, fs.clone()),
+      tokio::time::timeout(
+        std::time::Duration::from_millis(200),
+        acquire_write_lock(&root, &owners[1], 1000, fs.clone())
+      )
+    );
+    a?;
+    assert!(b.is_err());
+    assert!(!try_acquire_write_lock(&root, &owners[1], 1000, fs.clone()).await?);
+    assert!(try_acquire_write_lock(&root, &owners[0], 1000, fs.clone()).await?);
+    release_write_lock(&root, &owners[0], fs.clone()).await?;
+    assert!(try_acquire_write_lock(&root, &owners[1], 1000, fs.clone()).await?);
+    release_write_lock(&root, &owners[1], fs.clone()).await?;
+    Ok(())
+  }
+
+  async fn test_lock_keeper(fs: Arc<dyn FileSystem>) -> FSResult<()> {
+    let root = Arc::new(Utf8PathBuf::from("/lock/keeper"));
+    let owner = Arc::new(create_lock_owner());
+    let other = create_lock_owner();
+
+    acquire_write_lock(&root, &owner, 100, fs.clone()).await?;
+    write_read_lock(&root, &owner, fs.clone()).await?;
+    let write_keeper = LockKeeper::new(
+      LockKind::Write,
+      root.clone(),
+      owner.clone(),
+      100,
+      fs.clone(),
+    );
+    let read_keeper = LockKeeper::new(LockKind::Read, root.clone(), owner.clone(), 100, fs.clone());
+
+    // the locks held longer than the timeout are not stale
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+    assert!(has_other_readers(&root, &other, 100, fs.clone()).await?);
+    assert!(tokio::time::timeout(
+      std::time::Duration::from_millis(200),
+      acquire_write_lock(&root, &other, 100, fs.clone())
+    )
+    .await
+    .is_err());
+
+    write_keeper.check().await?;
+    write_keeper.stop().await;
+    read_keeper.stop().await;
+    release_write_lock(&root, &owner, fs.clone()).await?;
+    assert!(!fs.exists(&root.join("write.lock")).await?);
+
+    // a removed lock is lost and never recreated by the keeper
+    acquire_write_lock(&root, &owner, 100, fs.clone()).await?;
+    let write_keeper = LockKeeper::new(
+      LockKind::Write,
+      root.clone(),
+      owner.clone(),
+      100,
+      fs.clone(),
+    );
+    fs.remove_file(&root.join("write.lock")).await?;
+    assert!(refresh_write_lock(&root, &owner, fs.clone()).await.is_err());
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(write_keeper.check().await.is_err());
+    assert!(!fs.exists(&root.join("write.lock")).await?);
+    write_keeper.stop().await;
+    Ok(())
+  }
+
+  async fn test_read_lock(fs: Arc<dyn FileSystem>) -> FSResult<()> {
+    let root = Utf8PathBuf::from("/lock/read");
+    let owner = create_lock_owner();
+    let other = create_lock_owner();
+
+    write_read_lock(&root, &owner, fs.clone()).await?;
+    assert!(!has_other_readers(&root, &owner, 1000, fs.clone()).await?);
+    assert!(has_other_readers(&root, &other, 1000, fs.clone()).await?);
+
+    tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+    assert!(!has_other_readers(&root, &other, 100, fs.clone()).await?);
+    // stale read lock is removed
+    assert!(fs.read_dir(&root).await?.is_empty());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn should_handle_process_locks() {
+    let fs: Arc<dyn FileSystem> = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let _ = test_write_lock(fs.clone())
+      .await
+      .map_err(|e| panic!("{}", e));
+    let _ = test_read_lock(fs.clone())
+      .await
+      .map_err(|e| panic!("{}", e));
+    let _ = test_lock_keeper(fs).await.map_err(|e| panic!("{}", e));
+  }
+}
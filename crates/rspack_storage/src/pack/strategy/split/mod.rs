@@ -1,4 +1,8 @@
+mod compression;
+mod evict;
 mod handle_file;
+mod inspect;
+mod lock;
 mod read_pack;
 mod read_scope;
 mod util;
@@ -13,6 +17,10 @@ use handle_file::{
   remove_unused_scopes,
 };
 use itertools::Itertools;
+use lock::{
+  acquire_write_lock, create_lock_owner, has_other_readers, release_write_lock, remove_read_lock,
+  write_read_lock, LockKeeper, LockKind,
+};
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 use util::get_name;
@@ -34,6 +42,10 @@ pub struct SplitPackStrategy {
   pub temp_root: Arc<Utf8PathBuf>,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
+  pub lock_owner: Arc<String>,
+  pub lock_timeout: u64,
+  write_lock_keeper: Arc<tokio::sync::Mutex<Option<LockKeeper>>>,
+  read_lock_keeper: Arc<tokio::sync::Mutex<Option<LockKeeper>>>,
 }
 
 impl SplitPackStrategy {
@@ -43,6 +55,7 @@ impl SplitPackStrategy {
     fs: Arc<dyn FileSystem>,
     fresh_generation: Option<usize>,
     release_generation: Option<usize>,
+    lock_timeout: u64,
   ) -> Self {
     Self {
       fs,
@@ -50,6 +63,10 @@ impl SplitPackStrategy {
       temp_root: Arc::new(temp_root),
       fresh_generation,
       release_generation,
+      lock_owner: Arc::new(create_lock_owner()),
+      lock_timeout,
+      write_lock_keeper: Default::default(),
+      read_lock_keeper: Default::default(),
     }
   }
 
@@ -69,14 +86,86 @@ impl SplitPackStrategy {
 
     Ok(format!("{:016x}", hasher.finish()))
   }
+
+  /// Acquire the write lock and keep it fresh until `release_lock`
+  async fn acquire_lock(&self) -> Result<()> {
+    acquire_write_lock(
+      &self.root,
+      &self.lock_owner,
+      self.lock_timeout,
+      self.fs.clone(),
+    )
+    .await?;
+    let keeper = self.create_lock_keeper(LockKind::Write);
+    if let Some(keeper) = self.write_lock_keeper.lock().await.replace(keeper) {
+      keeper.stop().await;
+    }
+    Ok(())
+  }
+
+  /// Write the read lock and keep it fresh until `remove_read_lock`
+  async fn write_read_lock(&self) -> Result<()> {
+    write_read_lock(&self.root, &self.lock_owner, self.fs.clone()).await?;
+    let keeper = self.create_lock_keeper(LockKind::Read);
+    if let Some(keeper) = self.read_lock_keeper.lock().await.replace(keeper) {
+      keeper.stop().await;
+    }
+    Ok(())
+  }
+
+  async fn remove_read_lock(&self) -> Result<()> {
+    if let Some(keeper) = self.read_lock_keeper.lock().await.take() {
+      keeper.stop().await;
+    }
+    remove_read_lock(&self.root, &self.lock_owner, self.fs.clone()).await?;
+    Ok(())
+  }
+
+  /// Record the time the version is loaded, so that the least recently used
+  /// versions are evicted first
+  async fn write_access_time(&self) -> Result<()> {
+    let mut writer = self
+      .fs
+      .write_file(&RootMeta::get_access_path(&self.root))
+      .await?;
+    writer.write_line(&current_time().to_string()).await?;
+    writer.flush().await?;
+    Ok(())
+  }
+
+  fn create_lock_keeper(&self, kind: LockKind) -> LockKeeper {
+    LockKeeper::new(
+      kind,
+      self.root.clone(),
+      self.lock_owner.clone(),
+      self.lock_timeout,
+      self.fs.clone(),
+    )
+  }
+
+  /// Finish the removing and moving of files interrupted by a failed or killed process,
+  /// should be called with the write lock held
+  async fn recovery(&self) -> Result<()> {
+    recovery_move_lock(&self.root, &self.temp_root, self.fs.clone()).await?;
+    recovery_remove_lock(&self.root, &self.temp_root, self.fs.clone()).await?;
+    Ok(())
+  }
 }
 
 #[async_trait::async_trait]
 impl RootStrategy for SplitPackStrategy {
   async fn before_load(&self) -> Result<()> {
-    recovery_remove_lock(&self.root, &self.temp_root, self.fs.clone()).await?;
-    recovery_move_lock(&self.root, &self.temp_root, self.fs.clone()).await?;
-    Ok(())
+    // wait for the process which is saving the storage
+    self.acquire_lock().await?;
+    let res: Result<()> = async {
+      self.recovery().await?;
+      self.write_read_lock().await?;
+      self.write_access_time().await?;
+      Ok(())
+    }
+    .await;
+    self.release_lock().await?;
+    res
   }
   async fn read_root_meta(&self) -> Result<Option<RootMeta>> {
     let meta_path = RootMeta::get_path(&self.root);
@@ -107,7 +196,10 @@ impl RootStrategy for SplitPackStrategy {
   }
   async fn write_root_meta(&self, root_meta: &RootMeta) -> Result<()> {
     let meta_path = RootMeta::get_path(&self.root);
-    let mut writer = self.fs.write_file(&meta_path).await?;
+    // write to a hidden temp file and move it to root, so that other processes
+    // never read an incomplete root meta
+    let temp_path = self.root.join(format!(".storage_meta.{}", self.lock_owner));
+    let mut writer = self.fs.write_file(&temp_path).await?;
 
     writer
       .write_line(root_meta.expire_time.to_string().as_str())
@@ -118,6 +210,7 @@ impl RootStrategy for SplitPackStrategy {
       .await?;
 
     writer.flush().await?;
+    self.fs.move_file(&temp_path, &meta_path).await?;
 
     Ok(())
   }
@@ -144,14 +237,47 @@ impl RootStrategy for SplitPackStrategy {
       return Ok(());
     }
 
+    // files may be still read by other processes
+    if has_other_readers(
+      &self.root,
+      &self.lock_owner,
+      self.lock_timeout,
+      self.fs.clone(),
+    )
+    .await?
+    {
+      return Ok(());
+    }
+
     let _ = tokio::try_join!(
       remove_unused_scope_files(scopes, self.fs.clone()),
       remove_unused_scopes(&self.root, root_meta, self.fs.clone()),
-      remove_expired_versions(&self.root, root_options, self.fs.clone())
+      remove_expired_versions(
+        &self.root,
+        root_options,
+        self.lock_owner.clone(),
+        self.lock_timeout,
+        self.fs.clone(),
+      )
     );
 
     Ok(())
   }
+
+  async fn check_lock(&self) -> Result<()> {
+    if let Some(keeper) = self.write_lock_keeper.lock().await.as_ref() {
+      keeper.check().await?;
+    }
+    Ok(())
+  }
+
+  async fn release_lock(&self) -> Result<()> {
+    if let Some(keeper) = self.write_lock_keeper.lock().await.take() {
+      keeper.stop().await;
+    }
+    release_write_lock(&self.root, &self.lock_owner, self.fs.clone()).await?;
+    Ok(())
+  }
 }
 
 impl ScopeStrategy for SplitPackStrategy {}
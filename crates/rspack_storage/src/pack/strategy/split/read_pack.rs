@@ -3,16 +3,35 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use rspack_paths::Utf8Path;
 
-use super::SplitPackStrategy;
+use super::{
+  compression::{decompress, is_header_line, parse_header_line},
+  SplitPackStrategy,
+};
 use crate::{
   error::Result,
   pack::{
-    data::PackKeys,
+    data::{PackCompression, PackKeys},
     strategy::{PackMainContents, PackReadStrategy},
   },
-  FSError, FSOperation,
+  FSError, FSOperation, Reader,
 };
 
+/// Read the compression header if exists, return the compression, the length of
+/// compressed contents and the key lengths line
+async fn read_pack_header(
+  path: &Utf8Path,
+  reader: &mut Reader,
+) -> Result<(Option<(PackCompression, usize)>, String)> {
+  let line = reader.read_line().await?;
+  if is_header_line(&line) {
+    let header =
+      parse_header_line(&line).map_err(|e| FSError::from_message(path, FSOperation::Read, e))?;
+    Ok((Some(header), reader.read_line().await?))
+  } else {
+    Ok((None, line))
+  }
+}
+
 #[async_trait]
 impl PackReadStrategy for SplitPackStrategy {
   async fn read_pack_keys(&self, path: &Utf8Path) -> Result<Option<PackKeys>> {
@@ -22,9 +41,8 @@ impl PackReadStrategy for SplitPackStrategy {
 
     let mut reader = self.fs.read_file(path).await?;
     // read key lengths
-    let key_lengths = reader
-      .read_line()
-      .await?
+    let (_, key_lengths_line) = read_pack_header(path, &mut reader).await?;
+    let key_lengths = key_lengths_line
       .split(" ")
       .map(|item| {
         item.parse::<usize>().map_err(|e| {
@@ -56,9 +74,8 @@ impl PackReadStrategy for SplitPackStrategy {
     }
 
     let mut reader = self.fs.read_file(path).await?;
-    let total_key_length = reader
-      .read_line()
-      .await?
+    let (header, key_lengths_line) = read_pack_header(path, &mut reader).await?;
+    let total_key_length = key_lengths_line
       .split(" ")
       .map(|item| {
         item.parse::<usize>().map_err(|e| {
@@ -106,8 +123,22 @@ impl PackReadStrategy for SplitPackStrategy {
     reader.skip(total_key_length).await?;
 
     let mut contents = vec![];
-    for len in content_lengths {
-      contents.push(Arc::new(reader.read(len).await?));
+    if let Some((compression, compressed_length)) = header {
+      let data = decompress(
+        compression,
+        &reader.read(compressed_length).await?,
+        content_lengths.iter().sum(),
+      )
+      .map_err(|e| FSError::from_message(path, FSOperation::Read, e))?;
+      let mut offset = 0;
+      for len in content_lengths {
+        contents.push(Arc::new(data[offset..offset + len].to_vec()));
+        offset += len;
+      }
+    } else {
+      for len in content_lengths {
+        contents.push(Arc::new(reader.read(len).await?));
+      }
     }
 
     Ok(Some(PackMainContents {
@@ -20,7 +20,7 @@ impl ScopeReadStrategy for SplitPackStrategy {
   async fn ensure_meta(&self, scope: &mut PackScope) -> Result<()> {
     if !scope.meta.loaded() {
       let meta_path = ScopeMeta::get_path(&scope.path);
-      let meta = read_scope_meta(scope.name, &meta_path, self.fs.clone())
+      let meta = read_scope_meta(&scope.name, &meta_path, self.fs.clone())
         .await?
         .unwrap_or_else(|| ScopeMeta::new(&scope.path, &scope.options));
       scope.meta.set_value(meta);
@@ -91,7 +91,7 @@ impl ScopeReadStrategy for SplitPackStrategy {
 }
 
 async fn read_scope_meta(
-  scope: &'static str,
+  scope: &str,
   path: &Utf8Path,
   fs: Arc<dyn FileSystem>,
 ) -> Result<Option<ScopeMeta>> {
@@ -206,8 +206,13 @@ async fn read_keys(scope: &PackScope, strategy: &SplitPackStrategy) -> Result<Ve
     .map(|i| {
       let strategy = strategy.clone();
       let path = i.1.path.clone();
-      tokio::spawn(async move { strategy.read_pack_keys(&path).await })
-        .map_err(|e| Error::from_error(Some(ErrorType::Load), Some(scope.name), Box::new(e)))
+      tokio::spawn(async move { strategy.read_pack_keys(&path).await }).map_err(|e| {
+        Error::from_error(
+          Some(ErrorType::Load),
+          Some(scope.name.as_str()),
+          Box::new(e),
+        )
+      })
     })
     .collect_vec();
 
@@ -251,8 +256,13 @@ async fn read_contents(
     .map(|i| {
       let strategy = strategy.to_owned();
       let path = i.1.path.to_owned();
-      tokio::spawn(async move { strategy.read_pack_contents(&path).await })
-        .map_err(|e| Error::from_error(Some(ErrorType::Load), Some(scope.name), Box::new(e)))
+      tokio::spawn(async move { strategy.read_pack_contents(&path).await }).map_err(|e| {
+        Error::from_error(
+          Some(ErrorType::Load),
+          Some(scope.name.as_str()),
+          Box::new(e),
+        )
+      })
     })
     .collect_vec();
   let pack_contents = join_all(tasks).await.into_iter().process_results(|iter| {
@@ -285,7 +295,7 @@ mod tests {
   use crate::{
     error::Result,
     pack::{
-      data::{PackOptions, PackScope, ScopeMeta},
+      data::{PackCompression, PackOptions, PackScope, ScopeMeta},
       strategy::{
         split::util::test_pack_utils::{
           clean_strategy, create_strategies, mock_pack_file, mock_scope_meta_file,
@@ -373,6 +383,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 16,
+        compression: PackCompression::None,
       });
       let mut scope = PackScope::new(
         "scope_name",
@@ -261,6 +261,7 @@ pub mod test_pack_utils {
     changed.extend(strategy.write_packs(scope).await?);
     changed.extend(strategy.write_meta(scope).await?);
     strategy.merge_changed(changed.clone()).await?;
+    strategy.release_lock().await?;
     flag_scope_wrote(scope);
 
     Ok(changed)
@@ -297,6 +298,7 @@ pub mod test_pack_utils {
           fs.clone(),
           Some(1_usize),
           Some(2_usize),
+          1000,
         )
       })
       .collect_vec()
@@ -44,7 +44,13 @@ impl ScopeValidateStrategy for SplitPackStrategy {
             Err(_) => false,
           }
         })
-        .map_err(|e| Error::from_error(Some(ErrorType::Validate), Some(scope.name), e.into()))
+        .map_err(|e| {
+          Error::from_error(
+            Some(ErrorType::Validate),
+            Some(scope.name.as_str()),
+            e.into(),
+          )
+        })
       });
 
     let validate_results = join_all(tasks)
@@ -84,7 +90,7 @@ mod tests {
   use crate::{
     error::{Error, ErrorType, Result, ValidateResult},
     pack::{
-      data::{PackOptions, PackScope, RootMeta, ScopeMeta},
+      data::{PackCompression, PackOptions, PackScope, RootMeta, ScopeMeta},
       strategy::{
         split::{
           handle_file::prepare_scope,
@@ -106,6 +112,7 @@ mod tests {
     let same_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 100,
+      compression: PackCompression::None,
     });
     let mut scope = PackScope::new("scope_name", scope_path, same_options);
     strategy.ensure_meta(&mut scope).await?;
@@ -122,6 +129,7 @@ mod tests {
     let bucket_changed_options = Arc::new(PackOptions {
       bucket_size: 1,
       pack_size: 100,
+      compression: PackCompression::None,
     });
     let mut scope = PackScope::new(
       "scope_name",
@@ -142,6 +150,7 @@ mod tests {
     let max_size_changed_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 99,
+      compression: PackCompression::None,
     });
     let mut scope = PackScope::new(
       "scope_name",
@@ -218,6 +227,7 @@ mod tests {
       let pack_options = Arc::new(PackOptions {
         bucket_size: 10,
         pack_size: 100,
+        compression: PackCompression::None,
       });
       mock_scope_meta_file(
         &ScopeMeta::get_path(&scope_path),
@@ -254,6 +264,7 @@ mod tests {
       let pack_options = Arc::new(PackOptions {
         bucket_size: 10,
         pack_size: 100,
+        compression: PackCompression::None,
       });
       let mut mock_scope = PackScope::empty("scope_name", scope_path.clone(), pack_options.clone());
       let updates = mock_updates(0, 100, 30, UpdateVal::Value("val".to_string()));
@@ -6,11 +6,15 @@ use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-use super::{handle_file::redirect_to_path, SplitPackStrategy};
+use super::{
+  compression::{compress, create_header_line},
+  handle_file::redirect_to_path,
+  SplitPackStrategy,
+};
 use crate::{
   error::Result,
   pack::{
-    data::{Pack, PackFileMeta, PackOptions},
+    data::{Pack, PackCompression, PackFileMeta, PackOptions},
     strategy::{split::util::get_name, PackReadStrategy, PackWriteStrategy, UpdatePacksResult},
     ScopeUpdate,
   },
@@ -172,7 +176,7 @@ impl PackWriteStrategy for SplitPackStrategy {
     })
   }
 
-  async fn write_pack(&self, pack: &Pack) -> Result<()> {
+  async fn write_pack(&self, pack: &Pack, compression: PackCompression) -> Result<()> {
     let path = redirect_to_path(&pack.path, &self.root, &self.temp_root)?;
     let keys = pack.keys.expect_value();
     let contents = pack.contents.expect_value();
@@ -181,8 +185,23 @@ impl PackWriteStrategy for SplitPackStrategy {
       panic!("pack keys and contents length not match");
     }
 
+    let compressed = compress(
+      compression,
+      &contents
+        .iter()
+        .flat_map(|content| content.iter().copied())
+        .collect::<Vec<_>>(),
+    );
+
     let mut writer = self.fs.write_file(&path).await?;
 
+    // compression header line
+    if let Some(compressed) = &compressed {
+      writer
+        .write_line(&create_header_line(compression, compressed.len()))
+        .await?;
+    }
+
     // key meta line
     writer
       .write_line(
@@ -216,8 +235,12 @@ impl PackWriteStrategy for SplitPackStrategy {
     }
 
     // contents blob
-    for content in contents {
-      writer.write(content).await?;
+    if let Some(compressed) = &compressed {
+      writer.write(compressed).await?;
+    } else {
+      for content in contents {
+        writer.write(content).await?;
+      }
     }
 
     writer.flush().await?;
@@ -391,13 +414,13 @@ mod tests {
   use crate::{
     error::Result,
     pack::{
-      data::{Pack, PackFileMeta, PackOptions},
+      data::{Pack, PackCompression, PackFileMeta, PackOptions},
       strategy::{
         split::{
           handle_file::redirect_to_path,
           util::test_pack_utils::{clean_strategy, create_strategies, mock_updates, UpdateVal},
         },
-        PackWriteStrategy, SplitPackStrategy, UpdatePacksResult,
+        PackReadStrategy, PackWriteStrategy, SplitPackStrategy, UpdatePacksResult,
       },
     },
   };
@@ -414,7 +437,7 @@ mod tests {
       Arc::new("val_2".as_bytes().to_vec()),
     ]);
     pack.generations = vec![1_usize, 2_usize];
-    strategy.write_pack(&pack).await?;
+    strategy.write_pack(&pack, PackCompression::None).await?;
 
     let mut reader = strategy
       .fs
@@ -434,6 +457,43 @@ mod tests {
     Ok(())
   }
 
+  async fn test_write_compressed_pack(strategy: &SplitPackStrategy) -> Result<()> {
+    let dir = strategy.root.join("write_compressed");
+    let mut pack = Pack::new(dir);
+    let keys = (0..10)
+      .map(|i| Arc::new(format!("key_{i}").into_bytes()))
+      .collect_vec();
+    let contents = (0..10)
+      .map(|i| Arc::new(format!("val_{i}_{}", "x".repeat(100)).into_bytes()))
+      .collect_vec();
+    pack.keys.set_value(keys.clone());
+    pack.contents.set_value(contents.clone());
+    pack.generations = vec![1_usize; 10];
+    strategy
+      .write_pack(&pack, PackCompression::Deflate(6))
+      .await?;
+
+    let path = redirect_to_path(&pack.path, &strategy.root, &strategy.temp_root)?;
+    let mut reader = strategy.fs.read_file(&path).await?;
+    assert!(reader.read_line().await?.starts_with("@deflate "));
+    assert!(strategy.fs.metadata(&path).await?.size < pack.size() as u64);
+
+    assert_eq!(
+      strategy
+        .read_pack_keys(&path)
+        .await?
+        .expect("should have keys"),
+      keys
+    );
+    let main_contents = strategy
+      .read_pack_contents(&path)
+      .await?
+      .expect("should have contents");
+    assert_eq!(main_contents.contents, contents);
+    assert_eq!(main_contents.generations, vec![1_usize; 10]);
+    Ok(())
+  }
+
   fn update_packs(update_res: UpdatePacksResult) -> HashMap<PackFileMeta, Pack> {
     update_res
       .remain_packs
@@ -458,6 +518,7 @@ mod tests {
     let options = PackOptions {
       bucket_size: 1,
       pack_size: 2100,
+      compression: PackCompression::None,
     };
 
     // half pack
@@ -583,6 +644,9 @@ mod tests {
       let _ = test_write_pack(&strategy)
         .await
         .map_err(|e| panic!("{}", e));
+      let _ = test_write_compressed_pack(&strategy)
+        .await
+        .map_err(|e| panic!("{}", e));
     }
   }
 
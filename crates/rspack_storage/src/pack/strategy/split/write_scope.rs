@@ -9,6 +9,7 @@ use super::{
   handle_file::{
     move_files, prepare_scope_dirs, redirect_to_path, remove_files, remove_lock, write_lock,
   },
+  lock::{has_other_readers, refresh_write_lock},
   util::{choose_bucket, flag_scope_wrote},
   SplitPackStrategy,
 };
@@ -16,7 +17,7 @@ use crate::{
   error::Result,
   fs::BatchFSError,
   pack::{
-    data::{Pack, PackScope},
+    data::{Pack, PackCompression, PackScope},
     strategy::{PackWriteStrategy, ScopeUpdate, ScopeWriteStrategy, WriteScopeResult},
   },
 };
@@ -24,22 +25,25 @@ use crate::{
 #[async_trait]
 impl ScopeWriteStrategy for SplitPackStrategy {
   async fn before_all(&self, scopes: &mut HashMap<String, PackScope>) -> Result<()> {
+    // files are loaded before saving, reading of this process is finished
+    self.remove_read_lock().await?;
+    self.acquire_lock().await?;
+    // previous saving may be interrupted by a failed or killed process
+    self.recovery().await?;
     prepare_scope_dirs(scopes, &self.root, &self.temp_root, self.fs.clone()).await?;
     Ok(())
   }
 
   async fn merge_changed(&self, changed: WriteScopeResult) -> Result<()> {
-    // remove files with `.lock`
-    write_lock(
-      "remove.lock",
-      &changed.wrote_files,
-      &self.root,
-      &self.temp_root,
-      self.fs.clone(),
-    )
-    .await?;
-    remove_files(changed.removed_files, self.fs.clone()).await?;
-    remove_lock("remove.lock", &self.root, self.fs.clone()).await?;
+    refresh_write_lock(&self.root, &self.lock_owner, self.fs.clone()).await?;
+
+    // files are removed after new files are moved, so that the storage is
+    // always complete, and rewritten files should not be removed
+    let removed_files = changed
+      .removed_files
+      .difference(&changed.wrote_files)
+      .cloned()
+      .collect::<HashSet<_>>();
 
     // move files with `.lock`
     write_lock(
@@ -59,6 +63,29 @@ impl ScopeWriteStrategy for SplitPackStrategy {
     .await?;
     remove_lock("move.lock", &self.root, self.fs.clone()).await?;
 
+    // replaced files may be still read by other processes,
+    // they will be removed as unused files by later cleaning
+    if !has_other_readers(
+      &self.root,
+      &self.lock_owner,
+      self.lock_timeout,
+      self.fs.clone(),
+    )
+    .await?
+    {
+      // remove files with `.lock`
+      write_lock(
+        "remove.lock",
+        &removed_files,
+        &self.root,
+        &self.temp_root,
+        self.fs.clone(),
+      )
+      .await?;
+      remove_files(removed_files, self.fs.clone()).await?;
+      remove_lock("remove.lock", &self.root, self.fs.clone()).await?;
+    }
+
     self.fs.remove_dir(&self.temp_root).await?;
     Ok(())
   }
@@ -234,7 +261,7 @@ impl ScopeWriteStrategy for SplitPackStrategy {
       .partition(|x| x.1.wrote);
 
     let (new_packs, new_pack_metas): (Vec<_>, Vec<_>) = new_pack_infos.into_iter().unzip();
-    let write_results = batch_write_packs(new_packs, self).await?;
+    let write_results = batch_write_packs(new_packs, scope.options.compression, self).await?;
 
     let mut wrote_packs = wrote_pack_infos
       .into_iter()
@@ -324,13 +351,17 @@ impl ScopeWriteStrategy for SplitPackStrategy {
   }
 }
 
-async fn save_pack(pack: &Pack, strategy: &SplitPackStrategy) -> Result<String> {
+async fn save_pack(
+  pack: &Pack,
+  compression: PackCompression,
+  strategy: &SplitPackStrategy,
+) -> Result<String> {
   let keys = pack.keys.expect_value();
   let contents = pack.contents.expect_value();
   if keys.len() != contents.len() {
     panic!("pack keys and contents length not match");
   }
-  strategy.write_pack(pack).await?;
+  strategy.write_pack(pack, compression).await?;
   let hash = strategy
     .get_pack_hash(
       &redirect_to_path(&pack.path, &strategy.root, &strategy.temp_root)?,
@@ -343,11 +374,16 @@ async fn save_pack(pack: &Pack, strategy: &SplitPackStrategy) -> Result<String>
 
 async fn batch_write_packs(
   packs: Vec<Pack>,
+  compression: PackCompression,
   strategy: &SplitPackStrategy,
 ) -> Result<Vec<(String, Pack)>> {
   let tasks = packs.into_iter().map(|pack| {
     let strategy = strategy.to_owned();
-    tokio::spawn(async move { save_pack(&pack, &strategy).await.map(|hash| (hash, pack)) })
+    tokio::spawn(async move {
+      save_pack(&pack, compression, &strategy)
+        .await
+        .map(|hash| (hash, pack))
+    })
   });
 
   let res = BatchFSError::try_from_joined_result(
@@ -368,7 +404,7 @@ mod tests {
   use crate::{
     error::Result,
     pack::{
-      data::{PackOptions, PackScope},
+      data::{PackCompression, PackOptions, PackScope},
       strategy::{
         split::util::test_pack_utils::{
           clean_strategy, count_bucket_packs, count_scope_packs, create_strategies,
@@ -574,6 +610,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 36,
+        compression: PackCompression::None,
       });
       let mut scope = PackScope::empty(
         "scope_name",
@@ -597,6 +634,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 10,
         pack_size: 36,
+        compression: PackCompression::None,
       });
       let mut scope = PackScope::empty(
         "scope_name",
@@ -618,6 +656,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 2100,
+        compression: PackCompression::None,
       });
       let mut scope = PackScope::empty(
         "scope_name",
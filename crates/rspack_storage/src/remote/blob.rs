@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+
+use crate::{ItemKey, ItemPairs, ItemValue};
+
+/// Get the content hash of a blob, it is used as the blob key in remote store.
+///
+/// The remote store is shared between machines, so a cryptographic hash is used
+/// to make sure a blob can't be replaced by another one with the same hash.
+pub fn get_blob_hash(bytes: &[u8]) -> String {
+  Sha256::digest(bytes)
+    .iter()
+    .map(|byte| format!("{byte:02x}"))
+    .collect()
+}
+
+/// Encode scope items to a blob
+///
+/// The items are sorted by key so that the same scope contents always
+/// produce the same blob and the same hash.
+pub fn encode_blob<'a>(items: impl Iterator<Item = (&'a ItemKey, &'a ItemValue)>) -> Vec<u8> {
+  let mut items = items.collect::<Vec<_>>();
+  items.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+  let mut bytes = vec![];
+  for (key, value) in items {
+    bytes.extend((key.len() as u64).to_le_bytes());
+    bytes.extend(key);
+    bytes.extend((value.len() as u64).to_le_bytes());
+    bytes.extend(value);
+  }
+  bytes
+}
+
+/// Decode blob to scope items, return `None` if the blob is malformed.
+pub fn decode_blob(bytes: &[u8]) -> Option<ItemPairs> {
+  fn read_chunk<'a>(bytes: &'a [u8], offset: &mut usize) -> Option<&'a [u8]> {
+    let len_end = offset.checked_add(8)?;
+    let len = u64::from_le_bytes(bytes.get(*offset..len_end)?.try_into().ok()?);
+    let end = len_end.checked_add(usize::try_from(len).ok()?)?;
+    let chunk = bytes.get(len_end..end)?;
+    *offset = end;
+    Some(chunk)
+  }
+
+  let mut res = vec![];
+  let mut offset = 0;
+  while offset < bytes.len() {
+    let key = read_chunk(bytes, &mut offset)?;
+    let value = read_chunk(bytes, &mut offset)?;
+    res.push((Arc::new(key.to_vec()), Arc::new(value.to_vec())));
+  }
+  Some(res)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{decode_blob, encode_blob, get_blob_hash};
+
+  #[test]
+  fn should_blob_encode_and_decode() {
+    let items = [
+      (b"b".to_vec(), b"value_b".to_vec()),
+      (b"a".to_vec(), b"".to_vec()),
+    ];
+    let bytes = encode_blob(items.iter().map(|(k, v)| (k, v)));
+    let reversed = encode_blob(items.iter().rev().map(|(k, v)| (k, v)));
+    assert_eq!(get_blob_hash(&bytes), get_blob_hash(&reversed));
+    assert_eq!(get_blob_hash(&bytes).len(), 64);
+
+    let decoded = decode_blob(&bytes).expect("should decode blob");
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(decoded[0].0.as_slice(), b"a");
+    assert_eq!(decoded[1].1.as_slice(), b"value_b");
+
+    assert!(decode_blob(&bytes[0..bytes.len() - 1]).is_none());
+  }
+}
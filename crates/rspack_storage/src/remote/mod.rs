@@ -0,0 +1,262 @@
+mod blob;
+mod store;
+
+use std::sync::{Arc, Mutex};
+
+use blob::{decode_blob, encode_blob, get_blob_hash};
+use rustc_hash::FxHashMap as HashMap;
+pub use store::{HttpRemoteStore, HttpRemoteStoreOptions, RemoteStore};
+use tokio::sync::oneshot::{channel, Receiver};
+
+use crate::{
+  error::{Error, ErrorType, Result},
+  ItemKey, ItemPairs, ItemValue, Storage,
+};
+
+pub struct RemoteStorageOptions {
+  /// Local storage, usually a `PackStorage`
+  pub local: Arc<dyn Storage>,
+  /// Shared remote store
+  pub store: Arc<dyn RemoteStore>,
+  /// Storage version, scopes of different versions are not shared
+  pub version: String,
+  /// Whether to upload saved scopes to remote store
+  pub push: bool,
+}
+
+#[derive(Debug, Default)]
+struct ScopeState {
+  items: HashMap<ItemKey, ItemValue>,
+  dirty: bool,
+}
+
+/// Remote Storage
+///
+/// Scopes are read from local storage first, and pulled from remote store
+/// when local storage has nothing. The pulled items are written back to local
+/// storage when saving, so the next load won't access remote store again.
+/// Each scope is pulled at most once, a failed pull is treated as a cache miss
+/// and reported by `take_warnings`.
+///
+/// The remote store is content addressed, each scope is encoded to a blob which is
+/// stored at `blobs/{hash}`, and `refs/{version}/{scope}` records the hash of the
+/// latest blob of the scope. Blobs are checked with their hash after download.
+#[derive(Debug)]
+pub struct RemoteStorage {
+  local: Arc<dyn Storage>,
+  store: Arc<dyn RemoteStore>,
+  version: String,
+  push: bool,
+  scopes: Mutex<HashMap<&'static str, ScopeState>>,
+  /// Pulled items which are not saved to local storage yet, `None` means nothing to load
+  pulled: Mutex<HashMap<&'static str, Option<ItemPairs>>>,
+  warnings: Mutex<Vec<Error>>,
+}
+
+impl RemoteStorage {
+  pub fn new(options: RemoteStorageOptions) -> Self {
+    Self {
+      local: options.local,
+      store: options.store,
+      version: options.version,
+      push: options.push,
+      scopes: Default::default(),
+      pulled: Default::default(),
+      warnings: Default::default(),
+    }
+  }
+
+  /// Get the items of scope from remote store, only the first call accesses the remote store
+  async fn load_remote(&self, scope: &'static str) -> Option<ItemPairs> {
+    if let Some(items) = self.pulled.lock().expect("should get lock").get(scope) {
+      return items.clone();
+    }
+    let items = match self.pull(scope).await {
+      Ok(items) => items,
+      Err(e) => {
+        self
+          .warnings
+          .lock()
+          .expect("should get lock")
+          .push(Error::from_error(
+            Some(ErrorType::Load),
+            Some(scope),
+            Box::new(e),
+          ));
+        None
+      }
+    };
+    if let Some(items) = &items {
+      for (key, value) in items {
+        self
+          .local
+          .set(scope, key.as_ref().clone(), value.as_ref().clone());
+      }
+    }
+    self
+      .pulled
+      .lock()
+      .expect("should get lock")
+      .insert(scope, items.clone());
+    items
+  }
+
+  async fn pull(&self, scope: &'static str) -> Result<Option<ItemPairs>> {
+    let ref_key = get_ref_key(&self.version, scope);
+    let Some(hash) = self.store.get(&ref_key).await? else {
+      return Ok(None);
+    };
+    let hash = String::from_utf8(hash).map_err(|_| {
+      Error::from_reason(
+        Some(ErrorType::Load),
+        Some(scope),
+        format!("remote ref `{ref_key}` is not a valid hash"),
+      )
+    })?;
+    let Some(bytes) = self.store.get(&get_blob_key(&hash)).await? else {
+      return Ok(None);
+    };
+    if get_blob_hash(&bytes) != hash {
+      return Err(Error::from_reason(
+        Some(ErrorType::Load),
+        Some(scope),
+        format!("integrity check failed for remote blob `{hash}`"),
+      ));
+    }
+    decode_blob(&bytes).map(Some).ok_or_else(|| {
+      Error::from_reason(
+        Some(ErrorType::Load),
+        Some(scope),
+        format!("remote blob `{hash}` is malformed"),
+      )
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl Storage for RemoteStorage {
+  async fn load(&self, scope: &'static str) -> Result<ItemPairs> {
+    let mut items = self.local.load(scope).await?;
+    let mut pulled = false;
+    if items.is_empty() {
+      if let Some(remote_items) = self.load_remote(scope).await {
+        items = remote_items;
+        pulled = true;
+      }
+    }
+
+    if self.push {
+      let mut scopes = self.scopes.lock().expect("should get lock");
+      scopes.entry(scope).or_insert_with(|| ScopeState {
+        items: items
+          .iter()
+          .map(|(key, value)| (key.as_ref().clone(), value.as_ref().clone()))
+          .collect(),
+        // local items may not exist in remote store
+        dirty: !pulled && !items.is_empty(),
+      });
+    }
+    Ok(items)
+  }
+
+  fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
+    if self.push {
+      let mut scopes = self.scopes.lock().expect("should get lock");
+      if let Some(state) = scopes.get_mut(scope) {
+        state.items.insert(key.clone(), value.clone());
+        state.dirty = true;
+      }
+    }
+    self.local.set(scope, key, value);
+  }
+
+  fn remove(&self, scope: &'static str, key: &[u8]) {
+    if self.push {
+      let mut scopes = self.scopes.lock().expect("should get lock");
+      if let Some(state) = scopes.get_mut(scope) {
+        state.items.remove(key);
+        state.dirty = true;
+      }
+    }
+    self.local.remove(scope, key);
+  }
+
+  fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
+    let local_rx = self.local.trigger_save()?;
+    // pulled items are saved to local storage now
+    for items in self.pulled.lock().expect("should get lock").values_mut() {
+      *items = None;
+    }
+    if !self.push {
+      return Ok(local_rx);
+    }
+
+    // only the scopes which have been loaded are complete and can be pushed
+    let blobs = self
+      .scopes
+      .lock()
+      .expect("should get lock")
+      .iter_mut()
+      .filter(|(_, state)| state.dirty)
+      .map(|(scope, state)| {
+        state.dirty = false;
+        (*scope, encode_blob(state.items.iter()))
+      })
+      .collect::<Vec<_>>();
+
+    let store = self.store.clone();
+    let version = self.version.clone();
+    let (tx, rx) = channel();
+    tokio::spawn(async move {
+      let res = match local_rx.await {
+        Ok(Ok(())) => push_blobs(store.as_ref(), &version, blobs).await,
+        Ok(Err(e)) => Err(e),
+        Err(_) => Err(Error::from_reason(
+          Some(ErrorType::Save),
+          None,
+          "local storage save has been canceled".to_string(),
+        )),
+      };
+      let _ = tx.send(res);
+    });
+    Ok(rx)
+  }
+
+  fn take_warnings(&self) -> Vec<Error> {
+    let mut warnings = std::mem::take(&mut *self.warnings.lock().expect("should get lock"));
+    warnings.extend(self.local.take_warnings());
+    warnings
+  }
+}
+
+async fn push_blobs(
+  store: &dyn RemoteStore,
+  version: &str,
+  blobs: Vec<(&'static str, Vec<u8>)>,
+) -> Result<()> {
+  for (scope, bytes) in blobs {
+    let hash = get_blob_hash(&bytes);
+    let ref_key = get_ref_key(version, scope);
+    if store
+      .get(&ref_key)
+      .await?
+      .is_some_and(|current| current == hash.as_bytes())
+    {
+      continue;
+    }
+    let blob_key = get_blob_key(&hash);
+    if !store.exists(&blob_key).await? {
+      store.put(&blob_key, bytes).await?;
+    }
+    store.put(&ref_key, hash.into_bytes()).await?;
+  }
+  Ok(())
+}
+
+fn get_ref_key(version: &str, scope: &str) -> String {
+  format!("refs/{version}/{scope}")
+}
+
+fn get_blob_key(hash: &str) -> String {
+  format!("blobs/{hash}")
+}
@@ -0,0 +1,171 @@
+use std::{io::Read, time::Duration};
+
+use crate::error::{Error, Result};
+
+/// Remote blob store
+///
+/// A remote store is a flat key value store shared between machines,
+/// such as a http blob server. Keys only contain `[a-z0-9_/-.]`.
+#[async_trait::async_trait]
+pub trait RemoteStore: std::fmt::Debug + Sync + Send {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()>;
+  async fn exists(&self, key: &str) -> Result<bool>;
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpRemoteStoreOptions {
+  /// Base url of the store, `http://` and `https://` are supported
+  pub url: String,
+  /// Timeout of connecting to the server in milliseconds
+  pub connect_timeout: u64,
+  /// Timeout of reading the response in milliseconds
+  pub read_timeout: u64,
+}
+
+/// Http remote store
+///
+/// Use `GET`, `PUT` and `HEAD` requests to `{url}/{key}` to access blobs,
+/// any server which supports these three methods can be used as a shared store.
+#[derive(Debug)]
+pub struct HttpRemoteStore {
+  url: String,
+  agent: ureq::Agent,
+}
+
+struct HttpResponse {
+  status: u16,
+  body: Vec<u8>,
+}
+
+impl HttpRemoteStore {
+  pub fn new(options: HttpRemoteStoreOptions) -> Result<Self> {
+    let url = options.url.trim_end_matches('/');
+    let Some(rest) = url
+      .strip_prefix("http://")
+      .or_else(|| url.strip_prefix("https://"))
+    else {
+      return Err(Error::from_reason(
+        None,
+        None,
+        format!(
+          "unsupported remote store url `{}`, only `http://` and `https://` are supported",
+          options.url
+        ),
+      ));
+    };
+    if rest.is_empty() || rest.starts_with('/') {
+      return Err(Error::from_reason(
+        None,
+        None,
+        format!("invalid remote store url `{}`", options.url),
+      ));
+    }
+    let agent = ureq::AgentBuilder::new()
+      .timeout_connect(Duration::from_millis(options.connect_timeout))
+      .timeout_read(Duration::from_millis(options.read_timeout))
+      .timeout_write(Duration::from_millis(options.read_timeout))
+      .build();
+    Ok(Self {
+      url: url.to_string(),
+      agent,
+    })
+  }
+
+  async fn request(&self, method: &str, key: &str, body: Option<Vec<u8>>) -> Result<HttpResponse> {
+    let url = format!("{}/{key}", self.url);
+    let is_head = method == "HEAD";
+    let request = self.agent.request(method, &url);
+    // ureq is a blocking client, keep it out of the async worker threads
+    let res = tokio::task::spawn_blocking(move || {
+      let res = match body {
+        Some(body) => request.send_bytes(&body),
+        None => request.call(),
+      };
+      let res = match res {
+        Ok(res) => res,
+        // 4xx and 5xx responses are handled by the caller
+        Err(ureq::Error::Status(_, res)) => res,
+        Err(e) => return Err(e.to_string()),
+      };
+      let status = res.status();
+      let mut body = vec![];
+      if !is_head {
+        res
+          .into_reader()
+          .read_to_end(&mut body)
+          .map_err(|e| e.to_string())?;
+      }
+      Ok(HttpResponse { status, body })
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|res| res);
+
+    res.map_err(|e| Error::from_reason(None, None, format!("{method} {url} failed: {e}")))
+  }
+
+  fn unexpected_status(&self, method: &str, key: &str, status: u16) -> Error {
+    Error::from_reason(
+      None,
+      None,
+      format!(
+        "{method} {}/{key} failed: unexpected status {status}",
+        self.url
+      ),
+    )
+  }
+}
+
+#[async_trait::async_trait]
+impl RemoteStore for HttpRemoteStore {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    let res = self.request("GET", key, None).await?;
+    match res.status {
+      200 => Ok(Some(res.body)),
+      404 => Ok(None),
+      status => Err(self.unexpected_status("GET", key, status)),
+    }
+  }
+
+  async fn put(&self, key: &str, value: Vec<u8>) -> Result<()> {
+    let res = self.request("PUT", key, Some(value)).await?;
+    match res.status {
+      200..=299 => Ok(()),
+      status => Err(self.unexpected_status("PUT", key, status)),
+    }
+  }
+
+  async fn exists(&self, key: &str) -> Result<bool> {
+    let res = self.request("HEAD", key, None).await?;
+    match res.status {
+      200 => Ok(true),
+      404 => Ok(false),
+      status => Err(self.unexpected_status("HEAD", key, status)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{HttpRemoteStore, HttpRemoteStoreOptions};
+
+  fn options(url: &str) -> HttpRemoteStoreOptions {
+    HttpRemoteStoreOptions {
+      url: url.to_string(),
+      connect_timeout: 1000,
+      read_timeout: 1000,
+    }
+  }
+
+  #[test]
+  fn should_parse_url() {
+    let store =
+      HttpRemoteStore::new(options("http://127.0.0.1:8080/cache/")).expect("should parse url");
+    assert_eq!(store.url, "http://127.0.0.1:8080/cache");
+    let store = HttpRemoteStore::new(options("https://example.com")).expect("should support https");
+    assert_eq!(store.url, "https://example.com");
+    assert!(HttpRemoteStore::new(options("ftp://example.com")).is_err());
+    assert!(HttpRemoteStore::new(options("https:///cache")).is_err());
+  }
+}
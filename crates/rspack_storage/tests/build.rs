@@ -5,7 +5,7 @@ mod test_storage_build {
   use rspack_fs::{MemoryFileSystem, NativeFileSystem};
   use rspack_paths::{AssertUtf8, Utf8PathBuf};
   use rspack_storage::{
-    BridgeFileSystem, FileSystem, PackStorage, PackStorageOptions, Result, Storage,
+    BridgeFileSystem, FileSystem, PackCompression, PackStorage, PackStorageOptions, Result, Storage,
   };
 
   pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
@@ -33,10 +33,13 @@ mod test_storage_build {
       fs,
       bucket_size: 10,
       pack_size: 200,
+      compression: PackCompression::None,
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      lock_timeout: 1000,
     }
   }
 
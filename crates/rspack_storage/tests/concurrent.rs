@@ -0,0 +1,454 @@
+#[cfg(test)]
+mod test_storage_concurrent {
+  use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+      atomic::{AtomicUsize, Ordering},
+      Arc,
+    },
+  };
+
+  use rspack_fs::{FileMetadata, MemoryFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, PackCompression, PackStorage,
+    PackStorageOptions, Reader, Result, Storage, Writer,
+  };
+  use rustc_hash::FxHashSet as HashSet;
+
+  /// Mock a process killed after `kill_on` operations, all operations fail after that
+  #[derive(Debug)]
+  pub struct KilledFileSystem {
+    pub fs: Arc<dyn FileSystem>,
+    pub operations: AtomicUsize,
+    pub kill_on: usize,
+  }
+
+  impl KilledFileSystem {
+    fn check(&self, path: &Utf8Path, operation: FSOperation) -> FSResult<()> {
+      if self.operations.fetch_add(1, Ordering::Relaxed) >= self.kill_on {
+        Err(FSError::from_message(
+          path,
+          operation,
+          "process killed".to_string(),
+        ))
+      } else {
+        Ok(())
+      }
+    }
+  }
+
+  #[async_trait::async_trait]
+  impl FileSystem for KilledFileSystem {
+    async fn exists(&self, path: &Utf8Path) -> FSResult<bool> {
+      self.check(path, FSOperation::Stat)?;
+      self.fs.exists(path).await
+    }
+
+    async fn remove_dir(&self, path: &Utf8Path) -> FSResult<()> {
+      self.check(path, FSOperation::Remove)?;
+      self.fs.remove_dir(path).await
+    }
+
+    async fn ensure_dir(&self, path: &Utf8Path) -> FSResult<()> {
+      self.check(path, FSOperation::Dir)?;
+      self.fs.ensure_dir(path).await
+    }
+
+    async fn write_file(&self, path: &Utf8Path) -> FSResult<Writer> {
+      self.check(path, FSOperation::Write)?;
+      self.fs.write_file(path).await
+    }
+
+    async fn read_file(&self, path: &Utf8Path) -> FSResult<Reader> {
+      self.check(path, FSOperation::Read)?;
+      self.fs.read_file(path).await
+    }
+
+    async fn read_dir(&self, path: &Utf8Path) -> FSResult<HashSet<String>> {
+      self.check(path, FSOperation::Read)?;
+      self.fs.read_dir(path).await
+    }
+
+    async fn metadata(&self, path: &Utf8Path) -> FSResult<FileMetadata> {
+      self.check(path, FSOperation::Stat)?;
+      self.fs.metadata(path).await
+    }
+
+    async fn remove_file(&self, path: &Utf8Path) -> FSResult<()> {
+      self.check(path, FSOperation::Remove)?;
+      self.fs.remove_file(path).await
+    }
+
+    async fn move_file(&self, from: &Utf8Path, to: &Utf8Path) -> FSResult<()> {
+      self.check(from, FSOperation::Move)?;
+      self.fs.move_file(from, to).await
+    }
+
+    async fn create_new_file(&self, path: &Utf8Path, data: &[u8]) -> FSResult<()> {
+      self.check(path, FSOperation::Write)?;
+      self.fs.create_new_file(path, data).await
+    }
+  }
+
+  /// Mock a process paused when writing files in `slow_dir`
+  #[derive(Debug)]
+  pub struct SlowFileSystem {
+    pub fs: Arc<dyn FileSystem>,
+    pub slow_dir: Utf8PathBuf,
+    pub delay: u64,
+  }
+
+  #[async_trait::async_trait]
+  impl FileSystem for SlowFileSystem {
+    async fn exists(&self, path: &Utf8Path) -> FSResult<bool> {
+      self.fs.exists(path).await
+    }
+
+    async fn remove_dir(&self, path: &Utf8Path) -> FSResult<()> {
+      self.fs.remove_dir(path).await
+    }
+
+    async fn ensure_dir(&self, path: &Utf8Path) -> FSResult<()> {
+      self.fs.ensure_dir(path).await
+    }
+
+    async fn write_file(&self, path: &Utf8Path) -> FSResult<Writer> {
+      if path.starts_with(&self.slow_dir) {
+        tokio::time::sleep(std::time::Duration::from_millis(self.delay)).await;
+      }
+      self.fs.write_file(path).await
+    }
+
+    async fn read_file(&self, path: &Utf8Path) -> FSResult<Reader> {
+      self.fs.read_file(path).await
+    }
+
+    async fn read_dir(&self, path: &Utf8Path) -> FSResult<HashSet<String>> {
+      self.fs.read_dir(path).await
+    }
+
+    async fn metadata(&self, path: &Utf8Path) -> FSResult<FileMetadata> {
+      self.fs.metadata(path).await
+    }
+
+    async fn remove_file(&self, path: &Utf8Path) -> FSResult<()> {
+      self.fs.remove_file(path).await
+    }
+
+    async fn move_file(&self, from: &Utf8Path, to: &Utf8Path) -> FSResult<()> {
+      self.fs.move_file(from, to).await
+    }
+
+    async fn create_new_file(&self, path: &Utf8Path, data: &[u8]) -> FSResult<()> {
+      self.fs.create_new_file(path, data).await
+    }
+  }
+
+  type Items = HashMap<Vec<u8>, Vec<u8>>;
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_concurrent/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_pack_options(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    lock_timeout: u64,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorageOptions {
+    PackStorageOptions {
+      version: "xxx".to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 2,
+      pack_size: 200,
+      compression: PackCompression::None,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      lock_timeout,
+    }
+  }
+
+  fn mock_items(start: usize, end: usize, prefix: &str) -> Items {
+    (start..end)
+      .map(|i| {
+        (
+          format!("key_{:0>3}", i).into_bytes(),
+          format!("{prefix}_{:0>3}", i).into_bytes(),
+        )
+      })
+      .collect()
+  }
+
+  async fn load_items(storage: &PackStorage) -> Result<Items> {
+    Ok(
+      storage
+        .load("test_scope")
+        .await?
+        .into_iter()
+        .map(|(key, value)| (key.as_ref().clone(), value.as_ref().clone()))
+        .collect(),
+    )
+  }
+
+  /// Load the scope and save the updated items, return `true` if saved
+  async fn update_items(storage: &PackStorage, updated: &Items, removed: &Items) -> bool {
+    if storage.load("test_scope").await.is_err() {
+      return false;
+    }
+    for (key, value) in updated {
+      storage.set("test_scope", key.clone(), value.clone());
+    }
+    for key in removed.keys() {
+      storage.remove("test_scope", key);
+    }
+    match storage.trigger_save() {
+      Ok(rx) => rx.await.is_ok_and(|res| res.is_ok()),
+      Err(_) => false,
+    }
+  }
+
+  async fn test_killed_when_saving(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    kill_on: usize,
+  ) -> Result<bool> {
+    let fs: Arc<dyn FileSystem> = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let old_items = mock_items(0, 100, "old");
+    let updated_items = mock_items(50, 150, "new");
+    let removed_items = mock_items(0, 10, "old");
+    let mut new_items = old_items.clone();
+    new_items.retain(|key, _| !removed_items.contains_key(key));
+    new_items.extend(updated_items.clone());
+
+    let storage = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    assert!(update_items(&storage, &old_items, &HashMap::default()).await);
+
+    // killed process
+    let storage = PackStorage::new(create_pack_options(
+      root,
+      temp_root,
+      1000,
+      Arc::new(KilledFileSystem {
+        fs: fs.clone(),
+        operations: AtomicUsize::new(0),
+        kill_on,
+      }),
+    ));
+    let saved = update_items(&storage, &updated_items, &removed_items).await;
+
+    // next process takes over the stale lock and recovers the storage
+    let storage = PackStorage::new(create_pack_options(root, temp_root, 10, fs.clone()));
+    let items = load_items(&storage).await?;
+    if saved {
+      assert_eq!(items, new_items);
+    } else {
+      assert!(
+        items == old_items || items == new_items,
+        "should load complete items when killed on operation {kill_on}"
+      );
+    }
+    Ok(saved)
+  }
+
+  async fn get_pack_files(
+    scope_dir: &Utf8Path,
+    fs: &dyn FileSystem,
+  ) -> Result<HashSet<Utf8PathBuf>> {
+    let mut files = HashSet::default();
+    for bucket in fs.read_dir(scope_dir).await? {
+      let path = scope_dir.join(bucket);
+      if fs.metadata(&path).await?.is_directory {
+        files.extend(
+          fs.read_dir(&path)
+            .await?
+            .into_iter()
+            .map(|name| path.join(name)),
+        );
+      }
+    }
+    Ok(files)
+  }
+
+  async fn test_shared_readers(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let scope_dir = root.join("xxx/test_scope");
+    let storage = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    assert!(update_items(&storage, &mock_items(0, 100, "val"), &HashMap::default()).await);
+    let old_files = get_pack_files(&scope_dir, fs.as_ref()).await?;
+
+    // reader may read the old files lazily
+    let reader = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    assert_eq!(load_items(&reader).await?.len(), 100);
+
+    let writer = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    assert!(
+      update_items(
+        &writer,
+        &mock_items(100, 200, "val"),
+        &mock_items(0, 100, "val")
+      )
+      .await
+    );
+    let files = get_pack_files(&scope_dir, fs.as_ref()).await?;
+    assert!(old_files.is_subset(&files));
+
+    // old files are removed after the read lock is stale
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    assert!(
+      update_items(
+        &writer,
+        &mock_items(200, 300, "val"),
+        &mock_items(100, 200, "val")
+      )
+      .await
+    );
+    let files = get_pack_files(&scope_dir, fs.as_ref()).await?;
+    assert!(old_files.is_disjoint(&files));
+
+    let storage = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    assert_eq!(load_items(&storage).await?, mock_items(200, 300, "val"));
+    Ok(())
+  }
+
+  async fn test_concurrent_writers(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let first = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    let second = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    let first_items = mock_items(0, 100, "first");
+    let second_items = mock_items(0, 100, "second");
+
+    let (first_saved, second_saved) = tokio::join!(
+      update_items(&first, &first_items, &HashMap::default()),
+      update_items(&second, &second_items, &HashMap::default())
+    );
+    assert!(first_saved && second_saved);
+
+    let storage = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    let items = load_items(&storage).await?;
+    assert!(items == first_items || items == second_items);
+    Ok(())
+  }
+
+  async fn test_lock_taken_over(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let old_items = mock_items(0, 100, "old");
+    let storage = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    assert!(update_items(&storage, &old_items, &HashMap::default()).await);
+
+    // the keeper refreshes the lock every 10ms, and the process is paused for 200ms
+    // when writing packs
+    let paused = PackStorage::new(create_pack_options(
+      root,
+      temp_root,
+      30,
+      Arc::new(SlowFileSystem {
+        fs: fs.clone(),
+        slow_dir: temp_root.join("xxx"),
+        delay: 200,
+      }),
+    ));
+    let lock_path = root.join("xxx/write.lock");
+    let take_over = async {
+      // wait until the paused process is writing packs
+      while !fs.exists(&temp_root.join("xxx")).await? {
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+      }
+      tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+      // another process considers the lock to be stale between two ticks of the keeper
+      fs.remove_file(&lock_path).await?;
+      let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("should get current time")
+        .as_millis();
+      fs.create_new_file(&lock_path, format!("other\n{now}\n").as_bytes())
+        .await?;
+      Ok::<(), FSError>(())
+    };
+    let (saved, taken) = tokio::join!(
+      update_items(&paused, &mock_items(50, 150, "new"), &HashMap::default()),
+      take_over
+    );
+    taken?;
+    assert!(!saved);
+
+    // the lock of the new owner is kept, and nothing is published by the paused process
+    let mut reader = fs.read_file(&lock_path).await?;
+    assert_eq!(reader.read_line().await?, "other");
+    fs.remove_file(&lock_path).await?;
+    let storage = PackStorage::new(create_pack_options(root, temp_root, 1000, fs.clone()));
+    assert_eq!(load_items(&storage).await?, old_items);
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_take_over_when_saving() {
+    let (root, temp_root) = get_memory_path("test_take_over");
+    let _ = test_lock_taken_over(
+      &root.assert_utf8(),
+      &temp_root.assert_utf8(),
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+    )
+    .await
+    .map_err(|e| panic!("{}", e));
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_kill_when_saving() {
+    let (root, temp_root) = get_memory_path("test_kill");
+    let root = root.assert_utf8();
+    let temp_root = temp_root.assert_utf8();
+
+    let mut saved = false;
+    for kill_on in 0..1000 {
+      if test_killed_when_saving(&root, &temp_root, kill_on)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e))
+      {
+        saved = true;
+        break;
+      }
+    }
+    assert!(saved);
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_multi_process() {
+    let (root, temp_root) = get_memory_path("test_shared_readers");
+    let _ = test_shared_readers(
+      &root.assert_utf8(),
+      &temp_root.assert_utf8(),
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+    )
+    .await
+    .map_err(|e| panic!("{}", e));
+
+    let (root, temp_root) = get_memory_path("test_concurrent_writers");
+    let _ = test_concurrent_writers(
+      &root.assert_utf8(),
+      &temp_root.assert_utf8(),
+      Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+    )
+    .await
+    .map_err(|e| panic!("{}", e));
+  }
+}
@@ -5,7 +5,7 @@ mod test_storage_expire {
   use rspack_fs::{MemoryFileSystem, NativeFileSystem};
   use rspack_paths::{AssertUtf8, Utf8PathBuf};
   use rspack_storage::{
-    BridgeFileSystem, FileSystem, PackStorage, PackStorageOptions, Result, Storage,
+    BridgeFileSystem, FileSystem, PackCompression, PackStorage, PackStorageOptions, Result, Storage,
   };
 
   pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
@@ -33,10 +33,13 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: PackCompression::None,
       expire: 0,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      lock_timeout: 1000,
     });
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
@@ -71,10 +74,13 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: PackCompression::None,
       expire: 0,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      lock_timeout: 1000,
     });
     assert!(storage.load("test_scope").await.is_err_and(|e| {
       e.to_string()
@@ -98,11 +104,32 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: PackCompression::None,
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      lock_timeout: 100,
     });
+    // the expired version is not removed until the read lock of the dropped storage
+    // which loaded it is stale
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    // another version saved for the first time has no root meta until it is finished
+    let saving_version = root.join(format!("{version}_saving"));
+    fs.ensure_dir(&saving_version.join("test_scope")).await?;
+    // the lock is kept fresh by the saving process
+    let refreshed = std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .expect("should get current time")
+      .as_millis()
+      + 60 * 1000;
+    fs.create_new_file(
+      &saving_version.join("write.lock"),
+      format!("other\n{refreshed}\n").as_bytes(),
+    )
+    .await?;
+
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
     storage.set(
@@ -117,6 +144,7 @@ mod test_storage_expire {
       fs.exists(&root.join(version).join("test_scope/scope_meta"))
         .await?
     );
+    assert!(fs.exists(&saving_version.join("test_scope")).await?);
     assert!(
       !(fs
         .exists(&root.join(last_versoin).join("test_scope/scope_meta"))
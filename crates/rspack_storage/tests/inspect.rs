@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod test_storage_inspect {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackCompression, PackStorage, PackStorageOptions, Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_inspect")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_inspect/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_pack_options(
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    pack_size: usize,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorageOptions {
+    PackStorageOptions {
+      version: "xxx".to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 10,
+      pack_size,
+      compression: PackCompression::None,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
+      clean: false,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      lock_timeout: 1000,
+    }
+  }
+
+  async fn test_build(options: PackStorageOptions) -> Result<()> {
+    let storage = PackStorage::new(options);
+    assert!(storage.inspect(true).await?.is_none());
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..1000 {
+      storage.set(
+        "test_scope",
+        format!("key_{:0>3}", i).as_bytes().to_vec(),
+        format!("val_{:0>3}", i).as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    Ok(())
+  }
+
+  async fn test_inspect(
+    root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    options: PackStorageOptions,
+  ) -> Result<()> {
+    let storage = PackStorage::new(options);
+    let info = storage
+      .inspect(true)
+      .await?
+      .expect("should have storage info");
+    assert!(!info.is_expired());
+    assert_eq!(info.scopes.len(), 1);
+    let scope = &info.scopes[0];
+    assert_eq!(scope.name, "test_scope");
+    assert_eq!(scope.bucket_size, 10);
+    assert!(!scope.packs.is_empty());
+    assert!(scope.size() > 0);
+    assert_eq!(scope.invalid_packs().count(), 0);
+
+    // garbage left by interrupted builds
+    let scope_dir = root.join("xxx/test_scope");
+    let mut writer = fs.write_file(&scope_dir.join("0/unknown_pack")).await?;
+    writer.write_all(b"unknown").await?;
+    writer.flush().await?;
+    fs.ensure_dir(&root.join("xxx/unknown_scope")).await?;
+    fs.ensure_dir(&root.join("old_version")).await?;
+
+    let info = storage
+      .inspect(false)
+      .await?
+      .expect("should have storage info");
+    assert_eq!(
+      info.scopes[0].unused_files,
+      vec![scope_dir.join("0/unknown_pack")]
+    );
+    assert_eq!(info.unused_scopes, vec![root.join("xxx/unknown_scope")]);
+    assert!(info
+      .versions
+      .iter()
+      .any(|version| version.name == "old_version" && version.is_expired()));
+    Ok(())
+  }
+
+  async fn test_compact(
+    root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    options: PackStorageOptions,
+  ) -> Result<()> {
+    let storage = PackStorage::new(options);
+    storage.compact().await?;
+
+    let info = storage
+      .inspect(true)
+      .await?
+      .expect("should have storage info");
+    assert_eq!(info.scopes.len(), 1);
+    // the scope is rewritten with the options in its meta
+    assert_eq!(info.scopes[0].pack_size, 200);
+    assert!(info.scopes[0].unused_files.is_empty());
+    assert_eq!(info.scopes[0].invalid_packs().count(), 0);
+    assert!(info.unused_scopes.is_empty());
+    assert!(!fs.exists(&root.join("old_version")).await?);
+    Ok(())
+  }
+
+  async fn test_recovery(options: PackStorageOptions) -> Result<()> {
+    let storage = PackStorage::new(options);
+    assert_eq!(storage.load("test_scope").await?.len(), 1000);
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_inspect_and_compact() {
+    let cases = [
+      (
+        get_native_path("test_inspect_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem {}))),
+      ),
+      (
+        get_memory_path("test_inspect_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      let _ = test_build(create_pack_options(&root, &temp_root, 200, fs.clone()))
+        .await
+        .map_err(|e| panic!("{}", e));
+
+      let _ = test_inspect(
+        &root,
+        fs.clone(),
+        create_pack_options(&root, &temp_root, 200, fs.clone()),
+      )
+      .await
+      .map_err(|e| panic!("{}", e));
+
+      let _ = test_compact(
+        &root,
+        fs.clone(),
+        create_pack_options(&root, &temp_root, 500, fs.clone()),
+      )
+      .await
+      .map_err(|e| panic!("{}", e));
+
+      let _ = test_recovery(create_pack_options(&root, &temp_root, 200, fs.clone()))
+        .await
+        .map_err(|e| panic!("{}", e));
+    }
+  }
+}
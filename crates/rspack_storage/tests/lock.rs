@@ -8,8 +8,8 @@ mod test_storage_lock {
   use rspack_fs::{FileMetadata, MemoryFileSystem, NativeFileSystem};
   use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
   use rspack_storage::{
-    BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, PackStorage, PackStorageOptions,
-    Reader, Result, Storage, Writer,
+    BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, PackCompression, PackStorage,
+    PackStorageOptions, Reader, Result, Storage, Writer,
   };
   use rustc_hash::FxHashSet as HashSet;
 
@@ -55,6 +55,10 @@ mod test_storage_lock {
     }
 
     async fn move_file(&self, from: &Utf8Path, to: &Utf8Path) -> FSResult<()> {
+      // process locks are published by moving, only count the moving of storage files
+      if to.as_str().ends_with(".lock") {
+        return self.fs.move_file(from, to).await;
+      }
       let moved = self.moved.load(std::sync::atomic::Ordering::Relaxed);
       if moved == self.break_on {
         Err(FSError::from_message(
@@ -69,6 +73,10 @@ mod test_storage_lock {
         self.fs.move_file(from, to).await
       }
     }
+
+    async fn create_new_file(&self, path: &Utf8Path, data: &[u8]) -> FSResult<()> {
+      self.fs.create_new_file(path, data).await
+    }
   }
 
   pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
@@ -96,10 +104,13 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: PackCompression::None,
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      lock_timeout: 1000,
     });
     let data = storage.load("test_scope").await?;
     assert!(data.is_empty());
@@ -134,10 +145,13 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: PackCompression::None,
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      lock_timeout: 1000,
     });
     assert_eq!(storage.load("test_scope").await?.len(), 100);
     Ok(())
@@ -156,10 +170,13 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: PackCompression::None,
       expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
       clean: true,
       fresh_generation: Some(1),
       release_generation: Some(2),
+      lock_timeout: 1000,
     });
     assert!(storage.load("test_scope").await.is_err_and(|e| {
       e.to_string()
@@ -0,0 +1,225 @@
+#[cfg(test)]
+mod test_storage_remote {
+  use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+  };
+
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, HttpRemoteStore, HttpRemoteStoreOptions, PackCompression,
+    PackStorage, PackStorageOptions, RemoteStorage, RemoteStorageOptions, Result, Storage,
+  };
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+  };
+
+  type Blobs = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+  /// A minimal blob server which supports `GET`, `PUT` and `HEAD`
+  async fn start_server() -> (String, Blobs) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+      .await
+      .expect("should bind server");
+    let addr = listener.local_addr().expect("should have address");
+    let blobs: Blobs = Default::default();
+    let server_blobs = blobs.clone();
+    tokio::spawn(async move {
+      loop {
+        let Ok((stream, _)) = listener.accept().await else {
+          break;
+        };
+        let blobs = server_blobs.clone();
+        tokio::spawn(async move { handle_connection(stream, blobs).await });
+      }
+    });
+    (format!("http://{addr}/cache"), blobs)
+  }
+
+  async fn handle_connection(mut stream: TcpStream, blobs: Blobs) {
+    let mut bytes = vec![];
+    let mut buf = [0; 4096];
+    let (head_end, content_length) = loop {
+      let n = stream.read(&mut buf).await.expect("should read request");
+      bytes.extend_from_slice(&buf[..n]);
+      if let Some(pos) = bytes.windows(4).position(|w| w == b"\r\n\r\n") {
+        let head = String::from_utf8_lossy(&bytes[..pos]).to_string();
+        let content_length = head
+          .lines()
+          .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name
+              .eq_ignore_ascii_case("content-length")
+              .then(|| value.trim().to_string())
+          })
+          .map_or(0, |len| len.parse::<usize>().expect("should be number"));
+        break (pos + 4, content_length);
+      }
+    };
+    while bytes.len() < head_end + content_length {
+      let n = stream.read(&mut buf).await.expect("should read body");
+      bytes.extend_from_slice(&buf[..n]);
+    }
+    let head = String::from_utf8_lossy(&bytes[..head_end]).to_string();
+    let mut request_line = head.split(' ');
+    let method = request_line.next().expect("should have method").to_string();
+    let key = request_line
+      .next()
+      .expect("should have path")
+      .trim_start_matches("/cache/")
+      .to_string();
+
+    let (status, body) = match method.as_str() {
+      "PUT" => {
+        blobs
+          .lock()
+          .expect("should get lock")
+          .insert(key, bytes[head_end..head_end + content_length].to_vec());
+        ("200 OK", vec![])
+      }
+      _ => match blobs.lock().expect("should get lock").get(&key) {
+        Some(value) if method == "GET" => ("200 OK", value.clone()),
+        Some(_) => ("200 OK", vec![]),
+        None => ("404 Not Found", vec![]),
+      },
+    };
+    let head = format!(
+      "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+      body.len()
+    );
+    let _ = stream.write_all(head.as_bytes()).await;
+    let _ = stream.write_all(&body).await;
+  }
+
+  fn create_storage(
+    root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+    url: &str,
+    push: bool,
+  ) -> RemoteStorage {
+    let local = PackStorage::new(PackStorageOptions {
+      version: "xxx".to_string(),
+      root: root.join("cache").into(),
+      temp_root: root.join("temp").into(),
+      fs,
+      bucket_size: 5,
+      pack_size: 200,
+      compression: PackCompression::None,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      max_size: None,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+      lock_timeout: 1000,
+    });
+    RemoteStorage::new(RemoteStorageOptions {
+      local: Arc::new(local),
+      store: Arc::new(
+        HttpRemoteStore::new(HttpRemoteStoreOptions {
+          url: url.to_string(),
+          connect_timeout: 1000,
+          read_timeout: 1000,
+        })
+        .expect("should create remote store"),
+      ),
+      version: "xxx".to_string(),
+      push,
+    })
+  }
+
+  async fn test_push(root: &Utf8PathBuf, url: &str) -> Result<()> {
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let storage = create_storage(root, fs, url, true);
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{:0>3}", i).as_bytes().to_vec(),
+        format!("val_{:0>3}", i).as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    Ok(())
+  }
+
+  async fn test_pull(root: &Utf8PathBuf, url: &str) -> Result<()> {
+    // another machine with an empty local cache
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let storage = create_storage(root, fs.clone(), url, false);
+    let data = storage.load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    // pulled items are written to local storage
+    assert!(
+      fs.exists(&root.join("cache/xxx/test_scope/scope_meta"))
+        .await?
+    );
+    Ok(())
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  #[cfg_attr(miri, ignore)]
+  async fn test_remote() {
+    let (url, blobs) = start_server().await;
+    let root = PathBuf::from("/rspack_test/storage/test_storage_remote").assert_utf8();
+
+    let _ = test_push(&root, &url).await.map_err(|e| panic!("{}", e));
+    assert_eq!(blobs.lock().expect("should get lock").len(), 2);
+    assert!(blobs
+      .lock()
+      .expect("should get lock")
+      .contains_key("refs/xxx/test_scope"));
+
+    let _ = test_pull(&root, &url).await.map_err(|e| panic!("{}", e));
+
+    // corrupt the blob, pull should be a cache miss with integrity warning
+    for (key, value) in blobs.lock().expect("should get lock").iter_mut() {
+      if key.starts_with("blobs/") {
+        value[10] = value[10].wrapping_add(1);
+      }
+    }
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let storage = create_storage(&root, fs, &url, false);
+    let data = storage
+      .load("test_scope")
+      .await
+      .expect("should load corrupted blob as cache miss");
+    assert!(data.is_empty());
+    let warnings = storage.take_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].to_string().contains("integrity check failed"));
+    // the failed scope is not pulled again
+    assert!(storage
+      .load("test_scope")
+      .await
+      .expect("should load")
+      .is_empty());
+    assert!(storage.take_warnings().is_empty());
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  #[cfg_attr(miri, ignore)]
+  async fn test_remote_unavailable() {
+    // no server is listening on this port after the listener is dropped
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+      .expect("should bind")
+      .local_addr()
+      .expect("should have address");
+    let root = PathBuf::from("/rspack_test/storage/test_storage_remote_unavailable").assert_utf8();
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let storage = create_storage(&root, fs, &format!("http://{addr}/cache"), false);
+    let data = storage
+      .load("test_scope")
+      .await
+      .expect("should load unavailable remote as cache miss");
+    assert!(data.is_empty());
+    let warnings = storage.take_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].to_string().contains("test_scope"));
+  }
+}
@@ -126,7 +126,8 @@ export class JSDOMWebRunner<
 				return {
 					status: 200,
 					ok: true,
-					json: async () => JSON.parse(buffer.toString("utf-8"))
+					json: async () => JSON.parse(buffer.toString("utf-8")),
+					text: async () => buffer.toString("utf-8")
 				};
 			} catch (err) {
 				if ((err as { code: string }).code === "ENOENT") {
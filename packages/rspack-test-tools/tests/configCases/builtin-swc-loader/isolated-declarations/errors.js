@@ -0,0 +1 @@
+module.exports = [[/explicit return type/]];
@@ -0,0 +1,26 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should emit declaration files", () => {
+	const { add } = require("./src/math");
+	const { version } = require("./src/version.mts");
+	require("./src/invalid");
+	expect(add(1, 2)).toBe(3);
+	expect(version).toBe("1.0.0");
+
+	const mathDts = fs.readFileSync(
+		path.resolve(__dirname, "types/src/math.d.ts"),
+		"utf-8"
+	);
+	expect(mathDts).toContain("/** Adds two numbers. */");
+	expect(mathDts).toContain(
+		"export declare function add(a: number, b: number): number;"
+	);
+	expect(mathDts).not.toContain("return");
+
+	const versionDts = fs.readFileSync(
+		path.resolve(__dirname, "types/src/version.d.mts"),
+		"utf-8"
+	);
+	expect(versionDts).toContain("export declare const version: string;");
+});
@@ -0,0 +1,31 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		extensions: ["...", ".ts"]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.m?ts$/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "typescript"
+								}
+							},
+							rspackExperiments: {
+								isolatedDeclarations: {
+									outDir: "types"
+								}
+							}
+						}
+					}
+				],
+				type: "javascript/auto"
+			}
+		]
+	}
+};
@@ -0,0 +1,3 @@
+export function identity(value: number) {
+	return value;
+}
@@ -0,0 +1,21 @@
+const fs = __non_webpack_require__("fs");
+
+it("should strip the types and keep the positions", () => {
+	const { Square, add, config } = require("./src/math");
+	expect(new Square(3).area()).toBe(9);
+	expect(add(1, 2)).toBe(3);
+	expect(add(1)).toBe(1);
+	expect(config.scale).toBe(2);
+
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).toContain(
+		"function add" + " ".repeat(18) + "(a   , b    )" + " ".repeat(9) + "{"
+	);
+	expect(content).toContain("class Square" + " ".repeat(18) + "{");
+});
+
+it("should fall back to the full transform for enums", () => {
+	const { Color } = require("./src/enum");
+	expect(Color.Red).toBe(0);
+	expect(Color[1]).toBe("Green");
+});
@@ -0,0 +1,30 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		extensions: ["...", ".ts"]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "typescript"
+								},
+								target: "es2022"
+							},
+							rspackExperiments: {
+								stripTypes: true
+							}
+						}
+					}
+				],
+				type: "javascript/auto"
+			}
+		]
+	}
+};
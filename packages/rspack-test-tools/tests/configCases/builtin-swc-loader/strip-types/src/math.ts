@@ -0,0 +1,23 @@
+import type { Shape } from "./types";
+import { Unit } from "./types";
+
+type Size = number;
+
+export class Square implements Shape {
+	private readonly size!: Size;
+	unit?: Unit;
+
+	constructor(size: Size) {
+		this.size = size;
+	}
+
+	area(): number {
+		return this.size ** 2;
+	}
+}
+
+export function add<T extends number>(a: T, b?: T): number {
+	return a + (b ?? 0)!;
+}
+
+export const config = { scale: 2 } as const satisfies Record<string, number>;
@@ -0,0 +1,11 @@
+const lib = require("./lib");
+const { destructured } = require("./lib");
+
+it("should only keep exports accessed on the result of require", () => {
+	expect(lib.used).toBe("used");
+	expect(destructured).toBe("destructured");
+	const mainFile = require("fs").readFileSync(__filename, "utf-8");
+	for (let i of ["used", "destructured", "unused"]) {
+		expect(mainFile.includes(`/* unused exports.${i} */`)).toBe(i === "unused");
+	}
+});
@@ -0,0 +1,3 @@
+exports.used = "used";
+exports.destructured = "destructured";
+exports.unused = "unused";
@@ -0,0 +1,38 @@
+import { log } from "./lib/log";
+import flaggedDefault, { value } from "./lib/flagged";
+import cjs, { named } from "./lib/cjs";
+import dynamic from "./lib/dynamic";
+import { value as sloppyValue } from "./lib/sloppy";
+import { readCounter } from "./lib/free";
+import { counter } from "./lib/counter";
+
+it("should inline commonjs modules with static exports and keep the interop", () => {
+	expect(value).toBe(1);
+	expect(flaggedDefault).toBe("flagged default");
+	expect(named).toBe("named");
+	expect(cjs).toEqual({ named: "named" });
+	expect(dynamic()).toBe("dynamic");
+});
+
+it("should evaluate inlined commonjs modules in import order", () => {
+	expect(log).toEqual(["flagged", "cjs"]);
+});
+
+it("should not shadow free variables of inlined commonjs modules", () => {
+	expect(counter).toBe("esm counter");
+	expect(readCounter()).toBe("global");
+});
+
+it("should not inline commonjs modules in sloppy mode", () => {
+	expect(sloppyValue).toBe("sloppy");
+});
+
+it("should concatenate commonjs modules with static exports", () => {
+	const chunk = __STATS__.chunks[0];
+	expect(chunk.modules.map(module => module.name).sort()).toEqual([
+		"./index.js + 4 modules",
+		"./lib/dynamic.js",
+		"./lib/log.js",
+		"./lib/sloppy.js"
+	]);
+});
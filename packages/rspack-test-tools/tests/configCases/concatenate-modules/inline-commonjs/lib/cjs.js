@@ -0,0 +1,3 @@
+"use strict";
+require("./log").log.push("cjs");
+exports.named = "named";
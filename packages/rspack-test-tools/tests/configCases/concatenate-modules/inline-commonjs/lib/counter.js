@@ -0,0 +1 @@
+export const counter = "esm counter";
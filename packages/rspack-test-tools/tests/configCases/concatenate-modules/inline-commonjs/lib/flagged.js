@@ -0,0 +1,6 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+const { log } = require("./log");
+log.push("flagged");
+exports.value = 1;
+exports.default = "flagged default";
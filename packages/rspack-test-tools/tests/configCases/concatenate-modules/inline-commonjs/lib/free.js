@@ -0,0 +1,4 @@
+"use strict";
+exports.readCounter = function () {
+	return typeof counter === "undefined" ? "global" : counter;
+};
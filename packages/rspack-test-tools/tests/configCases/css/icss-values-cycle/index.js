@@ -0,0 +1,5 @@
+import * as styles from "./a.module.css";
+
+it("should not substitute the values imported in a cycle", () => {
+	expect(styles.a).toBe("./a.module-a");
+});
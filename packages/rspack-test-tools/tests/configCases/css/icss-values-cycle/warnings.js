@@ -0,0 +1,4 @@
+module.exports = [
+	[/Can't resolve the value 'color' imported from '\.\/b\.module\.css', it is re-exported more than 32 times/],
+	[/Can't resolve the value 'color' imported from '\.\/a\.module\.css', it is re-exported more than 32 times/]
+];
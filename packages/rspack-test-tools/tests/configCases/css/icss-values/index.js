@@ -0,0 +1,22 @@
+import * as styles from "./style.module.css";
+
+it("should substitute the values of @value and :import", () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	expect(styles.primary).toBe("#f00");
+	expect(styles.outline).toBe("1px solid #f00");
+	expect(styles.padding).toBe("4px");
+	expect(styles.spacing).toBe("8px");
+	expect(styles.button).toBe("./style.module-button");
+
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).not.toContain("@value");
+	expect(css).not.toContain(":import");
+	expect(css).toContain("color: #f00;");
+	expect(css).toContain("border: 1px solid #f00;");
+	expect(css).toContain("padding: 4px 8px;");
+	expect(css).toContain("margin: 8px;");
+	expect(css).toContain("@media (max-width: 600px)");
+	expect(css).toContain("/* primary is not replaced in comments */");
+	expect(css).toContain(`font-family: "primary", 'padding';`);
+});
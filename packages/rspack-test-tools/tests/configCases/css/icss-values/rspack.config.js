@@ -0,0 +1,19 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	mode: "development",
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				localIdentName: "[path][name]-[local]"
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
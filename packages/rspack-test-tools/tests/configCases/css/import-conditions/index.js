@@ -0,0 +1,24 @@
+import "./style.css";
+
+it("should wrap the imported modules in the conditions of @import", () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+
+	expect(css).toContain(
+		'@import url("https://fonts.googleapis.com/css2?family=Roboto") layer(fonts) screen;'
+	);
+	expect(css).toContain(
+		"@layer base {\n@supports (display: grid) {\n@media screen and (min-width: 100px) {\n.base {"
+	);
+	expect(css).toContain("@layer {\n.anonymous {");
+	expect(css).toContain("@layer outer {\n.nested {");
+	expect(css).toContain("@layer outer {\n@media print {\n.inner {");
+	expect(css).toContain(
+		'@import url("https://fonts.googleapis.com/css2?family=Inter") screen;'
+	);
+	expect(css).toContain("@layer main {\n.shared {");
+	expect(css).not.toContain("@layer other");
+	expect(css.indexOf(".inner")).toBeLessThan(css.indexOf(".nested"));
+	expect(css.indexOf(".nested")).toBeLessThan(css.indexOf(".root"));
+});
@@ -0,0 +1,25 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	entry: {
+		main: "./index.js",
+		other: "./other.js"
+	},
+	output: {
+		filename: "[name].js",
+		cssFilename: "[name].css"
+	},
+	node: false,
+	mode: "development",
+	devtool: false,
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
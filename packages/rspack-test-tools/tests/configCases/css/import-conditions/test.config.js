@@ -0,0 +1,9 @@
+module.exports = {
+	documentType: 'fake',
+	findBundle() {
+		return [
+			'main.css',
+			'main.js',
+		]
+	}
+}
@@ -0,0 +1 @@
+module.exports = [[/The conditions of the outer @imports of .*family=Inter.* are dropped/]];
@@ -0,0 +1,24 @@
+import { used, button } from "./style.module.css";
+
+it("should remove the rule sets of unused local classes", () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	expect(used).toBe("./style.module-used");
+	expect(button).toBe("./style.module-button ./style.module-base");
+
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).toContain("color: red");
+	expect(css).not.toContain("color: blue");
+	expect(css).not.toContain("color: green");
+	expect(css).toContain("color: yellow");
+	expect(css).toContain("color: black");
+	expect(css).toContain("margin: 0");
+	expect(css).toContain("padding: 0");
+	expect(css).not.toContain("color: purple");
+	expect(css).toContain("color: orange");
+	expect(css).toContain("color: gray");
+	// classes in `:not()`, `:is()` and `:where()` are not required to match
+	expect(css).toContain("color: white");
+	expect(css).toContain("color: cyan");
+	expect(css).toContain("color: pink");
+});
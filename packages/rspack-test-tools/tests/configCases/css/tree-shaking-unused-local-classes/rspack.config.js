@@ -0,0 +1,23 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	mode: "production",
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				localIdentName: "[path][name]-[local]"
+			}
+		}
+	},
+	optimization: {
+		minimize: false,
+		usedExports: true
+	},
+	experiments: {
+		css: true
+	}
+};
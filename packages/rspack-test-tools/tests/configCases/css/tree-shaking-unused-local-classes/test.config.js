@@ -0,0 +1,9 @@
+module.exports = {
+	documentType: 'fake',
+	findBundle() {
+		return [
+			'bundle0.css',
+			'bundle0.js',
+		]
+	}
+}
@@ -0,0 +1,57 @@
+it("should import matched modules lazily", async () => {
+	const modules = import.meta.glob("./modules/*.js");
+	expect(Object.keys(modules)).toEqual([
+		"./modules/a.js",
+		"./modules/b.js",
+		"./modules/c.test.js"
+	]);
+	expect(typeof modules["./modules/a.js"]).toBe("function");
+	const a = await modules["./modules/a.js"]();
+	expect(a.default).toBe("a");
+	expect(a.name).toBe("a");
+});
+
+it("should import matched modules eagerly", () => {
+	const modules = import.meta.glob("./modules/**/*.js", { eager: true });
+	expect(Object.keys(modules)).toEqual([
+		"./modules/a.js",
+		"./modules/b.js",
+		"./modules/c.test.js",
+		"./modules/nested/d.js"
+	]);
+	expect(modules["./modules/nested/d.js"].default).toBe("nested");
+});
+
+it("should exclude modules matched by negated patterns", () => {
+	const modules = import.meta.glob(["./modules/**/*.js", "!**/*.test.js"], {
+		eager: true
+	});
+	expect(Object.keys(modules)).toEqual([
+		"./modules/a.js",
+		"./modules/b.js",
+		"./modules/nested/d.js"
+	]);
+});
+
+it("should support multiple patterns", () => {
+	const modules = import.meta.glob(["./modules/a.js", "./modules/nested/*.js"], {
+		eager: true
+	});
+	expect(Object.keys(modules)).toEqual([
+		"./modules/a.js",
+		"./modules/nested/d.js"
+	]);
+});
+
+it("should pick the named export", async () => {
+	const eager = import.meta.glob("./modules/{a,b}.js", {
+		eager: true,
+		import: "name"
+	});
+	expect(eager).toEqual({
+		"./modules/a.js": "a",
+		"./modules/b.js": "b"
+	});
+	const lazy = import.meta.glob("./modules/nested/*.js", { import: "default" });
+	expect(await lazy["./modules/nested/d.js"]()).toBe("nested");
+});
@@ -0,0 +1,2 @@
+export default "nested";
+export const name = "nested";
@@ -0,0 +1,5 @@
+const { log } = require("./log");
+
+log.push("deferred-cjs");
+
+module.exports = { value: "cjs" };
@@ -0,0 +1,5 @@
+import { log } from "./log";
+
+log.push("deferred");
+
+export const value = "deferred";
@@ -0,0 +1,5 @@
+import { log } from "./log";
+
+log.push("eager");
+
+export const value = "eager";
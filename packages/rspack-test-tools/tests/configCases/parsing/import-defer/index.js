@@ -0,0 +1,36 @@
+import { log } from "./log";
+import defer * as deferred from "./deferred";
+import defer * as cjs from "./deferred-cjs";
+import { value } from "./eager";
+
+it("should not evaluate deferred modules before the namespace is accessed", () => {
+	expect(value).toBe("eager");
+	expect(log).toEqual(["eager"]);
+	expect(deferred.value).toBe("deferred");
+	expect(log).toEqual(["eager", "deferred"]);
+	expect(deferred.value).toBe("deferred");
+	expect(log).toEqual(["eager", "deferred"]);
+});
+
+it("should apply the interop of commonjs modules on access", () => {
+	expect(log).not.toContain("deferred-cjs");
+	expect(cjs.default).toEqual({ value: "cjs" });
+	expect(cjs.value).toBe("cjs");
+	expect(log).toContain("deferred-cjs");
+});
+
+it("should not treat the deferred namespace as a thenable", () => {
+	expect(deferred.then).toBeUndefined();
+	expect(Object.prototype.toString.call(deferred)).toBe("[object Deferred Module]");
+});
+
+it("should describe the toStringTag of the deferred namespace consistently", () => {
+	expect(Symbol.toStringTag in deferred).toBe(true);
+	expect(Object.getOwnPropertyDescriptor(deferred, Symbol.toStringTag)).toEqual({
+		value: "Deferred Module",
+		writable: false,
+		enumerable: false,
+		configurable: true
+	});
+	expect(Reflect.ownKeys(deferred)).toContain(Symbol.toStringTag);
+});
@@ -0,0 +1,5 @@
+export const big = [
+	"a large module which is bigger than maxSize itself and gets a chunk just for it",
+	"a large module which is bigger than maxSize itself and gets a chunk just for it",
+	"a large module which is bigger than maxSize itself and gets a chunk just for it"
+];
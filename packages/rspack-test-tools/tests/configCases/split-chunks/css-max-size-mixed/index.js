@@ -0,0 +1,26 @@
+import "./z.css";
+import "./a.css";
+import { big } from "./big";
+import { small } from "./small";
+
+it("should keep the grouping of other modules when splitting a chunk with css modules", () => {
+	expect(big.length).toBe(3);
+	expect(small).toBe("small");
+	const bigChunk = __STATS__.chunks.find(chunk =>
+		chunk.modules.some(module => module.name === "./big.js")
+	);
+	expect(bigChunk.modules.map(module => module.name)).toEqual(["./big.js"]);
+});
+
+it("should keep the order of css modules in a chunk with other modules", () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	const cssFiles = __STATS__.entrypoints.main.assets
+		.map(asset => asset.name)
+		.filter(name => name.endsWith(".css"));
+	expect(cssFiles.length).toBe(2);
+	const css = cssFiles
+		.map(file => fs.readFileSync(path.resolve(__dirname, file), "utf-8"))
+		.join("\n");
+	expect(css.indexOf(".z")).toBeLessThan(css.indexOf(".a"));
+});
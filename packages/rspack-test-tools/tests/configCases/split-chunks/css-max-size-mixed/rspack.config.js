@@ -0,0 +1,31 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	entry: {
+		main: "./index"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	node: {
+		__dirname: false
+	},
+	optimization: {
+		chunkIds: "named",
+		splitChunks: {
+			chunks: "all",
+			minSize: 0,
+			maxSize: {
+				css: 60,
+				javascript: 200
+			}
+		}
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false
+			}
+		}
+	}
+};
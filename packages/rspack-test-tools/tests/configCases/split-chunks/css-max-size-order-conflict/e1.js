@@ -0,0 +1,4 @@
+import "./a.css";
+import "./b.css";
+
+it("should build", () => {});
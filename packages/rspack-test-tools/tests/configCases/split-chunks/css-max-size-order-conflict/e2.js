@@ -0,0 +1,4 @@
+import "./b.css";
+import "./a.css";
+
+it("should build", () => {});
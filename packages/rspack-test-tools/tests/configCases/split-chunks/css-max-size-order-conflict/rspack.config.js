@@ -0,0 +1,34 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	entry: {
+		e1: "./e1",
+		e2: "./e2"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		chunkIds: "named",
+		splitChunks: {
+			cacheGroups: {
+				styles: {
+					name: "styles",
+					test: /\.css$/,
+					chunks: "all",
+					enforce: true,
+					maxSize: {
+						css: 60
+					}
+				}
+			}
+		}
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false
+			}
+		}
+	}
+};
@@ -0,0 +1,10 @@
+const fs = require("fs");
+
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: function (i, options) {
+		const files = fs.readdirSync(options.output.path);
+		const styles = files.filter(file => /^styles.*\.js$/.test(file));
+		return [...styles, "e1.js", ...styles, "e2.js"];
+	}
+};
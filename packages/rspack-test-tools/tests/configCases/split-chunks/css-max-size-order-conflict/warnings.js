@@ -0,0 +1,3 @@
+module.exports = [
+	[/Conflicting order between .+ and .+, the chunks split by `maxSize` can't keep the order/]
+];
@@ -0,0 +1,17 @@
+import "./z.css";
+import "./a.css";
+import "./m.css";
+
+it("should keep the order of css modules when splitting by maxSize", () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	const cssFiles = __STATS__.entrypoints.main.assets
+		.map(asset => asset.name)
+		.filter(name => name.endsWith(".css"));
+	expect(cssFiles.length).toBe(3);
+	const css = cssFiles
+		.map(file => fs.readFileSync(path.resolve(__dirname, file), "utf-8"))
+		.join("\n");
+	expect(css.indexOf(".z")).toBeLessThan(css.indexOf(".a"));
+	expect(css.indexOf(".a")).toBeLessThan(css.indexOf(".m"));
+});
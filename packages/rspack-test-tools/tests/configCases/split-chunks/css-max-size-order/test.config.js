@@ -0,0 +1,12 @@
+const fs = require("fs");
+
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: function (i, options) {
+		// the parts split from main are loaded before main
+		return fs
+			.readdirSync(options.output.path)
+			.filter(file => file.endsWith(".js") && file !== "main.js")
+			.concat("main.js");
+	}
+};
@@ -0,0 +1 @@
+export const heavy = "HEAVY_CONTENT";
@@ -0,0 +1,17 @@
+import { used } from "./lib";
+
+const fs = require("fs");
+
+it("should drop code only reachable from calls to pure functions", () => {
+	expect(used).toBe(1);
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("HEAVY" + "_CONTENT");
+});
+
+it("should keep calls to impure functions", () => {
+	expect(globalThis.__registered).toEqual(["lib"]);
+});
+
+it("should keep calls to reassigned functions", () => {
+	expect(globalThis.__configs).toEqual(["lib"]);
+});
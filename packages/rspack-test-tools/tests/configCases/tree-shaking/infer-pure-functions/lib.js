@@ -0,0 +1,7 @@
+import { createStore, makeConfig, register } from "./utils";
+import { heavy } from "./heavy";
+
+export const store = createStore(heavy);
+export const registered = register("lib");
+export const config = makeConfig("lib");
+export const used = 1;
@@ -0,0 +1,10 @@
+/**@type {import("@rspack/core").Configuration}*/
+module.exports = {
+	mode: "production",
+	context: __dirname,
+	optimization: {
+		inferPureFunctions: true,
+		concatenateModules: false,
+		minimize: false
+	}
+};
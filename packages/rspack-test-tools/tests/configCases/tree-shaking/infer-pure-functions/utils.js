@@ -0,0 +1,18 @@
+export function createStore(initial) {
+	return { state: initial, listeners: [] };
+}
+
+export function register(name) {
+	(globalThis.__registered ||= []).push(name);
+	return name;
+}
+
+export function makeConfig(value) {
+	return value;
+}
+
+// reassigned, so calling it is not free of side effects
+makeConfig = value => {
+	(globalThis.__configs ||= []).push(value);
+	return value;
+};
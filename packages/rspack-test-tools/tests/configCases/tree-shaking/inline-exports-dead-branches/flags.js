@@ -0,0 +1,2 @@
+export const IS_DEV = process.env.NODE_ENV !== "production";
+export const FEATURE = true;
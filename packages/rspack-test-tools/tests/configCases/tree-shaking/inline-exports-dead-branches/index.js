@@ -0,0 +1,34 @@
+import { IS_DEV, FEATURE } from "./flags";
+import * as flags from "./flags";
+
+const fs = require("fs");
+
+it("should inline the evaluated value of constant exports", () => {
+	expect(IS_DEV).toBe(false);
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).toContain("/* inlined export .IS_DEV */ false");
+});
+
+it("should skip the dependencies of dead branches", () => {
+	let devtools;
+	if (IS_DEV) {
+		devtools = require("./devtools");
+	}
+	const lazy = !IS_DEV || import("./lazy");
+	expect(devtools).toBe(undefined);
+	expect(lazy).toBe(true);
+});
+
+it("should build the dependencies of live branches", () => {
+	const feature = FEATURE ? require("./feature") : require("./fallback");
+	expect(feature).toBe("feature module");
+	expect(flags.FEATURE && require("./feature")).toBe("feature module");
+});
+
+it("should not bundle the modules only referenced in dead branches", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).toContain("feature" + " module");
+	expect(content).not.toContain("devtools" + " module");
+	expect(content).not.toContain("lazy" + " module");
+	expect(content).not.toContain("fallback" + " module");
+});
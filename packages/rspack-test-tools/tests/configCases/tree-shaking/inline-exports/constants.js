@@ -0,0 +1,11 @@
+export const ENABLED = true;
+export const CODE = 404;
+export const OFFSET = -1;
+export const NAME = "app";
+export const NOTHING = undefined;
+export const LONG_NAME = "this string is too long to be inlined";
+export let counter = 0;
+
+export function increment() {
+	counter += 1;
+}
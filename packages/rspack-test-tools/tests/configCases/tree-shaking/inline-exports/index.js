@@ -0,0 +1,42 @@
+import { ENABLED, CODE, OFFSET, NAME, NOTHING, LONG_NAME, counter, increment } from "./constants";
+import * as constants from "./constants";
+import { Status } from "./status";
+import { NOT_FOUND } from "./reexport";
+
+const fs = require("fs");
+
+it("should keep the values of inlined exports", () => {
+	expect(ENABLED).toBe(true);
+	expect(CODE).toBe(404);
+	expect(2 ** OFFSET).toBe(0.5);
+	expect(NAME).toBe("app");
+	expect(NOTHING).toBe(undefined);
+	expect(LONG_NAME).toBe("this string is too long to be inlined");
+	expect(constants.CODE).toBe(404);
+	expect(NOT_FOUND).toBe(404);
+	expect(Status.Ok).toBe(0);
+	expect(Status.Failed).toBe("failed");
+});
+
+it("should not inline exports that are reassigned", () => {
+	expect(counter).toBe(0);
+	increment();
+	expect(counter).toBe(1);
+});
+
+it("should inline the values at the use sites", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	const inlined = name => `/* inlined export .${name} */`;
+	expect(content).toContain(`${inlined("CODE")} 404`);
+	expect(content).toContain(`${inlined("NAME")} "app"`);
+	expect(content).toContain(`${inlined("NOT_FOUND")} 404`);
+	expect(content).toContain(`${inlined("Status.Ok")} 0`);
+	expect(content).not.toContain(inlined("LONG_NAME"));
+	expect(content).not.toContain(inlined("counter"));
+});
+
+it("should remove the inlined exports from the exports object", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).not.toMatch(/\bCODE: function\(\)/);
+	expect(content).toMatch(/\bLONG_NAME: function\(\)/);
+});
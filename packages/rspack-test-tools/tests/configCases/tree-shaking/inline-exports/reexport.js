@@ -0,0 +1 @@
+export { CODE as NOT_FOUND } from "./constants";
@@ -0,0 +1,11 @@
+/**@type {import("@rspack/core").Configuration}*/
+module.exports = {
+	mode: "production",
+	context: __dirname,
+	optimization: {
+		inlineExports: true,
+		concatenateModules: false,
+		mangleExports: false,
+		minimize: false
+	}
+};
@@ -0,0 +1,6 @@
+// the output of `export enum Status { Ok, Failed = "failed" }` compiled by swc
+export var Status = /*#__PURE__*/ function(Status) {
+	Status[Status["Ok"] = 0] = "Ok";
+	Status["Failed"] = "failed";
+	return Status;
+}({});
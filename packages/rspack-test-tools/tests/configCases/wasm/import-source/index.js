@@ -0,0 +1,17 @@
+import source addModule from "./add.wasm";
+
+it("should bind the compiled module without instantiating it", async () => {
+	expect(addModule).toBeInstanceOf(WebAssembly.Module);
+	expect(WebAssembly.Module.exports(addModule)).toEqual([
+		{ name: "add", kind: "function" }
+	]);
+	const instance = await WebAssembly.instantiate(addModule);
+	expect(instance.exports.add(1, 2)).toBe(3);
+});
+
+it("should compile the module imported from an async chunk", async () => {
+	const { default: other } = await import("./other");
+	expect(other).toBeInstanceOf(WebAssembly.Module);
+	const instance = await WebAssembly.instantiate(other);
+	expect(instance.exports.add(2, 3)).toBe(5);
+});
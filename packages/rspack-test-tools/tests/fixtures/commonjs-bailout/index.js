@@ -0,0 +1,3 @@
+const { a } = require("./lib");
+
+console.log(a);
@@ -0,0 +1,3 @@
+exports.a = 1;
+const self = module.exports;
+self.b = 2;
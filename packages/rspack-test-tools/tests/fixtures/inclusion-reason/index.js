@@ -0,0 +1,5 @@
+import { a } from "./reexport";
+import "./wrapper";
+import "./rule";
+
+console.log(a);
@@ -0,0 +1 @@
+import "./effect";
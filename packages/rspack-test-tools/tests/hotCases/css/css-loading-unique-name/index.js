@@ -6,8 +6,12 @@ it("css hmr", (done) => {
 	}
 	NEXT(require("../../update")(done, true, () => {
 		if (__webpack_require__.hmrC.css) {
-			expect(document.head.children[0].href).toContain("bundle.css?hmr");
-			expect(document.head.children[0].getAttribute('data-webpack')).toBe("css-test:chunk-main");
+			const style = document.head.children[0];
+			expect(style.tagName).toBe("STYLE");
+			expect(style.getAttribute('data-webpack')).toBe("css-test:css-module-./index.css");
+			expect(style.textContent).toContain("yellow");
+			expect(document.head.children[1].href).toContain("bundle.css");
+			expect(document.head.children[1].disabled).toBe(true);
 		}
 		done();
 	}));
@@ -0,0 +1,22 @@
+import style from './index.module.css';
+import './other.css';
+
+module.hot.accept('./index.module.css');
+
+it("should keep the external @import when replacing the rules of the updated css module", (done) => {
+	expect(style.button).toBeDefined();
+	NEXT(require("../../update")(done, true, () => {
+		expect(style.primary).toBeDefined();
+		if (__webpack_require__.hmrC.css) {
+			const styles = Array.from(document.head.getElementsByTagName("style"));
+			const externalStyle = styles.find(s => s.textContent.includes("https://example.com/external.css"));
+			expect(externalStyle).toBeDefined();
+			expect(externalStyle.textContent.trim().startsWith("@import")).toBe(true);
+			expect(styles.indexOf(externalStyle)).toBe(0);
+			const moduleStyle = styles.find(s => s.getAttribute("data-webpack").endsWith("./index.module.css"));
+			expect(moduleStyle.textContent).toContain("color: blue");
+			expect(document.head.getElementsByTagName("link")[0].disabled).toBe(true);
+		}
+		done();
+	}));
+});
@@ -0,0 +1,22 @@
+import style from './index.module.css';
+import './other.css';
+
+module.hot.accept('./index.module.css');
+
+it("should replace the rules of the updated css module only", (done) => {
+	expect(style.button).toBeDefined();
+	NEXT(require("../../update")(done, true, () => {
+		expect(style.button).toBeUndefined();
+		expect(style.primary).toBeDefined();
+		if (__webpack_require__.hmrC.css) {
+			const styles = Array.from(document.head.getElementsByTagName("style"));
+			const moduleStyle = styles.find(s => s.getAttribute("data-webpack").endsWith("./index.module.css"));
+			expect(moduleStyle.textContent).toContain("color: blue");
+			expect(moduleStyle.textContent).not.toContain("color: red");
+			const otherStyle = styles.find(s => s.getAttribute("data-webpack").endsWith("./other.css"));
+			expect(otherStyle.textContent).toContain("margin: 0");
+			expect(document.head.getElementsByTagName("link")[0].disabled).toBe(true);
+		}
+		done();
+	}));
+});
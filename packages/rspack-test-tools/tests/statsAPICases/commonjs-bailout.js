@@ -0,0 +1,35 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should report the reason of CommonJS exports bailouts",
+	options(context) {
+		return {
+			context: context.getSource(),
+			mode: "production",
+			optimization: {
+				minimize: false
+			},
+			entry: {
+				main: "./fixtures/commonjs-bailout/index"
+			}
+		};
+	},
+	async check(stats) {
+		const { modules } = stats?.toJson({
+			all: false,
+			modules: true,
+			optimizationBailout: true
+		});
+		const lib = modules.find(m => m.name === "./fixtures/commonjs-bailout/lib.js");
+		expect(lib.optimizationBailout).toEqual(
+			expect.arrayContaining([
+				expect.stringMatching(
+					/^CommonJS bailout: module\.exports is used directly at 2:13-27$/
+				)
+			])
+		);
+		const index = modules.find(m => m.name === "./fixtures/commonjs-bailout/index.js");
+		expect(index.optimizationBailout).not.toEqual(
+			expect.arrayContaining([expect.stringMatching(/^CommonJS bailout/)])
+		);
+	}
+};
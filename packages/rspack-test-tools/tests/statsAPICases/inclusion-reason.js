@@ -0,0 +1,74 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should explain why modules are included",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: {
+				main: "./fixtures/inclusion-reason/index"
+			},
+			module: {
+				rules: [
+					{
+						test: /rule\.js$/,
+						sideEffects: true
+					}
+				]
+			},
+			optimization: {
+				sideEffects: true,
+				concatenateModules: false
+			}
+		};
+	},
+	async check(stats) {
+		const { modules } = stats?.toJson({
+			all: false,
+			modules: true,
+			inclusionReason: true
+		});
+		const getReason = name =>
+			modules.find(m => m.name === `./fixtures/inclusion-reason/${name}`)
+				.inclusionReason;
+
+		const effect = getReason("effect.js");
+		expect(effect.importChain.map(i => i.module)).toEqual([
+			"./fixtures/inclusion-reason/index.js",
+			"./fixtures/inclusion-reason/wrapper.js",
+			"./fixtures/inclusion-reason/effect.js"
+		]);
+		expect(effect.importChain[0].originModule).toBeUndefined();
+		expect(effect.importChain[2]).toMatchObject({
+			originModule: "./fixtures/inclusion-reason/wrapper.js",
+			userRequest: "./effect"
+		});
+		expect(effect.sideEffects).toMatchObject({
+			type: "statement",
+			sideEffects: true,
+			statementType: "Statement"
+		});
+		expect(effect.sideEffects.loc).toMatch(/^2:0-/);
+
+		const wrapper = getReason("wrapper.js");
+		expect(wrapper.sideEffects).toMatchObject({
+			type: "dependency",
+			sideEffects: true,
+			module: "./fixtures/inclusion-reason/effect.js"
+		});
+
+		const rule = getReason("rule.js");
+		expect(rule.sideEffects).toMatchObject({
+			type: "moduleRule",
+			sideEffects: true
+		});
+
+		const pure = getReason("pure.js");
+		expect(pure.importChain.at(-1).originModule).toBe(
+			"./fixtures/inclusion-reason/index.js"
+		);
+		expect(pure.sideEffects).toMatchObject({
+			type: "none",
+			sideEffects: false
+		});
+	}
+};
@@ -0,0 +1,62 @@
+const fs = require("fs");
+const { rspack } = require("@rspack/core");
+const { createFsFromVolume, Volume } = require("memfs");
+
+let warmStats;
+
+function run(compiler) {
+	return new Promise((resolve, reject) => {
+		compiler.run((err, stats) => {
+			if (err) {
+				return reject(err);
+			}
+			resolve(stats);
+		});
+	});
+}
+
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description:
+		"should reuse code generation results and rendered chunks from persistent cache",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/abc",
+			mode: "production",
+			cache: true,
+			optimization: {
+				minimize: false,
+				concatenateModules: false
+			},
+			experiments: {
+				cache: {
+					type: "persistent",
+					storage: {
+						type: "filesystem",
+						directory: context.getDist("persistent-cache")
+					}
+				}
+			}
+		};
+	},
+	async build(context, compiler) {
+		fs.rmSync(context.getDist("persistent-cache"), {
+			recursive: true,
+			force: true
+		});
+		await run(compiler);
+		// a new compiler starts from the persistent cache only
+		const warmCompiler = rspack(module.exports.options(context));
+		warmCompiler.outputFileSystem = createFsFromVolume(new Volume());
+		warmStats = (await run(warmCompiler)).toString({
+			all: false,
+			logging: "verbose"
+		});
+		await new Promise(resolve => warmCompiler.close(resolve));
+	},
+	async check() {
+		expect(warmStats).toContain("module code generation cache: 100.0%");
+		expect(warmStats).toContain("chunk render cache: 100.0%");
+	}
+};
@@ -0,0 +1,12 @@
+import * as styles from "./style.module.css";
+
+it("should update the values imported through other modules", () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	const color = WATCH_STEP === "0" ? "#f00" : "#00f";
+	expect(styles.primary).toBe(color);
+
+	const css = __STATS__.assets.find(asset => asset.name.endsWith(".css"));
+	const content = fs.readFileSync(path.resolve(__dirname, css.name), "utf-8");
+	expect(content).toContain(`color: ${color};`);
+});
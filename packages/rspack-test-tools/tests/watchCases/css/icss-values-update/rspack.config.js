@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1 @@
+export const IS_DEV = false;
@@ -0,0 +1,21 @@
+import { IS_DEV } from "./flags";
+
+it("should build the dependencies of a guarded branch once it becomes live", async () => {
+	let devtools;
+	if (IS_DEV) {
+		devtools = require("./devtools");
+	}
+	const lazy = IS_DEV ? await import("./lazy") : undefined;
+	switch (WATCH_STEP) {
+		case "0":
+			expect(devtools).toBe(undefined);
+			expect(lazy).toBe(undefined);
+			expect(__STATS__.chunks.length).toBe(1);
+			break;
+		case "1":
+			expect(devtools).toBe("devtools module");
+			expect(lazy.default).toBe("lazy module");
+			expect(__STATS__.chunks.length).toBe(2);
+			break;
+	}
+});
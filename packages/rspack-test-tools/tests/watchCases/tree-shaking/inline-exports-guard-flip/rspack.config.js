@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		inlineExports: true,
+		concatenateModules: false,
+		mangleExports: false,
+		minimize: false
+	}
+};
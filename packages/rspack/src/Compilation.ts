@@ -168,6 +168,8 @@ export interface KnownNormalizedStatsOptions {
 	usedExports: boolean;
 	providedExports: boolean;
 	optimizationBailout: boolean;
+	inclusionReason: boolean;
+	cacheMisses: boolean;
 	depth: boolean;
 	assets: boolean;
 	chunks: boolean;
@@ -4,6 +4,7 @@ export type { PluginImportOptions } from "./pluginImport";
 export type {
 	SwcLoaderEnvConfig,
 	SwcLoaderEsParserConfig,
+	SwcLoaderIsolatedDeclarationsOptions,
 	SwcLoaderJscConfig,
 	SwcLoaderModuleConfig,
 	SwcLoaderOptions,
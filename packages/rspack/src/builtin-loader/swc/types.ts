@@ -25,5 +25,26 @@ export type SwcLoaderOptions = Config & {
 	 */
 	rspackExperiments?: {
 		import?: PluginImportOptions;
+		/**
+		 * Emit a declaration file for each TypeScript module, the code must be compatible with `isolatedDeclarations`.
+		 */
+		isolatedDeclarations?: boolean | SwcLoaderIsolatedDeclarationsOptions;
+		/**
+		 * Only strip the types of TypeScript modules when no other transform is needed,
+		 * falls back to the full transform for syntax that can't be erased, e.g. enums.
+		 */
+		stripTypes?: boolean;
 	};
 };
+export type SwcLoaderIsolatedDeclarationsOptions = {
+	/**
+	 * The directory the paths of declaration files are relative to.
+	 * @default context
+	 */
+	rootDir?: string;
+	/**
+	 * The directory to emit declaration files to, relative to `output.path`.
+	 * @default ""
+	 */
+	outDir?: string;
+};
@@ -33,6 +33,7 @@ export * from "./HtmlRspackPlugin";
 export * from "./HttpExternalsRspackPlugin";
 export * from "./IgnorePlugin";
 export * from "./InferAsyncModulesPlugin";
+export * from "./InferPureFunctionsPlugin";
 export * from "./JavascriptModulesPlugin";
 export * from "./JsLoaderRspackPlugin";
 export * from "./JsonModulesPlugin";
@@ -301,6 +301,11 @@ const getSwcLoaderOptions: GetLoaderOptions = (options, _) => {
 					rspackExperiments.import || rspackExperiments.pluginImport
 				);
 			}
+			if (rspackExperiments.isolatedDeclarations === true) {
+				rspackExperiments.isolatedDeclarations = {};
+			} else if (rspackExperiments.isolatedDeclarations === false) {
+				delete rspackExperiments.isolatedDeclarations;
+			}
 		}
 	}
 	return options;
@@ -331,14 +331,23 @@ export const getNormalizedRspackOptions = (
 						unmanagedPaths: nestedArray(snapshot.unmanagedPaths, p => [...p]),
 						managedPaths: optionalNestedArray(snapshot.managedPaths, p => [
 							...p
-						]) || [/\/node_modules\//]
+						]) || [/\/node_modules\//],
+						hashPaths: nestedArray(snapshot.hashPaths, p => [...p])
 					},
 					storage: {
 						type: "filesystem",
 						directory: path.resolve(
 							config.context || process.cwd(),
 							cache.storage?.directory || "node_modules/.cache/rspack"
-						)
+						),
+						remote: optionalNestedConfig(cache.storage?.remote, remote => ({
+							url: remote.url,
+							push: remote.push ?? false,
+							connectTimeout: remote.connectTimeout ?? 5000,
+							readTimeout: remote.readTimeout ?? 30000
+						})),
+						compression: cache.storage?.compression || undefined,
+						maxSize: cache.storage?.maxSize
 					}
 				};
 			}),
@@ -587,10 +596,19 @@ export type ExperimentCacheNormalized =
 				immutablePaths: Array<string | RegExp>;
 				unmanagedPaths: Array<string | RegExp>;
 				managedPaths: Array<string | RegExp>;
+				hashPaths: Array<string | RegExp>;
 			};
 			storage: {
 				type: "filesystem";
 				directory: string;
+				remote?: {
+					url: string;
+					push: boolean;
+					connectTimeout: number;
+					readTimeout: number;
+				};
+				compression?: "deflate";
+				maxSize?: number;
 			};
 	  };
 
@@ -1832,6 +1832,17 @@ export type StatsOptions = {
 	 * @default false
 	 */
 	optimizationBailout?: boolean;
+	/**
+	 * Enables or disables the display of why modules are included: the chain of imports from an entry,
+	 * and what makes the module have side effects.
+	 * @default false
+	 */
+	inclusionReason?: boolean;
+	/**
+	 * Enables or disables the display of modules which missed the persistent cache and the reasons.
+	 * @default false
+	 */
+	cacheMisses?: boolean;
 	/**
 	 * Enables or disables grouping of modules by type.
 	 */
@@ -2317,6 +2328,25 @@ export type Optimization = {
 	 */
 	innerGraph?: boolean;
 
+	/**
+	 * Tells Rspack to infer which top-level functions and classes are free of side effects across modules,
+	 * so that unused calls to them can be removed together with the code they reference.
+	 *
+	 * Requires `optimization.innerGraph` to remove unused calls and `optimization.sideEffects` to skip modules.
+	 * @default false
+	 */
+	inferPureFunctions?: boolean;
+
+	/**
+	 * Tells Rspack to inline exports that are never reassigned and whose value is a primitive literal,
+	 * or a member of a TypeScript enum, into the modules that import them.
+	 *
+	 * The inlined exports are no longer referenced and can be removed when `optimization.usedExports` is enabled.
+	 * Dependencies in branches ruled out by an inlined export, e.g. `if (DEBUG) require("./debug")`, are not built.
+	 * @default false
+	 */
+	inlineExports?: boolean;
+
 	/**
 	 * Tells Rspack to determine used exports for each module.
 	 *
@@ -2379,10 +2409,32 @@ export type ExperimentCacheOptions =
 				immutablePaths?: Array<string | RegExp>;
 				unmanagedPaths?: Array<string | RegExp>;
 				managedPaths?: Array<string | RegExp>;
+				hashPaths?: Array<string | RegExp>;
 			};
 			storage?: {
 				type: "filesystem";
 				directory?: string;
+				/**
+				 * Share the cache with other machines through a http store which supports `GET`, `PUT` and `HEAD`.
+				 * The missing cache is pulled from the store, and the saved cache is uploaded when `push` is enabled.
+				 */
+				remote?: {
+					url: string;
+					push?: boolean;
+					/** @default 5000 */
+					connectTimeout?: number;
+					/** @default 30000 */
+					readTimeout?: number;
+				};
+				/**
+				 * Compress the cache files with deflate.
+				 * @default false
+				 */
+				compression?: false | "deflate";
+				/**
+				 * The max size of the cache directory in bytes, the least recently used cache versions are removed first when exceeded.
+				 */
+				maxSize?: number;
 			};
 	  };
 
@@ -1073,6 +1073,8 @@ const statsOptions = z.strictObject({
 	usedExports: z.boolean().optional(),
 	providedExports: z.boolean().optional(),
 	optimizationBailout: z.boolean().optional(),
+	inclusionReason: z.boolean().optional(),
+	cacheMisses: z.boolean().optional(),
 	groupModulesByType: z.boolean().optional(),
 	groupModulesByCacheStatus: z.boolean().optional(),
 	groupModulesByLayer: z.boolean().optional(),
@@ -1266,6 +1268,8 @@ const optimization = z.strictObject({
 	providedExports: z.boolean().optional(),
 	concatenateModules: z.boolean().optional(),
 	innerGraph: z.boolean().optional(),
+	inferPureFunctions: z.boolean().optional(),
+	inlineExports: z.boolean().optional(),
 	usedExports: z.enum(["global"]).or(z.boolean()).optional(),
 	mangleExports: z.enum(["size", "deterministic"]).or(z.boolean()).optional(),
 	nodeEnv: z.union([z.string(), z.literal(false)]).optional(),
@@ -1320,13 +1324,24 @@ const experimentCacheOptions = z
 						.or(z.instanceof(RegExp))
 						.array()
 						.optional(),
-					managedPaths: z.string().or(z.instanceof(RegExp)).array().optional()
+					managedPaths: z.string().or(z.instanceof(RegExp)).array().optional(),
+					hashPaths: z.string().or(z.instanceof(RegExp)).array().optional()
 				})
 				.optional(),
 			storage: z
 				.object({
 					type: z.enum(["filesystem"]),
-					directory: z.string().optional()
+					directory: z.string().optional(),
+					remote: z
+						.object({
+							url: z.string().regex(/^https?:\/\//),
+							push: z.boolean().optional(),
+							connectTimeout: z.number().optional(),
+							readTimeout: z.number().optional()
+						})
+						.optional(),
+					compression: z.literal(false).or(z.literal("deflate")).optional(),
+					maxSize: z.number().positive().optional()
 				})
 				.optional()
 		})
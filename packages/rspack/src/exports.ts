@@ -276,6 +276,7 @@ export { ContextReplacementPlugin } from "./builtin-plugin";
 export type {
 	SwcLoaderEnvConfig,
 	SwcLoaderEsParserConfig,
+	SwcLoaderIsolatedDeclarationsOptions,
 	SwcLoaderJscConfig,
 	SwcLoaderModuleConfig,
 	SwcLoaderOptions,
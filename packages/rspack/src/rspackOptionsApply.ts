@@ -43,6 +43,7 @@ import {
 	FlagDependencyUsagePlugin,
 	HttpExternalsRspackPlugin,
 	InferAsyncModulesPlugin,
+	InferPureFunctionsPlugin,
 	JavascriptModulesPlugin,
 	JsonModulesPlugin,
 	LazyCompilationPlugin,
@@ -243,6 +244,9 @@ export class RspackOptionsApply {
 			new MergeDuplicateChunksPlugin().apply(compiler);
 		}
 
+		if (options.optimization.inferPureFunctions) {
+			new InferPureFunctionsPlugin().apply(compiler);
+		}
 		if (options.optimization.sideEffects) {
 			new SideEffectsFlagPlugin(/* options.optimization.sideEffects === true */).apply(
 				compiler
@@ -828,6 +828,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				}
 			}
 		},
+		cacheMisses: (object, compilation, context: KnownStatsFactoryContext) => {
+			object.cacheMisses = context.getInner(compilation).getCacheMisses();
+		},
 		hash: (object, compilation, context: KnownStatsFactoryContext) => {
 			const statsCompilation = context.getStatsCompilation(compilation);
 			object.hash = statsCompilation.hash;
@@ -1307,6 +1310,33 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object.optimizationBailout =
 				module.commonAttributes.optimizationBailout || null;
 		},
+		inclusionReason: (object, module) => {
+			const { inclusionReason } = module.commonAttributes;
+			if (!inclusionReason) return;
+			object.inclusionReason = {
+				importChain: inclusionReason.importChain.map(item => ({
+					originModuleIdentifier: item.originModuleDescriptor?.identifier,
+					originModule: item.originModuleDescriptor?.name,
+					originModuleId: item.originModuleDescriptor?.id,
+					moduleIdentifier: item.moduleDescriptor.identifier,
+					module: item.moduleDescriptor.name,
+					moduleId: item.moduleDescriptor.id,
+					userRequest: item.userRequest,
+					loc: item.loc
+				})),
+				sideEffects: {
+					type: inclusionReason.sideEffects.type,
+					sideEffects: inclusionReason.sideEffects.sideEffects,
+					descriptionFile: inclusionReason.sideEffects.descriptionFile,
+					statementType: inclusionReason.sideEffects.statementType,
+					loc: inclusionReason.sideEffects.loc,
+					moduleIdentifier:
+						inclusionReason.sideEffects.moduleDescriptor?.identifier,
+					module: inclusionReason.sideEffects.moduleDescriptor?.name,
+					moduleId: inclusionReason.sideEffects.moduleDescriptor?.id
+				}
+			};
+		},
 		depth: (object, module) => {
 			object.depth = module.commonAttributes.depth;
 		},
@@ -134,6 +134,7 @@ export type KnownStatsModule = {
 	usedExports?: boolean | string[] | null;
 	providedExports?: string[] | null;
 	optimizationBailout?: string[] | null;
+	inclusionReason?: StatsModuleInclusionReason;
 	depth?: number;
 	modules?: StatsModule[];
 	filteredModules?: number;
@@ -203,6 +204,47 @@ export type KnownStatsModuleReason = {
 
 export type StatsModuleReason = KnownStatsModuleReason & Record<string, any>;
 
+export type KnownStatsModuleInclusionReason = {
+	importChain: StatsModuleImport[];
+	sideEffects: StatsModuleSideEffects;
+};
+
+export type StatsModuleInclusionReason = KnownStatsModuleInclusionReason &
+	Record<string, any>;
+
+export type KnownStatsModuleImport = {
+	originModuleIdentifier?: string;
+	originModule?: string;
+	originModuleId?: string;
+	moduleIdentifier: string;
+	module: string;
+	moduleId?: string;
+	userRequest?: string;
+	loc?: string;
+};
+
+export type StatsModuleImport = KnownStatsModuleImport & Record<string, any>;
+
+export type KnownStatsModuleSideEffects = {
+	type:
+		| "moduleRule"
+		| "packageJson"
+		| "notAnalyzed"
+		| "statement"
+		| "dependency"
+		| "none";
+	sideEffects: boolean;
+	descriptionFile?: string;
+	statementType?: string;
+	loc?: string;
+	moduleIdentifier?: string;
+	module?: string;
+	moduleId?: string;
+};
+
+export type StatsModuleSideEffects = KnownStatsModuleSideEffects &
+	Record<string, any>;
+
 export type KnownStatsChunkOrigin = {
 	module: string;
 	moduleIdentifier: string;
@@ -241,6 +283,7 @@ export type KnownStatsCompilation = {
 	filteredModules?: number;
 	children?: StatsCompilation[];
 	logging?: Record<string, StatsLogging>;
+	cacheMisses?: StatsCacheMiss[];
 
 	// TODO: not aligned with webpack
 	// env?: any;
@@ -258,6 +301,8 @@ export type KnownStatsLogging = {
 	debug: boolean;
 };
 
+export type StatsCacheMiss = binding.JsStatsCacheMiss;
+
 export type StatsLoggingEntry = KnownStatsLoggingEntry & Record<string, any>;
 
 export type KnownStatsLoggingEntry = {
@@ -0,0 +1,195 @@
+//! Inspect and compact the persistent cache directory of rspack.
+//!
+//! ```bash
+//! # list versions in cache directory
+//! cargo run -p storage-inspect -- node_modules/.cache/rspack
+//! # show scopes, packs and unused files of a version
+//! cargo run -p storage-inspect -- node_modules/.cache/rspack <version>
+//! # also check pack files with the hashes in scope meta
+//! cargo run -p storage-inspect -- node_modules/.cache/rspack <version> verify
+//! # repack scopes and remove unused files and expired versions
+//! cargo run -p storage-inspect -- node_modules/.cache/rspack <version> compact
+//! ```
+use std::{path::PathBuf, process::ExitCode, sync::Arc};
+
+use rspack_fs::NativeFileSystem;
+use rspack_paths::{AssertUtf8, Utf8PathBuf};
+use rspack_storage::{
+  BridgeFileSystem, PackCompression, PackStorage, PackStorageOptions, StorageInfo,
+};
+
+const DEFAULT_BUCKET_SIZE: usize = 20;
+const DEFAULT_PACK_SIZE: usize = 500 * 1024;
+const DEFAULT_EXPIRE: u64 = 7 * 24 * 60 * 60 * 1000;
+const STALE_GENERATIONS: usize = 10;
+const DEFAULT_LOCK_TIMEOUT: u64 = 30 * 1000;
+
+enum Command {
+  Info,
+  Verify,
+  Compact,
+}
+
+fn create_storage(directory: &Utf8PathBuf, version: &str) -> PackStorage {
+  PackStorage::new(PackStorageOptions {
+    root: directory.clone().into(),
+    temp_root: directory.join(".temp").into(),
+    fs: Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem {}))),
+    bucket_size: DEFAULT_BUCKET_SIZE,
+    pack_size: DEFAULT_PACK_SIZE,
+    compression: PackCompression::None,
+    expire: DEFAULT_EXPIRE,
+    max_size: None,
+    version: version.to_string(),
+    clean: false,
+    fresh_generation: Some(1),
+    release_generation: Some(2),
+    lock_timeout: DEFAULT_LOCK_TIMEOUT,
+  })
+}
+
+fn format_size(size: u64) -> String {
+  const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+  let mut value = size as f64;
+  let mut unit = 0;
+  while value >= 1024.0 && unit < UNITS.len() - 1 {
+    value /= 1024.0;
+    unit += 1;
+  }
+  if unit == 0 {
+    format!("{size} B")
+  } else {
+    format!("{value:.1} {}", UNITS[unit])
+  }
+}
+
+fn print_info(info: &StorageInfo, verified: bool) {
+  println!("{}", info.path);
+  println!(
+    "  expire time: {}{}",
+    info.expire_time,
+    if info.is_expired() { " (expired)" } else { "" }
+  );
+  println!("  total size: {}", format_size(info.size() as u64));
+  for scope in &info.scopes {
+    println!(
+      "  scope `{}`: {} packs, {}, generation {}, bucket size {}, pack size {}",
+      scope.name,
+      scope.packs.len(),
+      format_size(scope.size() as u64),
+      scope.generation,
+      scope.bucket_size,
+      format_size(scope.pack_size as u64),
+    );
+    let stale = scope.stale_packs(STALE_GENERATIONS).count();
+    if stale > 0 {
+      println!("    {stale} packs are not rewritten in last {STALE_GENERATIONS} generations");
+    }
+    if !scope.unused_files.is_empty() {
+      println!(
+        "    {} unused files, {}",
+        scope.unused_files.len(),
+        format_size(scope.unused_size)
+      );
+    }
+    if verified {
+      for pack in scope.invalid_packs() {
+        println!("    invalid pack: {}", pack.path);
+      }
+    }
+  }
+  for path in &info.unused_scopes {
+    println!("  unused scope: {path}");
+  }
+  for version in &info.versions {
+    println!(
+      "  other version `{}`: {}{}",
+      version.name,
+      format_size(version.size),
+      if version.is_expired() {
+        " (expired)"
+      } else {
+        ""
+      }
+    );
+  }
+}
+
+fn list_versions(directory: &Utf8PathBuf) -> std::io::Result<()> {
+  let mut names = std::fs::read_dir(directory)?
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.path().join("storage_meta").exists())
+    .map(|entry| entry.file_name().to_string_lossy().to_string())
+    .collect::<Vec<_>>();
+  names.sort();
+  for name in names {
+    println!("{name}");
+  }
+  Ok(())
+}
+
+async fn run(
+  directory: Utf8PathBuf,
+  version: String,
+  command: Command,
+) -> rspack_storage::Result<bool> {
+  let storage = create_storage(&directory, &version);
+  let verify = matches!(command, Command::Verify);
+  let Some(info) = storage.inspect(verify).await? else {
+    eprintln!("no cache of version `{version}` in {directory}");
+    return Ok(false);
+  };
+  print_info(&info, verify);
+
+  match command {
+    Command::Info => Ok(true),
+    Command::Verify => Ok(info.scopes.iter().all(|s| s.invalid_packs().count() == 0)),
+    Command::Compact => {
+      // scopes are rewritten with the options in their meta
+      storage.compact().await?;
+      if let Some(info) = storage.inspect(false).await? {
+        println!("compacted:");
+        print_info(&info, false);
+      }
+      Ok(true)
+    }
+  }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let args = std::env::args().skip(1).collect::<Vec<_>>();
+  let Some(directory) = args.first() else {
+    eprintln!("usage: storage-inspect <cache directory> [version] [info|verify|compact]");
+    return ExitCode::FAILURE;
+  };
+  let directory = PathBuf::from(directory).assert_utf8();
+
+  let Some(version) = args.get(1) else {
+    return match list_versions(&directory) {
+      Ok(()) => ExitCode::SUCCESS,
+      Err(e) => {
+        eprintln!("read {directory} failed: {e}");
+        ExitCode::FAILURE
+      }
+    };
+  };
+  let command = match args.get(2).map(String::as_str) {
+    None | Some("info") => Command::Info,
+    Some("verify") => Command::Verify,
+    Some("compact") => Command::Compact,
+    Some(command) => {
+      eprintln!("unknown command `{command}`");
+      return ExitCode::FAILURE;
+    }
+  };
+
+  match run(directory, version.to_string(), command).await {
+    Ok(true) => ExitCode::SUCCESS,
+    Ok(false) => ExitCode::FAILURE,
+    Err(e) => {
+      eprintln!("{e}");
+      ExitCode::FAILURE
+    }
+  }
+}